@@ -194,8 +194,8 @@ async fn play(msg: Message, state: State) -> anyhow::Result<()> {
 
     let loaded = serde_json::from_slice::<LoadedTracks>(&response_bytes)?;
 
-    if let Some(track) = loaded.tracks.first() {
-        player.send(Play::from((guild_id, &track.track)))?;
+    if let Some(track) = loaded.tracks().first() {
+        player.send(Play::from((guild_id, &track.encoded)))?;
 
         let content = format!(
             "Playing **{:?}** by **{:?}**",
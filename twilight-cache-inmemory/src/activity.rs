@@ -0,0 +1,147 @@
+//! Last activity of members, used to prune inactive members.
+
+use dashmap::DashMap;
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Instant,
+};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+/// When each guild's cached members were last active.
+///
+/// Activity is measured in milliseconds since the tracker was created.
+#[derive(Debug)]
+pub(crate) struct MemberActivity {
+    /// Last activity of each guild's members.
+    last_active: DashMap<Id<GuildMarker>, HashMap<Id<UserMarker>, AtomicU64>>,
+    /// When the tracker was created.
+    start: Instant,
+}
+
+impl MemberActivity {
+    /// Create a tracker without any members.
+    pub fn new() -> Self {
+        Self {
+            last_active: DashMap::new(),
+            start: Instant::now(),
+        }
+    }
+
+    /// Milliseconds since the tracker was created.
+    pub fn now(&self) -> u64 {
+        u64::try_from(self.start.elapsed().as_millis()).unwrap_or(u64::MAX)
+    }
+
+    /// Stop tracking all members.
+    pub fn clear(&self) {
+        self.last_active.clear();
+    }
+
+    /// Start tracking a newly cached member, who is active now.
+    pub fn insert(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        self.insert_at(guild_id, user_id, self.now());
+    }
+
+    /// Start tracking a member active at a point in time.
+    pub fn insert_at(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>, now: u64) {
+        self.last_active
+            .entry(guild_id)
+            .or_default()
+            .insert(user_id, AtomicU64::new(now));
+    }
+
+    /// Guild members inactive since before a point in time, ordered from least
+    /// to most recently active.
+    pub fn inactive(&self, guild_id: Id<GuildMarker>, before: u64) -> Vec<Id<UserMarker>> {
+        let Some(members) = self.last_active.get(&guild_id) else {
+            return Vec::new();
+        };
+
+        let mut inactive = members
+            .iter()
+            .map(|(user_id, last_active)| (last_active.load(Ordering::Relaxed), *user_id))
+            .filter(|(last_active, _)| *last_active < before)
+            .collect::<Vec<_>>();
+        inactive.sort_unstable();
+
+        inactive.into_iter().map(|(_, user_id)| user_id).collect()
+    }
+
+    /// IDs of guilds with tracked members.
+    pub fn guilds(&self) -> Vec<Id<GuildMarker>> {
+        self.last_active.iter().map(|entry| *entry.key()).collect()
+    }
+
+    /// Stop tracking a removed member.
+    pub fn remove(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        if let Some(mut members) = self.last_active.get_mut(&guild_id) {
+            members.remove(&user_id);
+        }
+    }
+
+    /// Stop tracking all of a guild's members.
+    pub fn remove_guild(&self, guild_id: Id<GuildMarker>) {
+        self.last_active.remove(&guild_id);
+    }
+
+    /// Mark a tracked member as active now.
+    ///
+    /// Members that aren't tracked are ignored.
+    pub fn touch(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        if let Some(last_active) = self
+            .last_active
+            .get(&guild_id)
+            .as_ref()
+            .and_then(|members| members.get(&user_id))
+        {
+            last_active.store(self.now(), Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemberActivity;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn inactive_order() {
+        let activity = MemberActivity::new();
+        let guild_id = Id::new(1);
+
+        activity.insert_at(guild_id, Id::new(1), 3000);
+        activity.insert_at(guild_id, Id::new(2), 1000);
+        activity.insert_at(guild_id, Id::new(3), 2000);
+        activity.insert_at(guild_id, Id::new(4), 4000);
+        activity.insert_at(Id::new(2), Id::new(5), 0);
+
+        assert_eq!(
+            [Id::new(2), Id::new(3), Id::new(1)].as_slice(),
+            activity.inactive(guild_id, 4000)
+        );
+
+        // The tracker was just created, so members touched now are the least
+        // recently active.
+        activity.touch(guild_id, Id::new(4));
+        assert_eq!(
+            [Id::new(4), Id::new(2), Id::new(3), Id::new(1)].as_slice(),
+            activity.inactive(guild_id, 4000)
+        );
+
+        // Untracked members aren't inserted.
+        activity.touch(guild_id, Id::new(6));
+        activity.remove(guild_id, Id::new(3));
+        assert_eq!(
+            [Id::new(4), Id::new(2), Id::new(1)].as_slice(),
+            activity.inactive(guild_id, u64::MAX)
+        );
+
+        activity.remove_guild(guild_id);
+        assert!(activity.inactive(guild_id, u64::MAX).is_empty());
+        assert_eq!(vec![Id::new(2)], activity.guilds());
+    }
+}
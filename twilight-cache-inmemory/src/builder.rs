@@ -46,6 +46,59 @@ impl<CacheModels: CacheableModels> InMemoryCacheBuilder<CacheModels> {
 
         self
     }
+
+    /// Sets the maximum number of members to cache per guild.
+    ///
+    /// When the limit is exceeded the guild's member that was cached first is
+    /// evicted. Evictions are by insertion order, so updating a member doesn't
+    /// delay its eviction.
+    ///
+    /// Defaults to an unlimited number of members.
+    pub const fn member_cache_size(mut self, member_cache_size: usize) -> Self {
+        self.0.member_cache_size = Some(member_cache_size);
+
+        self
+    }
+
+    /// Sets the maximum number of presences to cache per guild.
+    ///
+    /// When the limit is exceeded the guild's presence that was cached first
+    /// is evicted. Evictions are by insertion order, so updating a presence
+    /// doesn't delay its eviction.
+    ///
+    /// Defaults to an unlimited number of presences.
+    pub const fn presence_cache_size(mut self, presence_cache_size: usize) -> Self {
+        self.0.presence_cache_size = Some(presence_cache_size);
+
+        self
+    }
+
+    /// Sets whether to track the last activity of members, allowing inactive
+    /// members to be [pruned].
+    ///
+    /// Members are active when they're cached, send a message, or their voice
+    /// state, presence, or member is updated.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [pruned]: InMemoryCache::prune_members
+    pub const fn track_member_activity(mut self, track_member_activity: bool) -> Self {
+        self.0.track_member_activity = track_member_activity;
+
+        self
+    }
+
+    /// Sets the maximum number of voice states to cache per guild.
+    ///
+    /// When the limit is exceeded the guild's least recently added voice
+    /// state is evicted.
+    ///
+    /// Defaults to an unlimited number of voice states.
+    pub const fn voice_state_cache_size(mut self, voice_state_cache_size: usize) -> Self {
+        self.0.voice_state_cache_size = Some(voice_state_cache_size);
+
+        self
+    }
 }
 
 impl<CacheModels: CacheableModels> Default for InMemoryCacheBuilder<CacheModels> {
@@ -49,6 +49,10 @@ bitflags! {
 pub struct Config {
     pub(super) resource_types: ResourceType,
     pub(super) message_cache_size: usize,
+    pub(super) member_cache_size: Option<usize>,
+    pub(super) presence_cache_size: Option<usize>,
+    pub(super) track_member_activity: bool,
+    pub(super) voice_state_cache_size: Option<usize>,
 }
 
 impl Config {
@@ -59,9 +63,27 @@ impl Config {
         Self {
             resource_types: ResourceType::all(),
             message_cache_size: 100,
+            member_cache_size: None,
+            presence_cache_size: None,
+            track_member_activity: false,
+            voice_state_cache_size: None,
         }
     }
 
+    /// Returns the maximum number of members to cache per guild.
+    ///
+    /// Defaults to [`None`], meaning an unlimited number of members are
+    /// cached.
+    pub const fn member_cache_size(&self) -> Option<usize> {
+        self.member_cache_size
+    }
+
+    /// Returns a mutable reference to the maximum number of members to cache
+    /// per guild.
+    pub fn member_cache_size_mut(&mut self) -> &mut Option<usize> {
+        &mut self.member_cache_size
+    }
+
     /// Returns an immutable reference to the message cache size.
     ///
     /// Defaults to 100.
@@ -73,6 +95,21 @@ impl Config {
     pub fn message_cache_size_mut(&mut self) -> &mut usize {
         &mut self.message_cache_size
     }
+
+    /// Returns the maximum number of presences to cache per guild.
+    ///
+    /// Defaults to [`None`], meaning an unlimited number of presences are
+    /// cached.
+    pub const fn presence_cache_size(&self) -> Option<usize> {
+        self.presence_cache_size
+    }
+
+    /// Returns a mutable reference to the maximum number of presences to cache
+    /// per guild.
+    pub fn presence_cache_size_mut(&mut self) -> &mut Option<usize> {
+        &mut self.presence_cache_size
+    }
+
     /// Returns an immutable reference to the resource types enabled.
     ///
     /// Defaults to all resource types.
@@ -84,6 +121,36 @@ impl Config {
     pub fn resource_types_mut(&mut self) -> &mut ResourceType {
         &mut self.resource_types
     }
+
+    /// Returns whether the last activity of members is tracked, allowing
+    /// inactive members to be [pruned].
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [pruned]: crate::InMemoryCache::prune_members
+    pub const fn track_member_activity(&self) -> bool {
+        self.track_member_activity
+    }
+
+    /// Returns a mutable reference to whether the last activity of members is
+    /// tracked.
+    pub fn track_member_activity_mut(&mut self) -> &mut bool {
+        &mut self.track_member_activity
+    }
+
+    /// Returns the maximum number of voice states to cache per guild.
+    ///
+    /// Defaults to [`None`], meaning an unlimited number of voice states are
+    /// cached.
+    pub const fn voice_state_cache_size(&self) -> Option<usize> {
+        self.voice_state_cache_size
+    }
+
+    /// Returns a mutable reference to the maximum number of voice states to
+    /// cache per guild.
+    pub fn voice_state_cache_size_mut(&mut self) -> &mut Option<usize> {
+        &mut self.voice_state_cache_size
+    }
 }
 
 impl Default for Config {
@@ -97,16 +164,31 @@ mod tests {
     use super::{Config, ResourceType};
     use static_assertions::assert_fields;
 
-    assert_fields!(Config: resource_types, message_cache_size);
+    assert_fields!(
+        Config: resource_types,
+        message_cache_size,
+        member_cache_size,
+        presence_cache_size,
+        track_member_activity,
+        voice_state_cache_size
+    );
 
     #[test]
     fn defaults() {
         let conf = Config {
             resource_types: ResourceType::all(),
             message_cache_size: 100,
+            member_cache_size: None,
+            presence_cache_size: None,
+            track_member_activity: false,
+            voice_state_cache_size: None,
         };
         let default = Config::default();
         assert_eq!(conf.resource_types, default.resource_types);
         assert_eq!(conf.message_cache_size, default.message_cache_size);
+        assert_eq!(conf.member_cache_size, default.member_cache_size);
+        assert_eq!(conf.presence_cache_size, default.presence_cache_size);
+        assert_eq!(conf.track_member_activity, default.track_member_activity);
+        assert_eq!(conf.voice_state_cache_size, default.voice_state_cache_size);
     }
 }
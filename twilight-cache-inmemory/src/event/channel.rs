@@ -53,6 +53,10 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelCreate {
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelDelete {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if cache.wants(ResourceType::VOICE_STATE) {
+            cache.delete_channel_voice_states(self.0.id);
+        }
+
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -86,9 +90,17 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelUpdate {
 #[cfg(test)]
 mod tests {
     use crate::{test, DefaultInMemoryCache};
-    use twilight_model::gateway::{
-        event::Event,
-        payload::incoming::{ChannelDelete, ChannelUpdate},
+    use twilight_model::{
+        channel::{
+            forum::{DefaultReaction, ForumLayout, ForumSortOrder, ForumTag},
+            ChannelType,
+        },
+        gateway::{
+            event::Event,
+            payload::incoming::{ChannelDelete, ChannelPinsUpdate, ChannelUpdate},
+        },
+        id::Id,
+        util::Timestamp,
     };
 
     #[test]
@@ -122,4 +134,57 @@ mod tests {
             .unwrap()
             .contains(&channel_id));
     }
+
+    #[test]
+    fn channel_update_forum() {
+        let cache = DefaultInMemoryCache::new();
+        let (_, channel_id, mut channel) = test::guild_channel_text();
+        channel.kind = ChannelType::GuildForum;
+        channel.available_tags = Some(Vec::from([ForumTag {
+            emoji_id: None,
+            emoji_name: Some("🦀".to_owned()),
+            id: Id::new(10),
+            moderated: false,
+            name: "rust".to_owned(),
+        }]));
+        channel.default_forum_layout = Some(ForumLayout::ListView);
+        channel.default_reaction_emoji = Some(DefaultReaction {
+            emoji_id: Some(Id::new(11)),
+            emoji_name: None,
+        });
+        channel.default_sort_order = Some(ForumSortOrder::LatestActivity);
+        channel.default_thread_rate_limit_per_user = Some(30);
+
+        cache.update(&ChannelUpdate(channel.clone()));
+        assert_eq!(channel, *cache.channel(channel_id).unwrap());
+    }
+
+    #[test]
+    fn channel_pins_update() {
+        let cache = DefaultInMemoryCache::new();
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        cache.cache_channel(channel);
+
+        let last_pin_timestamp = Some(Timestamp::from_secs(1_632_072_645).unwrap());
+        cache.update(&ChannelPinsUpdate {
+            channel_id,
+            guild_id: Some(guild_id),
+            last_pin_timestamp,
+        });
+        assert_eq!(
+            last_pin_timestamp,
+            cache.channel(channel_id).unwrap().last_pin_timestamp
+        );
+
+        cache.update(&ChannelPinsUpdate {
+            channel_id,
+            guild_id: Some(guild_id),
+            last_pin_timestamp: None,
+        });
+        assert!(cache
+            .channel(channel_id)
+            .unwrap()
+            .last_pin_timestamp
+            .is_none());
+    }
 }
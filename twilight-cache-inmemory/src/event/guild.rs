@@ -120,11 +120,13 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         }
 
         if self.wants(ResourceType::VOICE_STATE) {
-            // Clear out a guilds voice states when a guild leaves
-            self.voice_state_guilds.remove(&id);
+            self.delete_guild_voice_states(id);
         }
 
         if self.wants(ResourceType::MEMBER) {
+            self.member_activity.remove_guild(id);
+            self.member_queue.remove_guild(id);
+
             if let Some((_, ids)) = self.guild_members.remove(&id) {
                 for user_id in ids {
                     self.members.remove(&(id, user_id));
@@ -133,6 +135,8 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         }
 
         if self.wants(ResourceType::PRESENCE) {
+            self.presence_queue.remove_guild(id);
+
             if let Some((_, ids)) = self.guild_presences.remove(&id) {
                 for user_id in ids {
                     self.presences.remove(&(id, user_id));
@@ -233,6 +237,7 @@ mod tests {
             rtc_region: None,
             thread_metadata: None,
             topic: None,
+            total_message_sent: None,
             user_limit: None,
             video_quality_mode: None,
         }]);
@@ -285,6 +290,7 @@ mod tests {
                 locked: false,
             }),
             topic: None,
+            total_message_sent: None,
             user_limit: None,
             video_quality_mode: None,
         }]);
@@ -396,6 +402,30 @@ mod tests {
         assert!(cache.unavailable_guilds.get(&guild.id).is_none());
     }
 
+    /// Test that unavailable guild stubs mark guilds as unavailable rather
+    /// than replacing them.
+    #[test]
+    fn unavailable_guild_stub() {
+        let cache = DefaultInMemoryCache::new();
+        let stub = GuildCreate::Unavailable(twilight_model::guild::UnavailableGuild {
+            id: Id::new(1),
+            unavailable: true,
+        });
+
+        cache.update(&stub);
+        assert!(cache.unavailable_guilds.contains(&Id::new(1)));
+        assert!(cache.guilds.get(&Id::new(1)).is_none());
+
+        let guild = test::guild(Id::new(1), None);
+        cache.update(&GuildCreate::Available(guild.clone()));
+        cache.update(&stub);
+
+        let cached = cache.guilds.get(&guild.id).unwrap();
+        assert_eq!(Some(true), cached.unavailable());
+        assert_eq!(guild.name, cached.name());
+        assert_eq!(guild.owner_id, cached.owner_id());
+    }
+
     #[test]
     fn guild_update() {
         let cache = DefaultInMemoryCache::new();
@@ -119,6 +119,7 @@ mod tests {
                 recipients: None,
                 rtc_region: None,
                 topic: None,
+                total_message_sent: None,
                 user_limit: None,
                 application_id: None,
                 applied_tags: None,
@@ -223,6 +224,7 @@ mod tests {
                             message_snapshots: Vec::new(),
                             pinned: false,
                             poll: None,
+                            position: None,
                             reactions: Vec::new(),
                             reference: None,
                             referenced_message: None,
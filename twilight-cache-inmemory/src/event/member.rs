@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use crate::{
     config::ResourceType,
     model::member::ComputedInteractionMember,
-    traits::{CacheableGuild, CacheableMember},
+    traits::{CacheableCurrentUser, CacheableGuild, CacheableMember},
     CacheableModels, InMemoryCache, UpdateCache,
 };
 use twilight_model::{
@@ -39,11 +39,15 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
 
         self.cache_user(Cow::Borrowed(&member.user), Some(guild_id));
         let cached = CacheModels::Member::from(member);
-        self.members.insert(id, cached);
+        let is_new = self.members.insert(id, cached).is_none();
         self.guild_members
             .entry(guild_id)
             .or_default()
             .insert(member_id);
+
+        if is_new {
+            self.track_member(guild_id, member_id);
+        }
     }
 
     pub(crate) fn cache_borrowed_partial_member(
@@ -66,7 +70,10 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
             .insert(user_id);
 
         let cached = CacheModels::Member::from((user_id, member.clone()));
-        self.members.insert(id, cached);
+
+        if self.members.insert(id, cached).is_none() {
+            self.track_member(guild_id, user_id);
+        }
     }
 
     pub(crate) fn cache_borrowed_interaction_member(
@@ -96,7 +103,91 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
             user_id,
         });
 
-        self.members.insert(id, cached);
+        if self.members.insert(id, cached).is_none() {
+            self.track_member(guild_id, user_id);
+        }
+    }
+
+    /// Remove a member, and their user if they are in no other cached guilds.
+    fn remove_member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        self.members.remove(&(guild_id, user_id));
+        self.member_activity.remove(guild_id, user_id);
+        self.member_queue.remove(guild_id, user_id);
+
+        if let Some(mut members) = self.guild_members.get_mut(&guild_id) {
+            members.remove(&user_id);
+        }
+
+        // Avoid a deadlock by mutating the user, dropping the lock to the map,
+        // and then removing the user later if they are in no guilds.
+        let mut remove_user = false;
+
+        if let Some(mut user_guilds) = self.user_guilds.get_mut(&user_id) {
+            user_guilds.remove(&guild_id);
+
+            remove_user = user_guilds.is_empty();
+        }
+
+        if remove_user {
+            self.users.remove(&user_id);
+        }
+    }
+
+    /// Track a newly cached member, evicting the guild's oldest member if the
+    /// member cache size is exceeded.
+    fn track_member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        if self.config.track_member_activity() {
+            self.member_activity.insert(guild_id, user_id);
+        }
+
+        if let Some(evicted) =
+            self.member_queue
+                .insert(guild_id, user_id, self.config.member_cache_size())
+        {
+            self.remove_member(guild_id, evicted);
+        }
+    }
+
+    /// Mark a cached member as active now, if member activity is tracked.
+    pub(crate) fn touch_member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        if self.config.track_member_activity() {
+            self.member_activity.touch(guild_id, user_id);
+        }
+    }
+
+    /// Remove members inactive since before a point in time, keeping a minimum
+    /// number of members per guild.
+    pub(crate) fn prune_members_before(&self, before: u64, keep_per_guild_min: usize) -> usize {
+        let current_user_id = self
+            .current_user
+            .lock()
+            .expect("current user poisoned")
+            .as_ref()
+            .map(CacheableCurrentUser::id);
+        let mut pruned = 0;
+
+        for guild_id in self.member_activity.guilds() {
+            let owner_id = self.guilds.get(&guild_id).map(|guild| guild.owner_id());
+            let removable = self
+                .guild_members
+                .get(&guild_id)
+                .map_or(0, |members| members.len())
+                .saturating_sub(keep_per_guild_min);
+
+            let inactive = self
+                .member_activity
+                .inactive(guild_id, before)
+                .into_iter()
+                .filter(|user_id| Some(*user_id) != current_user_id && Some(*user_id) != owner_id)
+                .take(removable);
+
+            for user_id in inactive {
+                self.remove_member(guild_id, user_id);
+                pruned += 1;
+            }
+        }
+
+        pruned
     }
 }
 
@@ -142,25 +233,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberRemove {
             return;
         }
 
-        cache.members.remove(&(self.guild_id, self.user.id));
-
-        if let Some(mut members) = cache.guild_members.get_mut(&self.guild_id) {
-            members.remove(&self.user.id);
-        }
-
-        // Avoid a deadlock by mutating the user, dropping the lock to the map,
-        // and then removing the user later if they are in no guilds.
-        let mut remove_user = false;
-
-        if let Some(mut user_guilds) = cache.user_guilds.get_mut(&self.user.id) {
-            user_guilds.remove(&self.guild_id);
-
-            remove_user = user_guilds.is_empty();
-        }
-
-        if remove_user {
-            cache.users.remove(&self.user.id);
-        }
+        cache.remove_member(self.guild_id, self.user.id);
     }
 }
 
@@ -175,14 +248,19 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberUpdate {
         if let Some(mut member) = cache.members.get_mut(&key) {
             member.update_with_member_update(self);
         }
+
+        cache.touch_member(key.0, key.1);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{test, DefaultInMemoryCache};
-    use std::borrow::Cow;
-    use twilight_model::{gateway::payload::incoming::MemberRemove, id::Id};
+    use std::{borrow::Cow, thread, time::Duration};
+    use twilight_model::{
+        gateway::payload::incoming::{GuildCreate, MemberRemove, VoiceStateUpdate},
+        id::Id,
+    };
 
     #[test]
     fn cache_guild_member() {
@@ -284,4 +362,132 @@ mod tests {
         });
         assert!(!cache.users.contains_key(&user_id));
     }
+
+    /// Test that the oldest member is evicted when the member cache size is
+    /// exceeded, along with their user if they are in no other guild.
+    #[test]
+    fn member_eviction() {
+        let cache = DefaultInMemoryCache::builder().member_cache_size(2).build();
+        let guild_id = Id::new(1);
+
+        for user_id in 1..=3 {
+            cache.cache_member(guild_id, test::member(Id::new(user_id)));
+        }
+
+        assert!(cache.member(guild_id, Id::new(1)).is_none());
+        assert!(cache.user(Id::new(1)).is_none());
+        assert!(cache.member(guild_id, Id::new(2)).is_some());
+        assert!(cache.member(guild_id, Id::new(3)).is_some());
+        assert_eq!(cache.guild_members(guild_id).unwrap().len(), 2);
+        assert_eq!(cache.stats().member_evictions(), 1);
+
+        // Updating a member doesn't change its age.
+        cache.cache_member(guild_id, test::member(Id::new(2)));
+        cache.cache_member(guild_id, test::member(Id::new(4)));
+        assert!(cache.member(guild_id, Id::new(2)).is_none());
+        assert!(cache.member(guild_id, Id::new(3)).is_some());
+
+        // Removed members no longer count towards the limit.
+        cache.update(&MemberRemove {
+            guild_id,
+            user: test::user(Id::new(3)),
+        });
+        cache.cache_member(guild_id, test::member(Id::new(5)));
+        assert!(cache.member(guild_id, Id::new(4)).is_some());
+        assert!(cache.member(guild_id, Id::new(5)).is_some());
+        assert_eq!(cache.stats().member_evictions(), 2);
+    }
+
+    /// Build a cache tracking member activity with a guild owned by user 1,
+    /// the current user 2, and members 1 through 10.
+    fn activity_cache() -> DefaultInMemoryCache {
+        let cache = DefaultInMemoryCache::builder()
+            .track_member_activity(true)
+            .build();
+        cache.update(&GuildCreate::Available(test::guild(Id::new(1), None)));
+        *cache.current_user.lock().unwrap() = Some(test::current_user(2));
+        cache.cache_members(Id::new(1), (1..=10).map(Id::new).map(test::member));
+
+        cache
+    }
+
+    #[test]
+    fn prune_members() {
+        let cache = activity_cache();
+
+        // Members 1 through 8 were last active at 0, 9 and 10 at 10 seconds.
+        for id in 1..=10 {
+            let last_active = if id > 8 { 10_000 } else { 0 };
+            cache
+                .member_activity
+                .insert_at(Id::new(1), Id::new(id), last_active);
+        }
+
+        // The owner and current user are kept.
+        assert_eq!(6, cache.prune_members_before(5000, 0));
+        let members = cache.guild_members(Id::new(1)).unwrap();
+        assert_eq!(4, members.len());
+        assert!([1, 2, 9, 10]
+            .into_iter()
+            .all(|id| members.contains(&Id::new(id))));
+        drop(members);
+
+        assert!(cache.member(Id::new(1), Id::new(3)).is_none());
+        assert!(cache.member(Id::new(1), Id::new(9)).is_some());
+        assert_eq!(0, cache.prune_members_before(5000, 0));
+    }
+
+    #[test]
+    fn prune_members_floor() {
+        let cache = activity_cache();
+
+        // Members are less active the higher their ID.
+        for id in 1..=10 {
+            cache
+                .member_activity
+                .insert_at(Id::new(1), Id::new(id), 100 - id);
+        }
+
+        // Only the least recently active members are removed down to the
+        // floor.
+        assert_eq!(3, cache.prune_members_before(u64::MAX, 7));
+        assert!((8..=10).all(|id| cache.member(Id::new(1), Id::new(id)).is_none()));
+        assert_eq!(7, cache.guild_members(Id::new(1)).unwrap().len());
+
+        // Guilds at the floor aren't pruned.
+        assert_eq!(0, cache.prune_members_before(u64::MAX, 7));
+
+        // The owner and current user are kept, but count towards the floor.
+        assert_eq!(5, cache.prune_members_before(u64::MAX, 2));
+        assert_eq!(2, cache.guild_members(Id::new(1)).unwrap().len());
+    }
+
+    #[test]
+    fn prune_members_activity() {
+        let cache = activity_cache();
+
+        for id in 1..=10 {
+            cache.member_activity.insert_at(Id::new(1), Id::new(id), 0);
+        }
+
+        thread::sleep(Duration::from_millis(10));
+        cache.update(&VoiceStateUpdate(test::voice_state(
+            Id::new(1),
+            Some(Id::new(3)),
+            Id::new(3),
+        )));
+
+        // Member 3 was active after 10 milliseconds.
+        assert_eq!(7, cache.prune_members_before(10, 0));
+        assert!(cache.member(Id::new(1), Id::new(3)).is_some());
+    }
+
+    #[test]
+    fn prune_members_untracked() {
+        let cache = DefaultInMemoryCache::new();
+        cache.cache_members(Id::new(1), (1..=10).map(Id::new).map(test::member));
+
+        assert_eq!(0, cache.prune_members(Duration::ZERO, 0));
+        assert_eq!(10, cache.guild_members(Id::new(1)).unwrap().len());
+    }
 }
@@ -1,11 +1,17 @@
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
-use std::borrow::Cow;
+use crate::{
+    config::ResourceType, traits::CacheableChannel, CacheableModels, InMemoryCache, UpdateCache,
+};
+use std::{borrow::Cow, sync::atomic::Ordering};
 use twilight_model::gateway::payload::incoming::{
     MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
 };
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageCreate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if let Some(guild_id) = self.guild_id {
+            cache.touch_member(guild_id, self.author.id);
+        }
+
         if cache.wants(ResourceType::USER) {
             cache.cache_user(Cow::Borrowed(&self.author), self.guild_id);
         }
@@ -18,6 +24,19 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageCreate {
             cache.cache_borrowed_partial_member(guild_id, member, self.author.id);
         }
 
+        // Message events may arrive out of order, so only move the last
+        // message ID forward.
+        if let Some(mut channel) = cache.channels.get_mut(&self.channel_id) {
+            let message_id = self.id.cast();
+
+            if channel
+                .last_message_id()
+                .map_or(true, |last_message_id| last_message_id < message_id)
+            {
+                channel.set_last_message_id(Some(message_id));
+            }
+        }
+
         if !cache.wants(ResourceType::MESSAGE) {
             return;
         }
@@ -31,6 +50,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageCreate {
         if channel_messages.len() >= cache.config.message_cache_size() {
             if let Some(popped_id) = channel_messages.pop_back() {
                 cache.messages.remove(&popped_id);
+                cache.message_evictions.fetch_add(1, Ordering::Relaxed);
             }
         }
 
@@ -124,7 +144,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageUpdate {
 
 #[cfg(test)]
 mod tests {
-    use crate::{DefaultInMemoryCache, ResourceType};
+    use crate::{test, DefaultInMemoryCache, ResourceType};
     use twilight_model::{
         channel::message::{Message, MessageFlags, MessageType},
         gateway::payload::incoming::MessageCreate,
@@ -134,7 +154,7 @@ mod tests {
         util::{image_hash::ImageHashParseError, ImageHash, Timestamp},
     };
 
-    #[allow(deprecated)]
+    #[allow(clippy::too_many_lines, deprecated)]
     #[test]
     fn message_create() -> Result<(), ImageHashParseError> {
         let joined_at = Some(Timestamp::from_secs(1_632_072_645).expect("non zero"));
@@ -202,6 +222,7 @@ mod tests {
             message_snapshots: Vec::new(),
             pinned: false,
             poll: None,
+            position: None,
             reactions: Vec::new(),
             reference: None,
             referenced_message: None,
@@ -215,7 +236,7 @@ mod tests {
 
         cache.update(&MessageCreate(msg.clone()));
         msg.id = Id::new(5);
-        cache.update(&MessageCreate(msg));
+        cache.update(&MessageCreate(msg.clone()));
 
         {
             let entry = cache.user_guilds(Id::new(3)).unwrap();
@@ -239,7 +260,44 @@ mod tests {
         assert_eq!(Some(&Id::new(5)), iter.next());
         assert_eq!(Some(&Id::new(4)), iter.next());
         assert!(iter.next().is_none());
+        drop(messages);
+        assert_eq!(cache.stats().message_evictions(), 0);
+
+        // The oldest message is evicted once the cache size is exceeded.
+        msg.id = Id::new(6);
+        cache.update(&MessageCreate(msg));
+        assert!(cache.message(Id::new(4)).is_none());
+        assert!(cache.message(Id::new(6)).is_some());
+        assert_eq!(cache.stats().message_evictions(), 1);
 
         Ok(())
     }
+
+    #[test]
+    fn message_create_last_message_id() {
+        let cache = DefaultInMemoryCache::builder()
+            .resource_types(ResourceType::CHANNEL)
+            .build();
+        let (_, channel_id, channel) = test::guild_channel_text();
+        cache.cache_channel(channel);
+
+        let last_message_id = |cache: &DefaultInMemoryCache| {
+            cache
+                .channel(channel_id)
+                .unwrap()
+                .last_message_id
+                .map(Id::get)
+        };
+
+        cache.update(&MessageCreate(test::message(Id::new(5), channel_id)));
+        assert_eq!(Some(5), last_message_id(&cache));
+
+        // An older message arriving late doesn't move the ID back.
+        cache.update(&MessageCreate(test::message(Id::new(3), channel_id)));
+        assert_eq!(Some(5), last_message_id(&cache));
+
+        cache.update(&MessageCreate(test::message(Id::new(7), channel_id)));
+        assert_eq!(Some(7), last_message_id(&cache));
+        assert!(cache.messages.is_empty());
+    }
 }
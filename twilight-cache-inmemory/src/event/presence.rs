@@ -1,7 +1,10 @@
 use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
 use twilight_model::{
     gateway::{payload::incoming::PresenceUpdate, presence::Presence},
-    id::{marker::GuildMarker, Id},
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
 };
 
 impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
@@ -16,20 +19,43 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     }
 
     fn cache_presence(&self, guild_id: Id<GuildMarker>, presence: Presence) {
+        let user_id = presence.user.id();
+
         self.guild_presences
             .entry(guild_id)
             .or_default()
-            .insert(presence.user.id());
+            .insert(user_id);
+
+        let is_new = self
+            .presences
+            .insert((guild_id, user_id), CacheModels::Presence::from(presence))
+            .is_none();
+
+        if is_new {
+            self.track_presence(guild_id, user_id);
+        }
+    }
+
+    /// Track a newly cached presence, evicting the guild's oldest presence if
+    /// the presence cache size is exceeded.
+    fn track_presence(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        if let Some(evicted) =
+            self.presence_queue
+                .insert(guild_id, user_id, self.config.presence_cache_size())
+        {
+            self.presences.remove(&(guild_id, evicted));
 
-        self.presences.insert(
-            (guild_id, presence.user.id()),
-            CacheModels::Presence::from(presence),
-        );
+            if let Some(mut presences) = self.guild_presences.get_mut(&guild_id) {
+                presences.remove(&evicted);
+            }
+        }
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for PresenceUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.touch_member(self.guild_id, self.user.id());
+
         if !cache.wants(ResourceType::PRESENCE) {
             return;
         }
@@ -78,4 +104,37 @@ mod tests {
             .unwrap()
             .contains(&user_id));
     }
+
+    /// Test that the oldest presence is evicted when the presence cache size is
+    /// exceeded.
+    #[test]
+    fn presence_eviction() {
+        let cache = DefaultInMemoryCache::builder()
+            .presence_cache_size(2)
+            .build();
+        let guild_id = Id::new(1);
+
+        for user_id in 1..=3 {
+            let payload = PresenceUpdate(Presence {
+                activities: Vec::new(),
+                client_status: ClientStatus {
+                    desktop: Some(Status::Online),
+                    mobile: None,
+                    web: None,
+                },
+                guild_id,
+                status: Status::Online,
+                user: UserOrId::UserId {
+                    id: Id::new(user_id),
+                },
+            });
+            cache.update(&payload);
+        }
+
+        assert!(cache.presence(guild_id, Id::new(1)).is_none());
+        assert!(cache.presence(guild_id, Id::new(2)).is_some());
+        assert!(cache.presence(guild_id, Id::new(3)).is_some());
+        assert_eq!(cache.guild_presences(guild_id).unwrap().len(), 2);
+        assert_eq!(cache.stats().presence_evictions(), 1);
+    }
 }
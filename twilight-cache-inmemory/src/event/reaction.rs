@@ -4,7 +4,7 @@ use crate::{
     CacheableModels, InMemoryCache, UpdateCache,
 };
 use twilight_model::{
-    channel::message::{EmojiReactionType, Reaction, ReactionCountDetails},
+    channel::message::{Reaction, ReactionCountDetails},
     gateway::payload::incoming::{
         ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji,
     },
@@ -16,41 +16,43 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionAdd {
             return;
         }
 
-        let key = self.0.message_id;
-
-        let Some(mut message) = cache.messages.get_mut(&key) else {
+        let Some(mut message) = cache.messages.get_mut(&self.0.message_id) else {
             return;
         };
 
+        let me = cache
+            .current_user()
+            .is_some_and(|user| user.id() == self.0.user_id);
+
         if let Some(reaction) = message
             .reactions_mut()
             .iter_mut()
-            .find(|r| reactions_eq(&r.emoji, &self.0.emoji))
+            .find(|r| r.emoji.same_emoji(&self.0.emoji))
         {
-            if !reaction.me {
-                if let Some(current_user) = cache.current_user() {
-                    if current_user.id() == self.0.user_id {
-                        reaction.me = true;
-                    }
+            if self.0.burst {
+                reaction.count_details.burst += 1;
+                reaction.me_burst |= me;
+
+                if reaction.burst_colors.is_empty() {
+                    reaction.burst_colors.clone_from(&self.0.burst_colors);
                 }
+            } else {
+                reaction.count_details.normal += 1;
+                reaction.me |= me;
             }
 
             reaction.count += 1;
         } else {
-            let me = cache
-                .current_user()
-                .is_some_and(|user| user.id() == self.0.user_id);
-
             message.add_reaction(Reaction {
-                burst_colors: Vec::new(),
+                burst_colors: self.0.burst_colors.clone(),
                 count: 1,
                 count_details: ReactionCountDetails {
-                    burst: 0,
-                    normal: 1,
+                    burst: u64::from(self.0.burst),
+                    normal: u64::from(!self.0.burst),
                 },
                 emoji: self.0.emoji.clone(),
-                me,
-                me_burst: false,
+                me: me && !self.0.burst,
+                me_burst: me && self.0.burst,
             });
         }
     }
@@ -66,23 +68,27 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemove {
             return;
         };
 
+        let me = cache
+            .current_user()
+            .is_some_and(|user| user.id() == self.0.user_id);
+
         if let Some(reaction) = message
             .reactions_mut()
             .iter_mut()
-            .find(|r| reactions_eq(&r.emoji, &self.0.emoji))
+            .find(|r| r.emoji.same_emoji(&self.0.emoji))
         {
-            if reaction.me {
-                if let Some(current_user) = cache.current_user() {
-                    if current_user.id() == self.0.user_id {
-                        reaction.me = false;
-                    }
-                }
+            if self.0.burst {
+                reaction.count_details.burst = reaction.count_details.burst.saturating_sub(1);
+                reaction.me_burst &= !me;
+            } else {
+                reaction.count_details.normal = reaction.count_details.normal.saturating_sub(1);
+                reaction.me &= !me;
             }
 
             if reaction.count > 1 {
                 reaction.count -= 1;
             } else {
-                message.retain_reactions(|e| !(reactions_eq(&e.emoji, &self.0.emoji)));
+                message.retain_reactions(|e| !e.emoji.same_emoji(&self.0.emoji));
             }
         }
     }
@@ -115,7 +121,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemoveEm
         let maybe_index = message
             .reactions()
             .iter()
-            .position(|r| reactions_eq(&r.emoji, &self.emoji));
+            .position(|r| r.emoji.same_emoji(&self.emoji));
 
         if let Some(index) = maybe_index {
             message.remove_reaction(index);
@@ -123,44 +129,26 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemoveEm
     }
 }
 
-fn reactions_eq(a: &EmojiReactionType, b: &EmojiReactionType) -> bool {
-    match (a, b) {
-        (
-            EmojiReactionType::Custom { id: id_a, .. },
-            EmojiReactionType::Custom { id: id_b, .. },
-        ) => id_a == id_b,
-        (
-            EmojiReactionType::Unicode { name: name_a },
-            EmojiReactionType::Unicode { name: name_b },
-        ) => name_a == name_b,
-        _ => false,
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use super::reactions_eq;
     use crate::{model::CachedMessage, test};
+    use std::str::FromStr;
     use twilight_model::{
-        channel::message::{EmojiReactionType, Reaction},
+        channel::message::{EmojiReactionType, Reaction, ReactionCountDetails, ReactionKey},
         gateway::{
-            payload::incoming::{ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji},
+            payload::incoming::{
+                ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji,
+            },
             GatewayReaction,
         },
         id::Id,
+        util::HexColor,
     };
 
     fn find_custom_react(msg: &CachedMessage) -> Option<&Reaction> {
-        msg.reactions.iter().find(|&r| {
-            reactions_eq(
-                &r.emoji,
-                &EmojiReactionType::Custom {
-                    animated: false,
-                    id: Id::new(6),
-                    name: None,
-                },
-            )
-        })
+        msg.reactions
+            .iter()
+            .find(|&r| r.emoji == ReactionKey::Custom(Id::new(6)))
     }
 
     #[test]
@@ -188,6 +176,85 @@ mod tests {
         assert_eq!(custom_react.unwrap().count, 1);
     }
 
+    #[test]
+    fn reaction_add_renamed() {
+        let cache = test::cache_with_message_and_reactions();
+        cache.update(&ReactionAdd(GatewayReaction {
+            burst: false,
+            burst_colors: Vec::new(),
+            channel_id: Id::new(2),
+            emoji: EmojiReactionType::Custom {
+                animated: false,
+                id: Id::new(6),
+                name: Some("renamed".to_owned()),
+            },
+            guild_id: Some(Id::new(1)),
+            member: None,
+            message_author_id: None,
+            message_id: Id::new(4),
+            user_id: Id::new(7),
+        }));
+
+        let msg = cache.message(Id::new(4)).unwrap();
+        assert_eq!(3, msg.reactions.len());
+        assert_eq!(2, find_custom_react(&msg).unwrap().count);
+    }
+
+    #[test]
+    fn reaction_burst() {
+        let cache = test::cache_with_message_and_reactions();
+        let burst_colors = Vec::from([HexColor::from_str("#F200FF").unwrap()]);
+        let reaction = GatewayReaction {
+            burst: true,
+            burst_colors: burst_colors.clone(),
+            channel_id: Id::new(2),
+            emoji: EmojiReactionType::Unicode {
+                name: "😀".to_owned(),
+            },
+            guild_id: Some(Id::new(1)),
+            member: None,
+            message_author_id: Some(Id::new(3)),
+            message_id: Id::new(4),
+            user_id: Id::new(7),
+        };
+        cache.update(&ReactionAdd(reaction.clone()));
+
+        let smiley_react = |cache: &crate::DefaultInMemoryCache| {
+            cache
+                .message(Id::new(4))
+                .unwrap()
+                .reactions
+                .iter()
+                .find(|&r| matches!(&r.emoji, EmojiReactionType::Unicode {name} if name == "😀"))
+                .cloned()
+                .unwrap()
+        };
+
+        let react = smiley_react(&cache);
+        assert_eq!(3, react.count);
+        assert_eq!(
+            ReactionCountDetails {
+                burst: 1,
+                normal: 2,
+            },
+            react.count_details
+        );
+        assert_eq!(burst_colors, react.burst_colors);
+        assert!(!react.me_burst);
+
+        cache.update(&ReactionRemove(reaction));
+
+        let react = smiley_react(&cache);
+        assert_eq!(2, react.count);
+        assert_eq!(
+            ReactionCountDetails {
+                burst: 0,
+                normal: 2,
+            },
+            react.count_details
+        );
+    }
+
     #[test]
     fn reaction_remove() {
         let cache = test::cache_with_message_and_reactions();
@@ -42,3 +42,65 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadUpdate {
         cache.cache_channel(self.0.clone());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{test, DefaultInMemoryCache};
+    use twilight_model::{
+        channel::{
+            thread::{AutoArchiveDuration, ThreadMetadata},
+            ChannelType,
+        },
+        gateway::payload::incoming::{ThreadCreate, ThreadUpdate},
+        util::Timestamp,
+    };
+
+    /// Test that message counts are taken from thread updates.
+    #[test]
+    fn thread_update_message_counts() {
+        let cache = DefaultInMemoryCache::new();
+        let (_, thread_id, mut thread) = test::guild_channel_text();
+        thread.kind = ChannelType::PublicThread;
+        thread.message_count = Some(1);
+        thread.total_message_sent = Some(1);
+
+        cache.update(&ThreadCreate(thread.clone()));
+
+        thread.message_count = Some(2);
+        thread.total_message_sent = Some(3);
+        cache.update(&ThreadUpdate(thread));
+
+        let cached = cache.channel(thread_id).unwrap();
+        assert_eq!(cached.message_count, Some(2));
+        assert_eq!(cached.total_message_sent, Some(3));
+    }
+
+    /// Test that the creation time of threads is kept.
+    #[test]
+    fn thread_create_timestamp() {
+        let cache = DefaultInMemoryCache::new();
+        let (_, thread_id, mut thread) = test::guild_channel_text();
+        let timestamp = Timestamp::from_secs(1_632_061_052).unwrap();
+        thread.kind = ChannelType::PublicThread;
+        thread.newly_created = Some(true);
+        thread.thread_metadata = Some(ThreadMetadata {
+            archived: false,
+            auto_archive_duration: AutoArchiveDuration::Day,
+            archive_timestamp: timestamp,
+            create_timestamp: Some(timestamp),
+            invitable: None,
+            locked: false,
+        });
+
+        cache.update(&ThreadCreate(thread));
+
+        let cached = cache.channel(thread_id).unwrap();
+        assert_eq!(
+            Some(timestamp),
+            cached
+                .thread_metadata
+                .as_ref()
+                .and_then(|metadata| metadata.create_timestamp)
+        );
+    }
+}
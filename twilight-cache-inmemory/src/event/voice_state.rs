@@ -1,6 +1,10 @@
 use crate::CacheableVoiceState;
 use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
 use twilight_model::gateway::payload::incoming::VoiceStateUpdate;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
 use twilight_model::voice::VoiceState;
 
 impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
@@ -20,26 +24,17 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
 
         // Check if the user is switching channels in the same guild (ie. they already have a voice state entry)
         if let Some(voice_state) = self.voice_states.get(&(guild_id, user_id)) {
-            let remove_channel_mapping = self
-                .voice_state_channels
-                .get_mut(&voice_state.channel_id())
-                .is_some_and(|mut channel_voice_states| {
-                    channel_voice_states.remove(&(guild_id, user_id));
-
-                    channel_voice_states.is_empty()
-                });
-
-            if remove_channel_mapping {
-                self.voice_state_channels.remove(&voice_state.channel_id());
-            }
+            self.remove_voice_state_channel(voice_state.channel_id(), guild_id, user_id);
         }
 
         if let Some(channel_id) = voice_state.channel_id {
             let cached_voice_state =
                 CacheModels::VoiceState::from((channel_id, guild_id, voice_state));
 
-            self.voice_states
-                .insert((guild_id, user_id), cached_voice_state);
+            let is_new = self
+                .voice_states
+                .insert((guild_id, user_id), cached_voice_state)
+                .is_none();
 
             self.voice_state_guilds
                 .entry(guild_id)
@@ -50,30 +45,106 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
                 .entry(channel_id)
                 .or_default()
                 .insert((guild_id, user_id));
+
+            if is_new {
+                self.track_voice_state(guild_id, user_id);
+            }
         } else {
             // voice channel_id does not exist, signifying that the user has left
-            {
-                let remove_guild =
-                    self.voice_state_guilds
-                        .get_mut(&guild_id)
-                        .is_some_and(|mut guild_users| {
-                            guild_users.remove(&user_id);
-
-                            guild_users.is_empty()
-                        });
-
-                if remove_guild {
-                    self.voice_state_guilds.remove(&guild_id);
-                }
-            }
+            self.remove_voice_state_guild(guild_id, user_id);
+            self.voice_states.remove(&(guild_id, user_id));
+            self.voice_state_queue.remove(guild_id, user_id);
+        }
+    }
 
+    /// Remove the voice states of all users in a channel, such as when the
+    /// channel is deleted.
+    pub(crate) fn delete_channel_voice_states(&self, channel_id: Id<ChannelMarker>) {
+        let Some((_, voice_states)) = self.voice_state_channels.remove(&channel_id) else {
+            return;
+        };
+
+        for (guild_id, user_id) in voice_states {
             self.voice_states.remove(&(guild_id, user_id));
+            self.remove_voice_state_guild(guild_id, user_id);
+            self.voice_state_queue.remove(guild_id, user_id);
+        }
+    }
+
+    /// Remove the voice states of all users in a guild, such as when the guild
+    /// is deleted.
+    pub(crate) fn delete_guild_voice_states(&self, guild_id: Id<GuildMarker>) {
+        self.voice_state_queue.remove_guild(guild_id);
+
+        let Some((_, user_ids)) = self.voice_state_guilds.remove(&guild_id) else {
+            return;
+        };
+
+        for user_id in user_ids {
+            if let Some((_, voice_state)) = self.voice_states.remove(&(guild_id, user_id)) {
+                self.remove_voice_state_channel(voice_state.channel_id(), guild_id, user_id);
+            }
+        }
+    }
+
+    /// Remove a user from a channel's voice state mapping.
+    fn remove_voice_state_channel(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) {
+        let remove_channel_mapping = self.voice_state_channels.get_mut(&channel_id).is_some_and(
+            |mut channel_voice_states| {
+                channel_voice_states.remove(&(guild_id, user_id));
+
+                channel_voice_states.is_empty()
+            },
+        );
+
+        if remove_channel_mapping {
+            self.voice_state_channels.remove(&channel_id);
+        }
+    }
+
+    /// Remove a user from a guild's voice state mapping.
+    fn remove_voice_state_guild(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        let remove_guild =
+            self.voice_state_guilds
+                .get_mut(&guild_id)
+                .is_some_and(|mut guild_users| {
+                    guild_users.remove(&user_id);
+
+                    guild_users.is_empty()
+                });
+
+        if remove_guild {
+            self.voice_state_guilds.remove(&guild_id);
+        }
+    }
+
+    /// Track a newly cached voice state, evicting the guild's oldest voice
+    /// state if the voice state cache size is exceeded.
+    fn track_voice_state(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        if let Some(evicted) =
+            self.voice_state_queue
+                .insert(guild_id, user_id, self.config.voice_state_cache_size())
+        {
+            if let Some((_, voice_state)) = self.voice_states.remove(&(guild_id, evicted)) {
+                self.remove_voice_state_channel(voice_state.channel_id(), guild_id, evicted);
+            }
+
+            self.remove_voice_state_guild(guild_id, evicted);
         }
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for VoiceStateUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if let Some(guild_id) = self.0.guild_id {
+            cache.touch_member(guild_id, self.0.user_id);
+        }
+
         if !cache.wants(ResourceType::VOICE_STATE) {
             return;
         }
@@ -91,7 +162,8 @@ mod tests {
     use crate::{model::CachedVoiceState, test, DefaultInMemoryCache, ResourceType};
     use std::str::FromStr;
     use twilight_model::{
-        gateway::payload::incoming::VoiceStateUpdate,
+        channel::{Channel, ChannelType},
+        gateway::payload::incoming::{ChannelDelete, GuildDelete, VoiceStateUpdate},
         guild::{Member, MemberFlags},
         id::{
             marker::{ChannelMarker, GuildMarker, UserMarker},
@@ -239,6 +311,104 @@ mod tests {
         }
     }
 
+    /// Test that deleting a voice channel removes the voice states in it.
+    #[test]
+    fn channel_delete() {
+        let cache = DefaultInMemoryCache::new();
+        cache.cache_voice_state(test::voice_state(Id::new(1), Some(Id::new(11)), Id::new(1)));
+        cache.cache_voice_state(test::voice_state(Id::new(1), Some(Id::new(11)), Id::new(2)));
+        cache.cache_voice_state(test::voice_state(Id::new(1), Some(Id::new(12)), Id::new(3)));
+
+        cache.update(&ChannelDelete(voice_channel(Id::new(1), Id::new(11))));
+
+        assert!(cache.voice_channel_states(Id::new(11)).is_none());
+        assert!(cache.voice_state(Id::new(1), Id::new(1)).is_none());
+        assert!(cache.voice_state(Id::new(2), Id::new(1)).is_none());
+        assert!(cache.voice_state(Id::new(3), Id::new(1)).is_some());
+        assert_eq!(1, cache.guild_voice_states(Id::new(1)).unwrap().len());
+        test::assert_voice_state_indexes(&cache);
+    }
+
+    /// Test that deleting a guild removes its voice states from all indexes.
+    #[test]
+    fn guild_delete() {
+        let cache = DefaultInMemoryCache::new();
+        cache.cache_voice_state(test::voice_state(Id::new(1), Some(Id::new(11)), Id::new(1)));
+        cache.cache_voice_state(test::voice_state(Id::new(2), Some(Id::new(21)), Id::new(1)));
+
+        cache.update(&GuildDelete {
+            id: Id::new(1),
+            unavailable: None,
+        });
+
+        assert!(cache.voice_state(Id::new(1), Id::new(1)).is_none());
+        assert!(cache.voice_channel_states(Id::new(11)).is_none());
+        assert!(cache.guild_voice_states(Id::new(1)).is_none());
+        assert!(cache.voice_state(Id::new(1), Id::new(2)).is_some());
+        test::assert_voice_state_indexes(&cache);
+    }
+
+    /// Test that the voice state indexes stay consistent after pseudo-random
+    /// sequences of voice state updates and channel and guild deletes.
+    #[test]
+    fn randomized_indexes() {
+        /// Xorshift generator, so that failures are reproducible.
+        fn next(state: &mut u64) -> u64 {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+
+            *state
+        }
+
+        for (seed, limit) in [(1, None), (2, None), (3, Some(2)), (4, Some(3))] {
+            let mut builder = DefaultInMemoryCache::builder();
+
+            if let Some(limit) = limit {
+                builder = builder.voice_state_cache_size(limit);
+            }
+
+            let cache = builder.build();
+            let mut state = seed;
+
+            for _ in 0..2_000 {
+                let guild = next(&mut state) % 3 + 1;
+                let guild_id = Id::new(guild);
+                // Channel IDs are `<guild ID><index>`, so they're unique
+                // across guilds.
+                let channel_id = Id::new(guild * 10 + next(&mut state) % 3 + 1);
+                let user_id = Id::new(next(&mut state) % 5 + 1);
+
+                match next(&mut state) % 10 {
+                    0..=5 => cache.update(&VoiceStateUpdate(test::voice_state(
+                        guild_id,
+                        Some(channel_id),
+                        user_id,
+                    ))),
+                    6 | 7 => cache.update(&VoiceStateUpdate(test::voice_state(
+                        guild_id, None, user_id,
+                    ))),
+                    8 => cache.update(&ChannelDelete(voice_channel(guild_id, channel_id))),
+                    _ => cache.update(&GuildDelete {
+                        id: guild_id,
+                        unavailable: Some(next(&mut state) % 2 == 0),
+                    }),
+                }
+
+                test::assert_voice_state_indexes(&cache);
+            }
+        }
+    }
+
+    fn voice_channel(guild_id: Id<GuildMarker>, channel_id: Id<ChannelMarker>) -> Channel {
+        let (_, _, mut channel) = test::guild_channel_text();
+        channel.guild_id = Some(guild_id);
+        channel.id = channel_id;
+        channel.kind = ChannelType::GuildVoice;
+
+        channel
+    }
+
     #[test]
     fn voice_states() {
         let cache = DefaultInMemoryCache::new();
@@ -366,4 +536,37 @@ mod tests {
         let in_cache = cache.voice_state(USER_ID, GUILD_ID).unwrap();
         assert_eq!(in_cache.value(), &cached);
     }
+
+    /// Test that the oldest voice state is evicted when the voice state cache
+    /// size is exceeded.
+    #[test]
+    fn voice_state_eviction() {
+        const CHANNEL_ID: Id<ChannelMarker> = Id::new(2);
+        const GUILD_ID: Id<GuildMarker> = Id::new(1);
+
+        let cache = DefaultInMemoryCache::builder()
+            .voice_state_cache_size(2)
+            .build();
+
+        for user_id in 1..=3 {
+            cache.cache_voice_state(test::voice_state(
+                GUILD_ID,
+                Some(CHANNEL_ID),
+                Id::new(user_id),
+            ));
+        }
+
+        assert!(cache.voice_state(Id::new(1), GUILD_ID).is_none());
+        assert!(cache.voice_state(Id::new(2), GUILD_ID).is_some());
+        assert!(cache.voice_state(Id::new(3), GUILD_ID).is_some());
+        assert_eq!(cache.voice_channel_states(CHANNEL_ID).unwrap().count(), 2);
+        assert_eq!(cache.stats().guild_voice_states(GUILD_ID), Some(2));
+        assert_eq!(cache.stats().voice_state_evictions(), 1);
+
+        // Users leaving no longer count towards the limit.
+        cache.cache_voice_state(test::voice_state(GUILD_ID, None, Id::new(2)));
+        cache.cache_voice_state(test::voice_state(GUILD_ID, Some(CHANNEL_ID), Id::new(4)));
+        assert!(cache.voice_state(Id::new(3), GUILD_ID).is_some());
+        assert_eq!(cache.stats().voice_state_evictions(), 1);
+    }
 }
@@ -0,0 +1,198 @@
+//! Oldest-first eviction of per-guild resources exceeding a configured limit.
+
+use dashmap::DashMap;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::atomic::{AtomicU64, Ordering},
+};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+/// Insertion order of a per-guild resource keyed by user, such as members.
+///
+/// Resources are evicted in the order they were first cached; updating a
+/// cached resource doesn't move it to the back of the queue.
+///
+/// The order is only tracked if a limit is configured.
+#[derive(Debug, Default)]
+pub(crate) struct EvictionQueue {
+    /// Number of resources evicted.
+    evictions: AtomicU64,
+    /// Insertion order of each guild's resources.
+    order: DashMap<Id<GuildMarker>, GuildOrder>,
+}
+
+/// Insertion order of a single guild's resources.
+///
+/// Removed resources are only forgotten from [`live`] so that removal doesn't
+/// need to search [`queue`]; stale entries are skipped when evicting and
+/// dropped once they outnumber the live ones.
+///
+/// [`live`]: Self::live
+/// [`queue`]: Self::queue
+#[derive(Debug, Default)]
+struct GuildOrder {
+    /// Sequence number of each cached resource.
+    live: HashMap<Id<UserMarker>, u64>,
+    /// Sequence number to assign to the next resource.
+    next: u64,
+    /// Resources and their sequence numbers, ordered from oldest to newest.
+    ///
+    /// May contain stale entries of removed or re-added resources.
+    queue: VecDeque<(Id<UserMarker>, u64)>,
+}
+
+impl GuildOrder {
+    /// Remove and return the oldest live resource.
+    fn pop_oldest(&mut self) -> Option<Id<UserMarker>> {
+        while let Some((user_id, sequence)) = self.queue.pop_front() {
+            if self.live.get(&user_id) == Some(&sequence) {
+                self.live.remove(&user_id);
+
+                return Some(user_id);
+            }
+        }
+
+        None
+    }
+}
+
+impl EvictionQueue {
+    /// Clear the tracked order and number of evictions.
+    pub fn clear(&self) {
+        self.evictions.store(0, Ordering::Relaxed);
+        self.order.clear();
+    }
+
+    /// Number of resources evicted.
+    pub fn evictions(&self) -> u64 {
+        self.evictions.load(Ordering::Relaxed)
+    }
+
+    /// Track a newly cached resource, returning the guild's oldest resource
+    /// to evict if the limit is exceeded.
+    pub fn insert(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        limit: Option<usize>,
+    ) -> Option<Id<UserMarker>> {
+        let limit = limit?;
+        let mut order = self.order.entry(guild_id).or_default();
+        let sequence = order.next;
+        order.next += 1;
+        order.live.insert(user_id, sequence);
+        order.queue.push_back((user_id, sequence));
+
+        if order.live.len() <= limit {
+            return None;
+        }
+
+        let evicted = order.pop_oldest();
+
+        if evicted.is_some() {
+            self.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+
+        evicted
+    }
+
+    /// Stop tracking a removed resource.
+    pub fn remove(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        if let Some(mut order) = self.order.get_mut(&guild_id) {
+            if order.live.remove(&user_id).is_none() {
+                return;
+            }
+
+            // Bound the memory held by stale entries, amortized over the
+            // removals that created them.
+            if order.queue.len() > order.live.len() * 2 {
+                let GuildOrder { live, queue, .. } = &mut *order;
+                queue.retain(|(id, sequence)| live.get(id) == Some(sequence));
+            }
+        }
+    }
+
+    /// Stop tracking all of a guild's resources.
+    pub fn remove_guild(&self, guild_id: Id<GuildMarker>) {
+        self.order.remove(&guild_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EvictionQueue;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn oldest_first() {
+        let queue = EvictionQueue::default();
+        let guild_id = Id::new(1);
+
+        assert!(queue.insert(guild_id, Id::new(1), Some(2)).is_none());
+        assert!(queue.insert(guild_id, Id::new(2), Some(2)).is_none());
+        assert_eq!(
+            queue.insert(guild_id, Id::new(3), Some(2)),
+            Some(Id::new(1))
+        );
+        assert_eq!(queue.evictions(), 1);
+
+        queue.remove(guild_id, Id::new(2));
+        assert!(queue.insert(guild_id, Id::new(4), Some(2)).is_none());
+        assert_eq!(
+            queue.insert(guild_id, Id::new(5), Some(2)),
+            Some(Id::new(3))
+        );
+        assert_eq!(queue.evictions(), 2);
+
+        // Other guilds are tracked separately.
+        assert!(queue.insert(Id::new(2), Id::new(1), Some(2)).is_none());
+
+        queue.remove_guild(guild_id);
+        assert!(queue.insert(guild_id, Id::new(6), Some(2)).is_none());
+        assert!(queue.insert(guild_id, Id::new(7), Some(2)).is_none());
+
+        // Re-adding a removed resource moves it to the back of the queue.
+        queue.remove(guild_id, Id::new(6));
+        assert!(queue.insert(guild_id, Id::new(6), Some(2)).is_none());
+        assert_eq!(
+            queue.insert(guild_id, Id::new(8), Some(2)),
+            Some(Id::new(7))
+        );
+
+        queue.clear();
+        assert_eq!(queue.evictions(), 0);
+    }
+
+    #[test]
+    fn unlimited() {
+        let queue = EvictionQueue::default();
+
+        for id in 1..=10 {
+            assert!(queue.insert(Id::new(1), Id::new(id), None).is_none());
+        }
+
+        assert!(queue.order.is_empty());
+        assert_eq!(queue.evictions(), 0);
+    }
+
+    #[test]
+    fn stale_entries_compacted() {
+        let queue = EvictionQueue::default();
+        let guild_id = Id::new(1);
+
+        for id in 1..=100 {
+            queue.insert(guild_id, Id::new(id), Some(100));
+        }
+
+        for id in 1..=99 {
+            queue.remove(guild_id, Id::new(id));
+        }
+
+        let order = queue.order.get(&guild_id).unwrap();
+        assert_eq!(order.live.len(), 1);
+        assert!(order.queue.len() <= 2);
+    }
+}
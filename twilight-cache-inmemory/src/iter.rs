@@ -11,10 +11,21 @@
 //! [`IterReference::value`], both returning immutable references to the
 //! underlying key and value. It also implements [`std::ops::Deref`] and
 //! dereferences to the value.
+//!
+//! # Locking
+//!
+//! Iterators and the references they return hold read locks over portions of
+//! the resource being iterated over. Updating the cache, such as via
+//! [`InMemoryCache::update`], may need to acquire write locks over those same
+//! portions, so updating the cache on the same thread while holding an
+//! iterator or reference may deadlock. Drop iterators and references before
+//! updating the cache, or collect what is needed first.
+//!
+//! [`InMemoryCache::update`]: crate::InMemoryCache::update
 
-use crate::{CacheableModels, GuildResource, InMemoryCache};
-use dashmap::{iter::Iter, mapref::multiple::RefMulti};
-use std::{hash::Hash, ops::Deref};
+use crate::{CacheableModels, GuildResource, InMemoryCache, Reference};
+use dashmap::{iter::Iter, mapref::multiple::RefMulti, DashMap};
+use std::{hash::Hash, ops::Deref, vec::IntoIter};
 use twilight_model::id::{
     marker::{
         ChannelMarker, EmojiMarker, GuildMarker, IntegrationMarker, MessageMarker, RoleMarker,
@@ -151,6 +162,28 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheIter<'a, CacheModels> {
         ResourceIter::new(self.0.guilds.iter())
     }
 
+    /// Create an iterator over the members of a guild in the cache.
+    ///
+    /// Only the IDs of the guild's members are copied upfront; members removed
+    /// from the cache during iteration are skipped.
+    pub fn guild_members(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> GuildMembersIter<'a, CacheModels::Member> {
+        let user_ids = self
+            .0
+            .guild_members
+            .get(&guild_id)
+            .map(|user_ids| user_ids.iter().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        GuildMembersIter {
+            guild_id,
+            members: &self.0.members,
+            user_ids: user_ids.into_iter(),
+        }
+    }
+
     /// Create an iterator over the integrations in the cache.
     #[allow(clippy::type_complexity)]
     pub fn integrations(
@@ -255,19 +288,69 @@ impl<'a, K: Eq + Hash, V> Iterator for ResourceIter<'a, K, V> {
     }
 }
 
+/// Iterator over the members of a guild.
+///
+/// The iteration order is arbitrary.
+///
+/// # Examples
+///
+/// Count how many members of a guild are pending:
+///
+/// ```no_run
+/// use twilight_cache_inmemory::DefaultInMemoryCache;
+/// use twilight_model::id::Id;
+///
+/// let cache = DefaultInMemoryCache::new();
+///
+/// // later in the application...
+/// let count = cache
+///     .iter()
+///     .guild_members(Id::new(1))
+///     .filter(|member| member.pending())
+///     .count();
+///
+/// println!("pending members: {count}");
+/// ```
+pub struct GuildMembersIter<'a, V> {
+    /// ID of the guild.
+    guild_id: Id<GuildMarker>,
+    /// Members across all guilds in the cache.
+    members: &'a DashMap<(Id<GuildMarker>, Id<UserMarker>), V>,
+    /// IDs of the guild's members yet to be iterated over.
+    user_ids: IntoIter<Id<UserMarker>>,
+}
+
+impl<'a, V> Iterator for GuildMembersIter<'a, V> {
+    type Item = Reference<'a, (Id<GuildMarker>, Id<UserMarker>), V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.user_ids.by_ref().find_map(|user_id| {
+            self.members
+                .get(&(self.guild_id, user_id))
+                .map(Reference::new)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.user_ids.size_hint().1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{InMemoryCacheIter, IterReference, ResourceIter};
-    use crate::{test, DefaultCacheModels, DefaultInMemoryCache};
+    use super::{GuildMembersIter, InMemoryCacheIter, IterReference, ResourceIter};
+    use crate::{model::CachedMember, test, DefaultCacheModels, DefaultInMemoryCache};
     use static_assertions::assert_impl_all;
     use std::{borrow::Cow, fmt::Debug};
     use twilight_model::{
+        gateway::payload::incoming::{GuildCreate, MemberAdd, MemberRemove},
         id::{marker::UserMarker, Id},
         user::User,
     };
 
     assert_impl_all!(InMemoryCacheIter<'_, DefaultCacheModels>: Debug, Send, Sync);
     assert_impl_all!(IterReference<'_, Id<UserMarker>, User>: Send, Sync);
+    assert_impl_all!(GuildMembersIter<'_, CachedMember>: Iterator, Send, Sync);
     assert_impl_all!(ResourceIter<'_, Id<UserMarker>, User>: Iterator, Send, Sync);
 
     #[test]
@@ -291,4 +374,52 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn guilds() {
+        let cache = DefaultInMemoryCache::new();
+
+        for id in 1..=1000 {
+            cache.update(&GuildCreate::Available(test::guild(Id::new(id), Some(id))));
+        }
+
+        let total = cache
+            .iter()
+            .guilds()
+            .filter_map(|guild| guild.member_count())
+            .sum::<u64>();
+
+        assert_eq!(total, (1..=1000).sum::<u64>());
+    }
+
+    #[test]
+    fn guild_members() {
+        let guild_id = Id::new(1);
+        let cache = DefaultInMemoryCache::new();
+
+        for id in 2..=4 {
+            cache.update(&MemberAdd {
+                guild_id,
+                member: test::member(Id::new(id)),
+            });
+        }
+        cache.update(&MemberAdd {
+            guild_id: Id::new(5),
+            member: test::member(Id::new(6)),
+        });
+        cache.update(&MemberRemove {
+            guild_id,
+            user: test::user(Id::new(3)),
+        });
+
+        let mut actual = cache
+            .iter()
+            .guild_members(guild_id)
+            .map(|member| member.user_id())
+            .collect::<Vec<_>>();
+        actual.sort_unstable();
+
+        assert_eq!(actual, [Id::new(2), Id::new(4)]);
+        assert_eq!(cache.iter().guild_members(Id::new(7)).count(), 0);
+    }
 }
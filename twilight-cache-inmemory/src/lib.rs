@@ -19,9 +19,11 @@ pub mod traits;
 #[cfg(feature = "permission-calculator")]
 pub mod permission;
 
+mod activity;
 mod builder;
 mod config;
 mod event;
+mod eviction;
 mod stats;
 
 #[cfg(test)]
@@ -42,7 +44,7 @@ pub use self::{
 #[cfg(feature = "permission-calculator")]
 pub use self::permission::InMemoryCachePermissions;
 
-use self::iter::InMemoryCacheIter;
+use self::{activity::MemberActivity, eviction::EvictionQueue, iter::InMemoryCacheIter};
 use dashmap::{
     mapref::{entry::Entry, one::Ref},
     DashMap, DashSet,
@@ -52,7 +54,11 @@ use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
     hash::Hash,
     ops::Deref,
-    sync::Mutex,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
 };
 use twilight_model::{
     channel::{Channel, StageInstance},
@@ -63,9 +69,10 @@ use twilight_model::{
             ChannelMarker, EmojiMarker, GuildMarker, IntegrationMarker, MessageMarker, RoleMarker,
             ScheduledEventMarker, StageMarker, StickerMarker, UserMarker,
         },
-        Id,
+        Id, DISCORD_EPOCH,
     },
     user::{CurrentUser, User},
+    util::Timestamp,
 };
 
 /// Resource associated with a guild.
@@ -212,8 +219,16 @@ pub struct InMemoryCache<CacheModels: CacheableModels = DefaultCacheModels> {
         GuildResource<CacheModels::GuildIntegration>,
     >,
     members: DashMap<(Id<GuildMarker>, Id<UserMarker>), CacheModels::Member>,
+    /// Last activity of members, if member activity is tracked.
+    member_activity: MemberActivity,
+    /// Insertion order of members, if the member cache size is limited.
+    member_queue: EvictionQueue,
     messages: DashMap<Id<MessageMarker>, CacheModels::Message>,
+    /// Number of messages evicted due to the message cache size.
+    message_evictions: AtomicU64,
     presences: DashMap<(Id<GuildMarker>, Id<UserMarker>), CacheModels::Presence>,
+    /// Insertion order of presences, if the presence cache size is limited.
+    presence_queue: EvictionQueue,
     roles: DashMap<Id<RoleMarker>, GuildResource<CacheModels::Role>>,
     scheduled_events:
         DashMap<Id<ScheduledEventMarker>, GuildResource<CacheModels::GuildScheduledEvent>>,
@@ -229,6 +244,9 @@ pub struct InMemoryCache<CacheModels: CacheableModels = DefaultCacheModels> {
     voice_state_guilds: DashMap<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
     /// Mapping of guild ID and user ID pairs to their voice states.
     voice_states: DashMap<(Id<GuildMarker>, Id<UserMarker>), CacheModels::VoiceState>,
+    /// Insertion order of voice states, if the voice state cache size is
+    /// limited.
+    voice_state_queue: EvictionQueue,
 }
 
 #[allow(missing_docs)]
@@ -305,8 +323,12 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.guild_stickers.clear();
         self.integrations.clear();
         self.members.clear();
+        self.member_activity.clear();
+        self.member_queue.clear();
         self.messages.clear();
+        self.message_evictions.store(0, Ordering::Relaxed);
         self.presences.clear();
+        self.presence_queue.clear();
         self.roles.clear();
         self.stickers.clear();
         self.unavailable_guilds.clear();
@@ -314,6 +336,7 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.voice_state_channels.clear();
         self.voice_state_guilds.clear();
         self.voice_states.clear();
+        self.voice_state_queue.clear();
     }
 
     /// Returns a copy of the config cache.
@@ -401,6 +424,55 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         InMemoryCachePermissions::new(self)
     }
 
+    /// Remove members that haven't been active for a duration, keeping a
+    /// minimum number of members per guild.
+    ///
+    /// The least recently active members of a guild are removed first. The
+    /// current user and guild owners are never removed. Members are only
+    /// removed if their activity is [tracked].
+    ///
+    /// Returns the number of members removed.
+    ///
+    /// # Examples
+    ///
+    /// Periodically remove members that weren't active for an hour:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() {
+    /// use std::{sync::Arc, time::Duration};
+    /// use twilight_cache_inmemory::DefaultInMemoryCache;
+    ///
+    /// let cache = Arc::new(
+    ///     DefaultInMemoryCache::builder()
+    ///         .track_member_activity(true)
+    ///         .build(),
+    /// );
+    ///
+    /// let pruning = Arc::clone(&cache);
+    /// tokio::spawn(async move {
+    ///     loop {
+    ///         tokio::time::sleep(Duration::from_secs(60)).await;
+    ///         let pruned = pruning.prune_members(Duration::from_secs(60 * 60), 100);
+    ///         println!("pruned {pruned} members");
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// [tracked]: InMemoryCacheBuilder::track_member_activity
+    pub fn prune_members(&self, older_than: Duration, keep_per_guild_min: usize) -> usize {
+        if !self.config.track_member_activity() {
+            return 0;
+        }
+
+        let older_than = u64::try_from(older_than.as_millis()).unwrap_or(u64::MAX);
+
+        match self.member_activity.now().checked_sub(older_than) {
+            Some(before) => self.prune_members_before(before, keep_per_guild_min),
+            None => 0,
+        }
+    }
+
     /// Update the cache with an event from the gateway.
     pub fn update(&self, value: &impl UpdateCache<CacheModels>) {
         value.update(self);
@@ -423,6 +495,42 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.channels.get(&channel_id).map(Reference::new)
     }
 
+    /// Gets the channels in a guild with a message created since a timestamp.
+    ///
+    /// The time of a channel's last message is that of its [`last_message_id`],
+    /// which is kept up to date by message create events, so no messages need
+    /// to be cached. Channels without messages are excluded.
+    ///
+    /// This requires the [`GUILDS`] intent, and the [`GUILD_MESSAGES`] intent
+    /// to keep the channels up to date.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_MESSAGES`]: ::twilight_model::gateway::Intents::GUILD_MESSAGES
+    /// [`last_message_id`]: CacheableChannel::last_message_id
+    pub fn channels_active_since(
+        &self,
+        guild_id: Id<GuildMarker>,
+        since: Timestamp,
+    ) -> Vec<Id<ChannelMarker>> {
+        let Some(channel_ids) = self.guild_channels.get(&guild_id) else {
+            return Vec::new();
+        };
+
+        // Timestamps before the Discord epoch precede every message.
+        let since = u64::try_from(since.as_micros() / 1000).unwrap_or_default();
+
+        channel_ids
+            .iter()
+            .filter(|channel_id| {
+                self.channels
+                    .get(channel_id)
+                    .and_then(|channel| channel.last_message_id())
+                    .is_some_and(|message_id| (message_id.get() >> 22) + DISCORD_EPOCH >= since)
+            })
+            .copied()
+            .collect()
+    }
+
     /// Gets the set of messages in a channel.
     ///
     /// This requires the [`DIRECT_MESSAGES`] or [`GUILD_MESSAGES`] intents.
@@ -847,8 +955,12 @@ impl<CacheModels: CacheableModels> Default for InMemoryCache<CacheModels> {
             guilds: DashMap::new(),
             integrations: DashMap::new(),
             members: DashMap::new(),
+            member_activity: MemberActivity::new(),
+            member_queue: EvictionQueue::default(),
             messages: DashMap::new(),
+            message_evictions: AtomicU64::new(0),
             presences: DashMap::new(),
+            presence_queue: EvictionQueue::default(),
             roles: DashMap::new(),
             scheduled_events: DashMap::new(),
             stage_instances: DashMap::new(),
@@ -859,6 +971,7 @@ impl<CacheModels: CacheableModels> Default for InMemoryCache<CacheModels> {
             voice_state_channels: DashMap::new(),
             voice_state_guilds: DashMap::new(),
             voice_states: DashMap::new(),
+            voice_state_queue: EvictionQueue::default(),
         }
     }
 }
@@ -1042,6 +1155,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
             | Event::ThreadMembersUpdate(_)
             | Event::ThreadMemberUpdate(_)
             | Event::TypingStart(_)
+            | Event::Unknown(_)
             | Event::VoiceServerUpdate(_)
             | Event::WebhooksUpdate(_) => {}
         }
@@ -1052,9 +1166,9 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
 mod tests {
     use crate::{test, DefaultInMemoryCache};
     use twilight_model::{
-        gateway::payload::incoming::RoleDelete,
+        gateway::payload::incoming::{MessageCreate, RoleDelete},
         guild::{Member, MemberFlags, Permissions, Role, RoleFlags},
-        id::Id,
+        id::{Id, DISCORD_EPOCH},
         util::Timestamp,
     };
 
@@ -1140,4 +1254,46 @@ mod tests {
             Some(Id::new(2))
         );
     }
+
+    #[test]
+    fn channels_active_since() {
+        let cache = DefaultInMemoryCache::new();
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        let mut quiet = channel.clone();
+        quiet.id = Id::new(10);
+        let mut empty = channel.clone();
+        empty.id = Id::new(11);
+        cache.cache_channels([channel, quiet, empty]);
+
+        // Snowflakes of messages created at a number of seconds after the
+        // Discord epoch.
+        let message_id = |secs: u64| Id::new((secs * 1000) << 22);
+        let since = |secs: u64| {
+            Timestamp::from_micros(i64::try_from((DISCORD_EPOCH + secs * 1000) * 1000).unwrap())
+                .unwrap()
+        };
+
+        cache.update(&MessageCreate(test::message(message_id(100), channel_id)));
+        cache.update(&MessageCreate(test::message(message_id(50), Id::new(10))));
+
+        let mut active = cache.channels_active_since(guild_id, since(50));
+        active.sort();
+        assert_eq!(Vec::from([channel_id, Id::new(10)]), active);
+        assert_eq!(
+            Vec::from([channel_id]),
+            cache.channels_active_since(guild_id, since(51))
+        );
+        assert_eq!(
+            Vec::from([channel_id]),
+            cache.channels_active_since(guild_id, since(100))
+        );
+        assert!(cache.channels_active_since(guild_id, since(101)).is_empty());
+        assert_eq!(
+            2,
+            cache
+                .channels_active_since(guild_id, Timestamp::from_secs(1).unwrap())
+                .len()
+        );
+        assert!(cache.channels_active_since(Id::new(2), since(0)).is_empty());
+    }
 }
@@ -431,7 +431,6 @@ impl CacheableGuild for CachedGuild {
         self.id
     }
 
-    #[cfg(feature = "permission-calculator")]
     fn owner_id(&self) -> Id<UserMarker> {
         self.owner_id
     }
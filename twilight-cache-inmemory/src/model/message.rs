@@ -117,6 +117,7 @@ pub struct CachedMessage {
     pub(crate) message_snapshots: Vec<MessageSnapshot>,
     pub(crate) pinned: bool,
     pub(crate) poll: Option<Poll>,
+    position: Option<u64>,
     pub(crate) reactions: Vec<Reaction>,
     reference: Option<MessageReference>,
     role_subscription_data: Option<RoleSubscriptionData>,
@@ -257,6 +258,11 @@ impl CachedMessage {
         self.pinned
     }
 
+    /// Approximate position of the message in a thread.
+    pub const fn position(&self) -> Option<u64> {
+        self.position
+    }
+
     /// Reactions to the message.
     pub fn reactions(&self) -> &[Reaction] {
         &self.reactions
@@ -328,6 +334,7 @@ impl From<Message> for CachedMessage {
             message_snapshots,
             pinned,
             poll,
+            position,
             reactions,
             reference,
             referenced_message: _,
@@ -364,6 +371,7 @@ impl From<Message> for CachedMessage {
             message_snapshots,
             pinned,
             poll,
+            position,
             reactions,
             reference,
             role_subscription_data,
@@ -414,6 +422,7 @@ impl PartialEq<Message> for CachedMessage {
                 .zip(other.mentions.iter())
                 .all(|(user_id, mention)| user_id == &mention.id)
             && self.pinned == other.pinned
+            && self.position == other.position
             && self.reactions == other.reactions
             && self.reference == other.reference
             && self.role_subscription_data == other.role_subscription_data
@@ -481,6 +490,7 @@ mod tests {
         mention_roles,
         mentions,
         pinned,
+        position,
         reactions,
         reference,
         sticker_items,
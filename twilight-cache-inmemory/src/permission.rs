@@ -823,6 +823,7 @@ mod tests {
             rtc_region: None,
             thread_metadata: None,
             topic: None,
+            total_message_sent: None,
             user_limit: None,
             video_quality_mode: None,
         }
@@ -868,6 +869,7 @@ mod tests {
             rtc_region: None,
             thread_metadata: None,
             topic: None,
+            total_message_sent: None,
             user_limit: None,
             video_quality_mode: None,
         }
@@ -1020,6 +1022,120 @@ mod tests {
         Ok(())
     }
 
+    /// Test that channel overwrites are applied in the correct order: the
+    /// `@everyone` overwrite, then role overwrites, then the member overwrite.
+    #[test]
+    fn in_channel_overwrite_order() -> Result<(), Box<dyn Error>> {
+        fn overwrite(
+            id: Id<RoleMarker>,
+            kind: PermissionOverwriteType,
+            allow: Permissions,
+            deny: Permissions,
+        ) -> PermissionOverwrite {
+            PermissionOverwrite {
+                allow,
+                deny,
+                id: id.cast(),
+                kind,
+            }
+        }
+
+        let everyone =
+            |allow, deny| overwrite(EVERYONE_ROLE_ID, PermissionOverwriteType::Role, allow, deny);
+        let role =
+            |allow, deny| overwrite(OTHER_ROLE_ID, PermissionOverwriteType::Role, allow, deny);
+        let member =
+            |allow, deny| overwrite(USER_ID.cast(), PermissionOverwriteType::Member, allow, deny);
+
+        let cache = DefaultInMemoryCache::new();
+        let permissions = cache.permissions();
+
+        let mut guild = base_guild();
+        guild.roles = Vec::from([role_with_permissions(
+            EVERYONE_ROLE_ID,
+            Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+        )]);
+        cache.update(&GuildCreate::Available(guild));
+        cache.update(&role_create(
+            GUILD_ID,
+            role_with_permissions(OTHER_ROLE_ID, Permissions::empty()),
+        ));
+        let mut added = test::member(USER_ID);
+        added.roles.push(OTHER_ROLE_ID);
+        cache.update(&MemberAdd {
+            guild_id: GUILD_ID,
+            member: added,
+        });
+
+        let cases = [
+            // No overwrites keep the role permissions.
+            (
+                Vec::new(),
+                Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+            ),
+            // A deny on `@everyone` applies to everyone.
+            (
+                Vec::from([everyone(Permissions::empty(), Permissions::SEND_MESSAGES)]),
+                Permissions::VIEW_CHANNEL,
+            ),
+            // An allow on a role takes precedence over a deny on `@everyone`.
+            (
+                Vec::from([
+                    everyone(Permissions::empty(), Permissions::SEND_MESSAGES),
+                    role(Permissions::SEND_MESSAGES, Permissions::empty()),
+                ]),
+                Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+            ),
+            // A deny on a role takes precedence over an allow on `@everyone`.
+            (
+                Vec::from([
+                    everyone(Permissions::ATTACH_FILES, Permissions::empty()),
+                    role(Permissions::empty(), Permissions::ATTACH_FILES),
+                ]),
+                Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+            ),
+            // An allow on the member takes precedence over a deny on a role.
+            (
+                Vec::from([
+                    role(Permissions::empty(), Permissions::SEND_MESSAGES),
+                    member(Permissions::SEND_MESSAGES, Permissions::empty()),
+                ]),
+                Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+            ),
+            // A deny on the member takes precedence over an allow on a role.
+            (
+                Vec::from([
+                    role(Permissions::ATTACH_FILES, Permissions::empty()),
+                    member(Permissions::empty(), Permissions::ATTACH_FILES),
+                ]),
+                Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+            ),
+        ];
+
+        for (overwrites, expected) in cases {
+            let mut channel = channel();
+            channel.permission_overwrites = Some(overwrites);
+            cache.update(&ChannelCreate(channel));
+
+            assert_eq!(expected, permissions.in_channel(USER_ID, CHANNEL_ID)?);
+        }
+
+        // Administrators ignore overwrites.
+        cache.update(&role_create(
+            GUILD_ID,
+            role_with_permissions(OTHER_ROLE_ID, Permissions::ADMINISTRATOR),
+        ));
+        let mut channel = channel();
+        channel.permission_overwrites = Some(Vec::from([member(
+            Permissions::empty(),
+            Permissions::SEND_MESSAGES,
+        )]));
+        cache.update(&ChannelCreate(channel));
+        assert!(permissions.in_channel(USER_ID, CHANNEL_ID)?.is_all());
+
+        Ok(())
+    }
+
     /// Test that [`in_channel`] and [`root`] both return [`Permissions::all`]
     /// if the user is also the owner of the guild.
     ///
@@ -1,3 +1,4 @@
+use std::sync::atomic::Ordering;
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker},
     Id,
@@ -145,11 +146,35 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheStats<'a, CacheModels> {
         Some(guild.len())
     }
 
+    /// Number of members evicted due to exceeding the
+    /// [member cache size].
+    ///
+    /// [member cache size]: crate::InMemoryCacheBuilder::member_cache_size
+    pub fn member_evictions(&self) -> u64 {
+        self.0.member_queue.evictions()
+    }
+
     /// Number of members in the cache.
     pub fn members(&self) -> usize {
         self.0.members.len()
     }
 
+    /// Number of messages evicted due to exceeding the
+    /// [message cache size].
+    ///
+    /// [message cache size]: crate::InMemoryCacheBuilder::message_cache_size
+    pub fn message_evictions(&self) -> u64 {
+        self.0.message_evictions.load(Ordering::Relaxed)
+    }
+
+    /// Number of presences evicted due to exceeding the
+    /// [presence cache size].
+    ///
+    /// [presence cache size]: crate::InMemoryCacheBuilder::presence_cache_size
+    pub fn presence_evictions(&self) -> u64 {
+        self.0.presence_queue.evictions()
+    }
+
     /// Number of presences in the cache.
     pub fn presences(&self) -> usize {
         self.0.presences.len()
@@ -170,6 +195,14 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheStats<'a, CacheModels> {
         self.0.users.len()
     }
 
+    /// Number of voice states evicted due to exceeding the
+    /// [voice state cache size].
+    ///
+    /// [voice state cache size]: crate::InMemoryCacheBuilder::voice_state_cache_size
+    pub fn voice_state_evictions(&self) -> u64 {
+        self.0.voice_state_queue.evictions()
+    }
+
     /// Number of voice states in the cache.
     pub fn voice_states(&self) -> usize {
         self.0.voice_states.len()
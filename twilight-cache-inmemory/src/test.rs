@@ -19,8 +19,8 @@ use twilight_model::{
     },
     id::{
         marker::{
-            ChannelMarker, EmojiMarker, GuildMarker, RoleMarker, ScheduledEventMarker,
-            StickerMarker, UserMarker,
+            ChannelMarker, EmojiMarker, GuildMarker, MessageMarker, RoleMarker,
+            ScheduledEventMarker, StickerMarker, UserMarker,
         },
         Id,
     },
@@ -97,6 +97,7 @@ pub fn cache_with_message_and_reactions() -> DefaultInMemoryCache {
         message_snapshots: Vec::new(),
         pinned: false,
         poll: None,
+        position: None,
         reactions: Vec::new(),
         reference: None,
         referenced_message: None,
@@ -282,6 +283,7 @@ pub fn guild_channel_text() -> (Id<GuildMarker>, Id<ChannelMarker>, Channel) {
         rtc_region: None,
         thread_metadata: None,
         topic: None,
+        total_message_sent: None,
         user_limit: None,
         video_quality_mode: None,
     };
@@ -289,6 +291,47 @@ pub fn guild_channel_text() -> (Id<GuildMarker>, Id<ChannelMarker>, Channel) {
     (guild_id, channel_id, channel)
 }
 
+#[allow(deprecated)]
+pub fn message(id: Id<MessageMarker>, channel_id: Id<ChannelMarker>) -> Message {
+    Message {
+        activity: None,
+        application: None,
+        application_id: None,
+        attachments: Vec::new(),
+        author: user(Id::new(3)),
+        call: None,
+        channel_id,
+        components: Vec::new(),
+        content: "ping".to_owned(),
+        edited_timestamp: None,
+        embeds: Vec::new(),
+        flags: Some(MessageFlags::empty()),
+        guild_id: Some(Id::new(1)),
+        id,
+        interaction: None,
+        interaction_metadata: None,
+        kind: MessageType::Regular,
+        member: None,
+        mention_channels: Vec::new(),
+        mention_everyone: false,
+        mention_roles: Vec::new(),
+        mentions: Vec::new(),
+        message_snapshots: Vec::new(),
+        pinned: false,
+        poll: None,
+        position: None,
+        reactions: Vec::new(),
+        reference: None,
+        referenced_message: None,
+        role_subscription_data: None,
+        sticker_items: Vec::new(),
+        timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+        thread: None,
+        tts: false,
+        webhook_id: None,
+    }
+}
+
 pub fn member(id: Id<UserMarker>) -> Member {
     let joined_at = Some(Timestamp::from_secs(1_632_072_645).expect("non zero"));
     let flags = MemberFlags::BYPASSES_VERIFICATION | MemberFlags::DID_REJOIN;
@@ -469,3 +512,53 @@ pub fn guild_scheduled_event(
         user_count,
     }
 }
+
+/// Assert that the voice state indexes by guild and by channel are consistent
+/// with the cached voice states.
+pub fn assert_voice_state_indexes(cache: &DefaultInMemoryCache) {
+    for voice_state in &cache.voice_states {
+        let (guild_id, user_id) = *voice_state.key();
+        let channel_id = voice_state.channel_id();
+
+        assert!(
+            cache
+                .voice_state_guilds
+                .get(&guild_id)
+                .is_some_and(|user_ids| user_ids.contains(&user_id)),
+            "voice state of user {user_id} isn't indexed by guild {guild_id}"
+        );
+        assert!(
+            cache
+                .voice_state_channels
+                .get(&channel_id)
+                .is_some_and(|keys| keys.contains(&(guild_id, user_id))),
+            "voice state of user {user_id} isn't indexed by channel {channel_id}"
+        );
+    }
+
+    for user_ids in &cache.voice_state_guilds {
+        let guild_id = *user_ids.key();
+
+        for user_id in user_ids.iter() {
+            assert!(
+                cache.voice_states.contains_key(&(guild_id, *user_id)),
+                "guild {guild_id} indexes missing voice state of user {user_id}"
+            );
+        }
+    }
+
+    for keys in &cache.voice_state_channels {
+        let channel_id = *keys.key();
+        assert!(!keys.is_empty(), "channel {channel_id} index is empty");
+
+        for (guild_id, user_id) in keys.iter() {
+            assert!(
+                cache
+                    .voice_states
+                    .get(&(*guild_id, *user_id))
+                    .is_some_and(|voice_state| voice_state.channel_id() == channel_id),
+                "channel {channel_id} indexes stale voice state of user {user_id}"
+            );
+        }
+    }
+}
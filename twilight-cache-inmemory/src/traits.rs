@@ -40,7 +40,8 @@ use twilight_model::{
     },
     id::{
         marker::{
-            ChannelMarker, GuildMarker, RoleMarker, ScheduledEventMarker, StickerMarker, UserMarker,
+            ChannelMarker, GenericMarker, GuildMarker, RoleMarker, ScheduledEventMarker,
+            StickerMarker, UserMarker,
         },
         Id,
     },
@@ -160,10 +161,24 @@ pub trait CacheableChannel:
     /// ID of the channel.
     fn id(&self) -> Id<ChannelMarker>;
 
+    /// ID of the last message, or thread for forum channels, created in the
+    /// channel.
+    ///
+    /// Defaults to `None`, such as for channels not tracking their last
+    /// message.
+    fn last_message_id(&self) -> Option<Id<GenericMarker>> {
+        None
+    }
+
     /// Permission overwrites for the channel.
     #[cfg(feature = "permission-calculator")]
     fn permission_overwrites(&self) -> Option<&[PermissionOverwrite]>;
 
+    /// Set the ID of the last message created in the channel.
+    ///
+    /// Defaults to doing nothing.
+    fn set_last_message_id(&mut self, _message_id: Option<Id<GenericMarker>>) {}
+
     /// Set the last pin timestamp to a new timestamp.
     fn set_last_pin_timestamp(&mut self, timestamp: Option<Timestamp>);
 }
@@ -186,11 +201,19 @@ impl CacheableChannel for Channel {
         self.id
     }
 
+    fn last_message_id(&self) -> Option<Id<GenericMarker>> {
+        self.last_message_id
+    }
+
     #[cfg(feature = "permission-calculator")]
     fn permission_overwrites(&self) -> Option<&[PermissionOverwrite]> {
         self.permission_overwrites.as_deref()
     }
 
+    fn set_last_message_id(&mut self, message_id: Option<Id<GenericMarker>>) {
+        self.last_message_id = message_id;
+    }
+
     fn set_last_pin_timestamp(&mut self, timestamp: Option<Timestamp>) {
         self.last_pin_timestamp = timestamp;
     }
@@ -202,7 +225,6 @@ pub trait CacheableGuild: From<Guild> + PartialEq<Guild> + PartialEq<Self> + Clo
     fn id(&self) -> Id<GuildMarker>;
 
     /// ID of the guild's owner.
-    #[cfg(feature = "permission-calculator")]
     fn owner_id(&self) -> Id<UserMarker>;
 
     /// Set the guild's unavailable flag.
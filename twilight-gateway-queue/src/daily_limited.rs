@@ -0,0 +1,352 @@
+//! [`Queue`] wrapper guarding the daily identify limit.
+
+use super::{Queue, LIMIT_PERIOD};
+use std::{
+    fmt::Debug,
+    pin::pin,
+    sync::{Arc, Mutex, PoisonError},
+};
+use tokio::{
+    sync::{oneshot, Notify},
+    time::{sleep_until, Duration, Instant},
+};
+
+/// Estimate of the remaining daily permits.
+#[derive(Debug)]
+struct Limit {
+    /// Estimated remaining daily permits.
+    remaining: u32,
+    /// Number of permits to keep in reserve.
+    reserve: u32,
+    /// When the daily permits reset.
+    reset_at: Instant,
+    /// The number of permits to reset to.
+    total: u32,
+}
+
+impl Limit {
+    /// Take a permit if more than the reserve remains, otherwise return when
+    /// the permits reset.
+    fn acquire(&mut self, now: Instant) -> Result<(), Instant> {
+        self.refill(now);
+
+        if self.remaining > self.reserve {
+            self.remaining -= 1;
+
+            Ok(())
+        } else {
+            Err(self.reset_at)
+        }
+    }
+
+    /// Reset the remaining permits if the reset has passed.
+    fn refill(&mut self, now: Instant) {
+        if now >= self.reset_at {
+            self.remaining = self.total;
+            self.reset_at = now + LIMIT_PERIOD;
+        }
+    }
+}
+
+/// [`Queue`] wrapper delaying identifies that would exhaust the daily limit.
+///
+/// Every identify released by the wrapped queue is counted against an
+/// estimate of the remaining daily permits. Once the estimate reaches the
+/// configured reserve further identifies are delayed until the daily permits
+/// reset, keeping the reserve available for other purposes and preventing
+/// Discord from invalidating all sessions upon exhausting the limit.
+///
+/// The estimate drifts if other processes identify with the same token, so it
+/// should be periodically re-synced with [`DailyLimitedQueue::update`].
+///
+/// Cloning the queue is cheap and just increments a reference counter.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use twilight_gateway_queue::{DailyLimitedQueue, InMemoryQueue};
+///
+/// // Keep 50 identifies in reserve.
+/// let queue = DailyLimitedQueue::builder(InMemoryQueue::default())
+///     .remaining(900)
+///     .reset_after(Duration::from_secs(60 * 60))
+///     .reserve(50)
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct DailyLimitedQueue<Q> {
+    /// Wrapped queue.
+    inner: Arc<Q>,
+    /// Estimate of the remaining daily permits.
+    limit: Arc<Mutex<Limit>>,
+    /// Wakes delayed identifies when the estimate is updated.
+    notify: Arc<Notify>,
+}
+
+impl<Q> DailyLimitedQueue<Q> {
+    /// Create a builder wrapping a queue.
+    pub const fn builder(inner: Q) -> DailyLimitedQueueBuilder<Q> {
+        DailyLimitedQueueBuilder::new(inner)
+    }
+
+    /// Immutable reference to the wrapped queue.
+    pub fn inner(&self) -> &Q {
+        &self.inner
+    }
+
+    /// Estimated remaining daily permits, including the reserve.
+    pub fn remaining(&self) -> u32 {
+        let mut limit = self.limit.lock().unwrap_or_else(PoisonError::into_inner);
+        limit.refill(Instant::now());
+
+        limit.remaining
+    }
+
+    /// Re-sync the estimate with new info from the [Get Gateway Bot] endpoint.
+    ///
+    /// Delayed identifies are released if the permits now exceed the reserve.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `total` < `remaining`.
+    ///
+    /// [Get Gateway Bot]: https://discord.com/developers/docs/topics/gateway#get-gateway-bot
+    pub fn update(&self, remaining: u32, reset_after: Duration, total: u32) {
+        assert!(total >= remaining);
+
+        {
+            let mut limit = self.limit.lock().unwrap_or_else(PoisonError::into_inner);
+            limit.remaining = remaining;
+            limit.reset_at = Instant::now() + reset_after;
+            limit.total = total;
+        }
+
+        self.notify.notify_waiters();
+    }
+}
+
+/// Builder to configure and construct a [`DailyLimitedQueue`].
+///
+/// Defaults to the permits of a new bot without a reserve, which should be
+/// replaced with info from the [Get Gateway Bot] endpoint.
+///
+/// [Get Gateway Bot]: https://discord.com/developers/docs/topics/gateway#get-gateway-bot
+#[derive(Debug)]
+#[must_use = "builder must be completed to be used"]
+pub struct DailyLimitedQueueBuilder<Q> {
+    /// Queue to wrap.
+    inner: Q,
+    /// Remaining daily permits.
+    remaining: u32,
+    /// Number of permits to keep in reserve.
+    reserve: u32,
+    /// Duration until the daily permits reset.
+    reset_after: Duration,
+    /// Number of permits to reset to.
+    total: u32,
+}
+
+impl<Q> DailyLimitedQueueBuilder<Q> {
+    /// Create a builder wrapping a queue.
+    pub const fn new(inner: Q) -> Self {
+        Self {
+            inner,
+            remaining: 1000,
+            reserve: 0,
+            reset_after: LIMIT_PERIOD,
+            total: 1000,
+        }
+    }
+
+    /// Consume the builder, constructing a [`DailyLimitedQueue`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total is less than the remaining permits.
+    pub fn build(self) -> DailyLimitedQueue<Q> {
+        assert!(self.total >= self.remaining);
+
+        DailyLimitedQueue {
+            inner: Arc::new(self.inner),
+            limit: Arc::new(Mutex::new(Limit {
+                remaining: self.remaining,
+                reserve: self.reserve,
+                reset_at: Instant::now() + self.reset_after,
+                total: self.total,
+            })),
+            notify: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Set the remaining daily permits.
+    ///
+    /// Defaults to 1000.
+    pub const fn remaining(mut self, remaining: u32) -> Self {
+        self.remaining = remaining;
+
+        self
+    }
+
+    /// Set the number of permits to keep in reserve.
+    ///
+    /// Defaults to 0.
+    pub const fn reserve(mut self, reserve: u32) -> Self {
+        self.reserve = reserve;
+
+        self
+    }
+
+    /// Set the duration until the daily permits reset.
+    ///
+    /// Defaults to [`LIMIT_PERIOD`].
+    pub const fn reset_after(mut self, reset_after: Duration) -> Self {
+        self.reset_after = reset_after;
+
+        self
+    }
+
+    /// Set the number of permits to reset to.
+    ///
+    /// Defaults to 1000.
+    pub const fn total(mut self, total: u32) -> Self {
+        self.total = total;
+
+        self
+    }
+}
+
+impl<Q> Clone for DailyLimitedQueue<Q> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            limit: Arc::clone(&self.limit),
+            notify: Arc::clone(&self.notify),
+        }
+    }
+}
+
+impl<Q: Queue + Send + Sync + 'static> Queue for DailyLimitedQueue<Q> {
+    fn enqueue(&self, shard: u32) -> oneshot::Receiver<()> {
+        let (mut tx, rx) = oneshot::channel();
+        let Self {
+            inner,
+            limit,
+            notify,
+        } = self.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let notified = pin!(notify.notified());
+                let acquired = limit
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .acquire(Instant::now());
+
+                let Err(reset_at) = acquired else {
+                    break;
+                };
+
+                tracing::debug!(shard, ?reset_at, "reached reserved permits");
+
+                tokio::select! {
+                    () = sleep_until(reset_at) => {}
+                    () = notified => {}
+                    () = tx.closed() => return,
+                }
+            }
+
+            if inner.enqueue(shard).await.is_ok() {
+                _ = tx.send(());
+            } else {
+                // Return the unused permit; dropping the sender requeues the
+                // shard.
+                let mut limit = limit.lock().unwrap_or_else(PoisonError::into_inner);
+                limit.remaining = (limit.remaining + 1).min(limit.total);
+            }
+        });
+
+        rx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DailyLimitedQueue, DailyLimitedQueueBuilder};
+    use crate::{InMemoryQueue, Queue};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use tokio::time::{self, Duration};
+
+    assert_impl_all!(DailyLimitedQueue<InMemoryQueue>: Clone, Debug, Send, Sync, Queue);
+    assert_impl_all!(DailyLimitedQueueBuilder<InMemoryQueue>: Debug, Send, Sync);
+
+    const RESET_AFTER: Duration = Duration::from_secs(60);
+
+    /// Create a queue over an [`InMemoryQueue`] releasing identifies instantly.
+    fn queue(remaining: u32, total: u32, reserve: u32) -> DailyLimitedQueue<InMemoryQueue> {
+        DailyLimitedQueue::builder(InMemoryQueue::new(0, 0, Duration::ZERO, 0))
+            .remaining(remaining)
+            .reset_after(RESET_AFTER)
+            .total(total)
+            .reserve(reserve)
+            .build()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reserve() {
+        let queue = queue(3, 10, 1);
+
+        queue.enqueue(0).await.unwrap();
+        queue.enqueue(1).await.unwrap();
+        assert_eq!(queue.remaining(), 1);
+
+        // Only the reserve remains, so the identify is delayed until the reset.
+        let start = time::Instant::now();
+        let rx = queue.enqueue(2);
+        assert!(time::timeout(Duration::from_secs(1), queue.enqueue(3))
+            .await
+            .is_err());
+        rx.await.unwrap();
+        assert_eq!(start.elapsed(), RESET_AFTER);
+        assert_eq!(queue.remaining(), 9);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn exhausted() {
+        let queue = queue(0, 10, 0);
+        assert_eq!(queue.remaining(), 0);
+
+        let start = time::Instant::now();
+        queue.enqueue(0).await.unwrap();
+        assert_eq!(start.elapsed(), RESET_AFTER);
+        assert_eq!(queue.remaining(), 9);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn update() {
+        let queue = queue(1, 10, 1);
+
+        let rx = queue.enqueue(0);
+        time::sleep(Duration::from_secs(1)).await;
+
+        // Another process consumed permits, drifting the estimate.
+        queue.update(5, RESET_AFTER, 10);
+        let start = time::Instant::now();
+        rx.await.unwrap();
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        assert_eq!(queue.remaining(), 4);
+
+        queue.update(0, RESET_AFTER, 10);
+        assert_eq!(queue.remaining(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "total >= self.remaining")]
+    fn remaining_exceeds_total() {
+        DailyLimitedQueue::builder(())
+            .remaining(10)
+            .total(5)
+            .build();
+    }
+}
@@ -8,9 +8,13 @@
 )]
 #![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
 
+mod daily_limited;
 mod in_memory;
 
-pub use in_memory::InMemoryQueue;
+pub use self::{
+    daily_limited::{DailyLimitedQueue, DailyLimitedQueueBuilder},
+    in_memory::InMemoryQueue,
+};
 
 use tokio::{sync::oneshot, time::Duration};
 
@@ -13,6 +13,19 @@ pub async fn same_id_is_serial(queue: impl Queue) {
     assert!(now.elapsed() >= IDENTIFY_DELAY, "ran concurrently");
 }
 
+/// Requires a queue with `max_concurrency` of 16.
+pub async fn same_bucket_is_serial(queue: impl Queue) {
+    let now = Instant::now();
+
+    let t1 = queue.enqueue(0);
+    let t2 = queue.enqueue(16);
+
+    _ = t1.await;
+    _ = t2.await;
+
+    assert!(now.elapsed() >= IDENTIFY_DELAY, "ran concurrently");
+}
+
 /// Requires a queue with `max_concurrency` > 1.
 pub async fn different_id_is_parallel(queue: impl Queue) {
     let now = Instant::now();
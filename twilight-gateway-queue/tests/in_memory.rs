@@ -42,6 +42,9 @@ async fn integration() {
     queue = InMemoryQueue::new(2, 1000, Duration::ZERO, 1000);
     different_id_is_parallel(&queue).await;
 
+    queue = InMemoryQueue::new(16, 1000, Duration::ZERO, 1000);
+    same_bucket_is_serial(&queue).await;
+
     queue = InMemoryQueue::new(1, 0, Duration::from_secs(60), 1);
     reset_after_refills(&queue, Duration::from_secs(60)).await;
 
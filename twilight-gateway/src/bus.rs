@@ -0,0 +1,361 @@
+//! Distribute events to multiple subscribers.
+
+use crate::{
+    error::{ReceiveEventError, ReceiveEventErrorType},
+    EventTypeFlags,
+};
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use twilight_model::gateway::event::Event;
+
+/// Item buffered for a subscriber.
+#[derive(Debug)]
+enum Item {
+    /// Published event.
+    Event(Arc<Event>),
+    /// Number of events dropped since the previous item because the buffer
+    /// was full.
+    Lagged(u64),
+}
+
+/// Sending half of a subscription.
+#[derive(Debug)]
+struct Subscription {
+    /// Number of events dropped since the last buffered item.
+    missed: u64,
+    /// Sending half of the subscriber's buffer.
+    tx: mpsc::Sender<Item>,
+    /// Event types the subscriber wants.
+    wanted_event_types: EventTypeFlags,
+}
+
+impl Subscription {
+    /// Buffer an event, returning whether the subscriber is still alive.
+    ///
+    /// Events dropped beforehand are first reported, taking up a slot in the
+    /// buffer, so that the subscriber learns about them in order.
+    fn send(&mut self, event: &Arc<Event>) -> Result<bool, ()> {
+        if self.missed > 0 {
+            match self.tx.try_send(Item::Lagged(self.missed)) {
+                Ok(()) => self.missed = 0,
+                Err(TrySendError::Full(_)) => {
+                    self.missed += 1;
+
+                    return Ok(false);
+                }
+                Err(TrySendError::Closed(_)) => return Err(()),
+            }
+        }
+
+        match self.tx.try_send(Item::Event(Arc::clone(event))) {
+            Ok(()) => Ok(true),
+            Err(TrySendError::Full(_)) => {
+                self.missed += 1;
+
+                Ok(false)
+            }
+            Err(TrySendError::Closed(_)) => Err(()),
+        }
+    }
+}
+
+/// Fan-out of events to multiple subscribers.
+///
+/// Each subscriber only receives the event types it wants and has its own
+/// bounded buffer, so a slow subscriber doesn't hold back others or grow
+/// without bounds. Events published while a subscriber's buffer is full are
+/// dropped for that subscriber, which is notified via a
+/// [`ReceiveEventErrorType::Lagged`] error.
+///
+/// Events are shared between subscribers via an [`Arc`] instead of being
+/// cloned for each of them.
+///
+/// Cloning the bus is cheap and just increments a reference counter.
+///
+/// # Examples
+///
+/// Distribute the events of a shard to separate logging and moderation tasks:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_gateway::{EventBus, EventTypeFlags, Intents, Shard, ShardId, StreamExt as _};
+///
+/// let bus = EventBus::new();
+///
+/// let mut logging = bus.subscribe(EventTypeFlags::all(), 1024);
+/// tokio::spawn(async move {
+///     while let Ok(event) = logging.recv().await {
+///         println!("{:?}", event.kind());
+///     }
+/// });
+///
+/// let mut moderation = bus.subscribe(EventTypeFlags::MESSAGE_CREATE, 256);
+/// tokio::spawn(async move {
+///     while let Ok(event) = moderation.recv().await {
+///         // Check the message.
+///     }
+/// });
+///
+/// let token = env::var("DISCORD_TOKEN")?;
+/// let mut shard = Shard::new(ShardId::ONE, token, Intents::GUILD_MESSAGES);
+///
+/// while let Some(item) = shard.next_event(EventTypeFlags::all()).await {
+///     let Ok(event) = item else {
+///         continue;
+///     };
+///
+///     bus.publish(event);
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EventBus {
+    /// Registered subscriptions.
+    subscriptions: Arc<Mutex<Vec<Subscription>>>,
+}
+
+impl EventBus {
+    /// Create a new bus without subscribers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send an event to the subscribers wanting its type, returning the number
+    /// of subscribers it was buffered for.
+    ///
+    /// Subscribers whose buffer is full miss the event. Dropped subscribers
+    /// are removed.
+    pub fn publish(&self, event: Event) -> usize {
+        let event_type = EventTypeFlags::from(event.kind());
+        let event = Arc::new(event);
+        let mut sent = 0;
+
+        self.subscriptions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain_mut(|subscription| {
+                if !subscription.wanted_event_types.contains(event_type) {
+                    return !subscription.tx.is_closed();
+                }
+
+                match subscription.send(&event) {
+                    Ok(buffered) => {
+                        sent += usize::from(buffered);
+
+                        true
+                    }
+                    Err(()) => false,
+                }
+            });
+
+        sent
+    }
+
+    /// Register a subscriber receiving the wanted event types, buffering up to
+    /// `capacity` events.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn subscribe(
+        &self,
+        wanted_event_types: EventTypeFlags,
+        capacity: usize,
+    ) -> EventSubscriber {
+        let (tx, rx) = mpsc::channel(capacity);
+
+        self.subscriptions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Subscription {
+                missed: 0,
+                tx,
+                wanted_event_types,
+            });
+
+        EventSubscriber { rx }
+    }
+
+    /// Number of subscribers that haven't been dropped.
+    pub fn subscriber_count(&self) -> usize {
+        let mut subscriptions = self
+            .subscriptions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        subscriptions.retain(|subscription| !subscription.tx.is_closed());
+
+        subscriptions.len()
+    }
+}
+
+/// Subscriber of an [`EventBus`], receiving the event types it wants.
+///
+/// Dropping the subscriber unregisters it.
+#[derive(Debug)]
+pub struct EventSubscriber {
+    /// Receiving half of the buffer.
+    rx: mpsc::Receiver<Item>,
+}
+
+impl EventSubscriber {
+    /// Receive the next event.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReceiveEventErrorType::Lagged`] error type in place of the
+    /// events that were dropped because the buffer was full. The next call
+    /// receives the event published after them.
+    ///
+    /// Returns a [`ReceiveEventErrorType::Closed`] error type if the event bus
+    /// was dropped and all buffered events have been received.
+    pub async fn recv(&mut self) -> Result<Arc<Event>, ReceiveEventError> {
+        let kind = match self.rx.recv().await {
+            Some(Item::Event(event)) => return Ok(event),
+            Some(Item::Lagged(missed)) => ReceiveEventErrorType::Lagged { missed },
+            None => ReceiveEventErrorType::Closed,
+        };
+
+        Err(ReceiveEventError { kind, source: None })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventBus, EventSubscriber};
+    use crate::{error::ReceiveEventErrorType, EventTypeFlags};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use tokio::task;
+    use twilight_model::gateway::event::Event;
+
+    assert_impl_all!(EventBus: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(EventSubscriber: Debug, Send, Sync);
+
+    const EVENTS: u64 = 10_000;
+
+    #[tokio::test]
+    async fn isolation() {
+        let bus = EventBus::new();
+        let mut fast = bus.subscribe(EventTypeFlags::GATEWAY_HEARTBEAT, 1);
+        let mut slow = bus.subscribe(EventTypeFlags::GATEWAY_HEARTBEAT, 16);
+
+        let consumer = tokio::spawn(async move {
+            for expected in 0..EVENTS {
+                let event = fast.recv().await.unwrap();
+                assert!(
+                    matches!(*event, Event::GatewayHeartbeat(sequence) if sequence == expected)
+                );
+            }
+        });
+
+        for sequence in 0..EVENTS {
+            bus.publish(Event::GatewayHeartbeat(sequence));
+            // Let the fast subscriber keep up.
+            task::yield_now().await;
+        }
+
+        consumer.await.unwrap();
+
+        // The slow subscriber only missed events once its buffer was full.
+        for expected in 0..16 {
+            let event = slow.recv().await.unwrap();
+            assert!(matches!(*event, Event::GatewayHeartbeat(sequence) if sequence == expected));
+        }
+
+        // It receives events again after catching up, with the missed events
+        // reported first.
+        assert_eq!(1, bus.publish(Event::GatewayHeartbeat(EVENTS)));
+
+        let error = slow.recv().await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ReceiveEventErrorType::Lagged { missed } if *missed == EVENTS - 16
+        ));
+
+        let event = slow.recv().await.unwrap();
+        assert!(matches!(*event, Event::GatewayHeartbeat(sequence) if sequence == EVENTS));
+    }
+
+    /// Missed events are reported between the events around them.
+    #[tokio::test]
+    async fn lagged_order() {
+        let bus = EventBus::new();
+        let mut subscriber = bus.subscribe(EventTypeFlags::GATEWAY_HEARTBEAT, 2);
+
+        for sequence in 0..4 {
+            bus.publish(Event::GatewayHeartbeat(sequence));
+        }
+
+        assert!(matches!(
+            *subscriber.recv().await.unwrap(),
+            Event::GatewayHeartbeat(0)
+        ));
+
+        // Reporting the missed events takes up the free slot, so this event is
+        // missed as well.
+        assert_eq!(0, bus.publish(Event::GatewayHeartbeat(4)));
+        assert!(matches!(
+            *subscriber.recv().await.unwrap(),
+            Event::GatewayHeartbeat(1)
+        ));
+        assert!(matches!(
+            subscriber.recv().await.unwrap_err().kind(),
+            ReceiveEventErrorType::Lagged { missed: 2 }
+        ));
+
+        assert_eq!(1, bus.publish(Event::GatewayHeartbeat(5)));
+        assert!(matches!(
+            subscriber.recv().await.unwrap_err().kind(),
+            ReceiveEventErrorType::Lagged { missed: 1 }
+        ));
+        assert!(matches!(
+            *subscriber.recv().await.unwrap(),
+            Event::GatewayHeartbeat(5)
+        ));
+    }
+
+    #[tokio::test]
+    async fn filtered() {
+        let bus = EventBus::new();
+        let mut heartbeats = bus.subscribe(EventTypeFlags::GATEWAY_HEARTBEAT, 1);
+        let mut acks = bus.subscribe(EventTypeFlags::GATEWAY_HEARTBEAT_ACK, 1);
+
+        // Unwanted events don't take up buffer space.
+        for _ in 0..10 {
+            assert_eq!(1, bus.publish(Event::GatewayHeartbeatAck));
+            assert!(matches!(
+                *acks.recv().await.unwrap(),
+                Event::GatewayHeartbeatAck
+            ));
+        }
+
+        assert_eq!(1, bus.publish(Event::GatewayHeartbeat(1)));
+        assert!(matches!(
+            *heartbeats.recv().await.unwrap(),
+            Event::GatewayHeartbeat(1)
+        ));
+    }
+
+    #[tokio::test]
+    async fn dropped() {
+        let bus = EventBus::new();
+        let subscriber = bus.subscribe(EventTypeFlags::all(), 1);
+        let mut other = bus.subscribe(EventTypeFlags::all(), 1);
+        assert_eq!(2, bus.subscriber_count());
+
+        drop(subscriber);
+        assert_eq!(1, bus.subscriber_count());
+        assert_eq!(1, bus.publish(Event::GatewayHeartbeatAck));
+
+        drop(bus);
+        assert!(matches!(
+            *other.recv().await.unwrap(),
+            Event::GatewayHeartbeatAck
+        ));
+        assert!(matches!(
+            other.recv().await.unwrap_err().kind(),
+            ReceiveEventErrorType::Closed
+        ));
+    }
+}
@@ -4,9 +4,10 @@
 //! [`Shard::send`]: crate::Shard::send
 
 use crate::{
-    command::Command,
+    command::{self, Command},
+    config::Interceptor,
     error::{ChannelError, ChannelErrorType},
-    json, CloseFrame,
+    CloseFrame,
 };
 use tokio::sync::mpsc;
 
@@ -21,11 +22,13 @@ pub struct MessageChannel {
     pub command_rx: mpsc::UnboundedReceiver<String>,
     /// Sending half for users to send commands via shards.
     pub command_tx: mpsc::UnboundedSender<String>,
+    /// Command interceptor of the shard, if any.
+    interceptor: Option<Interceptor>,
 }
 
 impl MessageChannel {
     /// Initialize a new message channel.
-    pub fn new() -> Self {
+    pub fn new(interceptor: Option<Interceptor>) -> Self {
         let (command_tx, command_rx) = mpsc::unbounded_channel();
         let (close_tx, close_rx) = mpsc::channel(1);
 
@@ -34,6 +37,7 @@ impl MessageChannel {
             close_tx,
             command_rx,
             command_tx,
+            interceptor,
         }
     }
 
@@ -42,6 +46,7 @@ impl MessageChannel {
         MessageSender {
             close: self.close_tx.clone(),
             command: self.command_tx.clone(),
+            interceptor: self.interceptor.clone(),
         }
     }
 }
@@ -55,6 +60,8 @@ pub struct MessageSender {
     close: mpsc::Sender<CloseFrame<'static>>,
     /// Sending half of the command channel.
     command: mpsc::UnboundedSender<String>,
+    /// Command interceptor of the shard, if any.
+    interceptor: Option<Interceptor>,
 }
 
 impl MessageSender {
@@ -74,7 +81,12 @@ impl MessageSender {
     /// closed.
     #[allow(clippy::missing_panics_doc)]
     pub fn command(&self, command: &impl Command) -> Result<(), ChannelError> {
-        self.send(json::to_string(command).expect("serialization cannot fail"))
+        let interceptor = self
+            .interceptor
+            .as_ref()
+            .map(|interceptor| &interceptor.inner);
+
+        self.send(command::to_string(command, interceptor))
     }
 
     /// Send a JSON encoded gateway event to the associated shard.
@@ -2,8 +2,10 @@
 //!
 //! [`Shard::command`]: crate::Shard::command
 
+use crate::{config::CommandInterceptor, json};
+use serde::Serialize;
 use twilight_model::gateway::payload::outgoing::{
-    RequestGuildMembers, UpdatePresence, UpdateVoiceState,
+    Heartbeat, Identify, RequestGuildMembers, Resume, UpdatePresence, UpdateVoiceState,
 };
 
 mod private {
@@ -12,17 +14,35 @@ mod private {
     //!
     //! [`Command`]: super::Command
 
+    use super::OutgoingCommand;
     use serde::Serialize;
     use twilight_model::gateway::payload::outgoing::{
         RequestGuildMembers, UpdatePresence, UpdateVoiceState,
     };
 
     /// Sealed trait to prevent users from implementing the Command trait.
-    pub trait Sealed: Serialize {}
+    pub trait Sealed: Serialize {
+        /// Clone the command into an [`OutgoingCommand`].
+        fn to_outgoing(&self) -> OutgoingCommand;
+    }
 
-    impl Sealed for RequestGuildMembers {}
-    impl Sealed for UpdatePresence {}
-    impl Sealed for UpdateVoiceState {}
+    impl Sealed for RequestGuildMembers {
+        fn to_outgoing(&self) -> OutgoingCommand {
+            OutgoingCommand::RequestGuildMembers(self.clone())
+        }
+    }
+
+    impl Sealed for UpdatePresence {
+        fn to_outgoing(&self) -> OutgoingCommand {
+            OutgoingCommand::UpdatePresence(self.clone())
+        }
+    }
+
+    impl Sealed for UpdateVoiceState {
+        fn to_outgoing(&self) -> OutgoingCommand {
+            OutgoingCommand::UpdateVoiceState(self.clone())
+        }
+    }
 }
 
 /// Trait marker denoting what can be provided to [`Shard::command`].
@@ -47,14 +67,55 @@ impl Command for RequestGuildMembers {}
 impl Command for UpdatePresence {}
 impl Command for UpdateVoiceState {}
 
+/// Command passed to the [command interceptor] before it's sent to the
+/// gateway.
+///
+/// [command interceptor]: crate::ConfigBuilder::command_interceptor
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum OutgoingCommand {
+    /// Heartbeat sent by the shard.
+    Heartbeat(Heartbeat),
+    /// Identify sent by the shard.
+    Identify(Identify),
+    /// Request for guild members.
+    RequestGuildMembers(RequestGuildMembers),
+    /// Resume sent by the shard.
+    Resume(Resume),
+    /// Update of the presence.
+    UpdatePresence(UpdatePresence),
+    /// Update of the voice state.
+    UpdateVoiceState(UpdateVoiceState),
+}
+
+/// Serialize a command, passing it to the command interceptor first, if any.
+pub(crate) fn to_string(
+    command: &impl Command,
+    interceptor: Option<&CommandInterceptor>,
+) -> String {
+    let json = if let Some(interceptor) = interceptor {
+        let mut command = command.to_outgoing();
+        interceptor(&mut command);
+
+        json::to_string(&command)
+    } else {
+        json::to_string(command)
+    };
+
+    json.expect("serialization cannot fail")
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Command;
+    use super::{Command, OutgoingCommand};
     use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
     use twilight_model::gateway::payload::outgoing::{
         RequestGuildMembers, UpdatePresence, UpdateVoiceState,
     };
 
+    assert_impl_all!(OutgoingCommand: Clone, Debug, PartialEq, Send, Sync);
     assert_impl_all!(RequestGuildMembers: Command);
     assert_impl_all!(UpdatePresence: Command);
     assert_impl_all!(UpdateVoiceState: Command);
@@ -1,9 +1,10 @@
 //! User configuration for shards.
 
-use crate::{queue::InMemoryQueue, Session};
+use crate::{queue::InMemoryQueue, OutgoingCommand, Session};
 use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
     sync::Arc,
+    time::Duration,
 };
 use tokio_websockets::Connector;
 use twilight_model::gateway::{
@@ -32,6 +33,72 @@ impl Debug for Token {
     }
 }
 
+/// Function inspecting or modifying commands before they're sent to the
+/// gateway.
+///
+/// Set via [`ConfigBuilder::command_interceptor`].
+pub type CommandInterceptor = Arc<dyn Fn(&mut OutgoingCommand) + Send + Sync>;
+
+/// Wrapper for a command interceptor with a debug implementation.
+#[derive(Clone)]
+pub(crate) struct Interceptor {
+    /// Function called with each command.
+    pub(crate) inner: CommandInterceptor,
+}
+
+impl Debug for Interceptor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("<interceptor>")
+    }
+}
+
+/// Transport compression of gateway messages.
+///
+/// Compression reduces bandwidth at the cost of a per-shard zlib context and
+/// decompression buffer.
+///
+/// Defaults to [`ZlibStream`] if the `zlib-stock` or `zlib-simd` feature is
+/// enabled, otherwise [`None`].
+///
+/// [`None`]: Self::None
+/// [`ZlibStream`]: Self::ZlibStream
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Compression {
+    /// Messages are not compressed.
+    None,
+    /// Messages are compressed with a shared zlib context.
+    ///
+    /// Requires the `zlib-stock` or `zlib-simd` feature.
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    ZlibStream,
+}
+
+impl Compression {
+    /// Default compression with zlib support enabled.
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    const DEFAULT: Self = Self::ZlibStream;
+
+    /// Default compression without zlib support enabled.
+    #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
+    const DEFAULT: Self = Self::None;
+
+    /// Query argument to append to the gateway URL.
+    pub(crate) const fn query_argument(self) -> &'static str {
+        match self {
+            Self::None => "",
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Self::ZlibStream => "&compress=zlib-stream",
+        }
+    }
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// Configuration used by the shard to identify with the gateway and operate.
 ///
 /// May be reused by cloning, also reusing the hidden TLS context---reducing
@@ -40,13 +107,22 @@ impl Debug for Token {
 /// [`From<Config>`] implementation and then rebuilding it into a rew config.
 #[derive(Clone, Debug)]
 pub struct Config<Q = InMemoryQueue> {
+    /// Function inspecting or modifying outgoing commands.
+    command_interceptor: Option<Interceptor>,
+    /// Transport compression of gateway messages.
+    compression: Compression,
     /// Identification properties the shard will use.
     identify_properties: Option<IdentifyProperties>,
     /// Intents that the shard requests when identifying with the gateway.
     intents: Intents,
+    /// Whether the command interceptor is also called with identify, resume,
+    /// and heartbeat commands.
+    intercept_internal_commands: bool,
     /// When the gateway will stop sending a guild's member list in
     /// Guild Create events.
     large_threshold: u64,
+    /// Maximum size in bytes of received messages.
+    max_message_size: usize,
     /// Presence to set when identifying with the gateway.
     presence: Option<UpdatePresencePayload>,
     /// Gateway proxy URL.
@@ -61,6 +137,8 @@ pub struct Config<Q = InMemoryQueue> {
     resume_url: Option<Box<str>>,
     /// Session information to resume a shard on initialization.
     session: Option<Session>,
+    /// Minimum duration between publishing sequence updates of the session.
+    session_update_interval: Duration,
     /// TLS connector for Websocket connections.
     // We need this to be public so [`stream`] can reuse TLS on multiple shards
     // if unconfigured.
@@ -84,6 +162,18 @@ impl Config {
 }
 
 impl<Q> Config<Q> {
+    /// Function inspecting or modifying outgoing commands.
+    pub fn command_interceptor(&self) -> Option<&CommandInterceptor> {
+        self.command_interceptor
+            .as_ref()
+            .map(|interceptor| &interceptor.inner)
+    }
+
+    /// Transport compression of gateway messages.
+    pub const fn compression(&self) -> Compression {
+        self.compression
+    }
+
     /// Immutable reference to the identification properties the shard will use.
     pub const fn identify_properties(&self) -> Option<&IdentifyProperties> {
         self.identify_properties.as_ref()
@@ -94,12 +184,24 @@ impl<Q> Config<Q> {
         self.intents
     }
 
+    /// Whether the command interceptor is also called with identify, resume,
+    /// and heartbeat commands.
+    pub const fn intercept_internal_commands(&self) -> bool {
+        self.intercept_internal_commands
+    }
+
     /// Maximum threshold at which point the gateway will stop sending a guild's
     /// member list in Guild Create events.
     pub const fn large_threshold(&self) -> u64 {
         self.large_threshold
     }
 
+    /// Maximum size in bytes of received messages, both as received and
+    /// after decompression.
+    pub const fn max_message_size(&self) -> usize {
+        self.max_message_size
+    }
+
     /// Immutable reference to the presence to set when identifying
     /// with the gateway.
     ///
@@ -126,12 +228,25 @@ impl<Q> Config<Q> {
         self.ratelimit_messages
     }
 
+    /// Minimum duration between publishing sequence updates to
+    /// [`Shard::session_watch`] receivers.
+    ///
+    /// [`Shard::session_watch`]: crate::Shard::session_watch
+    pub const fn session_update_interval(&self) -> Duration {
+        self.session_update_interval
+    }
+
     /// Immutable reference to the token used to authenticate when identifying
     /// with the gateway.
     pub const fn token(&self) -> &str {
         &self.token.inner
     }
 
+    /// Wrapped command interceptor, if any.
+    pub(crate) const fn interceptor(&self) -> Option<&Interceptor> {
+        self.command_interceptor.as_ref()
+    }
+
     /// Url to connect to if the shard resumes on initialization.
     pub(crate) fn take_resume_url(&mut self) -> Option<Box<str>> {
         self.resume_url.take()
@@ -166,15 +281,20 @@ impl ConfigBuilder {
 
         Self {
             inner: Config {
+                command_interceptor: None,
+                compression: Compression::default(),
                 identify_properties: None,
                 intents,
+                intercept_internal_commands: false,
                 large_threshold: 50,
+                max_message_size: 16 * 1024 * 1024,
                 presence: None,
                 proxy_url: None,
                 queue: InMemoryQueue::default(),
                 ratelimit_messages: true,
                 resume_url: None,
                 session: None,
+                session_update_interval: Duration::from_secs(5),
                 tls: Arc::new(Connector::new().unwrap()),
                 token: Token::new(token.into_boxed_str()),
             },
@@ -189,10 +309,66 @@ impl<Q> ConfigBuilder<Q> {
         self.inner
     }
 
+    /// Set a function inspecting or modifying commands before they're sent to
+    /// the gateway, such as to log them.
+    ///
+    /// The interceptor is called once per command queued via
+    /// [`Shard::command`] or [`MessageSender::command`], whereas JSON queued
+    /// via [`Shard::send`] isn't intercepted. It's only called with the
+    /// identify, resume, and heartbeat commands the shard sends itself if
+    /// [`intercept_internal_commands`] is enabled.
+    ///
+    /// Defaults to no interceptor.
+    ///
+    /// # Examples
+    ///
+    /// Log outgoing commands:
+    ///
+    /// ```no_run
+    /// use std::{env, sync::Arc};
+    /// use twilight_gateway::{ConfigBuilder, Intents, OutgoingCommand};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    ///
+    /// let config = ConfigBuilder::new(token, Intents::empty())
+    ///     .command_interceptor(Arc::new(|command: &mut OutgoingCommand| {
+    ///         tracing::debug!(?command, "sending command");
+    ///     }))
+    ///     .build();
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`MessageSender::command`]: crate::MessageSender::command
+    /// [`Shard::command`]: crate::Shard::command
+    /// [`Shard::send`]: crate::Shard::send
+    /// [`intercept_internal_commands`]: Self::intercept_internal_commands
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn command_interceptor(mut self, command_interceptor: CommandInterceptor) -> Self {
+        self.inner.command_interceptor = Some(Interceptor {
+            inner: command_interceptor,
+        });
+
+        self
+    }
+
+    /// Set the transport compression of gateway messages.
+    ///
+    /// Disabling compression saves the memory of the per-shard zlib context
+    /// at the cost of more bandwidth, which may be preferable for small bots.
+    ///
+    /// Defaults to [`Compression::default`].
+    pub const fn compression(mut self, compression: Compression) -> Self {
+        self.inner.compression = compression;
+
+        self
+    }
+
     /// Set the properties to identify with.
     ///
     /// This may be used if you want to set a different operating system, for
-    /// example.
+    /// example. Properties may be set per shard via the `per_shard_config`
+    /// callback of [`create_iterator`] and related functions.
     ///
     /// # Examples
     ///
@@ -212,6 +388,8 @@ impl<Q> ConfigBuilder<Q> {
     ///     .build();
     /// # Ok(()) }
     /// ```
+    ///
+    /// [`create_iterator`]: crate::create_iterator
     #[allow(clippy::missing_const_for_fn)]
     pub fn identify_properties(mut self, identify_properties: IdentifyProperties) -> Self {
         self.inner.identify_properties = Some(identify_properties);
@@ -219,6 +397,18 @@ impl<Q> ConfigBuilder<Q> {
         self
     }
 
+    /// Set whether the [command interceptor] is also called with the identify,
+    /// resume, and heartbeat commands the shard sends itself.
+    ///
+    /// Defaults to being disabled.
+    ///
+    /// [command interceptor]: Self::command_interceptor
+    pub const fn intercept_internal_commands(mut self, intercept_internal_commands: bool) -> Self {
+        self.inner.intercept_internal_commands = intercept_internal_commands;
+
+        self
+    }
+
     /// Set the maximum number of members in a guild to load the member list.
     ///
     /// Default value is `50`. The minimum value is `50` and the maximum is
@@ -251,6 +441,26 @@ impl<Q> ConfigBuilder<Q> {
         self
     }
 
+    /// Set the maximum size in bytes of received messages.
+    ///
+    /// The size is enforced on messages as received from the WebSocket
+    /// connection and again after decompression, guarding against excessive
+    /// memory usage from misbehaving gateways or proxies. Messages exceeding
+    /// it return a [`ReceiveMessageErrorType::MessageTooLarge`] error and
+    /// cause the shard to reconnect.
+    ///
+    /// Refer to [`Shard::largest_message_size`] when tuning this value.
+    ///
+    /// Defaults to 16 mebibytes.
+    ///
+    /// [`ReceiveMessageErrorType::MessageTooLarge`]: crate::error::ReceiveMessageErrorType::MessageTooLarge
+    /// [`Shard::largest_message_size`]: crate::Shard::largest_message_size
+    pub const fn max_message_size(mut self, max_message_size: usize) -> Self {
+        self.inner.max_message_size = max_message_size;
+
+        self
+    }
+
     /// Set the presence to use automatically when starting a new session.
     ///
     /// The active presence of a session is maintained across re-connections
@@ -322,30 +532,40 @@ impl<Q> ConfigBuilder<Q> {
     /// turns itself into a no-op.
     pub fn queue<NewQ>(self, queue: NewQ) -> ConfigBuilder<NewQ> {
         let Config {
+            command_interceptor,
+            compression,
             identify_properties,
             intents,
+            intercept_internal_commands,
             large_threshold,
+            max_message_size,
             presence,
             proxy_url,
             queue: _,
             ratelimit_messages,
             resume_url,
             session,
+            session_update_interval,
             tls,
             token,
         } = self.inner;
 
         ConfigBuilder {
             inner: Config {
+                command_interceptor,
+                compression,
                 identify_properties,
                 intents,
+                intercept_internal_commands,
                 large_threshold,
+                max_message_size,
                 presence,
                 proxy_url,
                 queue,
                 ratelimit_messages,
                 resume_url,
                 session,
+                session_update_interval,
                 tls,
                 token,
             },
@@ -390,6 +610,24 @@ impl<Q> ConfigBuilder<Q> {
 
         self
     }
+
+    /// Set the minimum duration between publishing sequence updates to
+    /// [`Shard::session_watch`] receivers.
+    ///
+    /// New and invalidated sessions are always published immediately, while
+    /// sequence updates are debounced to avoid notifying receivers for every
+    /// dispatch event. The latest sequence is additionally published whenever
+    /// a heartbeat is sent. A duration of zero publishes every sequence
+    /// update.
+    ///
+    /// Defaults to 5 seconds.
+    ///
+    /// [`Shard::session_watch`]: crate::Shard::session_watch
+    pub const fn session_update_interval(mut self, session_update_interval: Duration) -> Self {
+        self.inner.session_update_interval = session_update_interval;
+
+        self
+    }
 }
 
 impl<Q> From<Config<Q>> for ConfigBuilder<Q> {
@@ -400,11 +638,22 @@ impl<Q> From<Config<Q>> for ConfigBuilder<Q> {
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, ConfigBuilder};
+    use super::{Compression, Config, ConfigBuilder};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, hash::Hash, time::Duration};
     use twilight_model::gateway::Intents;
 
+    assert_impl_all!(
+        Compression: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
     assert_impl_all!(Config: Clone, Debug, Send, Sync);
     assert_impl_all!(ConfigBuilder: Debug, Send, Sync);
 
@@ -436,6 +685,54 @@ mod tests {
         drop(builder().large_threshold(251));
     }
 
+    #[tokio::test]
+    async fn max_message_size() {
+        assert_eq!(builder().build().max_message_size(), 16 * 1024 * 1024);
+        assert_eq!(
+            builder().max_message_size(1024).build().max_message_size(),
+            1024
+        );
+    }
+
+    #[tokio::test]
+    async fn compression() {
+        assert_eq!(builder().build().compression(), Compression::default());
+        assert_eq!(
+            builder()
+                .compression(Compression::None)
+                .build()
+                .compression(),
+            Compression::None
+        );
+        assert_eq!(Compression::None.query_argument(), "");
+
+        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+        {
+            assert_eq!(Compression::default(), Compression::ZlibStream);
+            assert_eq!(
+                Compression::ZlibStream.query_argument(),
+                "&compress=zlib-stream"
+            );
+        }
+        #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
+        assert_eq!(Compression::default(), Compression::None);
+    }
+
+    #[tokio::test]
+    async fn session_update_interval() {
+        assert_eq!(
+            builder().build().session_update_interval(),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            builder()
+                .session_update_interval(Duration::ZERO)
+                .build()
+                .session_update_interval(),
+            Duration::ZERO
+        );
+    }
+
     #[tokio::test]
     async fn config_prefixes_bot_to_token() {
         const WITHOUT: &str = "test";
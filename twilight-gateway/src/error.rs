@@ -61,6 +61,74 @@ pub enum ChannelErrorType {
     Closed,
 }
 
+/// Receiving the next event from an [`EventBus`] failed.
+///
+/// [`EventBus`]: crate::EventBus
+#[derive(Debug)]
+pub struct ReceiveEventError {
+    /// Type of error.
+    pub(crate) kind: ReceiveEventErrorType,
+    /// Source error if available.
+    pub(crate) source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ReceiveEventError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ReceiveEventErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ReceiveEventErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ReceiveEventError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            ReceiveEventErrorType::Closed => f.write_str("event bus was dropped"),
+            ReceiveEventErrorType::Lagged { missed } => {
+                f.write_str("subscriber lagged behind and missed ")?;
+                Display::fmt(&missed, f)?;
+
+                f.write_str(" events")
+            }
+        }
+    }
+}
+
+impl Error for ReceiveEventError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ReceiveEventError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ReceiveEventErrorType {
+    /// Event bus was dropped and all buffered events have been received.
+    Closed,
+    /// Subscriber's buffer was full, so events were dropped.
+    ///
+    /// Receiving events may continue.
+    Lagged {
+        /// Number of events dropped since the last call.
+        missed: u64,
+    },
+}
+
 /// Failure when fetching the recommended number of shards to use from Discord's
 /// REST API.
 #[cfg(feature = "twilight-http")]
@@ -170,8 +238,15 @@ impl ReceiveMessageError {
     /// Shortcut to create a new error for a message compression error.
     #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
     pub(crate) fn from_compression(source: CompressionError) -> Self {
+        let kind = match source.kind() {
+            CompressionErrorType::MessageTooLarge { .. } => {
+                ReceiveMessageErrorType::MessageTooLarge
+            }
+            _ => ReceiveMessageErrorType::Compression,
+        };
+
         Self {
-            kind: ReceiveMessageErrorType::Compression,
+            kind,
             source: Some(Box::new(source)),
         }
     }
@@ -188,6 +263,9 @@ impl Display for ReceiveMessageError {
                 f.write_str("gateway event could not be deserialized: event=")?;
                 f.write_str(event)
             }
+            ReceiveMessageErrorType::MessageTooLarge => {
+                f.write_str("message exceeds the maximum message size")
+            }
             ReceiveMessageErrorType::Reconnect => f.write_str("failed to reconnect to the gateway"),
         }
     }
@@ -217,22 +295,37 @@ pub enum ReceiveMessageErrorType {
         /// Note that the `simd-json` feature may slightly modify the event.
         event: String,
     },
+    /// Message exceeds the [maximum message size], either as received or
+    /// after decompression.
+    ///
+    /// The shard reconnects to the gateway, attempting to resume its session.
+    ///
+    /// The associated error downcasts to [`CompressionError`] if the message
+    /// exceeded the maximum size during decompression.
+    ///
+    /// [maximum message size]: crate::ConfigBuilder::max_message_size
+    MessageTooLarge,
     /// Shard failed to reconnect to the gateway.
     Reconnect,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{ReceiveMessageError, ReceiveMessageErrorType};
-    use static_assertions::assert_impl_all;
+    use super::{
+        ReceiveEventError, ReceiveEventErrorType, ReceiveMessageError, ReceiveMessageErrorType,
+    };
+    use static_assertions::{assert_fields, assert_impl_all};
     use std::{error::Error, fmt::Debug};
 
+    assert_fields!(ReceiveEventErrorType::Lagged: missed);
+    assert_impl_all!(ReceiveEventErrorType: Debug, Send, Sync);
+    assert_impl_all!(ReceiveEventError: Error, Send, Sync);
     assert_impl_all!(ReceiveMessageErrorType: Debug, Send, Sync);
     assert_impl_all!(ReceiveMessageError: Error, Send, Sync);
 
     #[test]
     fn receive_message_error_display() {
-        let messages: [(ReceiveMessageErrorType, &str); 3] = [
+        let messages: [(ReceiveMessageErrorType, &str); 4] = [
             (
                 ReceiveMessageErrorType::Compression,
                 "binary message could not be decompressed",
@@ -243,6 +336,10 @@ mod tests {
                 },
                 r#"gateway event could not be deserialized: event={"t":null,"s":null,"op":10,"d":{"heartbeat_interval":41250,"_trace":["[\"gateway-prd-us-east1-b-0568\",{\"micros\":0.0}]"]}}"#,
             ),
+            (
+                ReceiveMessageErrorType::MessageTooLarge,
+                "message exceeds the maximum message size",
+            ),
             (
                 ReceiveMessageErrorType::Reconnect,
                 "failed to reconnect to the gateway",
@@ -255,4 +352,21 @@ mod tests {
             assert_eq!(error.to_string(), message);
         }
     }
+
+    #[test]
+    fn receive_event_error_display() {
+        let messages = [
+            (ReceiveEventErrorType::Closed, "event bus was dropped"),
+            (
+                ReceiveEventErrorType::Lagged { missed: 3 },
+                "subscriber lagged behind and missed 3 events",
+            ),
+        ];
+
+        for (kind, message) in messages {
+            let error = ReceiveEventError { kind, source: None };
+
+            assert_eq!(error.to_string(), message);
+        }
+    }
 }
@@ -187,6 +187,8 @@ bitflags! {
         const TYPING_START = 1 << 39;
         /// Guild is unavailable, potentially due to an outage.
         const UNAVAILABLE_GUILD = 1 << 40;
+        /// Dispatch event of a type unknown to twilight was received.
+        const UNKNOWN = 1 << 79;
         /// Current user's profile has been updated.
         const USER_UPDATE = 1 << 41;
         /// Voice server has provided an update with voice session details.
@@ -404,6 +406,7 @@ impl From<EventType> for EventTypeFlags {
             EventType::ThreadUpdate => Self::THREAD_UPDATE,
             EventType::TypingStart => Self::TYPING_START,
             EventType::UnavailableGuild => Self::UNAVAILABLE_GUILD,
+            EventType::Unknown => Self::UNKNOWN,
             EventType::UserUpdate => Self::USER_UPDATE,
             EventType::VoiceServerUpdate => Self::VOICE_SERVER_UPDATE,
             EventType::VoiceStateUpdate => Self::VOICE_STATE_UPDATE,
@@ -422,6 +425,9 @@ impl TryFrom<(OpCode, Option<&str>)> for EventTypeFlags {
             (OpCode::InvalidSession, _) => Ok(Self::GATEWAY_INVALIDATE_SESSION),
             (OpCode::Hello, _) => Ok(Self::GATEWAY_HELLO),
             (OpCode::HeartbeatAck, _) => Ok(Self::GATEWAY_HEARTBEAT_ACK),
+            (OpCode::Dispatch, Some(event_type)) => {
+                Ok(EventType::try_from(event_type).map_or(Self::UNKNOWN, Self::from))
+            }
             (_, Some(event_type)) => EventType::try_from(event_type)
                 .map(Self::from)
                 .map_err(|_| ()),
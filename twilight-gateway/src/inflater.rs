@@ -47,6 +47,12 @@ impl Display for CompressionError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self.kind {
             CompressionErrorType::Decompressing => f.write_str("message could not be decompressed"),
+            CompressionErrorType::MessageTooLarge { max_size } => {
+                f.write_str("message exceeds the maximum size of ")?;
+                Display::fmt(&max_size, f)?;
+
+                f.write_str(" bytes")
+            }
             CompressionErrorType::NotUtf8 => f.write_str("decompressed message is not UTF-8"),
         }
     }
@@ -66,6 +72,13 @@ impl Error for CompressionError {
 pub enum CompressionErrorType {
     /// Decompressing a frame failed.
     Decompressing,
+    /// Compressed or decompressed message exceeds the maximum message size.
+    ///
+    /// Guards against decompression bombs.
+    MessageTooLarge {
+        /// Configured maximum message size in bytes.
+        max_size: usize,
+    },
     /// Decompressed message is not UTF-8.
     NotUtf8,
 }
@@ -88,6 +101,12 @@ fn is_incomplete_message(message: &[u8]) -> bool {
 /// Each received compressed event gets inflated into a [`String`] who's input
 /// and output size is recorded.
 ///
+/// The zlib context is only allocated once the first compressed event is
+/// received, so the statistics remain zero if [`Compression::ZlibStream`] is
+/// disabled.
+///
+/// [`Compression::ZlibStream`]: crate::Compression::ZlibStream
+///
 /// # Example
 ///
 /// Calculate the percentage bytes saved:
@@ -103,14 +122,18 @@ fn is_incomplete_message(message: &[u8]) -> bool {
 /// ```
 #[derive(Debug)]
 pub struct Inflater {
-    /// Common decompressed message buffer.
+    /// Common decompressed message buffer, allocated along with
+    /// [`Self::decompress`].
     buffer: Box<[u8]>,
     /// Per event compressed message buffer.
     compressed: Vec<u8>,
-    /// Zlib decompressor with a dictionary of past data.
-    decompress: Decompress,
+    /// Zlib decompressor with a dictionary of past data, allocated when the
+    /// first message is decompressed.
+    decompress: Option<Decompress>,
     /// When the compression buffer last shrank.
     last_shrank: Instant,
+    /// Maximum size in bytes of compressed and decompressed messages.
+    max_message_size: usize,
 }
 
 impl Inflater {
@@ -118,12 +141,21 @@ impl Inflater {
     const BUFFER_SIZE: usize = 32 * 1024;
 
     /// Create a new inflator for a shard.
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(max_message_size: usize) -> Self {
         Self {
-            buffer: vec![0; Self::BUFFER_SIZE].into_boxed_slice(),
+            buffer: Box::default(),
             compressed: Vec::new(),
-            decompress: Decompress::new(true),
+            decompress: None,
             last_shrank: Instant::now(),
+            max_message_size,
+        }
+    }
+
+    /// Error for a message exceeding the maximum message size.
+    const fn too_large(max_size: usize) -> CompressionError {
+        CompressionError {
+            kind: CompressionErrorType::MessageTooLarge { max_size },
+            source: None,
         }
     }
 
@@ -153,6 +185,9 @@ impl Inflater {
     /// Returns a [`CompressionErrorType::Decompressing`] error type if the
     /// message could not be decompressed.
     ///
+    /// Returns a [`CompressionErrorType::MessageTooLarge`] error type if the
+    /// compressed or decompressed message exceeds the maximum message size.
+    ///
     /// Returns a [`CompressionErrorType::NotUtf8`] error type if the
     /// decompressed message is not UTF-8.
     pub(crate) fn inflate(&mut self, message: &[u8]) -> Result<Option<String>, CompressionError> {
@@ -166,6 +201,9 @@ impl Inflater {
             }
             message
         } else {
+            if self.compressed.len() + message.len() > self.max_message_size {
+                return Err(Self::too_large(self.max_message_size));
+            }
             self.compressed.extend_from_slice(message);
             if is_incomplete_message(&self.compressed) {
                 tracing::trace!("received incomplete message");
@@ -174,7 +212,12 @@ impl Inflater {
             &self.compressed
         };
 
-        let processed_pre = self.processed();
+        if self.buffer.is_empty() {
+            self.buffer = vec![0; Self::BUFFER_SIZE].into_boxed_slice();
+        }
+        let decompress = self.decompress.get_or_insert_with(|| Decompress::new(true));
+
+        let processed_pre = decompress.total_in();
 
         let mut processed = 0;
 
@@ -183,10 +226,10 @@ impl Inflater {
         let mut decompressed = Vec::new();
 
         loop {
-            let produced_pre = self.produced();
+            let produced_pre = decompress.total_out();
 
             // Use Sync to ensure data is flushed to the buffer.
-            self.decompress
+            decompress
                 .decompress(
                     &message[processed..],
                     &mut self.buffer,
@@ -197,9 +240,12 @@ impl Inflater {
                     source: Some(Box::new(source)),
                 })?;
 
-            processed = (self.processed() - processed_pre).try_into().unwrap();
-            let produced = (self.produced() - produced_pre).try_into().unwrap();
+            processed = (decompress.total_in() - processed_pre).try_into().unwrap();
+            let produced = (decompress.total_out() - produced_pre).try_into().unwrap();
 
+            if decompressed.len() + produced > self.max_message_size {
+                return Err(Self::too_large(self.max_message_size));
+            }
             decompressed.extend_from_slice(&self.buffer[..produced]);
 
             // Break when message has been fully decompressed.
@@ -215,7 +261,7 @@ impl Inflater {
             let total_percentage_compressed =
                 self.processed() as f64 * 100.0 / self.produced() as f64;
             let total_percentage_saved = 100.0 - total_percentage_compressed;
-            let total_kib_saved = (self.produced() - self.processed()) / 1024;
+            let total_kib_saved = self.produced().saturating_sub(self.processed()) / 1024;
 
             tracing::trace!(
                 bytes.compressed = message.len(),
@@ -238,23 +284,25 @@ impl Inflater {
     /// Reset the inflater's state.
     pub(crate) fn reset(&mut self) {
         self.compressed = Vec::new();
-        self.decompress.reset(true);
+        if let Some(decompress) = &mut self.decompress {
+            decompress.reset(true);
+        }
     }
 
     /// Total number of bytes processed.
     pub fn processed(&self) -> u64 {
-        self.decompress.total_in()
+        self.decompress.as_ref().map_or(0, Decompress::total_in)
     }
 
     /// Total number of bytes produced.
     pub fn produced(&self) -> u64 {
-        self.decompress.total_out()
+        self.decompress.as_ref().map_or(0, Decompress::total_out)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Inflater;
+    use super::{CompressionErrorType, Inflater};
 
     const MESSAGE: &[u8] = &[
         120, 156, 52, 201, 65, 10, 131, 48, 16, 5, 208, 187, 252, 117, 82, 98, 169, 32, 115, 21,
@@ -268,7 +316,7 @@ mod tests {
 
     #[test]
     fn decompress_single_segment() {
-        let mut inflator = Inflater::new();
+        let mut inflator = Inflater::new(usize::MAX);
         assert!(inflator.compressed.is_empty());
         assert_eq!(inflator.inflate(MESSAGE).unwrap(), Some(OUTPUT.to_owned()));
 
@@ -277,7 +325,7 @@ mod tests {
 
     #[test]
     fn decompress_split_message() {
-        let mut inflator = Inflater::new();
+        let mut inflator = Inflater::new(usize::MAX);
         assert!(inflator.compressed.is_empty());
         assert_eq!(
             inflator.inflate(&MESSAGE[0..MESSAGE.len() / 2]).unwrap(),
@@ -292,9 +340,27 @@ mod tests {
         assert!(inflator.compressed.is_empty());
     }
 
+    #[test]
+    fn statistics() {
+        let mut inflator = Inflater::new(usize::MAX);
+        assert!(inflator.buffer.is_empty());
+        assert!(inflator.decompress.is_none());
+        assert_eq!(inflator.processed(), 0);
+        assert_eq!(inflator.produced(), 0);
+
+        inflator.inflate(MESSAGE).unwrap();
+        assert!(inflator.decompress.is_some());
+        assert_eq!(inflator.processed(), MESSAGE.len() as u64);
+        assert_eq!(inflator.produced(), OUTPUT.len() as u64);
+
+        inflator.reset();
+        assert_eq!(inflator.processed(), 0);
+        assert_eq!(inflator.produced(), 0);
+    }
+
     #[test]
     fn invalid_is_none() {
-        let mut inflator = Inflater::new();
+        let mut inflator = Inflater::new(usize::MAX);
         assert_eq!(inflator.inflate(&[]).unwrap(), None);
 
         assert_eq!(
@@ -305,7 +371,7 @@ mod tests {
 
     #[test]
     fn reset() {
-        let mut inflator = Inflater::new();
+        let mut inflator = Inflater::new(usize::MAX);
         assert_eq!(
             inflator.inflate(&MESSAGE[..MESSAGE.len() - 2]).unwrap(),
             None
@@ -314,4 +380,29 @@ mod tests {
         inflator.reset();
         assert_eq!(inflator.inflate(MESSAGE).unwrap(), Some(OUTPUT.to_owned()));
     }
+
+    #[test]
+    fn message_too_large() {
+        let mut inflator = Inflater::new(OUTPUT.len() - 1);
+        assert!(matches!(
+            inflator.inflate(MESSAGE).unwrap_err().kind(),
+            CompressionErrorType::MessageTooLarge { max_size } if *max_size == OUTPUT.len() - 1
+        ));
+
+        let mut inflator = Inflater::new(MESSAGE.len() - 1);
+        assert_eq!(
+            inflator.inflate(&MESSAGE[..MESSAGE.len() / 2]).unwrap(),
+            None
+        );
+        assert!(matches!(
+            inflator
+                .inflate(&MESSAGE[MESSAGE.len() / 2..])
+                .unwrap_err()
+                .kind(),
+            CompressionErrorType::MessageTooLarge { .. }
+        ));
+
+        let mut inflator = Inflater::new(OUTPUT.len());
+        assert_eq!(inflator.inflate(MESSAGE).unwrap(), Some(OUTPUT.to_owned()));
+    }
 }
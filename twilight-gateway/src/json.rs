@@ -10,12 +10,78 @@ use crate::{
     error::{ReceiveMessageError, ReceiveMessageErrorType},
     EventTypeFlags,
 };
-use serde::de::DeserializeSeed;
+use serde::{
+    de::{DeserializeSeed, Deserializer, IgnoredAny, MapAccess, Visitor},
+    Deserialize,
+};
+use std::{fmt, marker::PhantomData};
 use twilight_model::gateway::{
-    event::{GatewayEvent, GatewayEventDeserializer},
+    event::{EventType, GatewayEvent, GatewayEventDeserializer},
     OpCode,
 };
 
+/// Field of a gateway event.
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    /// Event data.
+    D,
+    /// Opcode.
+    Op,
+    /// Dispatch event type.
+    T,
+    /// Any other field, such as the sequence.
+    #[serde(other)]
+    Other,
+}
+
+/// Deserialize a dispatch event's data in a single pass if it's of the wanted
+/// type.
+///
+/// The data is only deserialized if the opcode and event type precede it,
+/// which Discord does, and is otherwise ignored.
+struct DispatchSeed<'a, T> {
+    /// Wanted type of dispatch event.
+    event_type: &'a str,
+    /// Type to deserialize the data into.
+    phantom: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for DispatchSeed<'_, T> {
+    type Value = Option<T>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for DispatchSeed<'_, T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a gateway event")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut dispatch = false;
+        let mut wanted = false;
+        let mut data = None;
+
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::D if dispatch && wanted => data = Some(map.next_value()?),
+                Field::Op => dispatch = map.next_value::<u8>()? == OpCode::Dispatch as u8,
+                Field::T => wanted = map.next_value::<Option<&str>>()? == Some(self.event_type),
+                Field::D | Field::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(data.filter(|_| dispatch && wanted))
+    }
+}
+
 /// Parse a JSON encoded gateway event into a `GatewayEvent` if
 /// `wanted_event_types` contains its type.
 ///
@@ -82,3 +148,119 @@ pub fn parse(
         Ok(None)
     }
 }
+
+/// Parse a JSON encoded dispatch event into `T` borrowing from `event` if its
+/// type is `event_type`.
+///
+/// Returns `None` if the event is not a dispatch event of type `event_type`.
+///
+/// # Errors
+///
+/// Returns a [`ReceiveMessageErrorType::Deserializing`] error if the event
+/// could not be deserialized.
+pub(crate) fn parse_borrowed<'a, T: Deserialize<'a>>(
+    event: &'a str,
+    event_type: EventType,
+) -> Result<Option<T>, ReceiveMessageError> {
+    let Some(event_type) = event_type.name() else {
+        return Ok(None);
+    };
+
+    let seed = DispatchSeed {
+        event_type,
+        phantom: PhantomData,
+    };
+
+    seed.deserialize(&mut serde_json::Deserializer::from_str(event))
+        .map_err(|source| ReceiveMessageError {
+            kind: ReceiveMessageErrorType::Deserializing {
+                event: event.to_owned(),
+            },
+            source: Some(Box::new(source)),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, parse_borrowed};
+    use crate::{error::ReceiveMessageErrorType, EventTypeFlags};
+    use std::borrow::Cow;
+    use twilight_model::gateway::{
+        event::{DispatchEvent, EventType, GatewayEvent},
+        payload::incoming::MessageCreateRef,
+    };
+
+    const MESSAGE_CREATE: &str = r#"{"op":0,"s":2,"t":"MESSAGE_CREATE","d":{"author":{"id":"3","username":"test"},"channel_id":"2","content":"ping","id":"4","timestamp":"2021-09-19T17:30:45.000000+00:00","type":0}}"#;
+
+    #[test]
+    fn unknown() {
+        let event = r#"{"op":0,"s":2,"t":"MADE_UP_EVENT","d":{"id":"1"}}"#;
+
+        assert!(parse(
+            event.to_owned(),
+            EventTypeFlags::all() - EventTypeFlags::UNKNOWN
+        )
+        .unwrap()
+        .is_none());
+
+        let event = parse(event.to_owned(), EventTypeFlags::UNKNOWN)
+            .unwrap()
+            .unwrap();
+        let GatewayEvent::Dispatch(2, DispatchEvent::Unknown(event)) = event else {
+            panic!("event is not unknown: {event:?}");
+        };
+        assert_eq!("MADE_UP_EVENT", event.kind);
+    }
+
+    #[test]
+    fn borrowed() {
+        let message = parse_borrowed::<MessageCreateRef>(MESSAGE_CREATE, EventType::MessageCreate)
+            .unwrap()
+            .unwrap();
+
+        assert!(matches!(message.content, Cow::Borrowed("ping")));
+        assert!(matches!(message.author.name, Cow::Borrowed("test")));
+    }
+
+    #[test]
+    fn other_event() {
+        assert!(
+            parse_borrowed::<MessageCreateRef>(MESSAGE_CREATE, EventType::MessageUpdate)
+                .unwrap()
+                .is_none()
+        );
+        assert!(parse_borrowed::<MessageCreateRef>(
+            r#"{"op":11,"d":null}"#,
+            EventType::MessageCreate
+        )
+        .unwrap()
+        .is_none());
+    }
+
+    /// Test that data preceding the event type is ignored rather than
+    /// deserialized speculatively.
+    #[test]
+    fn data_before_type() {
+        let event = r#"{"d":{"id":"1"},"op":0,"s":2,"t":"MESSAGE_CREATE"}"#;
+
+        assert!(
+            parse_borrowed::<MessageCreateRef>(event, EventType::MessageCreate)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let error = parse_borrowed::<MessageCreateRef>(
+            r#"{"op":0,"s":2,"t":"MESSAGE_CREATE","d":{}}"#,
+            EventType::MessageCreate,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ReceiveMessageErrorType::Deserializing { .. }
+        ));
+    }
+}
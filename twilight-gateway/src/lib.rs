@@ -15,6 +15,7 @@
 
 pub mod error;
 
+mod bus;
 mod channel;
 mod command;
 mod config;
@@ -27,21 +28,26 @@ mod message;
 mod ratelimiter;
 mod session;
 mod shard;
+mod shutdown;
 mod stream;
+#[cfg(test)]
+mod test;
 
 #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
 pub use self::inflater::Inflater;
 pub use self::{
+    bus::{EventBus, EventSubscriber},
     channel::MessageSender,
-    command::Command,
-    config::{Config, ConfigBuilder},
+    command::{Command, OutgoingCommand},
+    config::{CommandInterceptor, Compression, Config, ConfigBuilder},
     event::EventTypeFlags,
     json::parse,
     latency::Latency,
-    message::Message,
+    message::{BorrowedMessage, Message},
     ratelimiter::CommandRatelimiter,
     session::Session,
     shard::{Shard, ShardState},
+    shutdown::{close_gracefully, close_resumable, CloseReport},
     stream::StreamExt,
 };
 pub use twilight_model::gateway::{CloseFrame, Intents, ShardId};
@@ -155,7 +161,8 @@ where
 /// Passing a primary config is required. Further customization of this config
 /// may be performed in the callback.
 ///
-/// Internally calls [`create_iterator`] with the values from [`GetGatewayAuthed`].
+/// Internally calls [`create_iterator`] with the values from [`GetGatewayAuthed`],
+/// which the client may cache via [`ClientBuilder::cache_gateway_info`].
 ///
 /// # Errors
 ///
@@ -169,6 +176,7 @@ where
 ///
 /// Panics if loading TLS certificates fails.
 ///
+/// [`ClientBuilder::cache_gateway_info`]: twilight_http::client::ClientBuilder::cache_gateway_info
 /// [`GetGatewayAuthed`]: twilight_http::request::GetGatewayAuthed
 #[cfg(feature = "twilight-http")]
 pub async fn create_recommended<F, Q>(
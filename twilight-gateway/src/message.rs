@@ -23,6 +23,21 @@ pub enum Message {
     Text(String),
 }
 
+/// Message received via [`Shard::next_borrowed`].
+///
+/// [`Shard::next_borrowed`]: crate::Shard::next_borrowed
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BorrowedMessage<'a, T> {
+    /// Close message with an optional frame including information about the
+    /// reason for the close.
+    Close(Option<CloseFrame<'static>>),
+    /// Data of a dispatch event of the requested type, borrowing from the
+    /// message.
+    Dispatch(T),
+    /// Any other text websocket message.
+    Text(&'a str),
+}
+
 impl Message {
     /// Close message indicating the connection was closed abnormally.
     pub(crate) const ABNORMAL_CLOSE: Self = Self::Close(Some(CloseFrame::new(1006, "")));
@@ -73,9 +88,33 @@ impl Message {
 
 #[cfg(test)]
 mod tests {
-    use super::Message;
+    use super::{BorrowedMessage, Message};
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
+    use twilight_model::gateway::CloseFrame;
 
+    assert_impl_all!(BorrowedMessage<'_, ()>: Clone, Debug, Eq, PartialEq);
     assert_impl_all!(Message: Clone, Debug, Eq, PartialEq);
+
+    #[test]
+    fn close_frame() {
+        // Close code 4000 in big endian, followed by the reason.
+        let message = Message::Close(Some(CloseFrame::RESUME)).into_websocket_msg();
+        assert!(message.is_close());
+        assert_eq!(
+            b"\x0f\xa0resuming connection".as_slice(),
+            &**message.as_payload()
+        );
+        assert_eq!(
+            Some(Message::Close(Some(CloseFrame::RESUME))),
+            Message::from_websocket_msg(&message)
+        );
+
+        // Close code 1000 in big endian, invalidating the session.
+        let message = Message::Close(Some(CloseFrame::NORMAL)).into_websocket_msg();
+        assert_eq!(
+            b"\x03\xe8closing connection".as_slice(),
+            &**message.as_payload()
+        );
+    }
 }
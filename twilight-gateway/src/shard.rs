@@ -10,13 +10,14 @@
 use crate::inflater::Inflater;
 use crate::{
     channel::{MessageChannel, MessageSender},
+    command,
     error::{ReceiveMessageError, ReceiveMessageErrorType},
     json,
     latency::Latency,
     queue::{InMemoryQueue, Queue},
     ratelimiter::CommandRatelimiter,
     session::Session,
-    Command, Config, Message, ShardId, API_VERSION,
+    BorrowedMessage, Command, Compression, Config, Message, OutgoingCommand, ShardId, API_VERSION,
 };
 use futures_core::Stream;
 use futures_sink::Sink;
@@ -31,19 +32,19 @@ use std::io::ErrorKind as IoErrorKind;
 use std::{
     env::consts::OS,
     fmt,
-    future::Future,
+    future::{poll_fn, Future},
     pin::Pin,
     str,
     task::{ready, Context, Poll},
 };
 use tokio::{
     net::TcpStream,
-    sync::oneshot,
+    sync::{oneshot, watch},
     time::{self, Duration, Instant, Interval, MissedTickBehavior},
 };
 use tokio_websockets::{ClientBuilder, Error as WebsocketError, Limits, MaybeTlsStream};
 use twilight_model::gateway::{
-    event::GatewayEventDeserializer,
+    event::{EventType, GatewayEventDeserializer},
     payload::{
         incoming::Hello,
         outgoing::{
@@ -57,14 +58,6 @@ use twilight_model::gateway::{
 /// URL of the Discord gateway.
 const GATEWAY_URL: &str = "wss://gateway.discord.gg";
 
-/// Query argument with zlib-stream enabled.
-#[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-const COMPRESSION_FEATURES: &str = "&compress=zlib-stream";
-
-/// No query arguments due to compression being disabled.
-#[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
-const COMPRESSION_FEATURES: &str = "";
-
 /// [`tokio_websockets`] library Websocket connection.
 type Connection = tokio_websockets::WebSocketStream<MaybeTlsStream<TcpStream>>;
 
@@ -289,6 +282,8 @@ pub struct Shard<Q = InMemoryQueue> {
     /// Zlib decompressor.
     #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
     inflater: Inflater,
+    /// Size in bytes of the largest message received.
+    largest_message_size: usize,
     /// Potentially pending outgoing message.
     pending: Option<Pending>,
     /// Recent heartbeat latency statistics.
@@ -308,8 +303,16 @@ pub struct Shard<Q = InMemoryQueue> {
     /// The shard may not have an active session if it hasn't yet identified and
     /// received a `READY` dispatch event response.
     session: Option<Session>,
+    /// When the session was last published to [`Self::session_watch`]
+    /// receivers.
+    session_published: Instant,
+    /// Sender publishing session updates to [`Self::session_watch`]
+    /// receivers.
+    session_tx: watch::Sender<Option<Session>>,
     /// Current state of the shard.
     state: ShardState,
+    /// Text of the last message received via [`Self::next_borrowed`].
+    text: String,
     /// Messages from the user to be relayed and sent over the Websocket
     /// connection.
     user_channel: MessageChannel,
@@ -332,6 +335,11 @@ impl<Q> Shard<Q> {
             resume_url = None;
         }
 
+        let (session_tx, _) = watch::channel(session.clone());
+        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+        let inflater = Inflater::new(config.max_message_size());
+        let user_channel = MessageChannel::new(config.interceptor().cloned());
+
         Self {
             config,
             connection_future: None,
@@ -341,19 +349,28 @@ impl<Q> Shard<Q> {
             id: shard_id,
             identify_rx: None,
             #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-            inflater: Inflater::new(),
+            inflater,
+            largest_message_size: 0,
             pending: None,
             latency: Latency::new(),
             ratelimiter: None,
             resume_url,
             session,
+            session_published: Instant::now(),
+            session_tx,
             state: ShardState::Disconnected {
                 reconnect_attempts: 0,
             },
-            user_channel: MessageChannel::new(),
+            text: String::new(),
+            user_channel,
         }
     }
 
+    /// Whether the shard currently has an open WebSocket connection.
+    pub(crate) const fn is_connected(&self) -> bool {
+        self.connection.is_some()
+    }
+
     /// Immutable reference to the configuration used to instantiate this shard.
     pub const fn config(&self) -> &Config<Q> {
         &self.config
@@ -366,12 +383,25 @@ impl<Q> Shard<Q> {
 
     /// Zlib decompressor statistics.
     ///
+    /// The statistics remain zero if [`Compression::ZlibStream`] is disabled.
+    ///
     /// Reset when reconnecting to the gateway.
+    ///
+    /// [`Compression::ZlibStream`]: crate::Compression::ZlibStream
     #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
     pub const fn inflater(&self) -> &Inflater {
         &self.inflater
     }
 
+    /// Size in bytes of the largest message received, after decompression.
+    ///
+    /// Useful for tuning [`ConfigBuilder::max_message_size`].
+    ///
+    /// [`ConfigBuilder::max_message_size`]: crate::ConfigBuilder::max_message_size
+    pub const fn largest_message_size(&self) -> usize {
+        self.largest_message_size
+    }
+
     /// State of the shard.
     pub const fn state(&self) -> ShardState {
         self.state
@@ -412,6 +442,43 @@ impl<Q> Shard<Q> {
         self.session.as_ref()
     }
 
+    /// Receiver notified when the active gateway session changes.
+    ///
+    /// Useful for continuously persisting the session, such as to disk, so
+    /// that it may be resumed via [`ConfigBuilder::session`] after the
+    /// process restarts.
+    ///
+    /// New and invalidated sessions are published immediately. Sequence
+    /// updates are debounced according to
+    /// [`ConfigBuilder::session_update_interval`] and are also published
+    /// whenever a heartbeat is sent.
+    ///
+    /// # Example
+    ///
+    /// Persist the session whenever it changes:
+    ///
+    /// ```no_run
+    /// # use twilight_gateway::{Intents, Shard, ShardId};
+    /// # #[tokio::main] async fn main() {
+    /// # let shard = Shard::new(ShardId::ONE, String::new(), Intents::empty());
+    /// let mut session_watch = shard.session_watch();
+    ///
+    /// tokio::spawn(async move {
+    ///     while session_watch.changed().await.is_ok() {
+    ///         let session = session_watch.borrow_and_update().clone();
+    ///         let json = serde_json::to_string(&session).unwrap();
+    ///         // Write `json` to disk...
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// [`ConfigBuilder::session`]: crate::ConfigBuilder::session
+    /// [`ConfigBuilder::session_update_interval`]: crate::ConfigBuilder::session_update_interval
+    pub fn session_watch(&self) -> watch::Receiver<Option<Session>> {
+        self.session_tx.subscribe()
+    }
+
     /// Queue a command to be sent to the gateway.
     ///
     /// Serializes the command and then calls [`send`].
@@ -419,7 +486,10 @@ impl<Q> Shard<Q> {
     /// [`send`]: Self::send
     #[allow(clippy::missing_panics_doc)]
     pub fn command(&self, command: &impl Command) {
-        self.send(json::to_string(command).expect("serialization cannot fail"));
+        self.send(command::to_string(
+            command,
+            self.config.command_interceptor(),
+        ));
     }
 
     /// Queue a JSON encoded gateway event to be sent to the gateway.
@@ -501,6 +571,77 @@ impl<Q> Shard<Q> {
         self.user_channel.sender()
     }
 
+    /// URL to connect to, without query parameters.
+    ///
+    /// Resumes use the resume URL, whereas new sessions use the configured
+    /// proxy URL, if any.
+    fn base_url(&self) -> &str {
+        self.resume_url
+            .as_deref()
+            .or_else(|| self.config.proxy_url())
+            .unwrap_or(GATEWAY_URL)
+    }
+
+    /// Attempts to connect to the gateway, waiting longer for every failed
+    /// attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReceiveMessageErrorType::Reconnect`] error type if the
+    /// connection could not be established.
+    fn poll_connect(
+        &mut self,
+        cx: &mut Context<'_>,
+        reconnect_attempts: u8,
+    ) -> Poll<Result<(), ReceiveMessageError>> {
+        if self.connection_future.is_none() {
+            let base_url = self.base_url();
+            let uri = gateway_url(base_url, self.config.compression());
+
+            tracing::debug!(url = base_url, "connecting to gateway");
+
+            let max_message_size = self.config.max_message_size();
+            let tls = self.config.tls.clone();
+            self.connection_future = Some(ConnectionFuture(Box::pin(async move {
+                let secs = 2u8.saturating_pow(reconnect_attempts.into());
+                time::sleep(Duration::from_secs(secs.into())).await;
+
+                Ok(ClientBuilder::new()
+                    .uri(&uri)
+                    .expect("URL should be valid")
+                    .limits(Limits::default().max_payload_len(Some(max_message_size)))
+                    .connector(&tls)
+                    .connect()
+                    .await?
+                    .0)
+            })));
+        }
+
+        let res = ready!(Pin::new(&mut self.connection_future.as_mut().unwrap().0).poll(cx));
+        self.connection_future = None;
+        match res {
+            Ok(connection) => {
+                self.connection = Some(connection);
+                self.state = ShardState::Identifying;
+                #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+                self.inflater.reset();
+
+                Poll::Ready(Ok(()))
+            }
+            Err(source) => {
+                self.resume_url = None;
+                self.state = ShardState::Disconnected {
+                    reconnect_attempts: reconnect_attempts + 1,
+                };
+
+                Poll::Ready(Err(ReceiveMessageError {
+                    kind: ReceiveMessageErrorType::Reconnect,
+                    source: Some(Box::new(source)),
+                }))
+            }
+        }
+    }
+
     /// Update internal state from gateway disconnect.
     fn disconnect(&mut self, initiator: CloseInitiator) {
         // May not send any additional WebSocket messages.
@@ -520,6 +661,7 @@ impl<Q> Shard<Q> {
             if matches!(frame.code, 1000 | 1001) {
                 self.resume_url = None;
                 self.session = None;
+                self.publish_session(true);
             }
             self.pending = Some(Pending {
                 gateway_event: Some(Message::Close(Some(frame))),
@@ -528,6 +670,23 @@ impl<Q> Shard<Q> {
         }
     }
 
+    /// Publish the active session to [`Self::session_watch`] receivers if it
+    /// changed.
+    ///
+    /// Unless forced, the session is only published if
+    /// [`Config::session_update_interval`] elapsed since it was last published.
+    fn publish_session(&mut self, force: bool) {
+        if !force && self.session_published.elapsed() < self.config.session_update_interval() {
+            return;
+        }
+
+        self.session_published = Instant::now();
+
+        if *self.session_tx.borrow() != self.session {
+            self.session_tx.send_replace(self.session.clone());
+        }
+    }
+
     /// Parse a JSON message into an event with minimal data for [processing].
     ///
     /// # Errors
@@ -550,6 +709,24 @@ impl<Q> Shard<Q> {
 }
 
 impl<Q: Queue> Shard<Q> {
+    /// Queue a command the shard sends itself, passing it to the
+    /// [command interceptor] if [internal commands are intercepted].
+    ///
+    /// [command interceptor]: Config::command_interceptor
+    /// [internal commands are intercepted]: Config::intercept_internal_commands
+    fn queue_internal(&mut self, mut command: OutgoingCommand, is_heartbeat: bool) {
+        if let Some(interceptor) = self.config.command_interceptor() {
+            if self.config.intercept_internal_commands() {
+                interceptor(&mut command);
+            }
+        }
+
+        self.pending = Pending::text(
+            json::to_string(&command).expect("serialization cannot fail"),
+            is_heartbeat,
+        );
+    }
+
     /// Attempts to send due commands to the gateway.
     ///
     /// # Returns
@@ -607,9 +784,11 @@ impl<Q: Queue> Shard<Q> {
                     self.disconnect(CloseInitiator::Shard(CloseFrame::RESUME));
                 } else {
                     tracing::debug!("sending heartbeat");
-                    self.pending = Pending::text(
-                        json::to_string(&Heartbeat::new(self.session().map(Session::sequence)))
-                            .expect("serialization cannot fail"),
+                    self.publish_session(true);
+                    self.queue_internal(
+                        OutgoingCommand::Heartbeat(Heartbeat::new(
+                            self.session().map(Session::sequence),
+                        )),
                         true,
                     );
                     self.heartbeat_interval_event = false;
@@ -635,21 +814,8 @@ impl<Q: Queue> Shard<Q> {
 
                     tracing::debug!("sending identify");
 
-                    self.pending = Pending::text(
-                        json::to_string(&Identify::new(IdentifyInfo {
-                            compress: false,
-                            intents: self.config.intents(),
-                            large_threshold: self.config.large_threshold(),
-                            presence: self.config.presence().cloned(),
-                            properties: self
-                                .config
-                                .identify_properties()
-                                .cloned()
-                                .unwrap_or_else(default_identify_properties),
-                            shard: Some(self.id),
-                            token: self.config.token().to_owned(),
-                        }))
-                        .expect("serialization cannot fail"),
+                    self.queue_internal(
+                        OutgoingCommand::Identify(identify(&self.config, self.id)),
                         false,
                     );
                     self.identify_rx = None;
@@ -730,12 +896,15 @@ impl<Q: Queue> Shard<Q> {
                 if let Some(session) = self.session.as_mut() {
                     session.set_sequence(sequence);
                 }
+
+                self.publish_session(event_type == "READY");
             }
             Some(OpCode::Heartbeat) => {
                 tracing::debug!("received heartbeat");
-                self.pending = Pending::text(
-                    json::to_string(&Heartbeat::new(self.session().map(Session::sequence)))
-                        .expect("serialization cannot fail"),
+                self.queue_internal(
+                    OutgoingCommand::Heartbeat(Heartbeat::new(
+                        self.session().map(Session::sequence),
+                    )),
                     true,
                 );
             }
@@ -769,13 +938,12 @@ impl<Q: Queue> Shard<Q> {
                 self.latency = Latency::new();
 
                 if let Some(session) = &self.session {
-                    self.pending = Pending::text(
-                        json::to_string(&Resume::new(
+                    self.queue_internal(
+                        OutgoingCommand::Resume(Resume::new(
                             session.sequence(),
                             session.id(),
                             self.config.token(),
-                        ))
-                        .expect("serialization cannot fail"),
+                        )),
                         false,
                     );
                     self.state = ShardState::Resuming;
@@ -803,6 +971,74 @@ impl<Q: Queue> Shard<Q> {
     }
 }
 
+impl<Q: Queue + Unpin> Shard<Q> {
+    /// Receive the next message, deserializing dispatch events of type
+    /// `event_type` into `T` borrowing from the message.
+    ///
+    /// This is an opt-in alternative to receiving owned messages via the
+    /// shard's [`Stream`] implementation, avoiding string allocations for
+    /// types such as [`MessageCreateRef`]. The message is retained by the
+    /// shard until the next call, so the returned value borrows the shard.
+    ///
+    /// Discord sends the event type before its data; should it not, the
+    /// event is returned as [`BorrowedMessage::Text`].
+    ///
+    /// # Examples
+    ///
+    /// Print the content of received messages:
+    ///
+    /// ```no_run
+    /// use twilight_gateway::{BorrowedMessage, EventType, Intents, Shard, ShardId};
+    /// use twilight_model::gateway::payload::incoming::MessageCreateRef;
+    ///
+    /// # #[tokio::main] async fn main() {
+    /// let intents = Intents::GUILD_MESSAGES | Intents::MESSAGE_CONTENT;
+    /// let mut shard = Shard::new(ShardId::ONE, "token".to_owned(), intents);
+    ///
+    /// while let Some(item) = shard
+    ///     .next_borrowed::<MessageCreateRef>(EventType::MessageCreate)
+    ///     .await
+    /// {
+    ///     let Ok(message) = item else {
+    ///         tracing::warn!(source = ?item.unwrap_err(), "error receiving message");
+    ///
+    ///         continue;
+    ///     };
+    ///
+    ///     if let BorrowedMessage::Dispatch(message) = message {
+    ///         println!("{}", message.content);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as the shard's [`Stream`] implementation, and
+    /// a [`ReceiveMessageErrorType::Deserializing`] error if a dispatch event
+    /// of type `event_type` could not be deserialized.
+    ///
+    /// [`MessageCreateRef`]: twilight_model::gateway::payload::incoming::MessageCreateRef
+    pub async fn next_borrowed<'a, T: Deserialize<'a>>(
+        &'a mut self,
+        event_type: EventType,
+    ) -> Option<Result<BorrowedMessage<'a, T>, ReceiveMessageError>> {
+        let text = match poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await? {
+            Ok(Message::Close(frame)) => return Some(Ok(BorrowedMessage::Close(frame))),
+            Ok(Message::Text(text)) => text,
+            Err(source) => return Some(Err(source)),
+        };
+
+        self.text = text;
+        let text = &self.text;
+
+        Some(
+            json::parse_borrowed(text, event_type)
+                .map(|data| data.map_or(BorrowedMessage::Text(text), BorrowedMessage::Dispatch)),
+        )
+    }
+}
+
 impl<Q: Queue + Unpin> Stream for Shard<Q> {
     type Item = Result<Message, ReceiveMessageError>;
 
@@ -821,55 +1057,8 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                     return Poll::Ready(None);
                 }
                 ShardState::Disconnected { reconnect_attempts } if self.connection.is_none() => {
-                    if self.connection_future.is_none() {
-                        let base_url = self
-                            .resume_url
-                            .as_deref()
-                            .or_else(|| self.config.proxy_url())
-                            .unwrap_or(GATEWAY_URL);
-                        let uri = format!(
-                            "{base_url}/?v={API_VERSION}&encoding=json{COMPRESSION_FEATURES}"
-                        );
-
-                        tracing::debug!(url = base_url, "connecting to gateway");
-
-                        let tls = self.config.tls.clone();
-                        self.connection_future = Some(ConnectionFuture(Box::pin(async move {
-                            let secs = 2u8.saturating_pow(reconnect_attempts.into());
-                            time::sleep(Duration::from_secs(secs.into())).await;
-
-                            Ok(ClientBuilder::new()
-                                .uri(&uri)
-                                .expect("URL should be valid")
-                                .limits(Limits::unlimited())
-                                .connector(&tls)
-                                .connect()
-                                .await?
-                                .0)
-                        })));
-                    }
-
-                    let res =
-                        ready!(Pin::new(&mut self.connection_future.as_mut().unwrap().0).poll(cx));
-                    self.connection_future = None;
-                    match res {
-                        Ok(connection) => {
-                            self.connection = Some(connection);
-                            self.state = ShardState::Identifying;
-                            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-                            self.inflater.reset();
-                        }
-                        Err(source) => {
-                            self.resume_url = None;
-                            self.state = ShardState::Disconnected {
-                                reconnect_attempts: reconnect_attempts + 1,
-                            };
-
-                            return Poll::Ready(Some(Err(ReceiveMessageError {
-                                kind: ReceiveMessageErrorType::Reconnect,
-                                source: Some(Box::new(source)),
-                            })));
-                        }
+                    if let Err(error) = ready!(self.poll_connect(cx, reconnect_attempts)) {
+                        return Poll::Ready(Some(Err(error)));
                     }
                 }
                 _ => {}
@@ -885,14 +1074,21 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
             match ready!(Pin::new(self.connection.as_mut().unwrap()).poll_next(cx)) {
                 Some(Ok(message)) => {
                     #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
-                    if message.is_binary() {
-                        if let Some(decompressed) = self
-                            .inflater
-                            .inflate(message.as_payload())
-                            .map_err(ReceiveMessageError::from_compression)?
-                        {
-                            break Message::Text(decompressed);
-                        };
+                    if message.is_binary() && self.config.compression() == Compression::ZlibStream {
+                        match self.inflater.inflate(message.as_payload()) {
+                            Ok(Some(decompressed)) => break Message::Text(decompressed),
+                            Ok(None) => {}
+                            Err(source) => {
+                                let error = ReceiveMessageError::from_compression(source);
+                                // The zlib context is unusable after aborting
+                                // decompression.
+                                if matches!(error.kind, ReceiveMessageErrorType::MessageTooLarge) {
+                                    self.disconnect(CloseInitiator::Shard(CloseFrame::RESUME));
+                                }
+
+                                return Poll::Ready(Some(Err(error)));
+                            }
+                        }
                     }
                     if let Some(message) = Message::from_websocket_msg(&message) {
                         break message;
@@ -916,6 +1112,15 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                 {
                     continue
                 }
+                Some(Err(source @ WebsocketError::PayloadTooLong { .. })) => {
+                    tracing::debug!(%source, "received message exceeding the maximum size");
+                    self.disconnect(CloseInitiator::Transport);
+
+                    return Poll::Ready(Some(Err(ReceiveMessageError {
+                        kind: ReceiveMessageErrorType::MessageTooLarge,
+                        source: Some(Box::new(source)),
+                    })));
+                }
                 Some(Err(_)) => {
                     self.disconnect(CloseInitiator::Transport);
                     return Poll::Ready(Some(Ok(Message::ABNORMAL_CLOSE)));
@@ -942,6 +1147,7 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                 }
             }
             Message::Text(event) => {
+                self.largest_message_size = self.largest_message_size.max(event.len());
                 self.process(event)?;
             }
         }
@@ -950,6 +1156,50 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
     }
 }
 
+/// Gateway URL with the API version, encoding, and compression query
+/// parameters.
+///
+/// Query parameters of the base URL are kept, except for the parameters set
+/// here, and a trailing slash doesn't result in an empty path segment.
+fn gateway_url(base_url: &str, compression: Compression) -> String {
+    let (path, query) = base_url.split_once('?').unwrap_or((base_url, ""));
+    let mut url = format!(
+        "{}/?v={API_VERSION}&encoding=json{}",
+        path.trim_end_matches('/'),
+        compression.query_argument()
+    );
+
+    for parameter in query.split('&').filter(|parameter| !parameter.is_empty()) {
+        let key = parameter.split_once('=').map_or(parameter, |(key, _)| key);
+
+        if !matches!(key, "compress" | "encoding" | "v") {
+            url.push('&');
+            url.push_str(parameter);
+        }
+    }
+
+    url
+}
+
+/// Identify payload of a shard.
+///
+/// Uses the configured [`Config::identify_properties`], falling back to
+/// [`default_identify_properties`].
+fn identify<Q>(config: &Config<Q>, shard_id: ShardId) -> Identify {
+    Identify::new(IdentifyInfo {
+        compress: false,
+        intents: config.intents(),
+        large_threshold: config.large_threshold(),
+        presence: config.presence().cloned(),
+        properties: config
+            .identify_properties()
+            .cloned()
+            .unwrap_or_else(default_identify_properties),
+        shard: Some(shard_id),
+        token: config.token().to_owned(),
+    })
+}
+
 /// Default identify properties to use when the user hasn't customized it in
 /// [`Config::identify_properties`].
 ///
@@ -966,4 +1216,463 @@ mod tests {
 
     assert_impl_all!(Shard: Debug, Send);
     assert_not_impl_any!(Shard: Sync);
+
+    /// Test that query parameters are merged into base URLs.
+    #[test]
+    fn gateway_url() {
+        use super::{gateway_url, GATEWAY_URL};
+        use crate::{Compression, API_VERSION};
+
+        let url = |base_url| gateway_url(base_url, Compression::None);
+        let expected = format!("wss://gateway.discord.gg/?v={API_VERSION}&encoding=json");
+
+        assert_eq!(expected, url(GATEWAY_URL));
+        assert_eq!(expected, url("wss://gateway.discord.gg/"));
+        assert_eq!(expected, url("wss://gateway.discord.gg?"));
+        assert_eq!(
+            format!("{expected}&token=a"),
+            url("wss://gateway.discord.gg/?token=a")
+        );
+        assert_eq!(
+            format!("{expected}&token=a&b"),
+            url("wss://gateway.discord.gg?v=9&token=a&encoding=etf&b&compress=zlib-stream")
+        );
+        assert_eq!(
+            format!("ws://localhost:3000/gateway/?v={API_VERSION}&encoding=json"),
+            url("ws://localhost:3000/gateway/")
+        );
+
+        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+        assert_eq!(
+            format!("{expected}&compress=zlib-stream&token=a"),
+            gateway_url("wss://gateway.discord.gg/?token=a", Compression::ZlibStream)
+        );
+    }
+
+    /// Test that sessions are resumed at the resume URL, whereas new sessions
+    /// connect to the proxy URL, if any.
+    #[tokio::test]
+    async fn base_url() {
+        use super::GATEWAY_URL;
+        use crate::{ConfigBuilder, Intents, Session, ShardId};
+
+        const PROXY_URL: &str = "ws://localhost:3000";
+        const RESUME_URL: &str = "wss://gateway-us-east1-b.discord.gg";
+
+        let config = || ConfigBuilder::new(String::new(), Intents::empty());
+
+        let shard = Shard::with_config(ShardId::ONE, config().build());
+        assert_eq!(GATEWAY_URL, shard.base_url());
+
+        let shard = Shard::with_config(
+            ShardId::ONE,
+            config().proxy_url(PROXY_URL.to_owned()).build(),
+        );
+        assert_eq!(PROXY_URL, shard.base_url());
+
+        let shard = Shard::with_config(
+            ShardId::ONE,
+            config()
+                .proxy_url(PROXY_URL.to_owned())
+                .resume_url(RESUME_URL.to_owned())
+                .session(Session::new(1, String::new()))
+                .build(),
+        );
+        assert_eq!(RESUME_URL, shard.base_url());
+
+        // Without a session to resume the resume URL is ignored.
+        let shard = Shard::with_config(
+            ShardId::ONE,
+            config().resume_url(RESUME_URL.to_owned()).build(),
+        );
+        assert_eq!(GATEWAY_URL, shard.base_url());
+    }
+
+    /// Test that compression is requested and messages are decompressed only
+    /// if compression is enabled.
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    #[tokio::test]
+    async fn compression() {
+        use crate::{
+            test::{compress, Gateway, HELLO},
+            Compression, ConfigBuilder, Intents, Message, ShardId,
+        };
+        use futures_util::{SinkExt, StreamExt};
+        use tokio_websockets::Message as WebsocketMessage;
+
+        for compression in [Compression::None, Compression::ZlibStream] {
+            let gateway = Gateway::bind().await;
+            let config = ConfigBuilder::new(String::new(), Intents::empty())
+                .compression(compression)
+                .proxy_url(gateway.url())
+                .build();
+            let mut shard = Shard::with_config(ShardId::ONE, config);
+
+            let ((uri, mut connection), message) = tokio::join!(gateway.accept_uri(), shard.next());
+            assert!(matches!(message, Some(Ok(Message::Text(_)))));
+
+            let enabled = compression == Compression::ZlibStream;
+            assert_eq!(enabled, uri.contains("compress=zlib-stream"), "{uri}");
+
+            let message = if enabled {
+                WebsocketMessage::binary(compress(HELLO.as_bytes()))
+            } else {
+                WebsocketMessage::text(HELLO)
+            };
+            connection.send(message).await.unwrap();
+            assert!(matches!(
+                shard.next().await,
+                Some(Ok(Message::Text(text))) if text == HELLO
+            ));
+
+            let produced = if enabled { HELLO.len() as u64 } else { 0 };
+            assert_eq!(produced, shard.inflater().produced());
+            assert_eq!(enabled, shard.inflater().processed() > 0);
+        }
+    }
+
+    /// Test that messages exceeding the maximum size return an error instead
+    /// of being received.
+    #[tokio::test]
+    async fn message_too_large() {
+        use crate::{
+            error::ReceiveMessageErrorType,
+            test::{Gateway, HELLO},
+            ConfigBuilder, Intents, Message, ShardId,
+        };
+        use futures_util::{SinkExt, StreamExt};
+        use tokio_websockets::Message as WebsocketMessage;
+
+        let gateway = Gateway::bind().await;
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .max_message_size(HELLO.len())
+            .proxy_url(gateway.url())
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        let (mut connection, message) = tokio::join!(gateway.accept(), shard.next());
+        assert!(matches!(message, Some(Ok(Message::Text(_)))));
+        assert_eq!(HELLO.len(), shard.largest_message_size());
+
+        let text = " ".repeat(HELLO.len() + 1);
+        connection.send(WebsocketMessage::text(text)).await.unwrap();
+        let error = shard.next().await.unwrap().unwrap_err();
+        assert!(
+            matches!(error.kind(), ReceiveMessageErrorType::MessageTooLarge),
+            "{error:?}"
+        );
+        assert_eq!(HELLO.len(), shard.largest_message_size());
+    }
+
+    /// Test that dispatch events of the requested type are deserialized
+    /// borrowing from the shard, and that other messages are passed through.
+    #[tokio::test]
+    async fn next_borrowed() {
+        use crate::{test::Gateway, BorrowedMessage, ConfigBuilder, Intents, ShardId};
+        use futures_util::SinkExt;
+        use std::borrow::Cow;
+        use tokio_websockets::Message as WebsocketMessage;
+        use twilight_model::gateway::{event::EventType, payload::incoming::MessageCreateRef};
+
+        const MESSAGE_CREATE: &str = r#"{"op":0,"s":1,"t":"MESSAGE_CREATE","d":{"author":{"id":"3","username":"test"},"channel_id":"2","content":"ping","id":"4","timestamp":"2021-09-19T17:30:45.000000+00:00","type":0}}"#;
+
+        let gateway = Gateway::bind().await;
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .proxy_url(gateway.url())
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        let (mut connection, message) = tokio::join!(
+            gateway.accept(),
+            shard.next_borrowed::<MessageCreateRef>(EventType::MessageCreate)
+        );
+        assert!(matches!(message, Some(Ok(BorrowedMessage::Text(_)))));
+
+        connection
+            .send(WebsocketMessage::text(MESSAGE_CREATE))
+            .await
+            .unwrap();
+        let message = shard
+            .next_borrowed::<MessageCreateRef>(EventType::MessageCreate)
+            .await;
+        let Some(Ok(BorrowedMessage::Dispatch(message))) = message else {
+            panic!("{message:?}");
+        };
+        assert!(matches!(message.content, Cow::Borrowed("ping")));
+
+        connection
+            .send(WebsocketMessage::close(None, ""))
+            .await
+            .unwrap();
+        let message = shard
+            .next_borrowed::<MessageCreateRef>(EventType::MessageCreate)
+            .await;
+        assert!(
+            matches!(message, Some(Ok(BorrowedMessage::Close(None)))),
+            "{message:?}"
+        );
+    }
+
+    /// Test that compressed messages exceeding the maximum size once
+    /// decompressed return an error, and that the shard closes the connection
+    /// to resume.
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    #[tokio::test]
+    async fn message_too_large_compressed() {
+        use crate::{
+            error::ReceiveMessageErrorType,
+            test::{compress, Gateway, HELLO},
+            CloseFrame, Compression, ConfigBuilder, Intents, Message, Session, ShardId,
+        };
+        use futures_util::{SinkExt, StreamExt};
+        use tokio_websockets::Message as WebsocketMessage;
+
+        let gateway = Gateway::bind().await;
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .compression(Compression::ZlibStream)
+            .max_message_size(HELLO.len())
+            .proxy_url(gateway.url())
+            .session(Session::new(1, "id".to_owned()))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        let (mut connection, message) = tokio::join!(gateway.accept(), shard.next());
+        assert!(matches!(message, Some(Ok(Message::Text(_)))));
+
+        let compressed = compress(" ".repeat(1024).as_bytes());
+        assert!(compressed.len() <= HELLO.len());
+
+        connection
+            .send(WebsocketMessage::binary(compressed))
+            .await
+            .unwrap();
+        let error = shard.next().await.unwrap().unwrap_err();
+        assert!(
+            matches!(error.kind(), ReceiveMessageErrorType::MessageTooLarge),
+            "{error:?}"
+        );
+
+        // The zlib context is unusable, so the shard reconnects to resume.
+        let (message, close_code) = tokio::join!(shard.next(), async {
+            // Skip the resume sent in reply to the hello.
+            let mut message = connection.next().await.unwrap().unwrap();
+            while !message.is_close() {
+                message = connection.next().await.unwrap().unwrap();
+            }
+            let close_code = message.as_close().map(|(code, _)| u16::from(code));
+            // Reply to the close frame.
+            while connection.next().await.is_some() {}
+
+            close_code
+        });
+        assert_eq!(Some(CloseFrame::RESUME.code), close_code);
+        assert!(matches!(message, Some(Ok(Message::Close(_)))));
+    }
+
+    /// Test that the identify payload contains the configured properties,
+    /// falling back to the default properties.
+    #[tokio::test]
+    async fn identify_properties() {
+        use crate::{ConfigBuilder, Intents, ShardId};
+        use std::env::consts::OS;
+        use twilight_model::gateway::payload::outgoing::identify::IdentifyProperties;
+
+        let builder = || ConfigBuilder::new("token".to_owned(), Intents::empty());
+        let properties = IdentifyProperties::new("Discord Client", "desktop", "Windows");
+        let config = builder().identify_properties(properties.clone()).build();
+
+        let identify = super::identify(&config, ShardId::new(1, 2));
+        assert_eq!(properties, identify.d.properties);
+
+        let value = serde_json::to_value(&identify).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "browser": "Discord Client",
+                "device": "desktop",
+                "os": "Windows",
+            }),
+            value["d"]["properties"]
+        );
+        assert_eq!(serde_json::json!([1, 2]), value["d"]["shard"]);
+
+        let identify = super::identify(&builder().build(), ShardId::ONE);
+        assert_eq!(
+            IdentifyProperties::new("twilight.rs", "twilight.rs", OS),
+            identify.d.properties
+        );
+    }
+
+    /// Test that commands sent by the user pass through the command
+    /// interceptor exactly once before they're sent to the gateway.
+    #[tokio::test]
+    async fn command_interceptor() {
+        use crate::{
+            test::{next_text, Gateway},
+            ConfigBuilder, Intents, OutgoingCommand, Session, ShardId,
+        };
+        use futures_util::{SinkExt, StreamExt};
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+        use tokio_websockets::Message as WebsocketMessage;
+        use twilight_model::gateway::{
+            payload::outgoing::UpdatePresence,
+            presence::{ActivityType, MinimalActivity, Status},
+        };
+
+        const RESUMED: &str = r#"{"op":0,"s":2,"t":"RESUMED","d":null}"#;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let interceptor_calls = Arc::clone(&calls);
+        let gateway = Gateway::bind().await;
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .command_interceptor(Arc::new(move |command: &mut OutgoingCommand| {
+                interceptor_calls.fetch_add(1, Ordering::Relaxed);
+
+                if let OutgoingCommand::UpdatePresence(presence) = command {
+                    presence.d.status = Status::Idle;
+                }
+            }))
+            .proxy_url(gateway.url())
+            .session(Session::new(1, "id".to_owned()))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        // Receive the hello and resume the session.
+        let (mut connection, _) = tokio::join!(gateway.accept(), shard.next());
+        tokio::select! {
+            text = next_text(&mut connection) => assert!(text.unwrap().contains(r#""op":6"#)),
+            message = shard.next() => panic!("{message:?}"),
+        }
+        connection
+            .send(WebsocketMessage::text(RESUMED))
+            .await
+            .unwrap();
+        shard.next().await.unwrap().unwrap();
+
+        let activity = MinimalActivity {
+            kind: ActivityType::Playing,
+            name: "twilight".to_owned(),
+            url: None,
+        };
+        let presence = UpdatePresence::new([activity.into()], false, None, Status::Online).unwrap();
+        shard.command(&presence);
+        shard.sender().command(&presence).unwrap();
+
+        for _ in 0..2 {
+            let json = tokio::select! {
+                text = next_text(&mut connection) => text.unwrap(),
+                message = shard.next() => panic!("{message:?}"),
+            };
+            assert!(json.contains(r#""status":"idle""#), "{json}");
+        }
+        assert_eq!(2, calls.load(Ordering::Relaxed));
+    }
+
+    /// Test that commands sent by the shard itself only pass through the
+    /// command interceptor if opted into.
+    #[tokio::test]
+    async fn command_interceptor_internal() {
+        use crate::{ConfigBuilder, Intents, OutgoingCommand, Session, ShardId};
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":41250}}"#;
+
+        for intercept_internal_commands in [false, true] {
+            let calls = Arc::new(AtomicUsize::new(0));
+            let interceptor_calls = Arc::clone(&calls);
+            let config = ConfigBuilder::new(String::new(), Intents::empty())
+                .command_interceptor(Arc::new(move |_: &mut OutgoingCommand| {
+                    interceptor_calls.fetch_add(1, Ordering::Relaxed);
+                }))
+                .intercept_internal_commands(intercept_internal_commands)
+                .session(Session::new(1, "id".to_owned()))
+                .build();
+            let mut shard = Shard::with_config(ShardId::ONE, config);
+
+            // Queues a resume.
+            shard.process(HELLO).unwrap();
+            assert!(shard.pending.is_some());
+            assert_eq!(
+                usize::from(intercept_internal_commands),
+                calls.load(Ordering::Relaxed)
+            );
+        }
+    }
+
+    /// Test that dispatch events of unknown types advance the sequence and
+    /// parse into unknown events instead of failing.
+    #[tokio::test]
+    async fn unknown_dispatch() {
+        use crate::{json, ConfigBuilder, EventTypeFlags, Intents, Session, ShardId};
+        use twilight_model::gateway::event::{DispatchEvent, GatewayEvent};
+
+        const EVENT: &str = r#"{"op":0,"s":5,"t":"SOME_FUTURE_EVENT","d":{"id":"1"}}"#;
+
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .session(Session::new(4, "id".to_owned()))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        shard.process(EVENT).unwrap();
+        assert_eq!(Some(5), shard.session().map(Session::sequence));
+
+        let event = json::parse(EVENT.to_owned(), EventTypeFlags::all())
+            .unwrap()
+            .unwrap();
+        let GatewayEvent::Dispatch(5, DispatchEvent::Unknown(event)) = event else {
+            panic!("event is not unknown: {event:?}");
+        };
+        assert_eq!("SOME_FUTURE_EVENT", event.kind);
+    }
+
+    /// Test that sequence updates are debounced while new and invalidated
+    /// sessions are published immediately.
+    #[tokio::test(start_paused = true)]
+    async fn session_watch() {
+        use crate::{ConfigBuilder, Intents, Session, ShardId};
+        use std::time::Duration;
+
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .session(Session::new(1, "id".to_owned()))
+            .session_update_interval(Duration::from_secs(5))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+        let mut session_watch = shard.session_watch();
+        assert_eq!(
+            session_watch
+                .borrow_and_update()
+                .as_ref()
+                .map(Session::sequence),
+            Some(1)
+        );
+
+        shard.session.as_mut().unwrap().set_sequence(2);
+        shard.publish_session(false);
+        assert!(!session_watch.has_changed().unwrap());
+
+        tokio::time::advance(Duration::from_secs(5)).await;
+        shard.session.as_mut().unwrap().set_sequence(3);
+        shard.publish_session(false);
+        assert!(session_watch.has_changed().unwrap());
+        assert_eq!(
+            session_watch
+                .borrow_and_update()
+                .as_ref()
+                .map(Session::sequence),
+            Some(3)
+        );
+
+        shard.session.as_mut().unwrap().set_sequence(4);
+        shard.publish_session(false);
+        assert!(!session_watch.has_changed().unwrap());
+
+        shard.session = None;
+        shard.publish_session(true);
+        assert!(session_watch.borrow_and_update().is_none());
+    }
 }
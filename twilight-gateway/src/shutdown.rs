@@ -0,0 +1,281 @@
+//! Utilities for gracefully closing a group of shards.
+//!
+//! Calling [`Shard::close`] only queues a close frame; the WebSocket
+//! connection is not closed until the shard is polled long enough for the
+//! close handshake to complete. Exiting the process before then leaves the
+//! gateway with half-open connections. [`close_gracefully`] and
+//! [`close_resumable`] drive the shards until every connection has closed or
+//! a timeout elapses.
+
+use crate::{queue::Queue, Message, Session, Shard, ShardId};
+use futures_core::Stream;
+use std::{
+    future::{poll_fn, Future},
+    pin::{pin, Pin},
+    task::Poll,
+};
+use tokio::time::{self, Duration};
+use twilight_model::gateway::CloseFrame;
+
+/// Outcome of closing a [`Shard`] via [`close_gracefully`] or
+/// [`close_resumable`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CloseReport {
+    /// Whether the WebSocket close handshake completed before the timeout.
+    clean: bool,
+    /// ID of the shard.
+    id: ShardId,
+    /// Session retained by the shard after closing.
+    session: Option<Session>,
+}
+
+impl CloseReport {
+    /// ID of the shard.
+    pub const fn id(&self) -> ShardId {
+        self.id
+    }
+
+    /// Whether the shard's connection closed cleanly.
+    ///
+    /// A connection is considered closed cleanly if the gateway acknowledged
+    /// the close frame before the timeout elapsed. Shards that weren't
+    /// connected are always considered closed cleanly.
+    pub const fn is_clean(&self) -> bool {
+        self.clean
+    }
+
+    /// Session retained by the shard after closing, if any.
+    ///
+    /// Only present if the session can be resumed, which is the case when
+    /// closing with a close frame other than `1000` or `1001`, such as
+    /// [`CloseFrame::RESUME`]. Pass it to [`ConfigBuilder::session`] to resume
+    /// the session after restarting.
+    ///
+    /// [`ConfigBuilder::session`]: crate::ConfigBuilder::session
+    pub const fn session(&self) -> Option<&Session> {
+        self.session.as_ref()
+    }
+
+    /// Consume the report, returning the retained session, if any.
+    pub fn into_session(self) -> Option<Session> {
+        self.session
+    }
+}
+
+/// Close every shard and wait until their connections have closed or the
+/// timeout elapses.
+///
+/// Sends the close frame to every connected shard and then concurrently polls
+/// them until the gateway acknowledges the close. Messages received in the
+/// meantime are discarded. Shards that aren't connected are left untouched.
+///
+/// Returns a report for every shard, in the same order as the provided
+/// shards.
+///
+/// # Examples
+///
+/// Close all shards, allowing up to 5 seconds for the connections to close:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::{env, time::Duration};
+/// use twilight_gateway::{CloseFrame, Config, Intents};
+///
+/// let token = env::var("DISCORD_TOKEN")?;
+/// let config = Config::new(token, Intents::GUILDS);
+/// let mut shards =
+///     twilight_gateway::create_iterator(0..2, 2, config, |_, builder| builder.build())
+///         .collect::<Vec<_>>();
+///
+/// // Run the shards until the application should shut down.
+///
+/// let reports = twilight_gateway::close_gracefully(
+///     &mut shards,
+///     CloseFrame::NORMAL,
+///     Duration::from_secs(5),
+/// )
+/// .await;
+///
+/// for report in reports {
+///     println!("shard {} closed cleanly: {}", report.id(), report.is_clean());
+/// }
+/// # Ok(()) }
+/// ```
+pub async fn close_gracefully<Q: Queue + Unpin>(
+    shards: &mut [Shard<Q>],
+    close_frame: CloseFrame<'static>,
+    timeout: Duration,
+) -> Vec<CloseReport> {
+    let mut closed = shards
+        .iter()
+        .map(|shard| {
+            let connected = shard.is_connected();
+            if connected {
+                shard.close(close_frame.clone());
+            }
+
+            // Only connected shards need to complete the close handshake.
+            (!connected).then_some(true)
+        })
+        .collect::<Vec<_>>();
+
+    let mut sleep = pin!(time::sleep(timeout));
+
+    poll_fn(|cx| {
+        for (shard, closed) in shards.iter_mut().zip(closed.iter_mut()) {
+            while closed.is_none() {
+                match Pin::new(&mut *shard).poll_next(cx) {
+                    Poll::Ready(Some(Ok(message @ Message::Close(_)))) => {
+                        *closed = Some(message != Message::ABNORMAL_CLOSE);
+                    }
+                    Poll::Ready(Some(Ok(Message::Text(_)) | Err(_))) => {}
+                    Poll::Ready(None) => *closed = Some(false),
+                    Poll::Pending => break,
+                }
+            }
+        }
+
+        if closed.iter().all(Option::is_some) || sleep.as_mut().poll(cx).is_ready() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await;
+
+    shards
+        .iter()
+        .zip(closed)
+        .map(|(shard, closed)| {
+            if closed.is_none() {
+                tracing::debug!(id = %shard.id(), "shard did not close before the timeout");
+            }
+
+            CloseReport {
+                clean: closed.unwrap_or_default(),
+                id: shard.id(),
+                session: shard.session().cloned(),
+            }
+        })
+        .collect()
+}
+
+/// Close every shard such that their sessions may be resumed and wait until
+/// their connections have closed or the timeout elapses.
+///
+/// Equivalent to calling [`close_gracefully`] with [`CloseFrame::RESUME`].
+/// The retained sessions are available via [`CloseReport::session`].
+pub async fn close_resumable<Q: Queue + Unpin>(
+    shards: &mut [Shard<Q>],
+    timeout: Duration,
+) -> Vec<CloseReport> {
+    close_gracefully(shards, CloseFrame::RESUME, timeout).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{close_gracefully, close_resumable, CloseReport};
+    use crate::{
+        queue::InMemoryQueue,
+        test::{next_text, Gateway},
+        ConfigBuilder, Intents, Message, Session, Shard, ShardId,
+    };
+    use futures_util::StreamExt;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+    use twilight_model::gateway::CloseFrame;
+
+    assert_impl_all!(CloseReport: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    fn shard(session: Option<Session>) -> Shard {
+        let mut builder = ConfigBuilder::new(String::new(), Intents::empty());
+        if let Some(session) = session {
+            builder = builder.session(session);
+        }
+
+        Shard::with_config(ShardId::ONE, builder.build())
+    }
+
+    /// Shard resuming a session at the mock gateway, connected once the hello
+    /// payload has been received.
+    async fn connected_shard(gateway: &Gateway, session: Session) -> Shard {
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .proxy_url(gateway.url())
+            .session(session)
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        assert!(matches!(shard.next().await, Some(Ok(Message::Text(_)))));
+
+        shard
+    }
+
+    /// Test that shards that aren't connected are immediately reported as
+    /// closed, retaining their sessions.
+    #[tokio::test]
+    async fn disconnected() {
+        let session = Session::new(1, "id".to_owned());
+        let mut shards = [shard(Some(session.clone())), shard(None)];
+
+        let reports = close_resumable(&mut shards, Duration::from_secs(60)).await;
+        assert_eq!(reports.len(), 2);
+        assert!(reports.iter().all(CloseReport::is_clean));
+        assert_eq!(reports[0].session(), Some(&session));
+        assert!(reports[1].session().is_none());
+
+        let reports =
+            close_gracefully::<InMemoryQueue>(&mut [], CloseFrame::NORMAL, Duration::ZERO).await;
+        assert!(reports.is_empty());
+    }
+
+    /// Test that connected shards are reported as closed cleanly once the
+    /// gateway acknowledges the close frame, retaining resumable sessions.
+    #[tokio::test]
+    async fn connected() {
+        let gateway = Gateway::bind().await;
+        let session = Session::new(1, "id".to_owned());
+
+        let (mut connection, shard) =
+            tokio::join!(gateway.accept(), connected_shard(&gateway, session.clone()));
+        let server = tokio::spawn(async move {
+            assert!(next_text(&mut connection)
+                .await
+                .unwrap()
+                .contains(r#""op":6"#));
+            // Close frames are replied to while draining the connection.
+            while connection.next().await.is_some() {}
+        });
+
+        let mut shards = [shard];
+        let reports = close_resumable(&mut shards, Duration::from_secs(60)).await;
+        assert!(reports[0].is_clean());
+        assert_eq!(reports[0].session(), Some(&session));
+
+        server.await.unwrap();
+    }
+
+    /// Test that connected shards are reported as not closed cleanly if the
+    /// gateway doesn't acknowledge the close frame before the timeout.
+    #[tokio::test]
+    async fn connected_timeout() {
+        let gateway = Gateway::bind().await;
+        let session = Session::new(1, "id".to_owned());
+
+        let (mut connection, shard) =
+            tokio::join!(gateway.accept(), connected_shard(&gateway, session));
+        let mut shards = [shard];
+        let reports = tokio::join!(
+            close_gracefully(&mut shards, CloseFrame::NORMAL, Duration::from_millis(100)),
+            // Receive the resume and the close frame without replying.
+            async {
+                assert!(next_text(&mut connection).await.is_some());
+                assert!(connection.next().await.unwrap().unwrap().is_close());
+            },
+        )
+        .0;
+
+        assert!(!reports[0].is_clean());
+        // Closing with a normal close frame invalidates the session.
+        assert!(reports[0].session().is_none());
+    }
+}
@@ -0,0 +1,74 @@
+//! Utilities for testing shards against a mock gateway.
+
+use futures_util::{SinkExt, StreamExt};
+use std::net::SocketAddr;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_websockets::{Message, ServerBuilder, WebSocketStream};
+
+/// Hello payload with a heartbeat interval long enough to not elapse during
+/// tests.
+pub const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":41250}}"#;
+
+/// Mock gateway accepting WebSocket connections on a local port.
+pub struct Gateway {
+    /// Listener accepting connections.
+    listener: TcpListener,
+}
+
+impl Gateway {
+    /// Bind the mock gateway to a random local port.
+    pub async fn bind() -> Self {
+        let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+            .await
+            .unwrap();
+
+        Self { listener }
+    }
+
+    /// URL of the mock gateway, to be configured as the shard's proxy URL.
+    pub fn url(&self) -> String {
+        format!("ws://{}", self.listener.local_addr().unwrap())
+    }
+
+    /// Accept a connection and send the hello payload.
+    pub async fn accept(&self) -> WebSocketStream<TcpStream> {
+        self.accept_uri().await.1
+    }
+
+    /// Accept a connection and send the hello payload, also returning the
+    /// requested URI.
+    pub async fn accept_uri(&self) -> (String, WebSocketStream<TcpStream>) {
+        let (stream, _) = self.listener.accept().await.unwrap();
+        let (request, mut connection) = ServerBuilder::new().accept(stream).await.unwrap();
+        connection.send(Message::text(HELLO)).await.unwrap();
+
+        (request.uri().to_string(), connection)
+    }
+}
+
+/// Receive the next text message from the shard, skipping other messages.
+pub async fn next_text(connection: &mut WebSocketStream<TcpStream>) -> Option<String> {
+    while let Some(message) = connection.next().await {
+        if let Some(text) = message.ok()?.as_text() {
+            return Some(text.to_owned());
+        }
+    }
+
+    None
+}
+
+/// Compress a message as sent with [`Compression::ZlibStream`], as the first
+/// message of the stream.
+///
+/// [`Compression::ZlibStream`]: crate::Compression::ZlibStream
+#[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+pub fn compress(message: &[u8]) -> Vec<u8> {
+    use flate2::{Compress, Compression, FlushCompress};
+
+    let mut compressed = Vec::with_capacity(message.len() + 64);
+    Compress::new(Compression::default(), true)
+        .compress_vec(message, &mut compressed, FlushCompress::Sync)
+        .unwrap();
+
+    compressed
+}
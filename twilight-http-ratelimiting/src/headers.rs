@@ -376,6 +376,11 @@ impl TryFrom<&'_ str> for RatelimitScope {
 pub enum RatelimitHeaders {
     /// Global ratelimit encountered.
     Global(Global),
+    /// Ratelimit headers present but partially missing or malformed.
+    ///
+    /// Not returned by [`RatelimitHeaders::from_pairs`], which errors instead,
+    /// but sent by API clients to the ratelimiter when parsing fails.
+    Invalid,
     /// No ratelimit headers present.
     None,
     /// Bucketed ratelimit present.
@@ -389,6 +394,12 @@ impl RatelimitHeaders {
         matches!(self, Self::Global(_))
     }
 
+    /// Whether the ratelimit headers are partially missing or malformed.
+    #[must_use]
+    pub const fn is_invalid(&self) -> bool {
+        matches!(self, Self::Invalid)
+    }
+
     /// Whether there are no ratelimit headers.
     #[must_use]
     pub const fn is_none(&self) -> bool {
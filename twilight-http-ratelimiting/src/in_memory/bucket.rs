@@ -37,6 +37,8 @@ pub enum TimeRemaining {
 /// A generic version not specific to this ratelimiter is [`crate::Bucket`].
 #[derive(Debug)]
 pub struct Bucket {
+    /// Number of responses with ratelimit headers that failed to parse.
+    pub header_failures: AtomicU64,
     /// Total number of tickets allotted in a cycle.
     pub limit: AtomicU64,
     /// Path this ratelimit applies to.
@@ -57,6 +59,7 @@ impl Bucket {
     /// Create a new bucket for the specified [`Path`].
     pub fn new(path: Path) -> Self {
         Self {
+            header_failures: AtomicU64::new(0),
             limit: AtomicU64::new(u64::MAX),
             path,
             queue: BucketQueue::default(),
@@ -66,6 +69,11 @@ impl Bucket {
         }
     }
 
+    /// Number of responses with ratelimit headers that failed to parse.
+    pub fn header_failures(&self) -> u64 {
+        self.header_failures.load(Ordering::Relaxed)
+    }
+
     /// Total number of tickets allotted in a cycle.
     pub fn limit(&self) -> u64 {
         self.limit.load(Ordering::Relaxed)
@@ -259,6 +267,11 @@ impl BucketQueueTask {
 
                 None
             }
+            RatelimitHeaders::Invalid => {
+                self.bucket.header_failures.fetch_add(1, Ordering::Relaxed);
+
+                return;
+            }
             RatelimitHeaders::None => return,
             RatelimitHeaders::Present(present) => {
                 Some((present.limit(), present.remaining(), present.reset_after()))
@@ -115,12 +115,15 @@ impl Ratelimiter for InMemoryRatelimiter {
                     let started_at = bucket.started_at.lock().expect("bucket poisoned");
                     let reset_after = Duration::from_millis(bucket.reset_after());
 
-                    Box::pin(future::ready(Ok(Some(InfoBucket::new(
-                        bucket.limit(),
-                        bucket.remaining(),
-                        reset_after,
-                        *started_at,
-                    )))))
+                    Box::pin(future::ready(Ok(Some(
+                        InfoBucket::new(
+                            bucket.limit(),
+                            bucket.remaining(),
+                            reset_after,
+                            *started_at,
+                        )
+                        .with_header_failures(bucket.header_failures()),
+                    ))))
                 },
             )
     }
@@ -34,6 +34,8 @@ use std::{
 
 /// A bucket containing ratelimiting information for a [`Path`].
 pub struct Bucket {
+    /// Number of responses with ratelimit headers that failed to parse.
+    header_failures: u64,
     /// Total number of tickets allotted in a cycle.
     limit: u64,
     /// Number of tickets remaining.
@@ -60,6 +62,7 @@ impl Bucket {
         started_at: Option<Instant>,
     ) -> Self {
         Self {
+            header_failures: 0,
             limit,
             remaining,
             reset_after,
@@ -67,6 +70,25 @@ impl Bucket {
         }
     }
 
+    /// Set the number of responses with ratelimit headers that failed to
+    /// parse.
+    #[must_use]
+    pub const fn with_header_failures(mut self, header_failures: u64) -> Self {
+        self.header_failures = header_failures;
+
+        self
+    }
+
+    /// Number of responses with ratelimit headers that failed to parse.
+    ///
+    /// Headers that are partially missing or malformed are sent to the
+    /// ratelimiter as [`RatelimitHeaders::Invalid`], in which case the bucket
+    /// isn't updated.
+    #[must_use]
+    pub const fn header_failures(&self) -> u64 {
+        self.header_failures
+    }
+
     /// Total number of tickets allotted in a cycle.
     #[must_use]
     pub const fn limit(&self) -> u64 {
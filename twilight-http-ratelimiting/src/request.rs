@@ -306,6 +306,41 @@ pub enum Path {
     WebhooksIdTokenMessagesId(u64, String),
 }
 
+impl Path {
+    /// Copy of the path with webhook tokens removed, such as for labelling
+    /// metrics by path.
+    ///
+    /// Webhook tokens are part of the ratelimit bucket of a path but are
+    /// secret, and interaction tokens differ for every interaction. They are
+    /// replaced with an empty string, so all token paths of a webhook or
+    /// application are equal. Other paths are returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use twilight_http_ratelimiting::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let path = Path::from_str("webhooks/123/token")?;
+    /// assert_eq!(
+    ///     Path::WebhooksIdToken(123, String::new()),
+    ///     path.without_token()
+    /// );
+    /// # Ok(()) }
+    /// ```
+    #[must_use = "creating a path without the token has no effect if left unused"]
+    pub fn without_token(&self) -> Self {
+        match self {
+            Self::WebhooksIdToken(id, _) => Self::WebhooksIdToken(*id, String::new()),
+            Self::WebhooksIdTokenMessagesId(id, _) => {
+                Self::WebhooksIdTokenMessagesId(*id, String::new())
+            }
+            path => path.clone(),
+        }
+    }
+}
+
 impl FromStr for Path {
     type Err = PathParseError;
 
@@ -542,6 +577,28 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn without_token() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            Path::WebhooksIdToken(123, String::new()),
+            Path::from_str("webhooks/123/token")?.without_token(),
+        );
+        assert_eq!(
+            Path::WebhooksIdTokenMessagesId(123, String::new()),
+            Path::from_str("webhooks/123/other/messages/456")?.without_token(),
+        );
+        assert_eq!(
+            Path::WebhooksId(123),
+            Path::from_str("webhooks/123")?.without_token(),
+        );
+        assert_eq!(
+            Path::InteractionCallback(123),
+            Path::from_str("interactions/123/token/callback")?.without_token(),
+        );
+
+        Ok(())
+    }
+
     assert_impl_all!(Method: Clone, Copy, Debug, Eq, PartialEq);
 
     #[test]
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use serde_json::{Map, Value};
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
@@ -12,6 +16,74 @@ pub enum ApiError {
     Message(MessageApiError),
 }
 
+impl ApiError {
+    /// JSON error code of the error, if it's a [`General`] error.
+    ///
+    /// [`General`]: Self::General
+    pub const fn code(&self) -> Option<u64> {
+        match self {
+            Self::General(inner) => Some(inner.code),
+            _ => None,
+        }
+    }
+
+    /// Flattened list of errors of invalid fields, if it's a [`General`]
+    /// error.
+    ///
+    /// Refer to [`GeneralApiError::field_errors`] for more information.
+    ///
+    /// [`General`]: Self::General
+    pub fn field_errors(&self) -> Vec<FieldError> {
+        match self {
+            Self::General(inner) => inner.field_errors(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Whether the error is due to sending a sticker the application can't
+    /// use, such as a guild sticker from another guild.
+    ///
+    /// Matches the error codes `50081` (invalid sticker sent) and `50600`
+    /// (missing permission to send the sticker).
+    pub const fn is_cannot_use_sticker(&self) -> bool {
+        matches!(self.code(), Some(50081 | 50600))
+    }
+
+    /// Whether the error is due to pinning a message in a channel that already
+    /// has the maximum of 50 pinned messages.
+    ///
+    /// Matches the error code `30003` (maximum number of pins reached).
+    pub const fn is_maximum_pins_reached(&self) -> bool {
+        matches!(self.code(), Some(30003))
+    }
+
+    /// Whether the error is due to using an external emoji without the
+    /// [`USE_EXTERNAL_EMOJIS`] permission.
+    ///
+    /// Discord reports emojis the application can't use as unknown, so this
+    /// matches the error code `10014` (unknown emoji).
+    ///
+    /// [`USE_EXTERNAL_EMOJIS`]: twilight_model::guild::Permissions::USE_EXTERNAL_EMOJIS
+    pub const fn is_missing_external_emoji_permission(&self) -> bool {
+        matches!(self.code(), Some(10014))
+    }
+
+    /// Whether the error is due to a sticker not existing.
+    ///
+    /// Matches the error code `10060` (unknown sticker).
+    pub const fn is_unknown_sticker(&self) -> bool {
+        matches!(self.code(), Some(10060))
+    }
+
+    /// Whether the error is due to a vanity URL code being invalid or already
+    /// used by another guild.
+    ///
+    /// Matches the error code `50020` (invite code invalid or taken).
+    pub const fn is_vanity_code_taken(&self) -> bool {
+        matches!(self.code(), Some(50020))
+    }
+}
+
 impl Display for ApiError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
@@ -26,9 +98,40 @@ impl Display for ApiError {
 #[non_exhaustive]
 pub struct GeneralApiError {
     pub code: u64,
+    /// Nested object of errors of invalid fields.
+    ///
+    /// Use [`field_errors`] to flatten this into a list.
+    ///
+    /// [`field_errors`]: Self::field_errors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Value>,
     pub message: String,
 }
 
+impl GeneralApiError {
+    /// Flatten the nested object of [`errors`] into a list of errors of
+    /// invalid fields.
+    ///
+    /// Discord nests errors by the path of the invalid field, with errors
+    /// listed under `_errors` keys. Paths are rendered with array indexes in
+    /// brackets and object keys separated by dots, such as
+    /// `options[3].choices[0].name`.
+    ///
+    /// Parts of [`errors`] that don't match this structure are skipped; the
+    /// raw [`errors`] remain available.
+    ///
+    /// [`errors`]: Self::errors
+    pub fn field_errors(&self) -> Vec<FieldError> {
+        let mut field_errors = Vec::new();
+
+        if let Some(Value::Object(errors)) = &self.errors {
+            flatten_field_errors(errors, &mut String::new(), &mut field_errors);
+        }
+
+        field_errors
+    }
+}
+
 impl Display for GeneralApiError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.write_str("Error code ")?;
@@ -39,6 +142,96 @@ impl Display for GeneralApiError {
     }
 }
 
+/// Error of an invalid field within a [`GeneralApiError`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct FieldError {
+    /// Error code, such as `BASE_TYPE_REQUIRED`.
+    pub code: String,
+    /// Human readable message provided by the API.
+    pub message: String,
+    /// Path of the invalid field, such as `options[3].choices[0].name`.
+    ///
+    /// Empty if the error is about the request body as a whole.
+    pub path: String,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if !self.path.is_empty() {
+            f.write_str(&self.path)?;
+            f.write_str(": ")?;
+        }
+
+        f.write_str(&self.message)?;
+        f.write_str(" (")?;
+        f.write_str(&self.code)?;
+
+        f.write_str(")")
+    }
+}
+
+/// Whether a key of a nested object of errors is an array index.
+fn is_index(key: &str) -> bool {
+    !key.is_empty() && key.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// Recursively collect the errors under `_errors` keys in a nested object of
+/// errors.
+///
+/// Keys are visited with array indexes first in numeric order, such that
+/// `options[2]` precedes `options[10]`, followed by the other keys in
+/// lexicographic order.
+fn flatten_field_errors(errors: &Map<String, Value>, path: &mut String, out: &mut Vec<FieldError>) {
+    let mut entries = errors.iter().collect::<Vec<_>>();
+    entries.sort_by(|(a, _), (b, _)| match (is_index(a), is_index(b)) {
+        // Indexes don't have leading zeros, so longer indexes are larger.
+        (true, true) => a.len().cmp(&b.len()).then_with(|| a.cmp(b)),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => a.cmp(b),
+    });
+
+    for (key, value) in entries {
+        if key == "_errors" {
+            let Value::Array(leaves) = value else {
+                continue;
+            };
+
+            out.extend(leaves.iter().filter_map(|leaf| {
+                Some(FieldError {
+                    code: leaf.get("code")?.as_str()?.to_owned(),
+                    message: leaf.get("message")?.as_str()?.to_owned(),
+                    path: path.clone(),
+                })
+            }));
+
+            continue;
+        }
+
+        let Value::Object(nested) = value else {
+            continue;
+        };
+
+        let len = path.len();
+
+        if is_index(key) {
+            path.push('[');
+            path.push_str(key);
+            path.push(']');
+        } else {
+            if !path.is_empty() {
+                path.push('.');
+            }
+
+            path.push_str(key);
+        }
+
+        flatten_field_errors(nested, path, out);
+        path.truncate(len);
+    }
+}
+
 /// Sending a message failed because the provided fields contained invalid
 /// input.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -131,7 +324,8 @@ impl PartialEq for RatelimitedApiError {
 #[cfg(test)]
 mod tests {
     use super::{
-        ApiError, GeneralApiError, MessageApiError, MessageApiErrorEmbedField, RatelimitedApiError,
+        ApiError, FieldError, GeneralApiError, MessageApiError, MessageApiErrorEmbedField,
+        RatelimitedApiError,
     };
     use serde_test::Token;
 
@@ -139,6 +333,7 @@ mod tests {
     fn api_error_deser() {
         let expected = GeneralApiError {
             code: 10001,
+            errors: None,
             message: "Unknown account".to_owned(),
         };
 
@@ -252,4 +447,243 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn api_error_codes() {
+        let error = |code| {
+            ApiError::General(GeneralApiError {
+                code,
+                errors: None,
+                message: String::new(),
+            })
+        };
+
+        assert_eq!(error(10060).code(), Some(10060));
+        assert!(error(10060).is_unknown_sticker());
+        assert!(error(50081).is_cannot_use_sticker());
+        assert!(error(50600).is_cannot_use_sticker());
+        assert!(error(10014).is_missing_external_emoji_permission());
+        assert!(error(50020).is_vanity_code_taken());
+        assert!(error(30003).is_maximum_pins_reached());
+
+        assert!(!error(10001).is_unknown_sticker());
+        assert!(!error(10001).is_cannot_use_sticker());
+        assert!(!error(10001).is_missing_external_emoji_permission());
+        assert!(!error(10001).is_vanity_code_taken());
+        assert!(!error(10001).is_maximum_pins_reached());
+
+        let ratelimited = ApiError::Ratelimited(RatelimitedApiError {
+            global: false,
+            message: String::new(),
+            retry_after: 1.0,
+        });
+        assert!(ratelimited.code().is_none());
+        assert!(!ratelimited.is_unknown_sticker());
+    }
+
+    fn field_error(path: &str, code: &str, message: &str) -> FieldError {
+        FieldError {
+            code: code.to_owned(),
+            message: message.to_owned(),
+            path: path.to_owned(),
+        }
+    }
+
+    #[test]
+    fn field_errors_command() {
+        let body = r#"{
+            "code": 50035,
+            "errors": {
+                "options": {
+                    "3": {
+                        "choices": {
+                            "0": {
+                                "name": {
+                                    "_errors": [
+                                        {
+                                            "code": "BASE_TYPE_REQUIRED",
+                                            "message": "This field is required"
+                                        }
+                                    ]
+                                }
+                            },
+                            "12": {
+                                "value": {
+                                    "_errors": [
+                                        {
+                                            "code": "BASE_TYPE_MAX_LENGTH",
+                                            "message": "Must be 100 or fewer in length."
+                                        }
+                                    ]
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "message": "Invalid Form Body"
+        }"#;
+
+        let error = serde_json::from_str::<ApiError>(body).unwrap();
+
+        assert_eq!(Some(50035), error.code());
+        assert_eq!(
+            vec![
+                field_error(
+                    "options[3].choices[0].name",
+                    "BASE_TYPE_REQUIRED",
+                    "This field is required"
+                ),
+                field_error(
+                    "options[3].choices[12].value",
+                    "BASE_TYPE_MAX_LENGTH",
+                    "Must be 100 or fewer in length."
+                ),
+            ],
+            error.field_errors()
+        );
+        assert_eq!(
+            "options[3].choices[0].name: This field is required (BASE_TYPE_REQUIRED)",
+            error.field_errors()[0].to_string()
+        );
+    }
+
+    #[test]
+    fn field_errors_embed() {
+        let body = r#"{
+            "code": 50035,
+            "errors": {
+                "_errors": [
+                    {
+                        "code": "MESSAGE_EMPTY",
+                        "message": "Cannot send an empty message"
+                    }
+                ],
+                "embeds": {
+                    "0": {
+                        "fields": {
+                            "1": {
+                                "value": {
+                                    "_errors": [
+                                        {
+                                            "code": "BASE_TYPE_REQUIRED",
+                                            "message": "This field is required"
+                                        },
+                                        {
+                                            "code": "BASE_TYPE_BAD_LENGTH",
+                                            "message": "Must be between 1 and 1024 in length."
+                                        }
+                                    ]
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "message": "Invalid Form Body"
+        }"#;
+
+        let error = serde_json::from_str::<ApiError>(body).unwrap();
+
+        assert_eq!(
+            vec![
+                field_error("", "MESSAGE_EMPTY", "Cannot send an empty message"),
+                field_error(
+                    "embeds[0].fields[1].value",
+                    "BASE_TYPE_REQUIRED",
+                    "This field is required"
+                ),
+                field_error(
+                    "embeds[0].fields[1].value",
+                    "BASE_TYPE_BAD_LENGTH",
+                    "Must be between 1 and 1024 in length."
+                ),
+            ],
+            error.field_errors()
+        );
+        assert_eq!(
+            "Cannot send an empty message (MESSAGE_EMPTY)",
+            error.field_errors()[0].to_string()
+        );
+    }
+
+    #[test]
+    fn field_errors_numeric_order() {
+        let body = r#"{
+            "code": 50035,
+            "errors": {
+                "options": {
+                    "10": {
+                        "name": {
+                            "_errors": [{"code": "A", "message": "ten"}]
+                        }
+                    },
+                    "2": {
+                        "name": {
+                            "_errors": [{"code": "A", "message": "two"}]
+                        }
+                    },
+                    "1": {
+                        "name": {
+                            "_errors": [{"code": "A", "message": "one"}]
+                        }
+                    }
+                }
+            },
+            "message": "Invalid Form Body"
+        }"#;
+
+        let error = serde_json::from_str::<ApiError>(body).unwrap();
+
+        assert_eq!(
+            vec![
+                field_error("options[1].name", "A", "one"),
+                field_error("options[2].name", "A", "two"),
+                field_error("options[10].name", "A", "ten"),
+            ],
+            error.field_errors()
+        );
+    }
+
+    #[test]
+    fn field_errors_malformed() {
+        let body = r#"{
+            "code": 50035,
+            "errors": {
+                "name": {
+                    "_errors": "not a list",
+                    "nested": ["not", "an", "object"]
+                },
+                "description": {
+                    "_errors": [{"code": 1}]
+                }
+            },
+            "message": "Invalid Form Body"
+        }"#;
+
+        let error = serde_json::from_str::<ApiError>(body).unwrap();
+        assert!(error.field_errors().is_empty());
+
+        let ApiError::General(general) = error else {
+            panic!("error is not general: {error:?}");
+        };
+        assert_eq!(
+            Some(serde_json::json!({
+                "name": {
+                    "_errors": "not a list",
+                    "nested": ["not", "an", "object"]
+                },
+                "description": {
+                    "_errors": [{"code": 1}]
+                }
+            })),
+            general.errors
+        );
+
+        let error = serde_json::from_str::<ApiError>(
+            r#"{"code":50035,"errors":["unknown"],"message":"Invalid Form Body"}"#,
+        )
+        .unwrap();
+        assert!(error.field_errors().is_empty());
+    }
 }
@@ -0,0 +1,432 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+};
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::{poll_fn, Future, IntoFuture},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::Poll,
+};
+use twilight_http_ratelimiting::request::Path;
+
+/// Default number of requests in flight at once.
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Function called with the index and result of each request.
+type Callback<'a, T> = Box<dyn FnMut(usize, &Result<Response<T>, Error>) + Send + 'a>;
+
+/// Iterator of the requests of a batch, converted as they're started.
+type Requests<'a> = Box<dyn Iterator<Item = Result<Request, Error>> + Send + 'a>;
+
+/// Progress of a [`RequestBatcher`].
+///
+/// Retrieved via [`RequestBatcher::progress`] before awaiting the batch, and
+/// may be sent to other tasks to report the progress.
+#[derive(Clone, Debug)]
+pub struct BatchProgress {
+    /// Number of requests that completed, successfully or not.
+    completed: Arc<AtomicUsize>,
+    /// Number of requests in the batch.
+    total: usize,
+}
+
+impl BatchProgress {
+    /// Number of requests that completed, successfully or not.
+    pub fn completed(&self) -> usize {
+        self.completed.load(Ordering::Relaxed)
+    }
+
+    /// Whether every request completed.
+    pub fn is_done(&self) -> bool {
+        self.completed() == self.total
+    }
+
+    /// Number of requests in the batch.
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+}
+
+/// Execute requests with bounded concurrency, collecting their results in
+/// order.
+///
+/// Requests are started in order, at most [`concurrency`] at once and at
+/// most [`per_bucket`] at once per ratelimit bucket. Requests of a saturated
+/// bucket wait without holding a slot, so requests of up to [`concurrency`]
+/// later requests of other buckets proceed instead of queueing in the
+/// ratelimiter. Requests are only taken from the iterator once they're about
+/// to be started.
+///
+/// Awaiting the batch resolves to the result of every request, in the same
+/// order as the requests. A failed request doesn't abort the batch.
+///
+/// # Examples
+///
+/// Add a role to members, printing the progress:
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+/// let (guild_id, role_id) = (Id::new(1), Id::new(2));
+/// let user_ids = [Id::new(3), Id::new(4), Id::new(5)];
+///
+/// let batch = client
+///     .batch(
+///         user_ids
+///             .into_iter()
+///             .map(|user_id| client.add_guild_member_role(guild_id, user_id, role_id)),
+///     )
+///     .concurrency(2);
+/// let progress = batch.progress();
+///
+/// let results = batch
+///     .on_item(move |index, result| {
+///         println!("{}/{}: {index} ok: {}", progress.completed(), progress.total(), result.is_ok());
+///     })
+///     .await;
+///
+/// for (user_id, result) in user_ids.iter().zip(results) {
+///     if let Err(source) = result {
+///         println!("failed to add role to {user_id}: {source}");
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`concurrency`]: Self::concurrency
+/// [`per_bucket`]: Self::per_bucket
+#[must_use = "requests are only executed when the batch is awaited"]
+pub struct RequestBatcher<'a, T> {
+    callback: Option<Callback<'a, T>>,
+    concurrency: usize,
+    http: &'a Client,
+    per_bucket: usize,
+    progress: BatchProgress,
+    requests: Requests<'a>,
+}
+
+impl<'a, T> RequestBatcher<'a, T> {
+    pub(crate) fn new<I, R>(http: &'a Client, requests: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        I::IntoIter: ExactSizeIterator + Send + 'a,
+        R: IntoFuture<IntoFuture = ResponseFuture<T>> + TryIntoRequest + 'a,
+    {
+        let requests = requests.into_iter();
+        let total = requests.len();
+
+        Self {
+            callback: None,
+            concurrency: DEFAULT_CONCURRENCY,
+            http,
+            per_bucket: usize::MAX,
+            progress: BatchProgress {
+                completed: Arc::new(AtomicUsize::new(0)),
+                total,
+            },
+            requests: Box::new(requests.map(TryIntoRequest::try_into_request)),
+        }
+    }
+
+    /// Set the maximum number of requests in flight at once.
+    ///
+    /// Values of `0` are treated as `1`. Defaults to 4.
+    pub const fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = if concurrency == 0 { 1 } else { concurrency };
+
+        self
+    }
+
+    /// Set a function to call with the index and result of each request as it
+    /// completes.
+    pub fn on_item(
+        mut self,
+        callback: impl FnMut(usize, &Result<Response<T>, Error>) + Send + 'a,
+    ) -> Self {
+        self.callback = Some(Box::new(callback));
+
+        self
+    }
+
+    /// Set the maximum number of requests in flight at once per ratelimit
+    /// bucket.
+    ///
+    /// Values of `0` are treated as `1`. Defaults to no limit other than the
+    /// [`concurrency`].
+    ///
+    /// [`concurrency`]: Self::concurrency
+    pub const fn per_bucket(mut self, per_bucket: usize) -> Self {
+        self.per_bucket = if per_bucket == 0 { 1 } else { per_bucket };
+
+        self
+    }
+
+    /// Progress of the batch.
+    pub fn progress(&self) -> BatchProgress {
+        self.progress.clone()
+    }
+}
+
+impl<T: Unpin> RequestBatcher<'_, T> {
+    /// Execute the requests, collecting their results in order.
+    async fn execute(self) -> Vec<Result<Response<T>, Error>> {
+        let Self {
+            mut callback,
+            concurrency,
+            http,
+            per_bucket,
+            progress,
+            requests,
+        } = self;
+
+        let mut results = Vec::with_capacity(progress.total);
+
+        let mut complete = |index: usize, result: Result<Response<T>, Error>| {
+            // Count the request first, so the callback sees it as completed.
+            progress.completed.fetch_add(1, Ordering::Relaxed);
+
+            if let Some(callback) = callback.as_mut() {
+                callback(index, &result);
+            }
+
+            if index >= results.len() {
+                results.resize_with(index + 1, || None);
+            }
+
+            results[index] = Some(result);
+        };
+
+        let mut requests = requests.enumerate();
+        // Requests taken from the iterator that wait for their bucket.
+        let mut pending = VecDeque::with_capacity(concurrency);
+        let mut in_flight = Vec::<(usize, Path, ResponseFuture<T>)>::with_capacity(concurrency);
+
+        loop {
+            while in_flight.len() < concurrency {
+                let position = pending.iter().position(|(_, request): &(usize, Request)| {
+                    in_flight
+                        .iter()
+                        .filter(|(_, path, _)| path == request.ratelimit_path())
+                        .count()
+                        < per_bucket
+                });
+
+                if let Some(position) = position {
+                    let (index, request) = pending.remove(position).expect("position is in bounds");
+                    let path = request.ratelimit_path().clone();
+                    in_flight.push((index, path, http.request(request)));
+
+                    continue;
+                }
+
+                // Look ahead a limited number of requests for ones of other
+                // buckets.
+                if pending.len() >= concurrency {
+                    break;
+                }
+
+                match requests.next() {
+                    Some((index, Ok(request))) => pending.push_back((index, request)),
+                    Some((index, Err(source))) => complete(index, Err(source)),
+                    None => break,
+                }
+            }
+
+            if in_flight.is_empty() {
+                break;
+            }
+
+            let (position, result) = poll_fn(|cx| {
+                for (position, (_, _, future)) in in_flight.iter_mut().enumerate() {
+                    if let Poll::Ready(result) = Pin::new(future).poll(cx) {
+                        return Poll::Ready((position, result));
+                    }
+                }
+
+                Poll::Pending
+            })
+            .await;
+
+            let (index, ..) = in_flight.swap_remove(position);
+            complete(index, result);
+        }
+
+        // Every request completed, so no results are skipped.
+        results.into_iter().flatten().collect()
+    }
+}
+
+impl<T> Debug for RequestBatcher<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("RequestBatcher")
+            .field("concurrency", &self.concurrency)
+            .field("per_bucket", &self.per_bucket)
+            .field("progress", &self.progress)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T: Send + Unpin + 'a> IntoFuture for RequestBatcher<'a, T> {
+    type Output = Vec<Result<Response<T>, Error>>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.execute())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BatchProgress, RequestBatcher};
+    use crate::{
+        error::ErrorType,
+        test::{Response, Server},
+        Client,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        time::Duration,
+    };
+    use tokio::time;
+    use twilight_model::{channel::Message, id::Id};
+
+    assert_impl_all!(BatchProgress: Clone, Debug, Send, Sync);
+    assert_impl_all!(RequestBatcher<'_, Message>: Debug, Send);
+
+    /// Start a mock API server creating messages, returning a client using it
+    /// and the maximum number of requests it handled at once.
+    ///
+    /// Requests to channels with even IDs fail with a 404. Requests take
+    /// longer the lower the channel ID is, so they complete out of order.
+    async fn server() -> (Client, Arc<AtomicUsize>) {
+        let active = Arc::new(AtomicUsize::new(0));
+        let max_active = Arc::new(AtomicUsize::new(0));
+
+        let handler_max_active = Arc::clone(&max_active);
+        let server = Server::start(move |path| {
+            let active = Arc::clone(&active);
+            let max_active = Arc::clone(&handler_max_active);
+
+            async move {
+                // /api/v10/channels/{channel_id}/messages
+                let channel_id = path.split('/').nth(4).unwrap().parse::<u64>().unwrap();
+
+                let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
+                max_active.fetch_max(now_active, Ordering::SeqCst);
+                time::sleep(Duration::from_millis(100 / channel_id)).await;
+                active.fetch_sub(1, Ordering::SeqCst);
+
+                if channel_id % 2 == 0 {
+                    Response::json(
+                        "404 Not Found",
+                        r#"{"code":10003,"message":"Unknown Channel"}"#,
+                    )
+                } else {
+                    Response::json("200 OK", format!(r#"{{"channel_id":"{channel_id}"}}"#))
+                }
+            }
+        })
+        .await;
+        let client = Client::builder()
+            .api_base_url(server.url())
+            .ratelimiter(None)
+            .build();
+
+        (client, max_active)
+    }
+
+    /// Test that results are in order, failures don't abort the batch, and
+    /// the concurrency is bounded.
+    #[tokio::test]
+    async fn order() {
+        let (client, max_active) = server().await;
+
+        let too_long = "a".repeat(2001);
+        let requests = [
+            (1, "a"),
+            (2, "b"),
+            (3, too_long.as_str()),
+            (5, "c"),
+            (7, "d"),
+        ];
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let callback_calls = Arc::clone(&calls);
+        let batch = client
+            .batch(requests.iter().map(|(channel_id, content)| {
+                client.create_message(Id::new(*channel_id)).content(content)
+            }))
+            .concurrency(2)
+            .on_item(move |index, _| callback_calls.lock().unwrap().push(index));
+        let progress = batch.progress();
+        assert_eq!(0, progress.completed());
+        assert_eq!(5, progress.total());
+
+        let mut results = batch.await.into_iter();
+
+        assert!(progress.is_done());
+        assert_eq!(2, max_active.load(Ordering::SeqCst));
+
+        let mut calls = calls.lock().unwrap().clone();
+        assert_eq!(5, calls.len());
+        // Requests are only taken from the iterator once a slot frees up, so
+        // the invalid request completes after the faster sent request.
+        assert_eq!([1, 2].as_slice(), &calls[..2]);
+        calls.sort_unstable();
+        assert_eq!([0, 1, 2, 3, 4].as_slice(), calls);
+
+        for channel_id in [1, 2, 3, 5, 7] {
+            let result = results.next().unwrap();
+
+            match channel_id {
+                2 => assert!(matches!(
+                    result.unwrap_err().kind(),
+                    ErrorType::Response { status, .. } if status.get() == 404
+                )),
+                3 => assert!(matches!(result.unwrap_err().kind(), ErrorType::Validation)),
+                _ => assert_eq!(
+                    format!(r#"{{"channel_id":"{channel_id}"}}"#),
+                    result.unwrap().text().await.unwrap()
+                ),
+            }
+        }
+    }
+
+    /// Test that requests of a saturated bucket don't hold a slot.
+    #[tokio::test]
+    async fn per_bucket() {
+        let (client, max_active) = server().await;
+
+        let results = client
+            .batch(
+                [1, 1, 1, 3, 3, 3]
+                    .map(|channel_id| client.create_message(Id::new(channel_id)).content("a")),
+            )
+            .concurrency(4)
+            .per_bucket(1)
+            .await;
+
+        assert_eq!(6, results.len());
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(2, max_active.load(Ordering::SeqCst));
+    }
+}
@@ -1,5 +1,5 @@
 use super::Token;
-use crate::{client::connector, Client};
+use crate::{client::connector, request::GatewayInfoCache, Client};
 use http::header::HeaderMap;
 use hyper_util::rt::TokioExecutor;
 use std::{
@@ -13,7 +13,9 @@ use twilight_model::channel::message::AllowedMentions;
 #[derive(Debug)]
 #[must_use = "has no effect if not built into a Client"]
 pub struct ClientBuilder {
+    pub(crate) api_base_url: Option<Box<str>>,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
+    gateway_info_ttl: Option<Duration>,
     pub(crate) proxy: Option<Box<str>>,
     pub(crate) ratelimiter: Option<Box<dyn Ratelimiter>>,
     remember_invalid_token: bool,
@@ -43,6 +45,7 @@ impl ClientBuilder {
         };
 
         Client {
+            api_base_url: self.api_base_url,
             http,
             default_headers: self.default_headers,
             proxy: self.proxy,
@@ -51,18 +54,74 @@ impl ClientBuilder {
             token_invalidated,
             token: self.token,
             default_allowed_mentions: self.default_allowed_mentions,
+            gateway_info: self
+                .gateway_info_ttl
+                .map(|ttl| Arc::new(GatewayInfoCache::new(ttl))),
             use_http: self.use_http,
         }
     }
 
+    /// Set the base URL of the API to send requests to, such as a mock server
+    /// in tests.
+    ///
+    /// Request paths are appended to the base URL after the API version, with
+    /// or without a trailing slash. This takes precedence over the [proxy].
+    ///
+    /// Defaults to `https://discord.com/api`.
+    ///
+    /// # Examples
+    ///
+    /// Send requests to a server on port 3000:
+    ///
+    /// ```
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .api_base_url("http://localhost:3000/")
+    ///     .build();
+    /// ```
+    ///
+    /// [proxy]: Self::proxy
+    pub fn api_base_url(mut self, api_base_url: impl Into<String>) -> Self {
+        let mut api_base_url = api_base_url.into();
+        let len = api_base_url.trim_end_matches('/').len();
+        api_base_url.truncate(len);
+
+        self.api_base_url.replace(api_base_url.into_boxed_str());
+
+        self
+    }
+
     /// Set the default allowed mentions setting to use on all messages sent through the HTTP
     /// client.
+    ///
+    /// These are validated when building each message request, such as by
+    /// [`twilight_validate::message::allowed_mentions`]. Allowed mentions may
+    /// be created with `twilight_util`'s `AllowedMentionsBuilder`.
     pub fn default_allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
         self.default_allowed_mentions.replace(allowed_mentions);
 
         self
     }
 
+    /// Cache the authenticated gateway information for a duration.
+    ///
+    /// Awaiting [`GetGatewayAuthed`] or calling [`GetGatewayAuthed::info`]
+    /// then only requests the gateway information once per duration, such as when frequently restarting groups of shards
+    /// via `twilight_gateway::create_recommended`. Note that the cached
+    /// [`SessionStartLimit`] isn't updated as sessions are started.
+    ///
+    /// Defaults to not caching.
+    ///
+    /// [`GetGatewayAuthed`]: crate::request::GetGatewayAuthed
+    /// [`GetGatewayAuthed::info`]: crate::request::GetGatewayAuthed::info
+    /// [`SessionStartLimit`]: twilight_model::gateway::SessionStartLimit
+    pub const fn cache_gateway_info(mut self, ttl: Duration) -> Self {
+        self.gateway_info_ttl = Some(ttl);
+
+        self
+    }
+
     /// Set the proxy to use for all HTTP(S) requests.
     ///
     /// **Note** that this isn't currently a traditional proxy, but is for
@@ -106,7 +165,13 @@ impl ClientBuilder {
 
     /// Set the timeout for HTTP requests.
     ///
+    /// The timeout starts once a request is sent, so time spent waiting for
+    /// the ratelimiter doesn't count against it. It may be overridden per
+    /// request via [`ResponseFuture::set_timeout`].
+    ///
     /// The default is 10 seconds.
+    ///
+    /// [`ResponseFuture::set_timeout`]: crate::response::ResponseFuture::set_timeout
     pub const fn timeout(mut self, duration: Duration) -> Self {
         self.timeout = duration;
 
@@ -154,8 +219,10 @@ impl Default for ClientBuilder {
     fn default() -> Self {
         #[allow(clippy::box_default)]
         Self {
+            api_base_url: None,
             default_allowed_mentions: None,
             default_headers: None,
+            gateway_info_ttl: None,
             proxy: None,
             ratelimiter: Some(Box::new(InMemoryRatelimiter::default())),
             remember_invalid_token: true,
@@ -0,0 +1,336 @@
+use crate::{
+    client::Client,
+    error::{Error, ErrorType},
+    request::{
+        application::interaction::UpdateResponse, channel::message::CreateMessage, Request,
+        TryIntoRequest,
+    },
+    response::Response,
+};
+use std::time::Duration;
+use tokio::time::{self, Instant};
+use twilight_model::{
+    channel::{message::Embed, Message},
+    id::{
+        marker::{ApplicationMarker, ChannelMarker},
+        Id,
+    },
+};
+
+/// Default minimum time between edits of the response.
+const DEFAULT_EDIT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Time an interaction token is valid for after the interaction is received.
+const TOKEN_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+/// Deferred response to an interaction, reporting the progress of a long
+/// task by editing the response.
+///
+/// Created by [`InteractionClient::defer`], which acknowledges the interaction
+/// with a deferred response. Edits are spaced by at least the [edit interval]
+/// to respect the ratelimit of the response's route.
+///
+/// The interaction token expires 15 minutes after the interaction is received.
+/// Edits that would be sent after the token expires fail with an error of type
+/// [`ErrorType::TokenExpired`] instead of being sent, and [`finish`] can fall
+/// back to creating a message in a [channel] instead. The expiry is counted
+/// from when the interaction is deferred, so defer it as soon as it is
+/// received.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+/// let interaction_client = client.interaction(Id::new(1));
+///
+/// let mut handle = interaction_client
+///     .defer(Id::new(2), "interaction token")
+///     .await?
+///     .fallback_channel(Id::new(3));
+///
+/// for step in 1..=3 {
+///     // Work on the task...
+///     handle
+///         .edit(Some(&format!("step {step} of 3")), None)
+///         .await?;
+/// }
+///
+/// handle.finish(Some("done!"), None).await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`InteractionClient::defer`]: super::InteractionClient::defer
+/// [`finish`]: Self::finish
+/// [channel]: Self::fallback_channel
+/// [edit interval]: Self::edit_interval
+#[derive(Debug)]
+#[must_use = "deferred responses should be finished"]
+pub struct DeferredResponseHandle<'a> {
+    application_id: Id<ApplicationMarker>,
+    edit_interval: Duration,
+    expires_at: Instant,
+    fallback_channel_id: Option<Id<ChannelMarker>>,
+    http: &'a Client,
+    interaction_token: &'a str,
+    next_edit: Instant,
+}
+
+impl<'a> DeferredResponseHandle<'a> {
+    /// Create a handle for an interaction deferred at `deferred_at`.
+    pub(super) fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        interaction_token: &'a str,
+        deferred_at: Instant,
+    ) -> Self {
+        Self {
+            application_id,
+            edit_interval: DEFAULT_EDIT_INTERVAL,
+            expires_at: deferred_at + TOKEN_LIFETIME,
+            fallback_channel_id: None,
+            http,
+            interaction_token,
+            next_edit: deferred_at,
+        }
+    }
+
+    /// Set the minimum time between edits.
+    ///
+    /// Defaults to one second.
+    pub const fn edit_interval(mut self, edit_interval: Duration) -> Self {
+        self.edit_interval = edit_interval;
+
+        self
+    }
+
+    /// Set the channel to create a message in if the interaction token expires
+    /// before the response is [finished].
+    ///
+    /// [finished]: Self::finish
+    pub const fn fallback_channel(mut self, channel_id: Id<ChannelMarker>) -> Self {
+        self.fallback_channel_id = Some(channel_id);
+
+        self
+    }
+
+    /// Instant at which the interaction token expires.
+    pub const fn expires_at(&self) -> Instant {
+        self.expires_at
+    }
+
+    /// Edit the response, waiting for the edit interval since the previous
+    /// edit to elapse.
+    ///
+    /// Fields that are [`None`] are left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`TokenExpired`] if the interaction token
+    /// would expire before the edit is sent.
+    ///
+    /// Returns an error of type [`Validation`] if the content or embeds are
+    /// invalid.
+    ///
+    /// [`TokenExpired`]: ErrorType::TokenExpired
+    /// [`Validation`]: ErrorType::Validation
+    pub async fn edit(
+        &mut self,
+        content: Option<&str>,
+        embeds: Option<&[Embed]>,
+    ) -> Result<Response<Message>, Error> {
+        let request = self.edit_request(content, embeds).await?;
+
+        self.http.request(request).await
+    }
+
+    /// Edit the response a final time, consuming the handle.
+    ///
+    /// Fields that are [`None`] are left unchanged. If the interaction token
+    /// would expire before the edit is sent and a [fallback channel] is set, a
+    /// message is created in it instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`TokenExpired`] if the interaction token
+    /// would expire before the edit is sent and no fallback channel is set.
+    ///
+    /// Returns an error of type [`Validation`] if the content or embeds are
+    /// invalid.
+    ///
+    /// [`TokenExpired`]: ErrorType::TokenExpired
+    /// [`Validation`]: ErrorType::Validation
+    /// [fallback channel]: Self::fallback_channel
+    pub async fn finish(
+        mut self,
+        content: Option<&str>,
+        embeds: Option<&[Embed]>,
+    ) -> Result<Response<Message>, Error> {
+        let request = self.finish_request(content, embeds).await?;
+
+        self.http.request(request).await
+    }
+
+    /// Wait for the next edit and build its request.
+    async fn edit_request(
+        &mut self,
+        content: Option<&str>,
+        embeds: Option<&[Embed]>,
+    ) -> Result<Request, Error> {
+        self.wait_for_edit().await?;
+
+        let mut update_response =
+            UpdateResponse::new(self.http, self.application_id, self.interaction_token);
+
+        if content.is_some() {
+            update_response = update_response.content(content);
+        }
+
+        if embeds.is_some() {
+            update_response = update_response.embeds(embeds);
+        }
+
+        update_response.try_into_request()
+    }
+
+    /// Wait for the final edit and build its request, falling back to
+    /// creating a message if the token would expire first.
+    async fn finish_request(
+        &mut self,
+        content: Option<&str>,
+        embeds: Option<&[Embed]>,
+    ) -> Result<Request, Error> {
+        match self.edit_request(content, embeds).await {
+            Err(source) if matches!(source.kind(), ErrorType::TokenExpired) => {
+                let Some(channel_id) = self.fallback_channel_id else {
+                    return Err(source);
+                };
+
+                let mut create_message = CreateMessage::new(self.http, channel_id);
+
+                if let Some(content) = content {
+                    create_message = create_message.content(content);
+                }
+
+                if let Some(embeds) = embeds {
+                    create_message = create_message.embeds(embeds);
+                }
+
+                create_message.try_into_request()
+            }
+            result => result,
+        }
+    }
+
+    /// Wait for the edit interval since the previous edit to elapse.
+    ///
+    /// Returns an error of type [`ErrorType::TokenExpired`] without waiting if
+    /// the token would expire first.
+    async fn wait_for_edit(&mut self) -> Result<(), Error> {
+        let at = self.next_edit.max(Instant::now());
+
+        if at >= self.expires_at {
+            return Err(Error {
+                kind: ErrorType::TokenExpired,
+                source: None,
+            });
+        }
+
+        time::sleep_until(at).await;
+        self.next_edit = at + self.edit_interval;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DeferredResponseHandle, DEFAULT_EDIT_INTERVAL, TOKEN_LIFETIME};
+    use crate::{error::ErrorType, Client};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, time::Duration};
+    use tokio::time::{self, Instant};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(DeferredResponseHandle<'_>: Debug, Send, Sync);
+
+    #[tokio::test(start_paused = true)]
+    async fn edit_spacing() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let start = Instant::now();
+        let mut handle = DeferredResponseHandle::new(&client, Id::new(1), "token", start);
+
+        // The first edit is sent immediately.
+        handle.edit_request(Some("1"), None).await?;
+        assert_eq!(start, Instant::now());
+
+        handle.edit_request(Some("2"), None).await?;
+        assert_eq!(start + DEFAULT_EDIT_INTERVAL, Instant::now());
+
+        // Time elapsed since the previous edit counts towards the interval.
+        time::advance(Duration::from_millis(400)).await;
+        let request = handle.edit_request(None, None).await?;
+        assert_eq!(start + DEFAULT_EDIT_INTERVAL * 2, Instant::now());
+        assert_eq!("webhooks/1/token/messages/@original", request.path());
+        assert_eq!(Some(b"{}".as_ref()), request.body());
+
+        let mut handle = handle.edit_interval(Duration::from_secs(5));
+        handle.edit_request(None, None).await?;
+        handle.edit_request(None, None).await?;
+        assert_eq!(
+            start + DEFAULT_EDIT_INTERVAL * 3 + Duration::from_secs(5),
+            Instant::now()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn expiry() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let start = Instant::now();
+        let mut handle = DeferredResponseHandle::new(&client, Id::new(1), "token", start)
+            .edit_interval(Duration::from_secs(60));
+
+        assert_eq!(start + TOKEN_LIFETIME, handle.expires_at());
+
+        time::advance(Duration::from_secs(14 * 60 + 30)).await;
+        handle.edit_request(Some("almost"), None).await?;
+
+        // The next edit would be sent after the token expires, so it fails
+        // without waiting.
+        let now = Instant::now();
+        let error = handle.edit_request(Some("late"), None).await.unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::TokenExpired));
+        assert_eq!(now, Instant::now());
+
+        // Finishing without a fallback channel fails the same way.
+        let error = handle.finish(Some("done"), None).await.unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::TokenExpired));
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn expiry_fallback() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let mut handle = DeferredResponseHandle::new(&client, Id::new(1), "token", Instant::now())
+            .fallback_channel(Id::new(2));
+
+        time::advance(TOKEN_LIFETIME).await;
+
+        let error = handle.edit_request(Some("late"), None).await.unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::TokenExpired));
+
+        let request = handle.finish_request(Some("done"), None).await?;
+        assert_eq!("channels/2/messages", request.path());
+        assert_eq!(Some(br#"{"content":"done"}"#.as_ref()), request.body());
+
+        Ok(())
+    }
+}
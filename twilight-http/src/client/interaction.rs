@@ -1,3 +1,4 @@
+use super::DeferredResponseHandle;
 use crate::{
     request::application::{
         command::{
@@ -11,11 +12,12 @@ use crate::{
             GetResponse, UpdateFollowup, UpdateResponse,
         },
     },
-    Client,
+    Client, Error,
 };
+use tokio::time::Instant;
 use twilight_model::{
     application::command::{permissions::CommandPermission, Command},
-    http::interaction::InteractionResponse,
+    http::interaction::{InteractionResponse, InteractionResponseType},
     id::{
         marker::{ApplicationMarker, CommandMarker, GuildMarker, InteractionMarker, MessageMarker},
         Id,
@@ -80,6 +82,38 @@ impl<'a> InteractionClient<'a> {
         CreateResponse::new(self.client, interaction_id, interaction_token, response)
     }
 
+    /// Acknowledge an interaction with a deferred response, returning a
+    /// handle to edit the response with the progress of a long task.
+    ///
+    /// Refer to [`DeferredResponseHandle`] for more information and an
+    /// example.
+    ///
+    /// This endpoint is not bound to the application's global rate limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if acknowledging the interaction fails.
+    pub async fn defer(
+        &self,
+        interaction_id: Id<InteractionMarker>,
+        interaction_token: &'a str,
+    ) -> Result<DeferredResponseHandle<'a>, Error> {
+        let deferred_at = Instant::now();
+        let response = InteractionResponse {
+            kind: InteractionResponseType::DeferredChannelMessageWithSource,
+            data: None,
+        };
+
+        CreateResponse::new(self.client, interaction_id, interaction_token, &response).await?;
+
+        Ok(DeferredResponseHandle::new(
+            self.client,
+            self.application_id,
+            interaction_token,
+            deferred_at,
+        ))
+    }
+
     /// Delete the original message, by its token.
     ///
     /// This endpoint is not bound to the application's global rate limit.
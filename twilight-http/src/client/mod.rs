@@ -1,8 +1,15 @@
+mod batch;
 mod builder;
 mod connector;
+mod deferred;
 mod interaction;
 
-pub use self::{builder::ClientBuilder, interaction::InteractionClient};
+pub use self::{
+    batch::{BatchProgress, RequestBatcher},
+    builder::ClientBuilder,
+    deferred::DeferredResponseHandle,
+    interaction::InteractionClient,
+};
 
 use crate::request::{
     application::{
@@ -16,6 +23,7 @@ use crate::request::{
         },
     },
     guild::user::{GetCurrentUserVoiceState, GetUserVoiceState},
+    GatewayInfoCache,
 };
 #[allow(deprecated)]
 use crate::{
@@ -70,12 +78,13 @@ use crate::{
             },
             update_guild_onboarding::{UpdateGuildOnboarding, UpdateGuildOnboardingFields},
             user::{UpdateCurrentUserVoiceState, UpdateUserVoiceState},
-            CreateGuild, CreateGuildChannel, CreateGuildPrune, DeleteGuild, GetActiveThreads,
-            GetAuditLog, GetGuild, GetGuildChannels, GetGuildInvites, GetGuildOnboarding,
-            GetGuildPreview, GetGuildPruneCount, GetGuildVanityUrl, GetGuildVoiceRegions,
-            GetGuildWebhooks, GetGuildWelcomeScreen, GetGuildWidget, GetGuildWidgetSettings,
-            UpdateCurrentMember, UpdateGuild, UpdateGuildChannelPositions, UpdateGuildMfa,
-            UpdateGuildWelcomeScreen, UpdateGuildWidgetSettings,
+            AuditLogIter, CreateGuild, CreateGuildChannel, CreateGuildPrune, DeleteGuild,
+            GetActiveThreads, GetAuditLog, GetGuild, GetGuildChannels, GetGuildInvites,
+            GetGuildOnboarding, GetGuildPreview, GetGuildPruneCount, GetGuildVanityUrl,
+            GetGuildVoiceRegions, GetGuildWebhooks, GetGuildWelcomeScreen, GetGuildWidget,
+            GetGuildWidgetSettings, UpdateCurrentMember, UpdateGuild, UpdateGuildChannelPositions,
+            UpdateGuildMfa, UpdateGuildVanityUrl, UpdateGuildWelcomeScreen,
+            UpdateGuildWidgetSettings,
         },
         poll::{EndPoll, GetAnswerVoters},
         scheduled_event::{
@@ -93,7 +102,7 @@ use crate::{
             UpdateCurrentUser,
         },
         GetCurrentAuthorizationInformation, GetGateway, GetUserApplicationInfo, GetVoiceRegions,
-        Method, Request, UpdateCurrentUserApplication,
+        Method, Request, TryIntoRequest, UpdateCurrentUserApplication,
     },
     response::ResponseFuture,
     API_VERSION,
@@ -106,6 +115,7 @@ use hyper::body::Bytes;
 use hyper_util::client::legacy::Client as HyperClient;
 use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
+    future::IntoFuture,
     ops::Deref,
     sync::{
         atomic::{AtomicBool, Ordering},
@@ -113,7 +123,6 @@ use std::{
     },
     time::Duration,
 };
-use tokio::time;
 use twilight_http_ratelimiting::Ratelimiter;
 use twilight_model::{
     channel::{message::AllowedMentions, ChannelType},
@@ -242,8 +251,10 @@ impl Deref for Token {
 /// [here]: https://discord.com/developers/applications
 #[derive(Debug)]
 pub struct Client {
+    api_base_url: Option<Box<str>>,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
     default_headers: Option<HeaderMap>,
+    pub(crate) gateway_info: Option<Arc<GatewayInfoCache>>,
     http: HyperClient<Connector, Full<Bytes>>,
     proxy: Option<Box<str>>,
     ratelimiter: Option<Box<dyn Ratelimiter>>,
@@ -442,6 +453,35 @@ impl Client {
         GetAuditLog::new(self, guild_id)
     }
 
+    /// Iterate over the audit log of a guild, fetching pages as needed.
+    ///
+    /// Refer to [`AuditLogIter`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Print who deleted messages:
+    ///
+    /// ```no_run
+    /// # use twilight_http::Client;
+    /// use twilight_model::{guild::audit_log::AuditLogEventType, id::Id};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::new("token".to_owned());
+    /// let guild_id = Id::new(101);
+    /// let mut entries = client
+    ///     .audit_log_iter(guild_id)
+    ///     .action_type(AuditLogEventType::MessageDelete);
+    ///
+    /// while let Some(entry) = entries.next().await {
+    ///     println!("{:?}", entry?.user.map(|user| user.name));
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub const fn audit_log_iter(&self, guild_id: Id<GuildMarker>) -> AuditLogIter<'_> {
+        AuditLogIter::new(self, guild_id)
+    }
+
     /// Retrieve the bans for a guild.
     ///
     /// # Examples
@@ -864,7 +904,15 @@ impl Client {
     /// `data:image/{type};base64,{data}` where `{type}` is the image MIME type
     /// and `{data}` is the base64-encoded image. See [Discord Docs/Image Data].
     ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`NameLengthInvalid`] or
+    /// [`NameCharacterInvalid`] when sending the request if the name is
+    /// invalid.
+    ///
     /// [Discord Docs/Image Data]: https://discord.com/developers/docs/reference#image-data
+    /// [`NameCharacterInvalid`]: twilight_validate::emoji::EmojiValidationErrorType::NameCharacterInvalid
+    /// [`NameLengthInvalid`]: twilight_validate::emoji::EmojiValidationErrorType::NameLengthInvalid
     pub const fn create_emoji<'a>(
         &'a self,
         guild_id: Id<GuildMarker>,
@@ -1328,6 +1376,54 @@ impl Client {
         GetGuildVanityUrl::new(self, guild_id)
     }
 
+    /// Update a guild's vanity url.
+    ///
+    /// The guild must have the [`VanityUrl`] feature; use
+    /// [`UpdateGuildVanityUrl::features`] to check this before sending the
+    /// request.
+    ///
+    /// # Examples
+    ///
+    /// Set the vanity url code of guild `1` to `"twilight-rs"`, checking that
+    /// the guild has the feature:
+    ///
+    /// ```no_run
+    /// # use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::new("my token".to_owned());
+    /// #
+    /// let guild_id = Id::new(1);
+    /// let guild = client.guild(guild_id).await?.model().await?;
+    ///
+    /// client
+    ///     .update_guild_vanity_url(guild_id, "twilight-rs")
+    ///     .features(&guild.features)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`VanityUrlCode`] if the code length is too
+    /// short or too long.
+    ///
+    /// Returns an error of type [`VanityUrlCodeCharacter`] if the code
+    /// contains characters other than ASCII alphanumerics and dashes.
+    ///
+    /// [`VanityUrl`]: twilight_model::guild::GuildFeature::VanityUrl
+    /// [`VanityUrlCode`]: twilight_validate::request::ValidationErrorType::VanityUrlCode
+    /// [`VanityUrlCodeCharacter`]: twilight_validate::request::ValidationErrorType::VanityUrlCodeCharacter
+    pub fn update_guild_vanity_url<'a>(
+        &'a self,
+        guild_id: Id<GuildMarker>,
+        code: &'a str,
+    ) -> UpdateGuildVanityUrl<'a> {
+        UpdateGuildVanityUrl::new(self, guild_id, code)
+    }
+
     /// Get voice region data for the guild.
     ///
     /// Can return VIP servers if the guild is VIP-enabled.
@@ -1565,6 +1661,8 @@ impl Client {
     }
 
     /// Create a new pin in a channel, by ID.
+    ///
+    /// Refer to [`CreatePin`] for the limit of pinned messages in a channel.
     pub const fn create_pin(
         &self,
         channel_id: Id<ChannelMarker>,
@@ -2882,8 +2980,76 @@ impl Client {
         DeleteApplicationEmoji::new(self, application_id, emoji_id)
     }
 
+    /// Execute requests with bounded concurrency, collecting their results in
+    /// order.
+    ///
+    /// Refer to [`RequestBatcher`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Fetch channels, two at a time:
+    ///
+    /// ```no_run
+    /// # use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::new("token".to_owned());
+    /// let channel_ids = [Id::new(1), Id::new(2), Id::new(3)];
+    /// let results = client
+    ///     .batch(channel_ids.map(|channel_id| client.channel(channel_id)))
+    ///     .concurrency(2)
+    ///     .await;
+    ///
+    /// for result in results {
+    ///     println!("{:?}", result?.model().await?.name);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn batch<'a, I, R, T>(&'a self, requests: I) -> RequestBatcher<'a, T>
+    where
+        I: IntoIterator<Item = R>,
+        I::IntoIter: ExactSizeIterator + Send + 'a,
+        R: IntoFuture<IntoFuture = ResponseFuture<T>> + TryIntoRequest + 'a,
+    {
+        RequestBatcher::new(self, requests)
+    }
+
     /// Execute a request, returning a future resolving to a [`Response`].
     ///
+    /// Requests can be built via [`Request::builder`], or converted from
+    /// request builders via [`TryIntoRequest`], such as to inspect them
+    /// before they're sent.
+    ///
+    /// # Examples
+    ///
+    /// Log requests before executing them:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::env;
+    /// use twilight_http::{request::TryIntoRequest, Client};
+    /// use twilight_model::{channel::Message, id::Id};
+    ///
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    ///
+    /// let request = client
+    ///     .create_message(Id::new(1))
+    ///     .content("test")
+    ///     .try_into_request()?;
+    /// println!(
+    ///     "{:?} {} multipart: {}",
+    ///     request.method(),
+    ///     request.path(),
+    ///     request.is_multipart(),
+    /// );
+    ///
+    /// let message = client.request::<Message>(request).await?.model().await?;
+    /// # Ok(()) }
+    /// ```
+    ///
     /// # Errors
     ///
     /// Returns an [`ErrorType::Unauthorized`] error type if the configured
@@ -2897,6 +3063,18 @@ impl Client {
         }
     }
 
+    /// URL of a request path.
+    fn url(&self, path: &str) -> String {
+        if let Some(api_base_url) = &self.api_base_url {
+            return format!("{api_base_url}/v{API_VERSION}/{path}");
+        }
+
+        let protocol = if self.use_http { "http" } else { "https" };
+        let host = self.proxy.as_deref().unwrap_or("discord.com");
+
+        format!("{protocol}://{host}/api/v{API_VERSION}/{path}")
+    }
+
     fn try_request<T>(&self, request: Request) -> Result<ResponseFuture<T>, Error> {
         if let Some(token_invalidated) = self.token_invalidated.as_ref() {
             if token_invalidated.load(Ordering::Relaxed) {
@@ -2917,10 +3095,7 @@ impl Client {
             use_authorization_token,
         } = request;
 
-        let protocol = if self.use_http { "http" } else { "https" };
-        let host = self.proxy.as_deref().unwrap_or("discord.com");
-
-        let url = format!("{protocol}://{host}/api/v{API_VERSION}/{path}");
+        let url = self.url(&path);
         tracing::debug!(?url);
 
         let mut builder = hyper::Request::builder().method(method.name()).uri(&url);
@@ -3003,7 +3178,7 @@ impl Client {
 
             ResponseFuture::ratelimit(invalid_token, inner, self.timeout, tx_future)
         } else {
-            ResponseFuture::new(Box::pin(time::timeout(self.timeout, inner)), invalid_token)
+            ResponseFuture::new(invalid_token, inner, self.timeout)
         })
     }
 }
@@ -3011,6 +3186,7 @@ impl Client {
 #[cfg(test)]
 mod tests {
     use super::Client;
+    use crate::API_VERSION;
 
     #[test]
     fn client_debug_with_token() {
@@ -3019,4 +3195,25 @@ mod tests {
         );
         assert!(format!("{:?}", Client::builder().build()).contains("token: None"));
     }
+
+    #[test]
+    fn api_base_url() {
+        let client = Client::new(String::new());
+        assert_eq!(
+            format!("https://discord.com/api/v{API_VERSION}/users/@me"),
+            client.url("users/@me")
+        );
+
+        for api_base_url in ["http://localhost:3000", "http://localhost:3000/"] {
+            let client = Client::builder()
+                .api_base_url(api_base_url)
+                .proxy("proxy.internal".to_owned(), true)
+                .build();
+
+            assert_eq!(
+                format!("http://localhost:3000/v{API_VERSION}/users/@me"),
+                client.url("users/@me")
+            );
+        }
+    }
 }
@@ -84,6 +84,9 @@ impl Display for Error {
             ErrorType::ServiceUnavailable { .. } => {
                 f.write_str("api may be temporarily unavailable (received a 503)")
             }
+            ErrorType::TokenExpired => {
+                f.write_str("interaction token expired before the request was sent")
+            }
             ErrorType::Unauthorized => {
                 f.write_str("token in use is invalid, expired, or is revoked")
             }
@@ -128,6 +131,13 @@ pub enum ErrorType {
     ServiceUnavailable {
         response: Response<Incoming>,
     },
+    /// Interaction token would expire before the request was sent, so it
+    /// wasn't sent.
+    ///
+    /// Returned by [`DeferredResponseHandle`].
+    ///
+    /// [`DeferredResponseHandle`]: crate::client::DeferredResponseHandle
+    TokenExpired,
     /// Token in use has become revoked or is otherwise invalid.
     ///
     /// This can occur if a bot token is invalidated or an access token expires
@@ -236,6 +246,7 @@ impl Debug for ErrorType {
                 .debug_struct("ServiceUnavailable")
                 .field("response", response)
                 .finish(),
+            Self::TokenExpired => f.write_str("TokenExpired"),
             Self::Unauthorized => f.write_str("Unauthorized"),
             Self::Validation => f.write_str("Validation"),
         }
@@ -291,6 +302,7 @@ mod tests {
             body,
             error: ApiError::General(GeneralApiError {
                 code: 0,
+                errors: None,
                 message: "401: Unauthorized".to_owned(),
             }),
             status: StatusCode::new(401),
@@ -322,6 +334,7 @@ mod tests {
     error: General(
         GeneralApiError {
             code: 0,
+            errors: None,
             message: \"401: Unauthorized\",
         },
     ),
@@ -15,6 +15,8 @@ pub mod routing;
 
 mod json;
 mod query_formatter;
+#[cfg(test)]
+mod test;
 
 /// Discord API version used by this crate.
 pub const API_VERSION: u8 = 10;
@@ -16,8 +16,9 @@ use twilight_model::{
     id::{marker::ApplicationMarker, Id},
 };
 use twilight_validate::message::{
-    attachment as validate_attachment, components as validate_components,
-    content as validate_content, embeds as validate_embeds, MessageValidationError,
+    allowed_mentions as validate_allowed_mentions, attachment as validate_attachment,
+    components as validate_components, content as validate_content, embeds as validate_embeds,
+    MessageValidationError,
 };
 
 #[derive(Serialize)]
@@ -121,12 +122,16 @@ impl<'a> CreateFollowup<'a> {
     ///
     /// # Errors
     ///
+    /// Returns an error of type [`AttachmentContentType`] if any content type
+    /// is invalid.
+    ///
     /// Returns an error of type [`AttachmentDescriptionTooLarge`] if
     /// the attachments's description is too large.
     ///
     /// Returns an error of type [`AttachmentFilename`] if any filename is
     /// invalid.
     ///
+    /// [`AttachmentContentType`]: twilight_validate::message::MessageValidationErrorType::AttachmentContentType
     /// [`AttachmentDescriptionTooLarge`]: twilight_validate::message::MessageValidationErrorType::AttachmentDescriptionTooLarge
     /// [`AttachmentFilename`]: twilight_validate::message::MessageValidationErrorType::AttachmentFilename
     pub fn attachments(mut self, attachments: &'a [Attachment]) -> Self {
@@ -295,6 +300,10 @@ impl TryIntoRequest for CreateFollowup<'_> {
             }
         }
 
+        if let Some(Nullable(Some(allowed_mentions))) = fields.allowed_mentions {
+            validate_allowed_mentions(allowed_mentions).map_err(Error::validation)?;
+        }
+
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
         if !self.attachment_manager.is_empty() {
@@ -321,10 +330,14 @@ impl TryIntoRequest for CreateFollowup<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{client::Client, request::TryIntoRequest};
+    use crate::{client::Client, error::ErrorType, request::TryIntoRequest};
     use std::error::Error;
     use twilight_http_ratelimiting::Path;
-    use twilight_model::id::Id;
+    use twilight_model::{
+        channel::message::{AllowedMentions, MentionType, MessageFlags},
+        http::attachment::Attachment,
+        id::Id,
+    };
 
     #[test]
     fn create_followup_message() -> Result<(), Box<dyn Error>> {
@@ -346,4 +359,97 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn ephemeral() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let req = client
+            .interaction(Id::new(1))
+            .create_followup("foo")
+            .content("test")
+            .flags(MessageFlags::EPHEMERAL)
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(br#"{"content":"test","flags":64}"#.as_ref()),
+            req.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn attachments() -> Result<(), Box<dyn Error>> {
+        let mut attachment = Attachment::from_bytes("b.png".to_owned(), b"b".to_vec(), 2);
+        attachment.description("description".to_owned());
+        let attachments = [
+            attachment,
+            Attachment::from_bytes("a.txt".to_owned(), b"a".to_vec(), 1),
+        ];
+
+        let client = Client::new(String::new());
+        let req = client
+            .interaction(Id::new(1))
+            .create_followup("foo")
+            .attachments(&attachments)
+            .try_into_request()?;
+
+        assert!(!req.use_authorization_token());
+        assert!(req.body().is_none());
+
+        let form = req.form().unwrap();
+        let content_type = String::from_utf8(form.content_type())?;
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+        let expected = format!(
+            "--{boundary}\r\n\
+            Content-Disposition: form-data; name=\"payload_json\"\r\n\
+            Content-Type: application/json\r\n\
+            \r\n\
+            {{\"attachments\":[{{\"filename\":\"a.txt\",\"id\":1}},{{\"description\":\"description\",\"filename\":\"b.png\",\"id\":2}}]}}\r\n\
+            --{boundary}\r\n\
+            Content-Disposition: form-data; name=\"files[1]\"; filename=\"a.txt\"\r\n\
+            \r\n\
+            a\r\n\
+            --{boundary}\r\n\
+            Content-Disposition: form-data; name=\"files[2]\"; filename=\"b.png\"\r\n\
+            \r\n\
+            b\r\n\
+            --{boundary}--",
+        );
+        assert_eq!(expected.as_bytes(), form.clone().build());
+
+        Ok(())
+    }
+
+    #[test]
+    fn allowed_mentions_invalid() {
+        let allowed_mentions = AllowedMentions {
+            parse: Vec::from([MentionType::Users]),
+            users: Vec::from([Id::new(2)]),
+            ..AllowedMentions::default()
+        };
+
+        let client = Client::new(String::new());
+        let request = client
+            .interaction(Id::new(1))
+            .create_followup("foo")
+            .allowed_mentions(Some(&allowed_mentions))
+            .content("test")
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+
+        // Invalid client defaults are caught the same way.
+        let client = Client::builder()
+            .token(String::new())
+            .default_allowed_mentions(allowed_mentions)
+            .build();
+        let request = client
+            .interaction(Id::new(1))
+            .create_followup("foo")
+            .content("test")
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+    }
 }
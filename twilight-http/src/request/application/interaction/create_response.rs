@@ -10,10 +10,18 @@ use twilight_model::{
     http::interaction::InteractionResponse,
     id::{marker::InteractionMarker, Id},
 };
+use twilight_validate::message::attachment_content_type as validate_attachment_content_type;
 
 /// Respond to an interaction, by its ID and token.
 ///
 /// This endpoint is not bound to the application's global rate limit.
+///
+/// # Errors
+///
+/// Returns an error of type [`AttachmentContentType`] when sending the request
+/// if any attachment's content type is invalid.
+///
+/// [`AttachmentContentType`]: twilight_validate::message::MessageValidationErrorType::AttachmentContentType
 #[must_use = "requests must be configured and executed"]
 pub struct CreateResponse<'a> {
     interaction_id: Id<InteractionMarker>,
@@ -72,6 +80,12 @@ impl TryIntoRequest for CreateResponse<'_> {
             .as_ref()
             .and_then(|data| data.attachments.as_ref())
         {
+            attachments
+                .iter()
+                .filter_map(|attachment| attachment.content_type.as_ref())
+                .try_for_each(validate_attachment_content_type)
+                .map_err(Error::validation)?;
+
             let fields = crate::json::to_vec(&self.response).map_err(Error::json)?;
 
             let form = AttachmentManager::new()
@@ -89,11 +103,14 @@ impl TryIntoRequest for CreateResponse<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{client::Client, request::TryIntoRequest};
+    use crate::{client::Client, error::ErrorType, request::TryIntoRequest};
     use std::error::Error;
     use twilight_http_ratelimiting::Path;
     use twilight_model::{
-        http::interaction::{InteractionResponse, InteractionResponseType},
+        http::{
+            attachment::Attachment,
+            interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+        },
         id::Id,
     };
 
@@ -123,4 +140,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn attachment_content_type() {
+        let client = Client::new(String::new());
+        let mut attachment = Attachment::from_bytes("a.txt".to_owned(), Vec::new(), 1);
+        attachment.content_type("text/plain\r\nx-injected: 1".to_owned());
+
+        let response = InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(InteractionResponseData {
+                attachments: Some(Vec::from([attachment])),
+                ..InteractionResponseData::default()
+            }),
+        };
+
+        let error = client
+            .interaction(Id::new(1))
+            .create_response(Id::new(2), "foo", &response)
+            .try_into_request()
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Validation));
+    }
 }
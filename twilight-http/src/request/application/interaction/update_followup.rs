@@ -21,8 +21,9 @@ use twilight_model::{
     },
 };
 use twilight_validate::message::{
-    attachment as validate_attachment, components as validate_components,
-    content as validate_content, embeds as validate_embeds, MessageValidationError,
+    allowed_mentions as validate_allowed_mentions, attachment as validate_attachment,
+    components as validate_components, content as validate_content, embeds as validate_embeds,
+    MessageValidationError,
 };
 
 #[derive(Serialize)]
@@ -133,12 +134,16 @@ impl<'a> UpdateFollowup<'a> {
     ///
     /// # Errors
     ///
+    /// Returns an error of type [`AttachmentContentType`] if any content type
+    /// is invalid.
+    ///
     /// Returns an error of type [`AttachmentDescriptionTooLarge`] if
     /// the attachments' description is too large.
     ///
     /// Returns an error of type [`AttachmentFilename`] if any filename is
     /// invalid.
     ///
+    /// [`AttachmentContentType`]: twilight_validate::message::MessageValidationErrorType::AttachmentContentType
     /// [`AttachmentDescriptionTooLarge`]: twilight_validate::message::MessageValidationErrorType::AttachmentDescriptionTooLarge
     /// [`AttachmentFilename`]: twilight_validate::message::MessageValidationErrorType::AttachmentFilename
     pub fn attachments(mut self, attachments: &'a [Attachment]) -> Self {
@@ -365,6 +370,10 @@ impl TryIntoRequest for UpdateFollowup<'_> {
             }
         }
 
+        if let Some(Nullable(Some(allowed_mentions))) = fields.allowed_mentions {
+            validate_allowed_mentions(allowed_mentions).map_err(Error::validation)?;
+        }
+
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
         if !self.attachment_manager.is_empty() {
@@ -393,10 +402,14 @@ impl TryIntoRequest for UpdateFollowup<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{client::Client, request::TryIntoRequest};
+    use crate::{client::Client, error::ErrorType, request::TryIntoRequest};
     use std::error::Error;
     use twilight_http_ratelimiting::Path;
-    use twilight_model::id::Id;
+    use twilight_model::{
+        channel::message::{AllowedMentions, MentionType},
+        http::attachment::Attachment,
+        id::Id,
+    };
 
     #[test]
     fn update_followup_message() -> Result<(), Box<dyn Error>> {
@@ -419,4 +432,68 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn attachments() -> Result<(), Box<dyn Error>> {
+        let attachments = [Attachment::from_bytes("a.txt".to_owned(), b"a".to_vec(), 1)];
+
+        let client = Client::new(String::new());
+        let req = client
+            .interaction(Id::new(1))
+            .update_followup("foo", Id::new(2))
+            .attachments(&attachments)
+            .keep_attachment_ids(&[Id::new(3)])
+            .try_into_request()?;
+
+        assert!(!req.use_authorization_token());
+
+        let form = req.form().unwrap();
+        let content_type = String::from_utf8(form.content_type())?;
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+        let expected = format!(
+            "--{boundary}\r\n\
+            Content-Disposition: form-data; name=\"payload_json\"\r\n\
+            Content-Type: application/json\r\n\
+            \r\n\
+            {{\"attachments\":[{{\"filename\":\"a.txt\",\"id\":1}},{{\"id\":3}}]}}\r\n\
+            --{boundary}\r\n\
+            Content-Disposition: form-data; name=\"files[1]\"; filename=\"a.txt\"\r\n\
+            \r\n\
+            a\r\n\
+            --{boundary}--",
+        );
+        assert_eq!(expected.as_bytes(), form.clone().build());
+
+        Ok(())
+    }
+
+    #[test]
+    fn allowed_mentions_invalid() {
+        let allowed_mentions = AllowedMentions {
+            parse: Vec::from([MentionType::Users]),
+            users: Vec::from([Id::new(2)]),
+            ..AllowedMentions::default()
+        };
+
+        let client = Client::new(String::new());
+        let request = client
+            .interaction(Id::new(1))
+            .update_followup("foo", Id::new(2))
+            .allowed_mentions(Some(&allowed_mentions))
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+
+        // Invalid client defaults are caught the same way.
+        let client = Client::builder()
+            .token(String::new())
+            .default_allowed_mentions(allowed_mentions)
+            .build();
+        let request = client
+            .interaction(Id::new(1))
+            .update_followup("foo", Id::new(2))
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+    }
 }
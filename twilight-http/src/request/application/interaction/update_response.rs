@@ -21,8 +21,9 @@ use twilight_model::{
     },
 };
 use twilight_validate::message::{
-    attachment as validate_attachment, components as validate_components,
-    content as validate_content, embeds as validate_embeds, MessageValidationError,
+    allowed_mentions as validate_allowed_mentions, attachment as validate_attachment,
+    components as validate_components, content as validate_content, embeds as validate_embeds,
+    MessageValidationError,
 };
 
 #[derive(Serialize)]
@@ -130,12 +131,16 @@ impl<'a> UpdateResponse<'a> {
     ///
     /// # Errors
     ///
+    /// Returns an error of type [`AttachmentContentType`] if any content type
+    /// is invalid.
+    ///
     /// Returns an error of type [`AttachmentDescriptionTooLarge`] if
     /// the attachments's description is too large.
     ///
     /// Returns an error of type [`AttachmentFilename`] if any filename is
     /// invalid.
     ///
+    /// [`AttachmentContentType`]: twilight_validate::message::MessageValidationErrorType::AttachmentContentType
     /// [`AttachmentDescriptionTooLarge`]: twilight_validate::message::MessageValidationErrorType::AttachmentDescriptionTooLarge
     /// [`AttachmentFilename`]: twilight_validate::message::MessageValidationErrorType::AttachmentFilename
     pub fn attachments(mut self, attachments: &'a [Attachment]) -> Self {
@@ -359,6 +364,10 @@ impl TryIntoRequest for UpdateResponse<'_> {
             }
         }
 
+        if let Some(Nullable(Some(allowed_mentions))) = fields.allowed_mentions {
+            validate_allowed_mentions(allowed_mentions).map_err(Error::validation)?;
+        }
+
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
         if !self.attachment_manager.is_empty() {
@@ -387,10 +396,13 @@ impl TryIntoRequest for UpdateResponse<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{client::Client, request::TryIntoRequest};
+    use crate::{client::Client, error::ErrorType, request::TryIntoRequest};
     use std::error::Error;
     use twilight_http_ratelimiting::Path;
-    use twilight_model::id::Id;
+    use twilight_model::{
+        channel::message::{AllowedMentions, MentionType},
+        id::Id,
+    };
 
     #[test]
     fn delete_followup_message() -> Result<(), Box<dyn Error>> {
@@ -412,4 +424,32 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn allowed_mentions_invalid() {
+        let allowed_mentions = AllowedMentions {
+            parse: Vec::from([MentionType::Users]),
+            users: Vec::from([Id::new(2)]),
+            ..AllowedMentions::default()
+        };
+
+        let client = Client::new(String::new());
+        let request = client
+            .interaction(Id::new(1))
+            .update_response("foo")
+            .allowed_mentions(Some(&allowed_mentions))
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+
+        // Invalid client defaults are caught the same way.
+        let client = Client::builder()
+            .token(String::new())
+            .default_allowed_mentions(allowed_mentions)
+            .build();
+        let request = client
+            .interaction(Id::new(1))
+            .update_response("foo")
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+    }
 }
@@ -1,5 +1,6 @@
 use crate::request::Form;
-use serde::{Deserialize, Serialize};
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
+use std::borrow::Cow;
 use twilight_model::{
     http::attachment::Attachment,
     id::{marker::AttachmentMarker, Id},
@@ -18,6 +19,8 @@ impl<'a> AttachmentManager<'a> {
         }
     }
 
+    /// Build a form with the payload JSON followed by the files, ordered by
+    /// ID.
     pub fn build_form(&self, fields: &'a [u8]) -> Form {
         let mut form = Form::new().json_part(b"payload_json", fields);
 
@@ -27,7 +30,12 @@ impl<'a> AttachmentManager<'a> {
             push_digits(file.id, &mut name);
             name.extend(b"]");
 
-            form = form.file_part(name.as_ref(), file.filename.as_bytes(), file.file.as_ref());
+            form = form.file_part(
+                name.as_ref(),
+                sanitize_filename(&file.filename).as_bytes(),
+                file.content_type.as_deref().map(str::as_bytes),
+                file.file.as_ref(),
+            );
         }
 
         form
@@ -38,7 +46,7 @@ impl<'a> AttachmentManager<'a> {
             .iter()
             .map(|attachment| PartialAttachment {
                 description: attachment.description.as_deref(),
-                filename: Some(attachment.filename.as_ref()),
+                filename: Some(&attachment.filename),
                 id: attachment.id,
             })
             .chain(self.ids.iter().map(|id| PartialAttachment {
@@ -54,7 +62,8 @@ impl<'a> AttachmentManager<'a> {
     }
 
     #[must_use = "has no effect if not built into a Form"]
-    pub fn set_files(mut self, files: Vec<&'a Attachment>) -> Self {
+    pub fn set_files(mut self, mut files: Vec<&'a Attachment>) -> Self {
+        files.sort_by_key(|file| file.id);
         self.files = files;
 
         self
@@ -74,15 +83,69 @@ impl Default for AttachmentManager<'_> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq)]
 pub struct PartialAttachment<'a> {
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub filename: Option<&'a str>,
     pub id: u64,
 }
 
+/// Serialize the filename sanitized as it is in multipart forms, so that it
+/// matches the filename of the file's part.
+impl Serialize for PartialAttachment<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len =
+            1 + usize::from(self.description.is_some()) + usize::from(self.filename.is_some());
+        let mut state = serializer.serialize_struct("PartialAttachment", len)?;
+
+        if let Some(description) = self.description {
+            state.serialize_field("description", description)?;
+        }
+
+        if let Some(filename) = self.filename {
+            state.serialize_field("filename", &sanitize_filename(filename))?;
+        }
+
+        state.serialize_field("id", &self.id)?;
+
+        state.end()
+    }
+}
+
+/// Sanitize the filename of an attachment for use in multipart forms.
+///
+/// Quotes and backslashes are escaped with a backslash and carriage returns
+/// and line feeds are percent-encoded, as they would otherwise corrupt the
+/// form's headers. Other characters, including non-ASCII ones, are kept.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_http::request::attachment::sanitize_filename;
+///
+/// assert_eq!("🐱 cat.png", sanitize_filename("🐱 cat.png"));
+/// assert_eq!(r#"\"cat\".png"#, sanitize_filename(r#""cat".png"#));
+/// ```
+pub fn sanitize_filename(filename: &str) -> Cow<'_, str> {
+    if !filename.contains(['"', '\\', '\r', '\n']) {
+        return Cow::Borrowed(filename);
+    }
+
+    let mut sanitized = String::with_capacity(filename.len() + 2);
+
+    for c in filename.chars() {
+        match c {
+            '"' => sanitized.push_str("\\\""),
+            '\\' => sanitized.push_str("\\\\"),
+            '\r' => sanitized.push_str("%0D"),
+            '\n' => sanitized.push_str("%0A"),
+            _ => sanitized.push(c),
+        }
+    }
+
+    Cow::Owned(sanitized)
+}
+
 /// Count the number of digits in a given number.
 const fn num_digits(index: u64) -> usize {
     let mut index = index;
@@ -159,6 +222,55 @@ mod tests {
         assert_eq!(max_d[..], max_v[..]);
     }
 
+    #[test]
+    fn sanitize_filename_hostile() {
+        assert!(matches!(
+            sanitize_filename("a b/crab🦀.ü"),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(r#"a\"b\".png"#, sanitize_filename(r#"a"b".png"#));
+        assert_eq!("a%0D%0Ab.png", sanitize_filename("a\r\nb.png"));
+        assert_eq!(r"..\\etc\\passwd", sanitize_filename(r"..\etc\passwd"));
+    }
+
+    /// The form contains the payload JSON followed by the files ordered by ID,
+    /// with sanitized filenames, which partial attachments serialize the same.
+    #[test]
+    fn build_form_hostile() {
+        let first = Attachment::from_bytes("a\"\r\nb 🦀.png".to_owned(), b"1".to_vec(), 1);
+        let second = Attachment::from_bytes("../dir\\c.txt".to_owned(), b"2".to_vec(), 2);
+
+        let manager = AttachmentManager::new().set_files(vec![&second, &first]);
+        let partials = manager.get_partial_attachments();
+        assert_eq!(
+            [Some("a\"\r\nb 🦀.png"), Some("../dir\\c.txt")],
+            [partials[0].filename, partials[1].filename]
+        );
+        assert_eq!(
+            r#"[{"filename":"a\\\"%0D%0Ab 🦀.png","id":1},{"filename":"../dir\\\\c.txt","id":2}]"#,
+            serde_json::to_string(&partials).unwrap()
+        );
+
+        let form = manager.build_form(b"{}");
+        assert_eq!(
+            "--BOUNDARY\r\n\
+            Content-Disposition: form-data; name=\"payload_json\"\r\n\
+            Content-Type: application/json\r\n\
+            \r\n\
+            {}\r\n\
+            --BOUNDARY\r\n\
+            Content-Disposition: form-data; name=\"files[1]\"; filename=\"a\\\"%0D%0Ab 🦀.png\"\r\n\
+            \r\n\
+            1\r\n\
+            --BOUNDARY\r\n\
+            Content-Disposition: form-data; name=\"files[2]\"; filename=\"../dir\\\\c.txt\"\r\n\
+            \r\n\
+            2\r\n\
+            --BOUNDARY--",
+            form.build_string()
+        );
+    }
+
     #[test]
     fn num_digits_count() {
         assert_eq!(1, num_digits(0));
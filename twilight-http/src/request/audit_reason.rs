@@ -21,6 +21,7 @@ mod private {
         channel::{
             invite::{CreateInvite, DeleteInvite},
             message::{DeleteMessage, DeleteMessages},
+            stage::{CreateStageInstance, DeleteStageInstance, UpdateStageInstance},
             thread::UpdateThread,
             webhook::{CreateWebhook, DeleteWebhook, DeleteWebhookMessage, UpdateWebhook},
             CreatePin, DeleteChannel, DeleteChannelPermissionConfigured, DeletePin, UpdateChannel,
@@ -35,10 +36,10 @@ mod private {
             integration::DeleteGuildIntegration,
             member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
             role::{CreateRole, DeleteRole, UpdateRole, UpdateRolePositions},
-            sticker::{CreateGuildSticker, UpdateGuildSticker},
+            sticker::{CreateGuildSticker, DeleteGuildSticker, UpdateGuildSticker},
             update_guild_onboarding::UpdateGuildOnboarding,
             CreateGuildChannel, CreateGuildPrune, UpdateCurrentMember, UpdateGuild, UpdateGuildMfa,
-            UpdateGuildWidgetSettings,
+            UpdateGuildVanityUrl, UpdateGuildWidgetSettings,
         },
         scheduled_event::{
             CreateGuildExternalScheduledEvent, CreateGuildScheduledEvent,
@@ -65,6 +66,7 @@ mod private {
     impl Sealed for CreateInvite<'_> {}
     impl Sealed for CreatePin<'_> {}
     impl Sealed for CreateRole<'_> {}
+    impl Sealed for CreateStageInstance<'_> {}
     impl Sealed for CreateWebhook<'_> {}
     impl Sealed for DeleteAutoModerationRule<'_> {}
     impl Sealed for DeleteBan<'_> {}
@@ -72,11 +74,13 @@ mod private {
     impl Sealed for DeleteChannelPermissionConfigured<'_> {}
     impl Sealed for DeleteEmoji<'_> {}
     impl Sealed for DeleteGuildIntegration<'_> {}
+    impl Sealed for DeleteGuildSticker<'_> {}
     impl Sealed for DeleteInvite<'_> {}
     impl Sealed for DeleteMessage<'_> {}
     impl Sealed for DeleteMessages<'_> {}
     impl Sealed for DeletePin<'_> {}
     impl Sealed for DeleteRole<'_> {}
+    impl Sealed for DeleteStageInstance<'_> {}
     impl Sealed for DeleteWebhook<'_> {}
     impl Sealed for DeleteWebhookMessage<'_> {}
     impl Sealed for RemoveMember<'_> {}
@@ -93,9 +97,11 @@ mod private {
     impl Sealed for UpdateGuildOnboarding<'_> {}
     impl Sealed for UpdateGuildScheduledEvent<'_> {}
     impl Sealed for UpdateGuildSticker<'_> {}
+    impl Sealed for UpdateGuildVanityUrl<'_> {}
     impl Sealed for UpdateGuildWidgetSettings<'_> {}
     impl Sealed for UpdateRole<'_> {}
     impl Sealed for UpdateRolePositions<'_> {}
+    impl Sealed for UpdateStageInstance<'_> {}
     impl Sealed for UpdateThread<'_> {}
     impl Sealed for UpdateWebhook<'_> {}
 }
@@ -107,6 +113,7 @@ mod tests {
         channel::{
             invite::{CreateInvite, DeleteInvite},
             message::{DeleteMessage, DeleteMessages},
+            stage::{CreateStageInstance, DeleteStageInstance, UpdateStageInstance},
             webhook::{CreateWebhook, DeleteWebhook, UpdateWebhook},
             CreatePin, DeleteChannel, DeleteChannelPermissionConfigured, DeletePin, UpdateChannel,
             UpdateChannelPermission,
@@ -117,8 +124,9 @@ mod tests {
             integration::DeleteGuildIntegration,
             member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
             role::{CreateRole, DeleteRole, UpdateRole, UpdateRolePositions},
-            sticker::{CreateGuildSticker, UpdateGuildSticker},
+            sticker::{CreateGuildSticker, DeleteGuildSticker, UpdateGuildSticker},
             CreateGuildChannel, CreateGuildPrune, UpdateCurrentMember, UpdateGuild,
+            UpdateGuildVanityUrl,
         },
         user::UpdateCurrentUser,
     };
@@ -135,17 +143,20 @@ mod tests {
     assert_impl_all!(CreateInvite<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreatePin<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateRole<'_>: AuditLogReason<'static>);
+    assert_impl_all!(CreateStageInstance<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateWebhook<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteBan<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteChannel<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteChannelPermissionConfigured<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteEmoji<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteGuildIntegration<'_>: AuditLogReason<'static>);
+    assert_impl_all!(DeleteGuildSticker<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteInvite<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteMessage<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteMessages<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeletePin<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteRole<'_>: AuditLogReason<'static>);
+    assert_impl_all!(DeleteStageInstance<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteWebhook<'_>: AuditLogReason<'static>);
     assert_impl_all!(RemoveMember<'_>: AuditLogReason<'static>);
     assert_impl_all!(RemoveRoleFromMember<'_>: AuditLogReason<'static>);
@@ -157,7 +168,9 @@ mod tests {
     assert_impl_all!(UpdateGuild<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateGuildMember<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateGuildSticker<'_>: AuditLogReason<'static>);
+    assert_impl_all!(UpdateGuildVanityUrl<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateRole<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateRolePositions<'_>: AuditLogReason<'static>);
+    assert_impl_all!(UpdateStageInstance<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateWebhook<'_>: AuditLogReason<'static>);
 }
@@ -219,6 +219,14 @@ impl Request {
         self.form.as_ref()
     }
 
+    /// Whether the request has a multipart [form] instead of a JSON [body].
+    ///
+    /// [body]: Self::body
+    /// [form]: Self::form
+    pub const fn is_multipart(&self) -> bool {
+        self.form.is_some()
+    }
+
     /// Headers to set in the request, if any.
     pub const fn headers(&self) -> Option<&HeaderMap<HeaderValue>> {
         self.headers.as_ref()
@@ -247,9 +255,53 @@ impl Request {
 
 #[cfg(test)]
 mod tests {
-    use super::RequestBuilder;
+    use super::{Request, RequestBuilder};
+    use crate::{
+        request::{Form, Method},
+        routing::{Path, Route},
+    };
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{error::Error, fmt::Debug};
 
+    assert_impl_all!(Request: Clone, Debug, Send, Sync);
     assert_impl_all!(RequestBuilder: Debug, Send, Sync);
+
+    #[test]
+    fn inspection() -> Result<(), Box<dyn Error>> {
+        let route = Route::CreateMessage { channel_id: 1 };
+
+        let request = Request::from_route(&Route::GetMessage {
+            channel_id: 1,
+            message_id: 2,
+        });
+        assert_eq!(Method::Get, request.method());
+        assert_eq!("channels/1/messages/2", request.path());
+        assert_eq!(
+            &Path::ChannelsIdMessagesId(Method::Get, 1),
+            request.ratelimit_path()
+        );
+        assert!(request.body().is_none());
+        assert!(request.form().is_none());
+        assert!(!request.is_multipart());
+        assert!(request.use_authorization_token());
+
+        let request = Request::builder(&route)
+            .json(&serde_json::json!({ "content": "test" }))
+            .use_authorization_token(false)
+            .build()?;
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("channels/1/messages", request.path());
+        assert_eq!(Some(br#"{"content":"test"}"#.as_ref()), request.body());
+        assert!(!request.is_multipart());
+        assert!(!request.use_authorization_token());
+
+        let request = Request::builder(&route)
+            .form(Form::new().json_part(b"payload_json", br#"{"content":"test"}"#))
+            .build()?;
+        assert!(request.body().is_none());
+        assert!(request.form().is_some());
+        assert!(request.is_multipart());
+
+        Ok(())
+    }
 }
@@ -13,6 +13,12 @@ use twilight_model::id::{
 use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
 
 /// Create a new pin in a channel.
+///
+/// Channels may have at most 50 pinned messages. Pinning a message in a
+/// channel at the limit fails with an error for which
+/// [`ApiError::is_maximum_pins_reached`] is true.
+///
+/// [`ApiError::is_maximum_pins_reached`]: crate::api_error::ApiError::is_maximum_pins_reached
 #[must_use = "requests must be configured and executed"]
 pub struct CreatePin<'a> {
     channel_id: Id<ChannelMarker>,
@@ -17,14 +17,15 @@ use twilight_model::{
     },
     http::attachment::Attachment,
     id::{
-        marker::{ChannelMarker, MessageMarker, StickerMarker},
+        marker::{ChannelMarker, GuildMarker, MessageMarker, StickerMarker},
         Id,
     },
     poll::Poll,
 };
 use twilight_validate::message::{
-    attachment as validate_attachment, components as validate_components,
-    content as validate_content, embeds as validate_embeds, sticker_ids as validate_sticker_ids,
+    allowed_mentions as validate_allowed_mentions, attachment as validate_attachment,
+    components as validate_components, content as validate_content, embeds as validate_embeds,
+    sticker_guild as validate_sticker_guild, sticker_ids as validate_sticker_ids,
     MessageValidationError,
 };
 
@@ -133,12 +134,16 @@ impl<'a> CreateMessage<'a> {
     ///
     /// # Errors
     ///
+    /// Returns an error of type [`AttachmentContentType`] if any content type
+    /// is invalid.
+    ///
     /// Returns an error of type [`AttachmentDescriptionTooLarge`] if
     /// the attachments's description is too large.
     ///
     /// Returns an error of type [`AttachmentFilename`] if any filename is
     /// invalid.
     ///
+    /// [`AttachmentContentType`]: twilight_validate::message::MessageValidationErrorType::AttachmentContentType
     /// [`AttachmentDescriptionTooLarge`]: twilight_validate::message::MessageValidationErrorType::AttachmentDescriptionTooLarge
     /// [`AttachmentFilename`]: twilight_validate::message::MessageValidationErrorType::AttachmentFilename
     pub fn attachments(mut self, attachments: &'a [Attachment]) -> Self {
@@ -238,7 +243,10 @@ impl<'a> CreateMessage<'a> {
 
     /// Whether to fail sending if the reply no longer exists.
     ///
-    /// Defaults to [`true`].
+    /// Defaults to [`true`]. Has no effect unless a message is replied to or
+    /// forwarded, such as via [`reply`].
+    ///
+    /// [`reply`]: Self::reply
     pub fn fail_if_not_exists(mut self, fail_if_not_exists: bool) -> Self {
         if let Ok(fields) = self.fields.as_mut() {
             if let Some(reference) = fields.message_reference.as_mut() {
@@ -313,13 +321,7 @@ impl<'a> CreateMessage<'a> {
                     ..reference
                 }
             } else {
-                MessageReference {
-                    kind: MessageReferenceType::Default,
-                    channel_id: Some(channel_id),
-                    guild_id: None,
-                    message_id: Some(other),
-                    fail_if_not_exists: None,
-                }
+                MessageReference::reply(channel_id, other)
             };
 
             fields.message_reference = Some(reference);
@@ -342,13 +344,7 @@ impl<'a> CreateMessage<'a> {
                     ..reference
                 }
             } else {
-                MessageReference {
-                    kind: MessageReferenceType::Forward,
-                    channel_id: Some(channel_id),
-                    guild_id: None,
-                    message_id: Some(other),
-                    fail_if_not_exists: None,
-                }
+                MessageReference::forward(channel_id, other)
             };
 
             fields.message_reference = Some(reference);
@@ -361,11 +357,15 @@ impl<'a> CreateMessage<'a> {
 
     /// Set the IDs of up to 3 guild stickers.
     ///
+    /// Use [`sticker_ids_in_guild`] to also check that the stickers may be
+    /// sent in the channel's guild.
+    ///
     /// # Errors
     ///
     /// Returns an error of type [`StickersInvalid`] if the length is invalid.
     ///
     /// [`StickersInvalid`]: twilight_validate::message::MessageValidationErrorType::StickersInvalid
+    /// [`sticker_ids_in_guild`]: Self::sticker_ids_in_guild
     pub fn sticker_ids(mut self, sticker_ids: &'a [Id<StickerMarker>]) -> Self {
         self.fields = self.fields.and_then(|mut fields| {
             validate_sticker_ids(sticker_ids)?;
@@ -377,6 +377,67 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Set the IDs of up to 3 stickers, checking that they may be sent in the
+    /// channel's guild.
+    ///
+    /// Guild stickers may only be sent within the guild they belong to.
+    /// `guild_id` is the guild of the channel, or `None` for private channels.
+    /// `sticker_guild_id` looks up the guild a sticker belongs to, such as via
+    /// the cache, returning `None` for standard stickers.
+    ///
+    /// # Examples
+    ///
+    /// Look up the guilds of known guild stickers:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::collections::HashMap;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// let client = Client::new("my token".to_owned());
+    /// let sticker_guilds = HashMap::from([(Id::new(1), Id::new(3))]);
+    ///
+    /// let sticker_ids = [Id::new(1)];
+    /// client
+    ///     .create_message(Id::new(2))
+    ///     .sticker_ids_in_guild(&sticker_ids, Some(Id::new(3)), |sticker_id| {
+    ///         sticker_guilds.get(&sticker_id).copied()
+    ///     })
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`StickersInvalid`] if the length is invalid.
+    ///
+    /// Returns an error of type [`StickerUnavailable`] if a sticker belongs to
+    /// another guild.
+    ///
+    /// [`StickerUnavailable`]: twilight_validate::message::MessageValidationErrorType::StickerUnavailable
+    /// [`StickersInvalid`]: twilight_validate::message::MessageValidationErrorType::StickersInvalid
+    pub fn sticker_ids_in_guild(
+        mut self,
+        sticker_ids: &'a [Id<StickerMarker>],
+        guild_id: Option<Id<GuildMarker>>,
+        sticker_guild_id: impl Fn(Id<StickerMarker>) -> Option<Id<GuildMarker>>,
+    ) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_sticker_ids(sticker_ids)?;
+
+            for &sticker_id in sticker_ids {
+                validate_sticker_guild(guild_id, sticker_id, sticker_guild_id(sticker_id))?;
+            }
+
+            fields.sticker_ids = Some(sticker_ids);
+
+            Ok(fields)
+        });
+
+        self
+    }
+
     /// Specify true if the message is TTS.
     pub fn tts(mut self, tts: bool) -> Self {
         if let Ok(fields) = self.fields.as_mut() {
@@ -405,6 +466,17 @@ impl IntoFuture for CreateMessage<'_> {
 impl TryIntoRequest for CreateMessage<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         let mut fields = self.fields.map_err(Error::validation)?;
+
+        // Only replies and forwards can fail if their message doesn't exist, so
+        // don't send a reference without a message.
+        if fields
+            .message_reference
+            .as_ref()
+            .is_some_and(|reference| reference.message_id.is_none())
+        {
+            fields.message_reference = None;
+        }
+
         let mut request = Request::builder(&Route::CreateMessage {
             channel_id: self.channel_id.get(),
         });
@@ -416,6 +488,10 @@ impl TryIntoRequest for CreateMessage<'_> {
             }
         }
 
+        if let Some(Nullable(Some(allowed_mentions))) = fields.allowed_mentions {
+            validate_allowed_mentions(allowed_mentions).map_err(Error::validation)?;
+        }
+
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
         if !self.attachment_manager.is_empty() {
@@ -439,3 +515,146 @@ impl TryIntoRequest for CreateMessage<'_> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorType;
+    use std::error::Error;
+    use twilight_model::channel::message::MentionType;
+    use twilight_util::builder::AllowedMentionsBuilder;
+
+    #[test]
+    fn reply() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".into());
+
+        let request = client
+            .create_message(Id::new(1))
+            .reply(Id::new(2))
+            .try_into_request()?;
+        let expected = crate::json::to_vec(&MessageReference::reply(Id::new(1), Id::new(2)))?;
+        let expected = [br#"{"message_reference":"#.as_slice(), &expected, b"}"].concat();
+        assert_eq!(Some(expected.as_slice()), request.body());
+
+        let request = client
+            .create_message(Id::new(1))
+            .fail_if_not_exists(false)
+            .reply(Id::new(2))
+            .try_into_request()?;
+        let expected = crate::json::to_vec(&MessageReference {
+            fail_if_not_exists: Some(false),
+            ..MessageReference::reply(Id::new(1), Id::new(2))
+        })?;
+        let expected = [br#"{"message_reference":"#.as_slice(), &expected, b"}"].concat();
+        assert_eq!(Some(expected.as_slice()), request.body());
+
+        Ok(())
+    }
+
+    #[test]
+    fn sticker_ids_in_guild() {
+        let client = Client::new("token".into());
+        let guild_id = Id::new(1);
+        let sticker_ids = [Id::new(2), Id::new(3)];
+        // The first sticker is a standard sticker.
+        let sticker_guild_id = |guild_id| {
+            move |sticker_id: Id<StickerMarker>| (sticker_id == Id::new(3)).then_some(guild_id)
+        };
+
+        let request = client
+            .create_message(Id::new(4))
+            .sticker_ids_in_guild(&sticker_ids, Some(guild_id), sticker_guild_id(guild_id))
+            .try_into_request()
+            .unwrap();
+        assert_eq!(
+            Some(br#"{"sticker_ids":["2","3"]}"#.as_slice()),
+            request.body()
+        );
+
+        let error = client
+            .create_message(Id::new(4))
+            .sticker_ids_in_guild(&sticker_ids, Some(guild_id), sticker_guild_id(Id::new(5)))
+            .try_into_request()
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Validation));
+
+        let error = client
+            .create_message(Id::new(4))
+            .sticker_ids_in_guild(&sticker_ids, None, sticker_guild_id(guild_id))
+            .try_into_request()
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Validation));
+    }
+
+    #[test]
+    fn fail_if_not_exists_without_reply() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".into());
+
+        let request = client
+            .create_message(Id::new(1))
+            .fail_if_not_exists(true)
+            .try_into_request()?;
+        assert_eq!(Some(b"{}".as_slice()), request.body());
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_allowed_mentions() -> Result<(), Box<dyn Error>> {
+        let client = Client::builder()
+            .token("token".to_owned())
+            .default_allowed_mentions(AllowedMentionsBuilder::new().users([Id::new(2)]).into())
+            .build();
+
+        let request = client.create_message(Id::new(1)).try_into_request()?;
+        assert_eq!(
+            Some(br#"{"allowed_mentions":{"parse":[],"users":["2"]}}"#.as_slice()),
+            request.body()
+        );
+
+        let allowed_mentions = AllowedMentionsBuilder::new().everyone().build();
+        let request = client
+            .create_message(Id::new(1))
+            .allowed_mentions(Some(&allowed_mentions))
+            .try_into_request()?;
+        assert_eq!(
+            Some(br#"{"allowed_mentions":{"parse":["everyone"]}}"#.as_slice()),
+            request.body()
+        );
+
+        let request = client
+            .create_message(Id::new(1))
+            .allowed_mentions(None)
+            .try_into_request()?;
+        assert_eq!(
+            Some(br#"{"allowed_mentions":null}"#.as_slice()),
+            request.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn allowed_mentions_conflict() {
+        let allowed_mentions = AllowedMentions {
+            parse: Vec::from([MentionType::Users]),
+            users: Vec::from([Id::new(2)]),
+            ..AllowedMentions::default()
+        };
+
+        let client = Client::new("token".into());
+        let request = client
+            .create_message(Id::new(1))
+            .allowed_mentions(Some(&allowed_mentions))
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+
+        // Invalid client defaults are caught the same way.
+        let client = Client::builder()
+            .token("token".to_owned())
+            .default_allowed_mentions(allowed_mentions)
+            .build();
+        let request = client.create_message(Id::new(1)).try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+    }
+}
@@ -19,8 +19,9 @@ use twilight_model::{
     },
 };
 use twilight_validate::message::{
-    attachment as validate_attachment, components as validate_components,
-    content as validate_content, embeds as validate_embeds, MessageValidationError,
+    allowed_mentions as validate_allowed_mentions, attachment as validate_attachment,
+    components as validate_components, content as validate_content, embeds as validate_embeds,
+    MessageValidationError,
 };
 
 #[derive(Serialize)]
@@ -135,12 +136,16 @@ impl<'a> UpdateMessage<'a> {
     ///
     /// # Errors
     ///
+    /// Returns an error of type [`AttachmentContentType`] if any content type
+    /// is invalid.
+    ///
     /// Returns an error of type [`AttachmentDescriptionTooLarge`] if
     /// the attachments's description is too large.
     ///
     /// Returns an error of type [`AttachmentFilename`] if any filename is
     /// invalid.
     ///
+    /// [`AttachmentContentType`]: twilight_validate::message::MessageValidationErrorType::AttachmentContentType
     /// [`AttachmentDescriptionTooLarge`]: twilight_validate::message::MessageValidationErrorType::AttachmentDescriptionTooLarge
     /// [`AttachmentFilename`]: twilight_validate::message::MessageValidationErrorType::AttachmentFilename
     pub fn attachments(mut self, attachments: &'a [Attachment]) -> Self {
@@ -157,6 +162,39 @@ impl<'a> UpdateMessage<'a> {
         self
     }
 
+    /// Remove all of the message's components.
+    ///
+    /// Sends an empty list of components, unlike not setting any
+    /// [`components`] which leaves them unchanged.
+    ///
+    /// Calling this method will clear previous calls.
+    ///
+    /// [`components`]: Self::components
+    pub fn clear_components(mut self) -> Self {
+        if let Ok(fields) = self.fields.as_mut() {
+            fields.components = Some(Nullable(Some(&[])));
+        }
+
+        self
+    }
+
+    /// Remove all of the message's embeds.
+    ///
+    /// Sends an empty list of embeds, unlike not setting any [`embeds`] which
+    /// leaves them unchanged. This is impossible if it would leave the message
+    /// empty of `attachments`, `content`, `embeds`, or `sticker_ids`.
+    ///
+    /// Calling this method will clear previous calls.
+    ///
+    /// [`embeds`]: Self::embeds
+    pub fn clear_embeds(mut self) -> Self {
+        if let Ok(fields) = self.fields.as_mut() {
+            fields.embeds = Some(Nullable(Some(&[])));
+        }
+
+        self
+    }
+
     /// Set the message's list of [`Component`]s.
     ///
     /// Calling this method will clear previous calls.
@@ -340,6 +378,10 @@ impl TryIntoRequest for UpdateMessage<'_> {
             }
         }
 
+        if let Some(Nullable(Some(allowed_mentions))) = fields.allowed_mentions {
+            validate_allowed_mentions(allowed_mentions).map_err(Error::validation)?;
+        }
+
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
         if !self.attachment_manager.is_empty() {
@@ -392,4 +434,126 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn clear_embeds_components() -> Result<(), Box<dyn Error>> {
+        const CHANNEL_ID: Id<ChannelMarker> = Id::new(1);
+        const MESSAGE_ID: Id<MessageMarker> = Id::new(2);
+
+        let client = Client::new("token".into());
+
+        let expected = r#"{"components":[],"embeds":[]}"#;
+        let actual = UpdateMessage::new(&client, CHANNEL_ID, MESSAGE_ID)
+            .clear_components()
+            .clear_embeds()
+            .try_into_request()?;
+
+        assert_eq!(Some(expected.as_bytes()), actual.body());
+
+        // Leaving them unchanged omits the keys.
+        let expected = r#"{"content":"test"}"#;
+        let actual = UpdateMessage::new(&client, CHANNEL_ID, MESSAGE_ID)
+            .content(Some("test"))
+            .try_into_request()?;
+
+        assert_eq!(Some(expected.as_bytes()), actual.body());
+
+        // Clearing replaces previously set embeds.
+        let embeds = [Embed {
+            author: None,
+            color: None,
+            description: Some("description".to_owned()),
+            fields: Vec::new(),
+            footer: None,
+            image: None,
+            kind: "rich".to_owned(),
+            provider: None,
+            thumbnail: None,
+            timestamp: None,
+            title: None,
+            url: None,
+            video: None,
+        }];
+        let expected = r#"{"embeds":[]}"#;
+        let actual = UpdateMessage::new(&client, CHANNEL_ID, MESSAGE_ID)
+            .embeds(Some(&embeds))
+            .clear_embeds()
+            .try_into_request()?;
+
+        assert_eq!(Some(expected.as_bytes()), actual.body());
+
+        Ok(())
+    }
+
+    #[test]
+    fn nullable_fields() -> Result<(), Box<dyn Error>> {
+        const CHANNEL_ID: Id<ChannelMarker> = Id::new(1);
+        const MESSAGE_ID: Id<MessageMarker> = Id::new(2);
+
+        let client = Client::new("token".into());
+
+        // Unset fields are omitted, leaving them unchanged.
+        let actual = UpdateMessage::new(&client, CHANNEL_ID, MESSAGE_ID).try_into_request()?;
+
+        assert_eq!(Some(b"{}".as_ref()), actual.body());
+
+        // `None` serializes as null, removing them.
+        let expected =
+            r#"{"allowed_mentions":null,"components":null,"content":null,"embeds":null}"#;
+        let actual = UpdateMessage::new(&client, CHANNEL_ID, MESSAGE_ID)
+            .allowed_mentions(None)
+            .components(None)
+            .content(None)
+            .embeds(None)
+            .try_into_request()?;
+
+        assert_eq!(Some(expected.as_bytes()), actual.body());
+
+        // Values replace them.
+        let allowed_mentions = AllowedMentions {
+            replied_user: true,
+            ..AllowedMentions::default()
+        };
+        let expected = r#"{"allowed_mentions":{"parse":[],"replied_user":true},"components":[],"content":"test","embeds":[]}"#;
+        let actual = UpdateMessage::new(&client, CHANNEL_ID, MESSAGE_ID)
+            .allowed_mentions(Some(&allowed_mentions))
+            .components(Some(&[]))
+            .content(Some("test"))
+            .embeds(Some(&[]))
+            .try_into_request()?;
+
+        assert_eq!(Some(expected.as_bytes()), actual.body());
+
+        Ok(())
+    }
+
+    #[test]
+    fn keep_attachment_ids() -> Result<(), Box<dyn Error>> {
+        const CHANNEL_ID: Id<ChannelMarker> = Id::new(1);
+        const MESSAGE_ID: Id<MessageMarker> = Id::new(2);
+
+        let client = Client::new("token".into());
+
+        let request = UpdateMessage::new(&client, CHANNEL_ID, MESSAGE_ID)
+            .keep_attachment_ids(&[Id::new(3), Id::new(4)])
+            .try_into_request()?;
+
+        assert!(request.body().is_none());
+        let form = request.form().unwrap();
+        let content_type = String::from_utf8(form.content_type())?;
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+        let expected = format!(
+            "--{boundary}\r\n\
+            Content-Disposition: form-data; name=\"payload_json\"\r\n\
+            Content-Type: application/json\r\n\
+            \r\n\
+            {{\"attachments\":[{{\"id\":3}},{{\"id\":4}}]}}\r\n\
+            --{boundary}--",
+        );
+        assert_eq!(expected.as_bytes(), form.clone().build());
+
+        Ok(())
+    }
 }
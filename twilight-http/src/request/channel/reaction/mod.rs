@@ -12,7 +12,10 @@ pub use self::{
 };
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use twilight_model::id::{marker::EmojiMarker, Id};
+use twilight_model::{
+    channel::message::{EmojiReactionType, ReactionKey},
+    id::{marker::EmojiMarker, Id},
+};
 
 /// Handle a reaction of either a custom or unicode emoji.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -87,6 +90,27 @@ impl Display for RequestReactionType<'_> {
     }
 }
 
+impl<'a> From<&'a EmojiReactionType> for RequestReactionType<'a> {
+    fn from(emoji: &'a EmojiReactionType) -> Self {
+        match emoji {
+            EmojiReactionType::Custom { id, name, .. } => Self::Custom {
+                id: *id,
+                name: name.as_deref(),
+            },
+            EmojiReactionType::Unicode { name } => Self::Unicode { name },
+        }
+    }
+}
+
+impl From<&RequestReactionType<'_>> for ReactionKey {
+    fn from(reaction: &RequestReactionType<'_>) -> Self {
+        match reaction {
+            RequestReactionType::Custom { id, .. } => Self::Custom(*id),
+            RequestReactionType::Unicode { name } => Self::Unicode((*name).to_owned()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // `clippy::non_ascii_literal` can't be allowed on an item level; it can
@@ -99,11 +123,15 @@ mod tests {
         fmt::{Debug, Display},
         hash::Hash,
     };
-    use twilight_model::id::Id;
+    use twilight_model::{
+        channel::message::{EmojiReactionType, ReactionKey},
+        id::Id,
+    };
 
     assert_fields!(RequestReactionType::Custom: id, name);
     assert_fields!(RequestReactionType::Unicode: name);
     assert_impl_all!(RequestReactionType<'_>: Clone, Copy, Debug, Display, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(ReactionKey: From<&'static RequestReactionType<'static>>);
 
     #[test]
     fn display_custom_with_name() {
@@ -139,4 +167,48 @@ mod tests {
             reaction.to_string()
         );
     }
+
+    #[test]
+    fn from_emoji() {
+        let emoji = EmojiReactionType::custom(Id::new(123), Some("foo".to_owned()), true);
+        let reaction = RequestReactionType::from(&emoji);
+        assert_eq!(
+            RequestReactionType::Custom {
+                id: Id::new(123),
+                name: Some("foo"),
+            },
+            reaction
+        );
+        assert_eq!(emoji.to_reaction_string(), reaction.to_string());
+
+        let emoji = EmojiReactionType::unicode("👍");
+        assert_eq!(
+            RequestReactionType::Unicode { name: "👍" },
+            RequestReactionType::from(&emoji)
+        );
+    }
+
+    #[test]
+    fn key() {
+        let reaction = RequestReactionType::Custom {
+            id: Id::new(123),
+            name: Some("foo"),
+        };
+        let emoji = EmojiReactionType::Custom {
+            animated: true,
+            id: Id::new(123),
+            name: Some("bar".to_owned()),
+        };
+        assert_eq!(ReactionKey::from(&reaction), ReactionKey::from(&emoji));
+
+        let reaction = RequestReactionType::Unicode { name: "👍" };
+        assert_eq!(
+            ReactionKey::Unicode("👍".to_owned()),
+            ReactionKey::from(&reaction)
+        );
+        assert_ne!(
+            ReactionKey::from(&reaction),
+            ReactionKey::from(&RequestReactionType::Unicode { name: "👍🏽" })
+        );
+    }
 }
@@ -15,6 +15,7 @@ use twilight_model::{
     id::{marker::StickerMarker, Id},
 };
 use twilight_validate::message::{
+    attachment_content_type as validate_attachment_content_type,
     attachment_filename as validate_attachment_filename, components as validate_components,
     content as validate_content, embeds as validate_embeds, sticker_ids as validate_sticker_ids,
     MessageValidationError,
@@ -67,15 +68,24 @@ impl<'a> CreateForumThreadMessage<'a> {
     ///
     /// # Errors
     ///
+    /// Returns an error of type [`AttachmentContentType`] if any content type
+    /// is invalid.
+    ///
     /// Returns an error of type [`AttachmentFilename`] if any filename is
     /// invalid.
     ///
+    /// [`AttachmentContentType`]: twilight_validate::message::MessageValidationErrorType::AttachmentContentType
     /// [`AttachmentFilename`]: twilight_validate::message::MessageValidationErrorType::AttachmentFilename
     pub fn attachments(mut self, attachments: &'a [Attachment]) -> Self {
         if self.0.is_ok() {
-            let validation = attachments
-                .iter()
-                .try_for_each(|attachment| validate_attachment_filename(&attachment.filename));
+            let validation = attachments.iter().try_for_each(|attachment| {
+                validate_attachment_filename(&attachment.filename)?;
+
+                attachment
+                    .content_type
+                    .as_ref()
+                    .map_or(Ok(()), validate_attachment_content_type)
+            });
 
             if let Err(source) = validation {
                 self.0 = Err(source);
@@ -1,3 +1,5 @@
+//! Create a thread in a forum channel along with its first message.
+
 mod message;
 
 pub use self::message::CreateForumThreadMessage;
@@ -18,11 +20,21 @@ use twilight_model::{
         Id,
     },
 };
+use twilight_validate::{
+    channel::{
+        auto_archive_duration as validate_auto_archive_duration,
+        rate_limit_per_user as validate_rate_limit_per_user,
+    },
+    message::allowed_mentions as validate_allowed_mentions,
+};
 
-#[derive(Deserialize, Serialize)]
+/// Created forum thread and its first message.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ForumThread {
+    /// Created thread.
     #[serde(flatten)]
     pub channel: Channel,
+    /// First message in the thread.
     pub message: Message,
 }
 
@@ -87,11 +99,17 @@ impl<'a> CreateForumThread<'a> {
         self
     }
 
-    /// Set the default auto archive duration for newly created threads in the
-    /// channel.
+    /// Set the thread's auto archive duration.
     ///
     /// Automatic archive durations are not locked behind the guild's boost
     /// level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AutoArchiveDurationInvalid`] when sending the
+    /// request if the duration is [`AutoArchiveDuration::Unknown`].
+    ///
+    /// [`AutoArchiveDurationInvalid`]: twilight_validate::channel::ChannelValidationErrorType::AutoArchiveDurationInvalid
     pub const fn auto_archive_duration(
         mut self,
         auto_archive_duration: AutoArchiveDuration,
@@ -101,10 +119,33 @@ impl<'a> CreateForumThread<'a> {
         self
     }
 
+    /// Configure the first message in the thread.
+    ///
+    /// A forum thread can't be created without a message, so the request is
+    /// sent by the returned builder.
     pub const fn message(self) -> CreateForumThreadMessage<'a> {
         CreateForumThreadMessage::new(self)
     }
 
+    /// Set the number of seconds that a user must wait before they are able
+    /// to send another message.
+    ///
+    /// The minimum is 0 and the maximum is 21600. This is also known as "Slow
+    /// Mode". See [Discord Docs/Channel Object].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`RateLimitPerUserInvalid`] when sending the
+    /// request if the amount is greater than 21600.
+    ///
+    /// [`RateLimitPerUserInvalid`]: twilight_validate::channel::ChannelValidationErrorType::RateLimitPerUserInvalid
+    /// [Discord Docs/Channel Object]: https://discordapp.com/developers/docs/resources/channel#channel-object-channel-structure
+    pub const fn rate_limit_per_user(mut self, rate_limit_per_user: u16) -> Self {
+        self.fields.rate_limit_per_user = Some(rate_limit_per_user);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -118,6 +159,14 @@ impl<'a> CreateForumThread<'a> {
     }
 
     fn try_into_request(mut self) -> Result<Request, Error> {
+        if let Some(auto_archive_duration) = self.fields.auto_archive_duration {
+            validate_auto_archive_duration(auto_archive_duration).map_err(Error::validation)?;
+        }
+
+        if let Some(rate_limit_per_user) = self.fields.rate_limit_per_user {
+            validate_rate_limit_per_user(rate_limit_per_user).map_err(Error::validation)?;
+        }
+
         let mut request = Request::builder(&Route::CreateForumThread {
             channel_id: self.channel_id.get(),
         });
@@ -129,6 +178,10 @@ impl<'a> CreateForumThread<'a> {
             }
         }
 
+        if let Some(Nullable(Some(allowed_mentions))) = self.fields.message.allowed_mentions {
+            validate_allowed_mentions(allowed_mentions).map_err(Error::validation)?;
+        }
+
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
         if !self.attachment_manager.is_empty() {
@@ -153,3 +206,131 @@ impl<'a> CreateForumThread<'a> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::ErrorType, request::TryIntoRequest, Client};
+    use std::error::Error;
+    use twilight_model::{
+        channel::{
+            message::{AllowedMentions, MentionType},
+            thread::AutoArchiveDuration,
+        },
+        http::attachment::Attachment,
+        id::Id,
+    };
+
+    #[test]
+    fn json() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".into());
+        let tags = [Id::new(2)];
+
+        let request = client
+            .create_forum_thread(Id::new(1), "thread")
+            .applied_tags(&tags)
+            .auto_archive_duration(AutoArchiveDuration::Day)
+            .rate_limit_per_user(60)
+            .message()
+            .content("content")
+            .try_into_request()?;
+
+        assert_eq!("channels/1/threads", request.path());
+        assert!(request.form().is_none());
+        assert_eq!(
+            Some(
+                br#"{"applied_tags":["2"],"auto_archive_duration":1440,"message":{"content":"content"},"name":"thread","rate_limit_per_user":60}"#
+                    .as_slice()
+            ),
+            request.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn multipart() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".into());
+        let attachments = [Attachment::from_bytes(
+            "file.txt".to_owned(),
+            b"file".to_vec(),
+            1,
+        )];
+
+        let request = client
+            .create_forum_thread(Id::new(1), "thread")
+            .message()
+            .attachments(&attachments)
+            .try_into_request()?;
+
+        assert!(request.body().is_none());
+        let form = request.form().unwrap();
+        let content_type = String::from_utf8(form.content_type())?;
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+        let expected = format!(
+            "--{boundary}\r\n\
+            Content-Disposition: form-data; name=\"payload_json\"\r\n\
+            Content-Type: application/json\r\n\
+            \r\n\
+            {{\"message\":{{\"attachments\":[{{\"filename\":\"file.txt\",\"id\":1}}]}},\"name\":\"thread\"}}\r\n\
+            --{boundary}\r\n\
+            Content-Disposition: form-data; name=\"files[1]\"; filename=\"file.txt\"\r\n\
+            \r\n\
+            file\r\n\
+            --{boundary}--",
+        );
+        assert_eq!(expected.as_bytes(), form.clone().build());
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() {
+        let client = Client::new("token".into());
+
+        let request = client
+            .create_forum_thread(Id::new(1), "thread")
+            .rate_limit_per_user(21_601)
+            .message()
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+
+        let request = client
+            .create_forum_thread(Id::new(1), "thread")
+            .auto_archive_duration(AutoArchiveDuration::from(30))
+            .message()
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+    }
+
+    #[test]
+    fn allowed_mentions_invalid() {
+        let allowed_mentions = AllowedMentions {
+            parse: Vec::from([MentionType::Users]),
+            users: Vec::from([Id::new(2)]),
+            ..AllowedMentions::default()
+        };
+
+        let client = Client::new(String::new());
+        let request = client
+            .create_forum_thread(Id::new(1), "thread")
+            .message()
+            .allowed_mentions(Some(&allowed_mentions))
+            .content("content")
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+
+        // Invalid client defaults are caught the same way.
+        let client = Client::builder()
+            .token(String::new())
+            .default_allowed_mentions(allowed_mentions)
+            .build();
+        let request = client
+            .create_forum_thread(Id::new(1), "thread")
+            .message()
+            .content("content")
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+    }
+}
@@ -12,7 +12,8 @@ use twilight_model::{
     id::{marker::ChannelMarker, Id},
 };
 use twilight_validate::channel::{
-    is_thread as validate_is_thread, name as validate_name, ChannelValidationError,
+    auto_archive_duration as validate_auto_archive_duration, is_thread as validate_is_thread,
+    name as validate_name, ChannelValidationError,
 };
 
 #[derive(Serialize)]
@@ -70,6 +71,13 @@ impl<'a> CreateThread<'a> {
     ///
     /// Automatic archive durations are not locked behind the guild's boost
     /// level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AutoArchiveDurationInvalid`] when sending the
+    /// request if the duration is [`AutoArchiveDuration::Unknown`].
+    ///
+    /// [`AutoArchiveDurationInvalid`]: twilight_validate::channel::ChannelValidationErrorType::AutoArchiveDurationInvalid
     pub fn auto_archive_duration(mut self, auto_archive_duration: AutoArchiveDuration) -> Self {
         if let Ok(fields) = self.fields.as_mut() {
             fields.auto_archive_duration = Some(auto_archive_duration);
@@ -107,6 +115,10 @@ impl TryIntoRequest for CreateThread<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         let fields = self.fields.map_err(Error::validation)?;
 
+        if let Some(auto_archive_duration) = fields.auto_archive_duration {
+            validate_auto_archive_duration(auto_archive_duration).map_err(Error::validation)?;
+        }
+
         Request::builder(&Route::CreateThread {
             channel_id: self.channel_id.get(),
         })
@@ -114,3 +126,48 @@ impl TryIntoRequest for CreateThread<'_> {
         .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, error::ErrorType, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::{
+        channel::{thread::AutoArchiveDuration, ChannelType},
+        id::Id,
+    };
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        let request = client
+            .create_thread(Id::new(1), "thread", ChannelType::PublicThread)
+            .auto_archive_duration(AutoArchiveDuration::Week)
+            .try_into_request()?;
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("channels/1/threads", request.path());
+        assert_eq!(
+            Some(br#"{"auto_archive_duration":10080,"type":11,"name":"thread"}"#.as_slice()),
+            request.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() {
+        let client = Client::new(String::new());
+
+        let request = client
+            .create_thread(Id::new(1), "thread", ChannelType::GuildText)
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+
+        let request = client
+            .create_thread(Id::new(1), "thread", ChannelType::PublicThread)
+            .auto_archive_duration(AutoArchiveDuration::from(30))
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+    }
+}
@@ -14,7 +14,10 @@ use twilight_model::{
         Id,
     },
 };
-use twilight_validate::channel::{name as validate_name, ChannelValidationError};
+use twilight_validate::channel::{
+    auto_archive_duration as validate_auto_archive_duration, name as validate_name,
+    ChannelValidationError,
+};
 
 #[derive(Serialize)]
 struct CreateThreadFromMessageFields<'a> {
@@ -80,6 +83,13 @@ impl<'a> CreateThreadFromMessage<'a> {
     ///
     /// Automatic archive durations are not locked behind the guild's boost
     /// level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AutoArchiveDurationInvalid`] when sending the
+    /// request if the duration is [`AutoArchiveDuration::Unknown`].
+    ///
+    /// [`AutoArchiveDurationInvalid`]: twilight_validate::channel::ChannelValidationErrorType::AutoArchiveDurationInvalid
     pub fn auto_archive_duration(mut self, auto_archive_duration: AutoArchiveDuration) -> Self {
         if let Ok(fields) = self.fields.as_mut() {
             fields.auto_archive_duration = Some(auto_archive_duration);
@@ -108,6 +118,10 @@ impl TryIntoRequest for CreateThreadFromMessage<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         let fields = self.fields.map_err(Error::validation)?;
 
+        if let Some(auto_archive_duration) = fields.auto_archive_duration {
+            validate_auto_archive_duration(auto_archive_duration).map_err(Error::validation)?;
+        }
+
         Request::builder(&Route::CreateThreadFromMessage {
             channel_id: self.channel_id.get(),
             message_id: self.message_id.get(),
@@ -116,3 +130,52 @@ impl TryIntoRequest for CreateThreadFromMessage<'_> {
         .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, error::ErrorType, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::{channel::thread::AutoArchiveDuration, id::Id};
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        let request = client
+            .create_thread_from_message(Id::new(1), Id::new(2), "thread")
+            .try_into_request()?;
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("channels/1/messages/2/threads", request.path());
+        assert_eq!(Some(br#"{"name":"thread"}"#.as_slice()), request.body());
+
+        let request = client
+            .create_thread_from_message(Id::new(1), Id::new(2), "thread")
+            .auto_archive_duration(AutoArchiveDuration::Week)
+            .try_into_request()?;
+        assert_eq!(
+            Some(br#"{"auto_archive_duration":10080,"name":"thread"}"#.as_slice()),
+            request.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() {
+        let client = Client::new(String::new());
+        let name = "a".repeat(101);
+
+        let request = client
+            .create_thread_from_message(Id::new(1), Id::new(2), &name)
+            .auto_archive_duration(AutoArchiveDuration::Hour)
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+
+        let request = client
+            .create_thread_from_message(Id::new(1), Id::new(2), "thread")
+            .auto_archive_duration(AutoArchiveDuration::from(30))
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+    }
+}
@@ -16,8 +16,8 @@ use twilight_model::{
 };
 use twilight_validate::{
     channel::{
-        name as validate_name, rate_limit_per_user as validate_rate_limit_per_user,
-        ChannelValidationError,
+        auto_archive_duration as validate_auto_archive_duration, name as validate_name,
+        rate_limit_per_user as validate_rate_limit_per_user, ChannelValidationError,
     },
     request::{audit_reason as validate_audit_reason, ValidationError},
 };
@@ -98,6 +98,13 @@ impl<'a> UpdateThread<'a> {
     ///
     /// Automatic archive durations are not locked behind the guild's boost
     /// level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AutoArchiveDurationInvalid`] when sending the
+    /// request if the duration is [`AutoArchiveDuration::Unknown`].
+    ///
+    /// [`AutoArchiveDurationInvalid`]: twilight_validate::channel::ChannelValidationErrorType::AutoArchiveDurationInvalid
     pub fn auto_archive_duration(mut self, auto_archive_duration: AutoArchiveDuration) -> Self {
         if let Ok(fields) = self.fields.as_mut() {
             fields.auto_archive_duration = Some(auto_archive_duration);
@@ -200,6 +207,11 @@ impl IntoFuture for UpdateThread<'_> {
 impl TryIntoRequest for UpdateThread<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         let fields = self.fields.map_err(Error::validation)?;
+
+        if let Some(auto_archive_duration) = fields.auto_archive_duration {
+            validate_auto_archive_duration(auto_archive_duration).map_err(Error::validation)?;
+        }
+
         let mut request = Request::builder(&Route::UpdateChannel {
             channel_id: self.channel_id.get(),
         })
@@ -217,12 +229,13 @@ impl TryIntoRequest for UpdateThread<'_> {
 mod tests {
     use super::{UpdateThread, UpdateThreadFields};
     use crate::{
+        error::ErrorType,
         request::{Request, TryIntoRequest},
         routing::Route,
         Client,
     };
     use std::error::Error;
-    use twilight_model::id::Id;
+    use twilight_model::{channel::thread::AutoArchiveDuration, id::Id};
 
     #[test]
     fn request() -> Result<(), Box<dyn Error>> {
@@ -251,6 +264,26 @@ mod tests {
         assert_eq!(expected.path(), actual.path());
         assert_eq!(expected.ratelimit_path(), actual.ratelimit_path());
 
+        Ok(())
+    }
+    #[test]
+    fn auto_archive_duration() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_string());
+        let channel_id = Id::new(123);
+
+        let request = UpdateThread::new(&client, channel_id)
+            .auto_archive_duration(AutoArchiveDuration::Day)
+            .try_into_request()?;
+        assert_eq!(
+            Some(br#"{"auto_archive_duration":1440}"#.as_slice()),
+            request.body()
+        );
+
+        let request = UpdateThread::new(&client, channel_id)
+            .auto_archive_duration(AutoArchiveDuration::from(30))
+            .try_into_request();
+        assert!(matches!(request.unwrap_err().kind(), ErrorType::Validation));
+
         Ok(())
     }
 }
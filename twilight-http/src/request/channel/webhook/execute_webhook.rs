@@ -21,9 +21,9 @@ use twilight_model::{
 };
 use twilight_validate::{
     message::{
-        attachment as validate_attachment, components as validate_components,
-        content as validate_content, embeds as validate_embeds, MessageValidationError,
-        MessageValidationErrorType,
+        allowed_mentions as validate_allowed_mentions, attachment as validate_attachment,
+        components as validate_components, content as validate_content, embeds as validate_embeds,
+        MessageValidationError, MessageValidationErrorType,
     },
     request::webhook_username as validate_webhook_username,
 };
@@ -138,12 +138,16 @@ impl<'a> ExecuteWebhook<'a> {
     ///
     /// # Errors
     ///
+    /// Returns an error of type [`AttachmentContentType`] if any content type
+    /// is invalid.
+    ///
     /// Returns an error of type [`AttachmentDescriptionTooLarge`] if
     /// the attachments's description is too large.
     ///
     /// Returns an error of type [`AttachmentFilename`] if any filename is
     /// invalid.
     ///
+    /// [`AttachmentContentType`]: twilight_validate::message::MessageValidationErrorType::AttachmentContentType
     /// [`AttachmentDescriptionTooLarge`]: twilight_validate::message::MessageValidationErrorType::AttachmentDescriptionTooLarge
     /// [`AttachmentFilename`]: twilight_validate::message::MessageValidationErrorType::AttachmentFilename
     pub fn attachments(mut self, attachments: &'a [Attachment]) -> Self {
@@ -418,6 +422,10 @@ impl TryIntoRequest for ExecuteWebhook<'_> {
             }
         }
 
+        if let Some(Nullable(Some(allowed_mentions))) = fields.allowed_mentions {
+            validate_allowed_mentions(allowed_mentions).map_err(Error::validation)?;
+        }
+
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
         if !self.attachment_manager.is_empty() {
@@ -441,3 +449,91 @@ impl TryIntoRequest for ExecuteWebhook<'_> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorType;
+    use std::error::Error;
+    use twilight_util::builder::AllowedMentionsBuilder;
+    use twilight_validate::request::WEBHOOK_USERNAME_LIMIT_MAX;
+
+    const TOKEN: &str = "token";
+    const WEBHOOK_ID: Id<WebhookMarker> = Id::new(1);
+
+    #[test]
+    fn query() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".into());
+
+        let request = ExecuteWebhook::new(&client, WEBHOOK_ID, TOKEN)
+            .content("test")
+            .try_into_request()?;
+        assert_eq!("webhooks/1/token?wait=false", request.path());
+        assert!(!request.use_authorization_token());
+
+        let request = ExecuteWebhook::new(&client, WEBHOOK_ID, TOKEN)
+            .content("test")
+            .thread_id(Id::new(2))
+            .wait()
+            .try_into_request()?;
+        assert_eq!("webhooks/1/token?thread_id=2&wait=true", request.path());
+
+        Ok(())
+    }
+
+    #[test]
+    fn overrides() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".into());
+
+        let request = ExecuteWebhook::new(&client, WEBHOOK_ID, TOKEN)
+            .avatar_url("https://example.com/avatar.png")
+            .content("test")
+            .flags(MessageFlags::SUPPRESS_EMBEDS)
+            .username("twilight")
+            .try_into_request()?;
+
+        let expected = r#"{"avatar_url":"https://example.com/avatar.png","content":"test","flags":4,"username":"twilight"}"#;
+        assert_eq!(Some(expected.as_bytes()), request.body());
+
+        Ok(())
+    }
+
+    #[test]
+    fn username_invalid() {
+        let client = Client::new("token".into());
+
+        let username = "a".repeat(WEBHOOK_USERNAME_LIMIT_MAX + 1);
+        let names = ["Clyde", "not clyde", username.as_str()];
+
+        for name in names {
+            let error = ExecuteWebhook::new(&client, WEBHOOK_ID, TOKEN)
+                .content("test")
+                .username(name)
+                .try_into_request()
+                .unwrap_err();
+
+            assert!(matches!(error.kind(), ErrorType::Validation), "{name}");
+        }
+    }
+
+    #[test]
+    fn default_allowed_mentions() -> Result<(), Box<dyn Error>> {
+        let client = Client::builder()
+            .default_allowed_mentions(AllowedMentionsBuilder::new().replied_user(true).into())
+            .build();
+
+        let request = client
+            .execute_webhook(WEBHOOK_ID, TOKEN)
+            .content("test")
+            .try_into_request()?;
+        assert_eq!(
+            Some(
+                br#"{"allowed_mentions":{"parse":[],"replied_user":true},"content":"test"}"#
+                    .as_slice()
+            ),
+            request.body()
+        );
+
+        Ok(())
+    }
+}
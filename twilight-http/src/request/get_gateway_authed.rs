@@ -1,13 +1,55 @@
 use crate::{
     client::Client,
-    error::Error,
+    error::{Error, ErrorType},
     request::{Request, TryIntoRequest},
     response::{Response, ResponseFuture},
     routing::Route,
 };
-use std::future::IntoFuture;
+use hyper::body::Bytes;
+use std::{
+    future::IntoFuture,
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
 use twilight_model::gateway::connection_info::BotConnectionInfo;
 
+/// Gateway information cached by a [`Client`].
+///
+/// Configured via [`ClientBuilder::cache_gateway_info`].
+///
+/// [`ClientBuilder::cache_gateway_info`]: crate::client::ClientBuilder::cache_gateway_info
+#[derive(Debug)]
+pub(crate) struct GatewayInfoCache {
+    /// Response body of the gateway information and when it was fetched.
+    entry: Mutex<Option<(Instant, Bytes)>>,
+    /// Duration the gateway information is cached for.
+    ttl: Duration,
+}
+
+impl GatewayInfoCache {
+    pub(crate) const fn new(ttl: Duration) -> Self {
+        Self {
+            entry: Mutex::new(None),
+            ttl,
+        }
+    }
+
+    /// Cached gateway information, if it hasn't expired.
+    fn get(&self) -> Option<Bytes> {
+        let entry = self.entry.lock().unwrap_or_else(PoisonError::into_inner);
+
+        entry
+            .as_ref()
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < self.ttl)
+            .map(|(_, body)| body.clone())
+    }
+
+    /// Replace the cached gateway information.
+    fn set(&self, body: Bytes) {
+        *self.entry.lock().unwrap_or_else(PoisonError::into_inner) = Some((Instant::now(), body));
+    }
+}
+
 /// Get information about the gateway, authenticated as a bot user.
 ///
 /// Returns additional information: the recommended number of shards to use, and information on
@@ -21,6 +63,35 @@ impl<'a> GetGatewayAuthed<'a> {
     pub(crate) const fn new(http: &'a Client) -> Self {
         Self { http }
     }
+
+    /// Execute the request and deserialize the gateway information.
+    ///
+    /// Like awaiting the request, this returns the information cached by the
+    /// client if configured via [`ClientBuilder::cache_gateway_info`] and it
+    /// hasn't expired.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorType::ChunkingResponse`] error type if the response
+    /// body could not be read.
+    ///
+    /// Returns an [`ErrorType::Parsing`] error type if the response body
+    /// failed to deserialize.
+    ///
+    /// Refer to [`Client::request`] for other possible errors.
+    ///
+    /// [`ClientBuilder::cache_gateway_info`]: crate::client::ClientBuilder::cache_gateway_info
+    pub async fn info(self) -> Result<BotConnectionInfo, Error> {
+        let bytes = self.await?.bytes().await.map_err(|source| Error {
+            kind: ErrorType::ChunkingResponse,
+            source: Some(Box::new(source)),
+        })?;
+
+        crate::json::from_bytes(&bytes).map_err(|source| Error {
+            kind: ErrorType::Parsing { body: bytes },
+            source: Some(Box::new(source)),
+        })
+    }
 }
 
 impl IntoFuture for GetGatewayAuthed<'_> {
@@ -30,10 +101,25 @@ impl IntoFuture for GetGatewayAuthed<'_> {
 
     fn into_future(self) -> Self::IntoFuture {
         let http = self.http;
+        let cache = http.gateway_info.clone();
+
+        if let Some(body) = cache.as_deref().and_then(GatewayInfoCache::get) {
+            return ResponseFuture::cached(body);
+        }
 
-        match self.try_into_request() {
+        let future = match self.try_into_request() {
             Ok(request) => http.request(request),
-            Err(source) => ResponseFuture::error(source),
+            Err(source) => return ResponseFuture::error(source),
+        };
+
+        match cache {
+            Some(cache) => future.inspect_body(move |body| {
+                // Only cache gateway information that deserializes.
+                if crate::json::from_bytes::<BotConnectionInfo>(body).is_ok() {
+                    cache.set(body.clone());
+                }
+            }),
+            None => future,
         }
     }
 }
@@ -43,3 +129,72 @@ impl TryIntoRequest for GetGatewayAuthed<'_> {
         Ok(Request::from_route(&Route::GetGatewayBot))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test::{Response, Server},
+        Client,
+    };
+    use std::time::Duration;
+    use tokio::time;
+
+    const BODY: &str = r#"{"url":"wss://gateway.discord.gg","shards":2,"session_start_limit":{"max_concurrency":1,"remaining":999,"reset_after":14400000,"total":1000}}"#;
+
+    /// Start a mock API server returning gateway information.
+    async fn server() -> Server {
+        Server::start(|_| async { Response::json("200 OK", BODY) }).await
+    }
+
+    #[tokio::test]
+    async fn cached() {
+        let server = server().await;
+        let client = Client::builder()
+            .api_base_url(server.url())
+            .cache_gateway_info(Duration::from_millis(200))
+            .ratelimiter(None)
+            .build();
+
+        let info = client.gateway().authed().info().await.unwrap();
+        assert_eq!(2, info.shards);
+        assert_eq!(999, info.session_start_limit.remaining);
+        assert_eq!(info, client.gateway().authed().info().await.unwrap());
+        assert_eq!(1, server.requests());
+
+        time::sleep(Duration::from_millis(200)).await;
+        client.gateway().authed().info().await.unwrap();
+        assert_eq!(2, server.requests());
+    }
+
+    #[tokio::test]
+    async fn cached_response() {
+        let server = server().await;
+        let client = Client::builder()
+            .api_base_url(server.url())
+            .cache_gateway_info(Duration::from_secs(60))
+            .ratelimiter(None)
+            .build();
+
+        let response = client.gateway().authed().await.unwrap();
+        assert_eq!(BODY, response.text().await.unwrap());
+
+        let response = client.gateway().authed().await.unwrap();
+        assert_eq!(200, response.status().get());
+        let info = response.model().await.unwrap();
+        assert_eq!(info, client.gateway().authed().info().await.unwrap());
+        assert_eq!(1, server.requests());
+    }
+
+    #[tokio::test]
+    async fn uncached() {
+        let server = server().await;
+        let client = Client::builder()
+            .api_base_url(server.url())
+            .ratelimiter(None)
+            .build();
+
+        client.gateway().authed().info().await.unwrap();
+        client.gateway().authed().info().await.unwrap();
+        assert_eq!(2, server.requests());
+    }
+}
@@ -0,0 +1,333 @@
+use crate::{
+    client::Client,
+    error::{Error, ErrorType},
+};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, VecDeque},
+};
+use twilight_model::{
+    guild::audit_log::{AuditLog, AuditLogEntry, AuditLogEventType},
+    id::{
+        marker::{AuditLogEntryMarker, GuildMarker, UserMarker},
+        Id, DISCORD_EPOCH,
+    },
+    user::User,
+    util::Timestamp,
+};
+
+/// Maximum number of entries to request per page.
+const PAGE_LIMIT: u16 = 100;
+
+/// Audit log entry with the user that performed the action.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditLogIterEntry {
+    /// Entry in the audit log.
+    pub entry: AuditLogEntry,
+    /// User that performed the action, if the audit log included them.
+    pub user: Option<User>,
+}
+
+/// Iterate over the audit log of a guild from newest to oldest entry, fetching
+/// pages as needed.
+///
+/// Entries are joined with the [`User`] that performed the action, which
+/// audit log responses include separately from the entries.
+///
+/// # Examples
+///
+/// Print the bans of the last 30 days:
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::{env, time::Duration};
+/// use twilight_http::Client;
+/// use twilight_model::{guild::audit_log::AuditLogEventType, id::Id, util::Timestamp};
+///
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+/// let until = Timestamp::now()
+///     .checked_sub(Duration::from_secs(30 * 24 * 60 * 60))
+///     .unwrap();
+///
+/// let mut bans = client
+///     .audit_log_iter(Id::new(1))
+///     .action_type(AuditLogEventType::MemberBanAdd)
+///     .until(until);
+///
+/// while let Some(ban) = bans.next().await {
+///     let ban = ban?;
+///     let executor = ban.user.map(|user| user.name);
+///
+///     println!("{:?} banned by {executor:?}", ban.entry.target_id);
+/// }
+/// # Ok(()) }
+/// ```
+#[must_use = "iterators must be polled"]
+pub struct AuditLogIter<'a> {
+    action_type: Option<AuditLogEventType>,
+    /// ID of the oldest entry fetched so far.
+    before: Option<Id<AuditLogEntryMarker>>,
+    /// Whether no more pages need to be fetched.
+    done: bool,
+    /// Fetched entries that have yet to be returned.
+    entries: VecDeque<AuditLogIterEntry>,
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+    /// Unix timestamp in milliseconds of the oldest entries to return.
+    until: Option<u64>,
+    user_id: Option<Id<UserMarker>>,
+}
+
+impl<'a> AuditLogIter<'a> {
+    pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self {
+            action_type: None,
+            before: None,
+            done: false,
+            entries: VecDeque::new(),
+            guild_id,
+            http,
+            until: None,
+            user_id: None,
+        }
+    }
+
+    /// Only return entries of an action type.
+    pub const fn action_type(mut self, action_type: AuditLogEventType) -> Self {
+        self.action_type = Some(action_type);
+
+        self
+    }
+
+    /// Only return entries created at or after a timestamp.
+    ///
+    /// No more pages are fetched once an older entry is encountered.
+    pub fn until(mut self, timestamp: Timestamp) -> Self {
+        // Timestamps before the Unix epoch are before any entry.
+        self.until = Some(u64::try_from(timestamp.as_micros() / 1000).unwrap_or(0));
+
+        self
+    }
+
+    /// Only return entries from a user.
+    ///
+    /// This is the user who did the auditable action, not the target of the
+    /// auditable action.
+    pub const fn user_id(mut self, user_id: Id<UserMarker>) -> Self {
+        self.user_id = Some(user_id);
+
+        self
+    }
+
+    /// Return the next entry, fetching the next page if needed.
+    ///
+    /// Returns `None` once all entries were returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching or deserializing a page failed, after which
+    /// the iterator can be polled again to retry.
+    pub async fn next(&mut self) -> Option<Result<AuditLogIterEntry, Error>> {
+        loop {
+            if let Some(entry) = self.entries.pop_front() {
+                return Some(Ok(entry));
+            }
+
+            if self.done {
+                return None;
+            }
+
+            match self.fetch().await {
+                Ok(audit_log) => self.push_page(audit_log),
+                Err(source) => return Some(Err(source)),
+            }
+        }
+    }
+
+    /// Fetch the page before the oldest entry fetched so far.
+    async fn fetch(&self) -> Result<AuditLog, Error> {
+        let mut request = self.http.audit_log(self.guild_id).limit(PAGE_LIMIT);
+
+        if let Some(action_type) = self.action_type {
+            request = request.action_type(action_type);
+        }
+
+        if let Some(before) = self.before {
+            request = request.before(before.get());
+        }
+
+        if let Some(user_id) = self.user_id {
+            request = request.user_id(user_id);
+        }
+
+        let bytes = request.await?.bytes().await.map_err(|source| Error {
+            kind: ErrorType::ChunkingResponse,
+            source: Some(Box::new(source)),
+        })?;
+
+        crate::json::from_bytes(&bytes).map_err(|source| Error {
+            kind: ErrorType::Parsing { body: bytes },
+            source: Some(Box::new(source)),
+        })
+    }
+
+    /// Join the entries of a page with their users and buffer those within the
+    /// time boundary.
+    fn push_page(&mut self, audit_log: AuditLog) {
+        let AuditLog {
+            mut entries, users, ..
+        } = audit_log;
+
+        if entries.len() < usize::from(PAGE_LIMIT) {
+            self.done = true;
+        }
+
+        let users = users
+            .into_iter()
+            .map(|user| (user.id, user))
+            .collect::<HashMap<_, _>>();
+
+        entries.sort_unstable_by_key(|entry| Reverse(entry.id));
+
+        if let Some(oldest) = entries.last() {
+            self.before = Some(oldest.id);
+        }
+
+        for entry in entries {
+            if self
+                .until
+                .is_some_and(|until| (entry.id.get() >> 22) + DISCORD_EPOCH < until)
+            {
+                self.done = true;
+
+                break;
+            }
+
+            let user = entry
+                .user_id
+                .and_then(|user_id| users.get(&user_id))
+                .cloned();
+
+            self.entries.push_back(AuditLogIterEntry { entry, user });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuditLogIter, AuditLogIterEntry};
+    use crate::Client;
+    use serde_json::json;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        guild::audit_log::AuditLog,
+        id::{Id, DISCORD_EPOCH},
+        util::Timestamp,
+    };
+
+    assert_impl_all!(AuditLogIter<'_>: Send, Sync);
+    assert_impl_all!(AuditLogIterEntry: Clone, Debug, PartialEq, Send, Sync);
+
+    /// ID of an entry created at a Unix timestamp in milliseconds.
+    const fn entry_id(unix_millis: u64) -> u64 {
+        (unix_millis - DISCORD_EPOCH) << 22
+    }
+
+    /// Audit log page with entries created at Unix timestamps in milliseconds,
+    /// performed by user 1.
+    fn page(unix_millis: impl IntoIterator<Item = u64>) -> AuditLog {
+        let entries = unix_millis
+            .into_iter()
+            .map(|unix_millis| {
+                json!({
+                    "action_type": 22,
+                    "id": entry_id(unix_millis).to_string(),
+                    "target_id": "3",
+                    "user_id": "1",
+                })
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::from_value(json!({
+            "application_commands": [],
+            "audit_log_entries": entries,
+            "auto_moderation_rules": [],
+            "guild_scheduled_events": [],
+            "integrations": [],
+            "threads": [],
+            "users": [{
+                "avatar": null,
+                "discriminator": "0",
+                "id": "1",
+                "username": "moderator",
+            }],
+            "webhooks": [],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn join() {
+        let client = Client::new(String::new());
+        let mut iter = AuditLogIter::new(&client, Id::new(2));
+
+        let mut audit_log = page([DISCORD_EPOCH + 2000, DISCORD_EPOCH + 1000]);
+        audit_log.entries[1].user_id = Some(Id::new(4));
+        iter.push_page(audit_log);
+
+        assert!(iter.done);
+        assert_eq!(2, iter.entries.len());
+        assert_eq!(
+            Some("moderator"),
+            iter.entries[0].user.as_ref().map(|user| user.name.as_str())
+        );
+        // The user isn't included in the audit log.
+        assert!(iter.entries[1].user.is_none());
+    }
+
+    #[test]
+    fn pagination() {
+        let client = Client::new(String::new());
+        let mut iter = AuditLogIter::new(&client, Id::new(2));
+
+        // Entries are returned newest first, even if a page isn't sorted.
+        let start = DISCORD_EPOCH + 1000;
+        iter.push_page(page((start..start + 100).rev().chain([start + 100])));
+
+        assert!(!iter.done);
+        assert_eq!(Some(Id::new(entry_id(start))), iter.before);
+        assert_eq!(
+            Id::new(entry_id(start + 100)),
+            iter.entries.front().unwrap().entry.id
+        );
+        assert_eq!(
+            Id::new(entry_id(start)),
+            iter.entries.back().unwrap().entry.id
+        );
+
+        iter.entries.clear();
+        iter.push_page(page([]));
+        assert!(iter.done);
+        assert!(iter.entries.is_empty());
+    }
+
+    #[test]
+    fn until() {
+        let client = Client::new(String::new());
+        let until = DISCORD_EPOCH + 1_000_000;
+        let mut iter = AuditLogIter::new(&client, Id::new(2))
+            .until(Timestamp::from_micros(i64::try_from(until * 1000).unwrap()).unwrap());
+
+        // Full page whose oldest entries are older than the boundary.
+        iter.push_page(page((until - 50..until + 50).rev()));
+
+        assert!(iter.done);
+        assert_eq!(50, iter.entries.len());
+        assert_eq!(
+            Id::new(entry_id(until)),
+            iter.entries.back().unwrap().entry.id
+        );
+    }
+}
@@ -472,3 +472,116 @@ impl TryIntoRequest for CreateAutoModerationRule<'_> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::CreateAutoModerationRuleFieldsTriggerMetadata;
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        request::{AuditLogReason, TryIntoRequest, REASON_HEADER_NAME},
+    };
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::{
+        guild::auto_moderation::{AutoModerationEventType, AutoModerationTriggerMetadata},
+        id::Id,
+    };
+    use twilight_validate::request::AUTO_MODERATION_METADATA_KEYWORD_FILTER_MAX;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = client
+            .create_auto_moderation_rule(
+                Id::new(1),
+                "no darns",
+                AutoModerationEventType::MessageSend,
+            )
+            .action_send_alert_message(Id::new(2))
+            .enabled(true)
+            .reason("moderation")
+            .try_into_request()?;
+
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("guilds/1/auto-moderation/rules", request.path());
+        assert!(request
+            .headers()
+            .is_some_and(|headers| headers.get(REASON_HEADER_NAME).is_some()));
+        assert_eq!(
+            serde_json::json!({
+                "actions": [{
+                    "type": 2,
+                    "metadata": {
+                        "channel_id": "2",
+                        "custom_message": null,
+                        "duration_seconds": null
+                    }
+                }],
+                "enabled": true,
+                "event_type": 1,
+                "exempt_channels": null,
+                "exempt_roles": null,
+                "name": "no darns",
+                "trigger_metadata": null,
+                "trigger_type": null
+            }),
+            serde_json::from_slice::<serde_json::Value>(request.body().unwrap())?
+        );
+
+        Ok(())
+    }
+
+    /// Keyword trigger metadata deserializes into the model it's sent as.
+    #[test]
+    fn keyword_trigger_metadata() -> Result<(), Box<dyn Error>> {
+        let metadata = CreateAutoModerationRuleFieldsTriggerMetadata {
+            allow_list: Some(&["darn it"]),
+            keyword_filter: Some(&["darn"]),
+            presets: None,
+            mention_total_limit: None,
+            regex_patterns: Some(&["d(?:4|a)rn"]),
+        };
+        let value = serde_json::to_value(&metadata)?;
+
+        assert_eq!(
+            serde_json::json!({
+                "allow_list": ["darn it"],
+                "keyword_filter": ["darn"],
+                "regex_patterns": ["d(?:4|a)rn"]
+            }),
+            value
+        );
+        assert_eq!(
+            AutoModerationTriggerMetadata {
+                allow_list: Some(vec!["darn it".to_owned()]),
+                keyword_filter: Some(vec!["darn".to_owned()]),
+                presets: None,
+                mention_raid_protection_enabled: None,
+                mention_total_limit: None,
+                regex_patterns: Some(vec!["d(?:4|a)rn".to_owned()]),
+            },
+            serde_json::from_value(value)?
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn keyword_filter_limit() {
+        let client = Client::new(String::new());
+        let keywords = vec!["darn"; AUTO_MODERATION_METADATA_KEYWORD_FILTER_MAX + 1];
+
+        let result = client
+            .create_auto_moderation_rule(
+                Id::new(1),
+                "no darns",
+                AutoModerationEventType::MessageSend,
+            )
+            .action_block_message()
+            .with_keyword(&keywords, &[], &[])
+            .await;
+
+        assert!(matches!(result.unwrap_err().kind(), ErrorType::Validation));
+    }
+}
@@ -140,6 +140,7 @@ impl TryIntoRequest for CreateBan<'_> {
 mod tests {
     use crate::{
         client::Client,
+        error::ErrorType,
         request::{AuditLogReason, TryIntoRequest, REASON_HEADER_NAME},
     };
     use http::header::HeaderValue;
@@ -149,6 +150,7 @@ mod tests {
         marker::{GuildMarker, UserMarker},
         Id,
     };
+    use twilight_validate::request::{ValidationError, ValidationErrorType, AUDIT_REASON_MAX};
 
     #[test]
     fn request() -> Result<(), Box<dyn Error>> {
@@ -176,4 +178,46 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn reason_encoded() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let request = client
+            .create_ban(Id::new(1), Id::new(2))
+            .reason("spam & eggs/ü")
+            .try_into_request()?;
+
+        let header = HeaderValue::from_static("spam%20%26%20eggs%2F%C3%BC");
+        assert!(matches!(
+            request.headers(),
+            Some(map) if map.get(REASON_HEADER_NAME) == Some(&header)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn reason_length() {
+        let client = Client::new(String::new());
+        let reason = "a".repeat(AUDIT_REASON_MAX);
+        assert!(client
+            .create_ban(Id::new(1), Id::new(2))
+            .reason(&reason)
+            .try_into_request()
+            .is_ok());
+
+        let reason = "a".repeat(AUDIT_REASON_MAX + 1);
+        let error = client
+            .create_ban(Id::new(1), Id::new(2))
+            .reason(&reason)
+            .try_into_request()
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Validation));
+
+        let source = error.into_source().unwrap();
+        let error = source.downcast_ref::<ValidationError>().unwrap();
+        assert!(matches!(
+            error.kind(),
+            ValidationErrorType::AuditReason { len } if *len == AUDIT_REASON_MAX + 1
+        ));
+    }
 }
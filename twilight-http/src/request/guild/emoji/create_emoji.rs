@@ -14,7 +14,10 @@ use twilight_model::{
         Id,
     },
 };
-use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+use twilight_validate::{
+    emoji::name as validate_name,
+    request::{audit_reason as validate_audit_reason, ValidationError},
+};
 
 #[derive(Serialize)]
 struct CreateEmojiFields<'a> {
@@ -30,7 +33,15 @@ struct CreateEmojiFields<'a> {
 /// `data:image/{type};base64,{data}` where `{type}` is the image MIME type and
 /// `{data}` is the base64-encoded image. See [Discord Docs/Image Data].
 ///
+/// # Errors
+///
+/// Returns an error of type [`NameLengthInvalid`] or
+/// [`NameCharacterInvalid`] when sending the request if the name is
+/// invalid.
+///
 /// [Discord Docs/Image Data]: https://discord.com/developers/docs/reference#image-data
+/// [`NameCharacterInvalid`]: twilight_validate::emoji::EmojiValidationErrorType::NameCharacterInvalid
+/// [`NameLengthInvalid`]: twilight_validate::emoji::EmojiValidationErrorType::NameLengthInvalid
 #[must_use = "requests must be configured and executed"]
 pub struct CreateEmoji<'a> {
     fields: CreateEmojiFields<'a>,
@@ -95,6 +106,8 @@ impl IntoFuture for CreateEmoji<'_> {
 
 impl TryIntoRequest for CreateEmoji<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
+        validate_name(self.fields.name).map_err(Error::validation)?;
+
         let mut request = Request::builder(&Route::CreateEmoji {
             guild_id: self.guild_id.get(),
         });
@@ -112,6 +125,7 @@ impl TryIntoRequest for CreateEmoji<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::ErrorType;
     use std::error::Error;
 
     #[test]
@@ -149,4 +163,20 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn name_invalid() {
+        let client = Client::new("token".into());
+
+        let error = CreateEmoji::new(
+            &client,
+            Id::new(1),
+            "square emoji",
+            "data:image/png;base64,",
+        )
+        .try_into_request()
+        .unwrap_err();
+
+        assert!(matches!(error.kind(), ErrorType::Validation));
+    }
 }
@@ -14,7 +14,10 @@ use twilight_model::{
         Id,
     },
 };
-use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+use twilight_validate::{
+    emoji::name as validate_name,
+    request::{audit_reason as validate_audit_reason, ValidationError},
+};
 
 #[derive(Serialize)]
 struct UpdateEmojiFields<'a> {
@@ -53,6 +56,15 @@ impl<'a> UpdateEmoji<'a> {
     }
 
     /// Change the name of the emoji.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`NameLengthInvalid`] or
+    /// [`NameCharacterInvalid`] when sending the request if the name is
+    /// invalid.
+    ///
+    /// [`NameCharacterInvalid`]: twilight_validate::emoji::EmojiValidationErrorType::NameCharacterInvalid
+    /// [`NameLengthInvalid`]: twilight_validate::emoji::EmojiValidationErrorType::NameLengthInvalid
     pub const fn name(mut self, name: &'a str) -> Self {
         self.fields.name = Some(name);
 
@@ -92,6 +104,10 @@ impl IntoFuture for UpdateEmoji<'_> {
 
 impl TryIntoRequest for UpdateEmoji<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
+        if let Some(name) = self.fields.name {
+            validate_name(name).map_err(Error::validation)?;
+        }
+
         let mut request = Request::builder(&Route::UpdateEmoji {
             emoji_id: self.emoji_id.get(),
             guild_id: self.guild_id.get(),
@@ -106,3 +122,30 @@ impl TryIntoRequest for UpdateEmoji<'_> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, error::ErrorType, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn name() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".into());
+
+        let request = client
+            .update_emoji(Id::new(1), Id::new(2))
+            .name("square")
+            .try_into_request()?;
+        assert_eq!(Some(br#"{"name":"square"}"#.as_slice()), request.body());
+
+        let error = client
+            .update_emoji(Id::new(1), Id::new(2))
+            .name("square emoji")
+            .try_into_request()
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Validation));
+
+        Ok(())
+    }
+}
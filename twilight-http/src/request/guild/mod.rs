@@ -10,6 +10,7 @@ pub mod update_guild_channel_positions;
 pub mod update_guild_onboarding;
 pub mod user;
 
+mod audit_log_iter;
 mod create_guild_channel;
 mod create_guild_prune;
 mod delete_guild;
@@ -30,20 +31,35 @@ mod get_guild_widget_settings;
 mod update_current_member;
 mod update_guild;
 mod update_guild_mfa;
+mod update_guild_vanity_url;
 mod update_guild_welcome_screen;
 mod update_guild_widget_settings;
 
 pub use self::{
-    create_guild::CreateGuild, create_guild_channel::CreateGuildChannel,
-    create_guild_prune::CreateGuildPrune, delete_guild::DeleteGuild,
-    get_active_threads::GetActiveThreads, get_audit_log::GetAuditLog, get_guild::GetGuild,
-    get_guild_channels::GetGuildChannels, get_guild_invites::GetGuildInvites,
-    get_guild_onboarding::GetGuildOnboarding, get_guild_preview::GetGuildPreview,
-    get_guild_prune_count::GetGuildPruneCount, get_guild_vanity_url::GetGuildVanityUrl,
-    get_guild_voice_regions::GetGuildVoiceRegions, get_guild_webhooks::GetGuildWebhooks,
-    get_guild_welcome_screen::GetGuildWelcomeScreen, get_guild_widget::GetGuildWidget,
-    get_guild_widget_settings::GetGuildWidgetSettings, update_current_member::UpdateCurrentMember,
-    update_guild::UpdateGuild, update_guild_channel_positions::UpdateGuildChannelPositions,
-    update_guild_mfa::UpdateGuildMfa, update_guild_welcome_screen::UpdateGuildWelcomeScreen,
+    audit_log_iter::{AuditLogIter, AuditLogIterEntry},
+    create_guild::CreateGuild,
+    create_guild_channel::CreateGuildChannel,
+    create_guild_prune::CreateGuildPrune,
+    delete_guild::DeleteGuild,
+    get_active_threads::GetActiveThreads,
+    get_audit_log::GetAuditLog,
+    get_guild::GetGuild,
+    get_guild_channels::GetGuildChannels,
+    get_guild_invites::GetGuildInvites,
+    get_guild_onboarding::GetGuildOnboarding,
+    get_guild_preview::GetGuildPreview,
+    get_guild_prune_count::GetGuildPruneCount,
+    get_guild_vanity_url::GetGuildVanityUrl,
+    get_guild_voice_regions::GetGuildVoiceRegions,
+    get_guild_webhooks::GetGuildWebhooks,
+    get_guild_welcome_screen::GetGuildWelcomeScreen,
+    get_guild_widget::GetGuildWidget,
+    get_guild_widget_settings::GetGuildWidgetSettings,
+    update_current_member::UpdateCurrentMember,
+    update_guild::UpdateGuild,
+    update_guild_channel_positions::UpdateGuildChannelPositions,
+    update_guild_mfa::UpdateGuildMfa,
+    update_guild_vanity_url::UpdateGuildVanityUrl,
+    update_guild_welcome_screen::UpdateGuildWelcomeScreen,
     update_guild_widget_settings::UpdateGuildWidgetSettings,
 };
@@ -0,0 +1,176 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{self, AuditLogReason, Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+    routing::Route,
+};
+use serde::Serialize;
+use std::future::IntoFuture;
+use twilight_model::{
+    guild::{GuildFeature, VanityUrl},
+    id::{marker::GuildMarker, Id},
+};
+use twilight_validate::request::{
+    audit_reason as validate_audit_reason, vanity_url_code as validate_vanity_url_code,
+    vanity_url_feature as validate_vanity_url_feature, ValidationError,
+};
+
+#[derive(Serialize)]
+struct UpdateGuildVanityUrlFields<'a> {
+    code: &'a str,
+}
+
+/// Update a guild's vanity url.
+///
+/// The guild must have the [`GuildFeature::VanityUrl`] feature. Use
+/// [`features`] to check this before sending the request.
+///
+/// [`features`]: Self::features
+#[must_use = "requests must be configured and executed"]
+pub struct UpdateGuildVanityUrl<'a> {
+    fields: Result<UpdateGuildVanityUrlFields<'a>, ValidationError>,
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+    reason: Result<Option<&'a str>, ValidationError>,
+}
+
+impl<'a> UpdateGuildVanityUrl<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: Id<GuildMarker>, code: &'a str) -> Self {
+        Self {
+            fields: validate_vanity_url_code(code).and(Ok(UpdateGuildVanityUrlFields { code })),
+            guild_id,
+            http,
+            reason: Ok(None),
+        }
+    }
+
+    /// Check that the guild has the [`GuildFeature::VanityUrl`] feature
+    /// before sending the request.
+    ///
+    /// Accepts the features of a guild model, such as a [`PartialGuild`], or of
+    /// a cached guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`VanityUrlFeature`] if the features don't
+    /// include [`GuildFeature::VanityUrl`].
+    ///
+    /// [`PartialGuild`]: twilight_model::guild::PartialGuild
+    /// [`VanityUrlFeature`]: twilight_validate::request::ValidationErrorType::VanityUrlFeature
+    pub fn features<'b>(mut self, features: impl IntoIterator<Item = &'b GuildFeature>) -> Self {
+        self.fields = self.fields.and_then(|fields| {
+            validate_vanity_url_feature(features)?;
+
+            Ok(fields)
+        });
+
+        self
+    }
+}
+
+impl<'a> AuditLogReason<'a> for UpdateGuildVanityUrl<'a> {
+    fn reason(mut self, reason: &'a str) -> Self {
+        self.reason = validate_audit_reason(reason).and(Ok(Some(reason)));
+
+        self
+    }
+}
+
+impl IntoFuture for UpdateGuildVanityUrl<'_> {
+    type Output = Result<Response<VanityUrl>, Error>;
+
+    type IntoFuture = ResponseFuture<VanityUrl>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for UpdateGuildVanityUrl<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let fields = self.fields.map_err(Error::validation)?;
+        let mut request = Request::builder(&Route::UpdateGuildVanityUrl {
+            guild_id: self.guild_id.get(),
+        })
+        .json(&fields);
+
+        if let Some(reason) = self.reason.map_err(Error::validation)? {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        request.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        request::{AuditLogReason, TryIntoRequest, REASON_HEADER_NAME},
+    };
+    use http::header::HeaderValue;
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::{
+        guild::GuildFeature,
+        id::{marker::GuildMarker, Id},
+    };
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        const REASON: &str = "rebrand";
+
+        let client = Client::new(String::new());
+        let request = client
+            .update_guild_vanity_url(GUILD_ID, "twilight-rs")
+            .features(&[GuildFeature::Community, GuildFeature::VanityUrl])
+            .reason(REASON)
+            .try_into_request()?;
+
+        assert_eq!(Method::Patch, request.method());
+        assert_eq!(request.path(), "guilds/1/vanity-url");
+        assert_eq!(
+            request.body(),
+            Some(br#"{"code":"twilight-rs"}"#.as_slice())
+        );
+
+        let header = HeaderValue::from_static(REASON);
+        assert!(matches!(
+            request.headers(),
+            Some(map)
+            if map.len() == 1 && map.get(REASON_HEADER_NAME) == Some(&header)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() {
+        let client = Client::new(String::new());
+
+        let invalid_code = client
+            .update_guild_vanity_url(GUILD_ID, "twilight_rs")
+            .try_into_request();
+        assert!(matches!(
+            invalid_code.unwrap_err().kind(),
+            ErrorType::Validation
+        ));
+
+        let missing_feature = client
+            .update_guild_vanity_url(GUILD_ID, "twilight-rs")
+            .features(&[GuildFeature::Community])
+            .try_into_request();
+        assert!(matches!(
+            missing_feature.unwrap_err().kind(),
+            ErrorType::Validation
+        ));
+    }
+}
@@ -75,6 +75,8 @@ pub use self::{
 };
 pub use twilight_http_ratelimiting::request::Method;
 
+pub(crate) use self::get_gateway_authed::GatewayInfoCache;
+
 use crate::error::{Error, ErrorType};
 use http::header::{HeaderName, HeaderValue};
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
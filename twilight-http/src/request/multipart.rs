@@ -56,7 +56,13 @@ impl Form {
         self
     }
 
-    pub fn file_part(mut self, name: &[u8], filename: &[u8], value: &[u8]) -> Self {
+    pub fn file_part(
+        mut self,
+        name: &[u8],
+        filename: &[u8],
+        content_type: Option<&[u8]>,
+        value: &[u8],
+    ) -> Self {
         // Write the Content-Disposition header.
         self.buffer.extend(Self::NEWLINE);
         self.buffer.extend(Self::CONTENT_DISPOSITION_1);
@@ -66,6 +72,13 @@ impl Form {
         self.buffer.extend(Self::CONTENT_DISPOSITION_3);
         self.buffer.extend(Self::NEWLINE);
 
+        // If there is a Content-Type, write its key, itself, and a newline.
+        if let Some(content_type) = content_type {
+            self.buffer.extend(Self::CONTENT_TYPE);
+            self.buffer.extend(content_type);
+            self.buffer.extend(Self::NEWLINE);
+        }
+
         // Write a newline between the headers and the value, the value
         // itself, a newline, and finally the boundary.
         self.buffer.extend(Self::NEWLINE);
@@ -134,35 +147,54 @@ pub fn random_boundary() -> [u8; 15] {
     boundary
 }
 
+#[cfg(test)]
+impl Form {
+    /// Consume the form, returning its contents with the boundary replaced by
+    /// `BOUNDARY`.
+    pub(crate) fn build_string(self) -> String {
+        let boundary = String::from_utf8(self.boundary.to_vec()).unwrap();
+
+        String::from_utf8(self.build())
+            .unwrap()
+            .replace(&boundary, "BOUNDARY")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::str;
 
     #[test]
     fn form_builder() {
         let form = Form::new()
             .json_part(b"payload_json", b"json_value")
-            .file_part(b"files[0]", b"filename.jpg", b"file_value");
+            .file_part(b"files[0]", b"filename.jpg", None, b"file_value")
+            .file_part(
+                b"files[1]",
+                b"filename.png",
+                Some(b"image/png"),
+                b"file_value",
+            );
 
-        let boundary = str::from_utf8(&form.boundary).unwrap();
-        let expected = format!(
-            "--{boundary}\r\n\
+        let buffer_len = form.len();
+        assert_eq!(buffer_len, form.clone().build().len());
+        assert_eq!(
+            "--BOUNDARY\r\n\
         Content-Disposition: form-data; name=\"payload_json\"\r\n\
         Content-Type: application/json\r\n\
         \r\n\
         json_value\r\n\
-        --{boundary}\r\n\
+        --BOUNDARY\r\n\
         Content-Disposition: form-data; name=\"files[0]\"; filename=\"filename.jpg\"\r\n\
         \r\n\
         file_value\r\n\
-        --{boundary}--",
+        --BOUNDARY\r\n\
+        Content-Disposition: form-data; name=\"files[1]\"; filename=\"filename.png\"\r\n\
+        Content-Type: image/png\r\n\
+        \r\n\
+        file_value\r\n\
+        --BOUNDARY--",
+            form.build_string()
         );
-
-        let buffer_len = form.len();
-        let buffer = form.build();
-
-        assert_eq!(expected.as_bytes(), buffer);
-        assert_eq!(buffer_len, buffer.len());
     }
 }
@@ -12,6 +12,12 @@ use twilight_model::{
 };
 
 /// Get information about the current user in a guild.
+///
+/// Unlike [`Client::guild_member`], this doesn't require the
+/// [`GUILD_MEMBERS`] intent. It works with bot tokens and with OAuth2 Bearer
+/// tokens that have the `guilds.members.read` scope.
+///
+/// [`GUILD_MEMBERS`]: twilight_model::gateway::Intents::GUILD_MEMBERS
 #[must_use = "requests must be configured and executed"]
 pub struct GetCurrentUserGuildMember<'a> {
     guild_id: Id<GuildMarker>,
@@ -46,3 +52,57 @@ impl TryIntoRequest for GetCurrentUserGuildMember<'_> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GetCurrentUserGuildMember;
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::{guild::Member, id::Id};
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("Bearer token".to_owned());
+        let request = GetCurrentUserGuildMember::new(&client, Id::new(1)).try_into_request()?;
+
+        assert_eq!(Method::Get, request.method());
+        assert_eq!(request.path(), "users/@me/guilds/1/member");
+        assert!(request.body().is_none());
+
+        Ok(())
+    }
+
+    /// Test that the response, which doesn't include the guild's ID,
+    /// deserializes into a member.
+    #[test]
+    fn response() -> Result<(), Box<dyn Error>> {
+        const RESPONSE: &str = r#"{
+            "avatar": null,
+            "communication_disabled_until": null,
+            "deaf": false,
+            "flags": 0,
+            "joined_at": "2015-04-26T06:26:56.936000+00:00",
+            "mute": false,
+            "nick": "twilight",
+            "pending": false,
+            "premium_since": null,
+            "roles": ["2"],
+            "user": {
+                "avatar": null,
+                "discriminator": "0001",
+                "id": "3",
+                "public_flags": 0,
+                "username": "twilight"
+            }
+        }"#;
+
+        let member = serde_json::from_str::<Member>(RESPONSE)?;
+
+        assert_eq!(member.nick.as_deref(), Some("twilight"));
+        assert_eq!(member.roles, [Id::new(2)]);
+        assert_eq!(member.user.id, Id::new(3));
+
+        Ok(())
+    }
+}
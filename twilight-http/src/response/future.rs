@@ -4,9 +4,10 @@ use crate::{
     error::{Error, ErrorType},
 };
 use http::StatusCode as HyperStatusCode;
+use hyper::body::Bytes;
 use hyper_util::client::legacy::ResponseFuture as HyperResponseFuture;
 use std::{
-    future::Future,
+    future::{self, Future},
     marker::PhantomData,
     mem,
     pin::Pin,
@@ -22,12 +23,32 @@ use twilight_http_ratelimiting::{ticket::TicketSender, RatelimitHeaders, WaitFor
 
 type Output<T> = Result<Response<T>, Error>;
 
+/// Future resolving to a response whose body has been read into memory.
+type BufferedFuture = Pin<Box<dyn Future<Output = Output<()>> + Send + 'static>>;
+
 enum InnerPoll<T> {
     Advance(ResponseFutureStage),
     Pending(ResponseFutureStage),
     Ready(Output<T>),
 }
 
+/// Response whose body is read into memory, such as a cached one.
+struct Buffered {
+    future: BufferedFuture,
+}
+
+impl Buffered {
+    fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
+        match Pin::new(&mut self.future).poll(cx) {
+            Poll::Ready(result) => InnerPoll::Ready(result.map(|response| Response {
+                inner: response.inner,
+                phantom: PhantomData,
+            })),
+            Poll::Pending => InnerPoll::Pending(ResponseFutureStage::Buffered(self)),
+        }
+    }
+}
+
 struct Chunking {
     future: Pin<Box<dyn Future<Output = Result<Vec<u8>, Error>> + Send + Sync + 'static>>,
     status: HyperStatusCode,
@@ -119,7 +140,7 @@ impl InFlight {
                 Err(source) => {
                     tracing::warn!("header parsing failed: {source:?}; {resp:?}");
 
-                    let _res = tx.headers(None);
+                    let _res = tx.headers(Some(RatelimitHeaders::Invalid));
                 }
             }
         }
@@ -204,12 +225,31 @@ impl RatelimitQueue {
     }
 }
 
+/// Request that isn't ratelimited and hasn't been sent yet.
+struct Unsent {
+    invalid_token: Option<Arc<AtomicBool>>,
+    response_future: HyperResponseFuture,
+    timeout: Duration,
+}
+
+impl Unsent {
+    fn poll<T>(self, _: &mut Context<'_>) -> InnerPoll<T> {
+        InnerPoll::Advance(ResponseFutureStage::InFlight(InFlight {
+            future: Box::pin(time::timeout(self.timeout, self.response_future)),
+            invalid_token: self.invalid_token,
+            tx: None,
+        }))
+    }
+}
+
 enum ResponseFutureStage {
+    Buffered(Buffered),
     Chunking(Chunking),
     Completed,
     Failed(Failed),
     InFlight(InFlight),
     RatelimitQueue(RatelimitQueue),
+    Unsent(Unsent),
 }
 
 /// Future that will resolve to a [`Response`].
@@ -237,7 +277,8 @@ enum ResponseFutureStage {
 /// failed.
 ///
 /// Returns an [`ErrorType::RequestTimedOut`] error type if the request timed
-/// out. The timeout value is configured via [`ClientBuilder::timeout`].
+/// out. The timeout value is configured via [`ClientBuilder::timeout`] and may
+/// be overridden per request via [`ResponseFuture::set_timeout`].
 ///
 /// Returns an [`ErrorType::Response`] error type if the request failed.
 ///
@@ -261,15 +302,16 @@ pub struct ResponseFuture<T> {
 
 impl<T> ResponseFuture<T> {
     pub(crate) const fn new(
-        future: Pin<Box<Timeout<HyperResponseFuture>>>,
         invalid_token: Option<Arc<AtomicBool>>,
+        response_future: HyperResponseFuture,
+        timeout: Duration,
     ) -> Self {
         Self {
             phantom: PhantomData,
-            stage: ResponseFutureStage::InFlight(InFlight {
-                future,
+            stage: ResponseFutureStage::Unsent(Unsent {
                 invalid_token,
-                tx: None,
+                response_future,
+                timeout,
             }),
         }
     }
@@ -340,6 +382,97 @@ impl<T> ResponseFuture<T> {
         }
     }
 
+    /// Set the timeout of the request, overriding the client's [timeout].
+    ///
+    /// The timeout starts once the request is sent, so time spent waiting for
+    /// the ratelimiter doesn't count against it.
+    ///
+    /// This function will be a no-op if the request has failed or has already
+    /// been sent.
+    ///
+    /// Returns whether the timeout was set.
+    ///
+    /// # Examples
+    ///
+    /// Give up on creating a message if Discord takes longer than two seconds
+    /// to respond:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::{env, future::IntoFuture, time::Duration};
+    /// use twilight_http::{error::ErrorType, Client};
+    /// use twilight_model::id::Id;
+    ///
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    /// let mut req = client
+    ///     .create_message(Id::new(1))
+    ///     .content("hello")
+    ///     .into_future();
+    /// req.set_timeout(Duration::from_secs(2));
+    ///
+    /// match req.await {
+    ///     Err(source) if matches!(source.kind(), ErrorType::RequestTimedOut) => {
+    ///         println!("request timed out");
+    ///     }
+    ///     result => {
+    ///         result?;
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [timeout]: crate::client::ClientBuilder::timeout
+    pub fn set_timeout(&mut self, timeout: Duration) -> bool {
+        match &mut self.stage {
+            ResponseFutureStage::RatelimitQueue(queue) => queue.timeout = timeout,
+            ResponseFutureStage::Unsent(unsent) => unsent.timeout = timeout,
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Create a future resolving to a successful response with a body that
+    /// has already been read, such as a cached one.
+    pub(crate) fn cached(bytes: Bytes) -> Self {
+        Self {
+            phantom: PhantomData,
+            stage: ResponseFutureStage::Buffered(Buffered {
+                future: Box::pin(future::ready(Ok(Response::from_bytes(bytes)))),
+            }),
+        }
+    }
+
+    /// Read the body of a successful response into memory once received,
+    /// calling a function with it.
+    ///
+    /// The response resolved to still contains the body.
+    pub(crate) fn inspect_body(self, f: impl FnOnce(&Bytes) + Send + 'static) -> Self
+    where
+        T: Send + Unpin + 'static,
+    {
+        let future = async move {
+            let (response, bytes) = self.await?.buffer().await.map_err(|source| Error {
+                kind: ErrorType::ChunkingResponse,
+                source: Some(Box::new(source)),
+            })?;
+
+            f(&bytes);
+
+            Ok(Response {
+                inner: response.inner,
+                phantom: PhantomData,
+            })
+        };
+
+        Self {
+            phantom: PhantomData,
+            stage: ResponseFutureStage::Buffered(Buffered {
+                future: Box::pin(future),
+            }),
+        }
+    }
+
     pub(crate) const fn error(source: Error) -> Self {
         Self {
             phantom: PhantomData,
@@ -374,11 +507,13 @@ impl<T: Unpin> Future for ResponseFuture<T> {
             let stage = mem::replace(&mut self.stage, ResponseFutureStage::Completed);
 
             let result = match stage {
+                ResponseFutureStage::Buffered(buffered) => buffered.poll(cx),
                 ResponseFutureStage::Chunking(chunking) => chunking.poll(cx),
                 ResponseFutureStage::Completed => panic!("future already completed"),
                 ResponseFutureStage::Failed(failed) => failed.poll(cx),
                 ResponseFutureStage::InFlight(in_flight) => in_flight.poll(cx),
                 ResponseFutureStage::RatelimitQueue(queue) => queue.poll(cx),
+                ResponseFutureStage::Unsent(unsent) => unsent.poll(cx),
             };
 
             match result {
@@ -399,3 +534,140 @@ impl<T: Unpin> Future for ResponseFuture<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ResponseFuture;
+    use crate::{
+        error::ErrorType,
+        test::{Response, Server},
+        Client,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{future::IntoFuture, time::Duration};
+    use tokio::time::{self, Instant};
+    use twilight_http_ratelimiting::Path;
+    use twilight_model::{gateway::connection_info::ConnectionInfo, id::Id};
+
+    assert_impl_all!(ResponseFuture<ConnectionInfo>: Send, Unpin);
+
+    const BODY: &str = r#"{"url":"wss://gateway.discord.gg"}"#;
+
+    const HEADERS: [&str; 4] = [
+        "x-ratelimit-limit: 5",
+        "x-ratelimit-remaining: 4",
+        "x-ratelimit-reset: 1470173023.123",
+        "x-ratelimit-reset-after: 64.57",
+    ];
+
+    /// Start a mock API server responding with the ratelimit headers.
+    async fn server(headers: Vec<&'static str>) -> Server {
+        Server::start(move |_| {
+            let headers = headers.clone();
+
+            async move {
+                Response {
+                    headers,
+                    ..Response::json("200 OK", BODY)
+                }
+            }
+        })
+        .await
+    }
+
+    /// Send a request, asserting that the response deserializes, and return the
+    /// number of ratelimit header failures of its bucket.
+    async fn request(headers: Vec<&'static str>) -> u64 {
+        let server = server(headers).await;
+        let client = Client::builder().api_base_url(server.url()).build();
+
+        let info = client.gateway().await.unwrap().model().await.unwrap();
+        assert_eq!("wss://gateway.discord.gg", info.url);
+
+        // The next ticket is only granted once the response's headers have
+        // been handled.
+        let ratelimiter = client.ratelimiter().unwrap();
+        drop(ratelimiter.wait_for_ticket(Path::Gateway).await.unwrap());
+
+        ratelimiter
+            .bucket(&Path::Gateway)
+            .await
+            .unwrap()
+            .unwrap()
+            .header_failures()
+    }
+
+    #[tokio::test]
+    async fn headers_valid() {
+        assert_eq!(0, request(Vec::new()).await);
+        assert_eq!(0, request(HEADERS.to_vec()).await);
+    }
+
+    #[tokio::test]
+    async fn headers_missing() {
+        for missing in HEADERS {
+            let headers = HEADERS
+                .into_iter()
+                .filter(|header| *header != missing)
+                .collect();
+
+            assert_eq!(1, request(headers).await, "{missing}");
+        }
+    }
+
+    #[tokio::test]
+    async fn headers_malformed() {
+        let headers = vec![
+            HEADERS[0],
+            HEADERS[1],
+            "x-ratelimit-reset: soon",
+            HEADERS[3],
+        ];
+
+        assert_eq!(1, request(headers).await);
+    }
+
+    #[tokio::test]
+    async fn timeout() {
+        let server = Server::stalled().await;
+        let client = Client::builder()
+            .api_base_url(server.url())
+            .timeout(Duration::from_millis(100))
+            .build();
+
+        let error = client.gateway().await.unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::RequestTimedOut));
+
+        // The request's timeout takes precedence over the client's.
+        let mut future = client.gateway().into_future();
+        assert!(future.set_timeout(Duration::from_millis(300)));
+        let start = Instant::now();
+        let error = future.await.unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::RequestTimedOut));
+        assert!(start.elapsed() >= Duration::from_millis(300));
+
+        // Requests without a ratelimiter can also set a timeout.
+        let client = Client::builder()
+            .api_base_url(server.url())
+            .ratelimiter(None)
+            .build();
+        let mut future = client.gateway().into_future();
+        assert!(future.set_timeout(Duration::from_millis(100)));
+        let error = time::timeout(Duration::from_secs(5), future)
+            .await
+            .unwrap()
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::RequestTimedOut));
+    }
+
+    #[tokio::test]
+    async fn timeout_after_failure() {
+        let client = Client::new("token".to_owned());
+        let mut future = client
+            .create_message(Id::new(1))
+            .content(&"a".repeat(2001))
+            .into_future();
+
+        assert!(!future.set_timeout(Duration::from_secs(1)));
+    }
+}
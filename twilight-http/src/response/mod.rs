@@ -54,7 +54,7 @@ pub use self::{future::ResponseFuture, status_code::StatusCode};
 
 use self::marker::ListBody;
 use http::{
-    header::{HeaderValue, Iter as HeaderMapIter},
+    header::{HeaderMap, HeaderValue, Iter as HeaderMapIter},
     Response as HyperResponse,
 };
 use http_body_util::BodyExt;
@@ -63,12 +63,22 @@ use serde::de::DeserializeOwned;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
-    future::Future,
+    future::{self as std_future, Future},
     iter::FusedIterator,
     marker::PhantomData,
     pin::Pin,
     task::{Context, Poll},
 };
+use twilight_http_ratelimiting::RatelimitHeaders;
+
+/// Body of a [`Response`].
+#[derive(Debug)]
+enum Body {
+    /// Body being received from the API.
+    Incoming(Incoming),
+    /// Body that has already been read into memory.
+    Read(Bytes),
+}
 
 /// Failure when processing a response body.
 #[derive(Debug)]
@@ -175,24 +185,88 @@ pub enum DeserializeBodyErrorType {
 /// ```
 #[derive(Debug)]
 pub struct Response<T> {
-    inner: HyperResponse<Incoming>,
+    inner: HyperResponse<Body>,
     phantom: PhantomData<T>,
 }
 
 impl<T> Response<T> {
-    pub(crate) const fn new(inner: HyperResponse<Incoming>) -> Self {
+    pub(crate) fn new(inner: HyperResponse<Incoming>) -> Self {
+        Self {
+            inner: inner.map(Body::Incoming),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Create a successful response with a body that has already been read,
+    /// such as a cached one.
+    pub(crate) fn from_bytes(bytes: Bytes) -> Self {
         Self {
-            inner,
+            inner: HyperResponse::new(Body::Read(bytes)),
             phantom: PhantomData,
         }
     }
 
+    /// Read the body into memory, returning the bytes and a response with the
+    /// read body.
+    pub(crate) async fn buffer(self) -> Result<(Self, Bytes), DeserializeBodyError> {
+        let status = self.inner.status();
+        let mut headers = self.inner.headers().clone();
+        let bytes = self.bytes().inner.await?;
+
+        // The read body is already decompressed.
+        headers.remove(http::header::CONTENT_ENCODING);
+
+        let mut inner = HyperResponse::new(Body::Read(bytes.clone()));
+        *inner.headers_mut() = headers;
+        *inner.status_mut() = status;
+
+        Ok((
+            Self {
+                inner,
+                phantom: PhantomData,
+            },
+            bytes,
+        ))
+    }
+
     /// Iterator of the response headers.
     #[must_use = "creating an iterator of the headers has no use on its own"]
     pub fn headers(&self) -> HeaderIter<'_> {
         HeaderIter(self.inner.headers().iter())
     }
 
+    /// Ratelimit headers of the response, if any.
+    ///
+    /// Returns `None` if the response has no ratelimit headers or they failed
+    /// to parse.
+    ///
+    /// # Examples
+    ///
+    /// Wait for a bucket to reset once no requests remain:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let user_id = twilight_model::id::Id::new(1);
+    /// use std::{env, time::Duration};
+    /// use twilight_http::Client;
+    /// use twilight_http_ratelimiting::RatelimitHeaders;
+    ///
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    /// let response = client.user(user_id).await?;
+    ///
+    /// if let Some(RatelimitHeaders::Present(bucket)) = response.ratelimit() {
+    ///     if bucket.remaining() == 0 {
+    ///         tokio::time::sleep(Duration::from_millis(bucket.reset_after())).await;
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    #[must_use = "retrieving the ratelimit headers has no use on its own"]
+    pub fn ratelimit(&self) -> Option<RatelimitHeaders> {
+        ratelimit_headers(self.inner.headers())
+    }
+
     /// Status code of the response.
     #[must_use = "retrieving the status code has no use on its own"]
     pub fn status(&self) -> StatusCode {
@@ -241,7 +315,14 @@ impl<T> Response<T> {
             .get(http::header::CONTENT_ENCODING)
             .is_some();
 
-        let body = self.inner.into_body();
+        let body = match self.inner.into_body() {
+            Body::Incoming(body) => body,
+            Body::Read(bytes) => {
+                return BytesFuture {
+                    inner: Box::pin(std_future::ready(Ok(bytes))),
+                }
+            }
+        };
 
         let fut = async move {
             {
@@ -348,10 +429,25 @@ impl<T: DeserializeOwned> Response<ListBody<T>> {
     /// Returns a [`DeserializeBodyErrorType::Deserializing`] error type if the
     /// response body could not be deserialized into a list of something.
     pub fn models(self) -> ModelFuture<Vec<T>> {
-        Response::<Vec<T>>::new(self.inner).model()
+        Response::<Vec<T>> {
+            inner: self.inner,
+            phantom: PhantomData,
+        }
+        .model()
     }
 }
 
+/// Parse the ratelimit headers of a response, if any.
+fn ratelimit_headers(headers: &HeaderMap) -> Option<RatelimitHeaders> {
+    let pairs = headers
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_bytes()));
+
+    RatelimitHeaders::from_pairs(pairs)
+        .ok()
+        .filter(|headers| !headers.is_none())
+}
+
 /// Iterator over the headers of a [`Response`].
 ///
 /// Header names are returned as a string slice and header values are returned
@@ -616,8 +712,10 @@ mod tests {
         BytesFuture, DeserializeBodyError, DeserializeBodyErrorType, HeaderIter, ModelFuture,
         Response, TextFuture,
     };
+    use http::{HeaderMap, HeaderValue};
     use static_assertions::assert_impl_all;
     use std::{fmt::Debug, future::Future, iter::FusedIterator};
+    use twilight_http_ratelimiting::RatelimitHeaders;
     use twilight_model::{channel::Message, guild::Emoji};
 
     #[cfg(feature = "decompression")]
@@ -632,6 +730,41 @@ mod tests {
     assert_impl_all!(Response<ListBody<Message>>: Debug, Send, Sync);
     assert_impl_all!(TextFuture: Future);
 
+    #[test]
+    fn ratelimit_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("content-type", HeaderValue::from_static("application/json"));
+        assert!(super::ratelimit_headers(&headers).is_none());
+
+        headers.insert(
+            "x-ratelimit-bucket",
+            HeaderValue::from_static("d721dea6054f6322373d361f98e5c38b"),
+        );
+        headers.insert("x-ratelimit-limit", HeaderValue::from_static("10"));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("9"));
+        headers.insert(
+            "x-ratelimit-reset",
+            HeaderValue::from_static("1573795260.333"),
+        );
+        headers.insert(
+            "x-ratelimit-reset-after",
+            HeaderValue::from_static("10.000"),
+        );
+
+        let Some(RatelimitHeaders::Present(present)) = super::ratelimit_headers(&headers) else {
+            panic!("bucket ratelimit headers are present");
+        };
+        assert_eq!(Some("d721dea6054f6322373d361f98e5c38b"), present.bucket());
+        assert_eq!(10, present.limit());
+        assert_eq!(9, present.remaining());
+        assert_eq!(1_573_795_260_333, present.reset());
+        assert_eq!(10_000, present.reset_after());
+
+        // Invalid headers are ignored.
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("nine"));
+        assert!(super::ratelimit_headers(&headers).is_none());
+    }
+
     #[cfg(feature = "decompression")]
     #[tokio::test]
     async fn test_decompression() -> Result<(), Box<dyn Error + Send + Sync>> {
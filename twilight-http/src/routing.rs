@@ -1113,6 +1113,11 @@ pub enum Route<'a> {
         /// ID of the sticker.
         sticker_id: u64,
     },
+    /// Route information to update a guild's vanity URL.
+    UpdateGuildVanityUrl {
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to update a guild's welcome screen.
     UpdateGuildWelcomeScreen {
         /// ID of the guild.
@@ -1352,6 +1357,7 @@ impl Route<'_> {
             | Self::UpdateGuildIntegration { .. }
             | Self::UpdateGuildScheduledEvent { .. }
             | Self::UpdateGuildSticker { .. }
+            | Self::UpdateGuildVanityUrl { .. }
             | Self::UpdateGuildWelcomeScreen { .. }
             | Self::UpdateInteractionOriginal { .. }
             | Self::UpdateMember { .. }
@@ -1587,8 +1593,8 @@ impl Route<'_> {
             | Self::GetReactionUsers { channel_id, .. } => {
                 Path::ChannelsIdMessagesIdReactions(channel_id)
             }
-            Self::DeleteMessage { message_id, .. } => {
-                Path::ChannelsIdMessagesId(Method::Delete, message_id)
+            Self::DeleteMessage { channel_id, .. } => {
+                Path::ChannelsIdMessagesId(Method::Delete, channel_id)
             }
             Self::DeleteMessages { channel_id } => Path::ChannelsIdMessagesBulkDelete(channel_id),
             Self::DeletePermissionOverwrite { channel_id, .. }
@@ -1704,7 +1710,9 @@ impl Route<'_> {
                 Path::GuildsIdScheduledEventsIdUsers(guild_id)
             }
             Self::GetGuildPreview { guild_id } => Path::GuildsIdPreview(guild_id),
-            Self::GetGuildVanityUrl { guild_id } => Path::GuildsIdVanityUrl(guild_id),
+            Self::GetGuildVanityUrl { guild_id } | Self::UpdateGuildVanityUrl { guild_id } => {
+                Path::GuildsIdVanityUrl(guild_id)
+            }
             Self::GetGuildVoiceRegions { guild_id } => Path::GuildsIdRegions(guild_id),
             Self::GetGuildWelcomeScreen { guild_id }
             | Self::UpdateGuildWelcomeScreen { guild_id } => Path::GuildsIdWelcomeScreen(guild_id),
@@ -2716,7 +2724,7 @@ impl Display for Route<'_> {
 
                 Display::fmt(sticker_id, f)
             }
-            Route::GetGuildVanityUrl { guild_id } => {
+            Route::GetGuildVanityUrl { guild_id } | Route::UpdateGuildVanityUrl { guild_id } => {
                 f.write_str("guilds/")?;
                 Display::fmt(guild_id, f)?;
 
@@ -3036,7 +3044,7 @@ impl Display for Route<'_> {
 
 #[cfg(test)]
 mod tests {
-    use super::Route;
+    use super::{Path, Route};
     use crate::request::{channel::reaction::RequestReactionType, Method};
     use twilight_model::id::Id;
 
@@ -3103,6 +3111,118 @@ mod tests {
         }
     }
 
+    /// Test that routes map to the path of their ratelimit bucket.
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn to_path() {
+        let routes = [
+            (
+                Route::AddMemberRole {
+                    guild_id: GUILD_ID,
+                    role_id: ROLE_ID,
+                    user_id: USER_ID,
+                },
+                Path::GuildsIdMembersIdRolesId(GUILD_ID),
+            ),
+            (
+                Route::CreateGlobalCommand {
+                    application_id: APPLICATION_ID,
+                },
+                Path::ApplicationCommand(APPLICATION_ID),
+            ),
+            (
+                Route::CreateMessage {
+                    channel_id: CHANNEL_ID,
+                },
+                Path::ChannelsIdMessages(CHANNEL_ID),
+            ),
+            (
+                Route::CreateReaction {
+                    channel_id: CHANNEL_ID,
+                    emoji: &emoji(),
+                    message_id: MESSAGE_ID,
+                },
+                Path::ChannelsIdMessagesIdReactionsUserIdType(CHANNEL_ID),
+            ),
+            (
+                Route::DeleteMessage {
+                    channel_id: CHANNEL_ID,
+                    message_id: MESSAGE_ID,
+                },
+                Path::ChannelsIdMessagesId(Method::Delete, CHANNEL_ID),
+            ),
+            (
+                Route::GetChannel {
+                    channel_id: CHANNEL_ID,
+                },
+                Path::ChannelsId(CHANNEL_ID),
+            ),
+            (Route::GetCurrentUser, Path::UsersId),
+            (
+                Route::GetGuild {
+                    guild_id: GUILD_ID,
+                    with_counts: true,
+                },
+                Path::GuildsId(GUILD_ID),
+            ),
+            (
+                Route::GetMember {
+                    guild_id: GUILD_ID,
+                    user_id: USER_ID,
+                },
+                Path::GuildsIdMembersId(GUILD_ID),
+            ),
+            (
+                Route::UpdateMessage {
+                    channel_id: CHANNEL_ID,
+                    message_id: MESSAGE_ID,
+                },
+                Path::ChannelsIdMessagesId(Method::Patch, CHANNEL_ID),
+            ),
+            (
+                Route::InteractionCallback {
+                    interaction_id: INTERACTION_ID,
+                    interaction_token: INTERACTION_TOKEN,
+                },
+                Path::InteractionCallback(INTERACTION_ID),
+            ),
+            (
+                Route::ExecuteWebhook {
+                    thread_id: None,
+                    token: INTERACTION_TOKEN,
+                    wait: Some(true),
+                    webhook_id: APPLICATION_ID,
+                },
+                Path::WebhooksIdToken(APPLICATION_ID, INTERACTION_TOKEN.to_owned()),
+            ),
+            (
+                Route::GetFollowupMessage {
+                    application_id: APPLICATION_ID,
+                    interaction_token: INTERACTION_TOKEN,
+                    thread_id: None,
+                    message_id: MESSAGE_ID,
+                },
+                Path::WebhooksIdTokenMessagesId(APPLICATION_ID, INTERACTION_TOKEN.to_owned()),
+            ),
+        ];
+
+        for (route, path) in routes {
+            assert_eq!(path, route.to_path(), "{route:?}");
+        }
+
+        // Tokens are removed for labelling.
+        let route = Route::UpdateWebhookMessage {
+            message_id: MESSAGE_ID,
+            thread_id: None,
+            token: INTERACTION_TOKEN,
+            webhook_id: APPLICATION_ID,
+        };
+        assert_eq!(
+            Path::WebhooksIdTokenMessagesId(APPLICATION_ID, String::new()),
+            route.to_path().without_token()
+        );
+    }
+
     #[test]
     fn get_public_archived_threads() {
         let route = Route::GetPublicArchivedThreads {
@@ -4264,6 +4384,12 @@ mod tests {
         assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/vanity-url"));
     }
 
+    #[test]
+    fn update_guild_vanity_url() {
+        let route = Route::UpdateGuildVanityUrl { guild_id: GUILD_ID };
+        assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/vanity-url"));
+    }
+
     #[test]
     fn get_guild_voice_regions() {
         let route = Route::GetGuildVoiceRegions { guild_id: GUILD_ID };
@@ -0,0 +1,150 @@
+//! Utilities for testing requests against a mock API server.
+
+use std::{
+    future::Future,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+};
+
+/// Response sent by the mock API server.
+pub struct Response {
+    /// JSON body of the response.
+    pub body: String,
+    /// Additional headers, such as `x-ratelimit-limit: 5`.
+    pub headers: Vec<&'static str>,
+    /// Status line of the response, such as `200 OK`.
+    pub status: &'static str,
+}
+
+impl Response {
+    /// Create a response with a JSON body and no additional headers.
+    pub fn json(status: &'static str, body: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            headers: Vec::new(),
+            status,
+        }
+    }
+}
+
+/// Mock API server handling each connection's request concurrently.
+pub struct Server {
+    /// Address the server listens on.
+    address: SocketAddr,
+    /// Number of requests received.
+    requests: Arc<AtomicUsize>,
+}
+
+impl Server {
+    /// Start a mock API server responding to requests with a handler called
+    /// with each request's path, such as `/api/v10/gateway`.
+    pub async fn start<F, Fut>(handler: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Response> + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let server = Self {
+            address: listener.local_addr().unwrap(),
+            requests: Arc::clone(&requests),
+        };
+
+        let handler = Arc::new(handler);
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                requests.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(handle(stream, Arc::clone(&handler)));
+            }
+        });
+
+        server
+    }
+
+    /// Start a mock API server that accepts requests but never responds.
+    pub async fn stalled() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let server = Self {
+            address: listener.local_addr().unwrap(),
+            requests: Arc::new(AtomicUsize::new(0)),
+        };
+
+        let requests = Arc::clone(&server.requests);
+        tokio::spawn(async move {
+            let mut streams = Vec::new();
+
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                requests.fetch_add(1, Ordering::SeqCst);
+                streams.push(stream);
+            }
+        });
+
+        server
+    }
+
+    /// Number of requests the server received.
+    pub fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+
+    /// API base URL of the server, to be configured on clients.
+    pub fn url(&self) -> String {
+        format!("http://{}/api", self.address)
+    }
+}
+
+/// Read a request from a connection and write the handler's response.
+async fn handle<F, Fut>(stream: TcpStream, handler: Arc<F>)
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Response>,
+{
+    let mut stream = BufReader::new(stream);
+    let mut request_line = String::new();
+    stream.read_line(&mut request_line).await.unwrap();
+
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        stream.read_line(&mut line).await.unwrap();
+
+        if line == "\r\n" {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap();
+            }
+        }
+    }
+
+    // Read the body, which is ignored, before responding.
+    let mut body = vec![0; content_length];
+    stream.read_exact(&mut body).await.unwrap();
+
+    // POST /api/v10/channels/1/messages HTTP/1.1
+    let path = request_line.split(' ').nth(1).unwrap().to_owned();
+    let response = handler(path).await;
+
+    let headers = response
+        .headers
+        .iter()
+        .fold(String::new(), |headers, header| headers + header + "\r\n");
+    let response = format!(
+        "HTTP/1.1 {}\r\n{headers}content-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        response.status,
+        response.body.len(),
+        response.body,
+    );
+    stream.write_all(response.as_bytes()).await.unwrap();
+}
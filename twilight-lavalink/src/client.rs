@@ -2,11 +2,14 @@
 
 use crate::{
     model::VoiceUpdate,
-    node::{IncomingEvents, Node, NodeConfig, NodeError, Resume},
+    node::{
+        Backoff, CleanupPolicy, IncomingEvents, Node, NodeConfig, NodeError, NodeStatus, Resume,
+    },
     player::{Player, PlayerManager},
 };
 use dashmap::DashMap;
 use std::{
+    collections::HashMap,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     net::SocketAddr,
@@ -97,6 +100,7 @@ pub enum ClientErrorType {
 /// [`process`]: Self::process
 #[derive(Debug)]
 pub struct Lavalink {
+    backoff: Backoff,
     nodes: DashMap<SocketAddr, Arc<Node>>,
     players: PlayerManager,
     resume: Option<Resume>,
@@ -104,6 +108,7 @@ pub struct Lavalink {
     user_id: Id<UserMarker>,
     server_updates: DashMap<Id<GuildMarker>, VoiceServerUpdate>,
     sessions: DashMap<Id<GuildMarker>, Box<str>>,
+    voice_close: CleanupPolicy,
 }
 
 impl Lavalink {
@@ -139,6 +144,7 @@ impl Lavalink {
 
     fn _new_with_resume(user_id: Id<UserMarker>, shard_count: u32, resume: Option<Resume>) -> Self {
         Self {
+            backoff: Backoff::default(),
             nodes: DashMap::new(),
             players: PlayerManager::new(),
             resume,
@@ -146,9 +152,57 @@ impl Lavalink {
             user_id,
             server_updates: DashMap::new(),
             sessions: DashMap::new(),
+            voice_close: CleanupPolicy::Keep,
         }
     }
 
+    /// Set how to back off between attempts to (re)connect to nodes.
+    ///
+    /// Only applies to nodes added afterwards. See the [`Backoff`]
+    /// documentation for defaults.
+    ///
+    /// # Examples
+    ///
+    /// Keep trying to reconnect, waiting at most 30 seconds between attempts:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use twilight_lavalink::{node::Backoff, Lavalink};
+    /// use twilight_model::id::Id;
+    ///
+    /// let backoff = Backoff::new(Duration::from_secs(1), Duration::from_secs(30), None);
+    /// let lavalink = Lavalink::new(Id::new(1), 1).backoff(backoff);
+    /// ```
+    #[must_use = "has no effect if not used"]
+    pub const fn backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+
+        self
+    }
+
+    /// Set what to do with a player when Discord closes its voice connection,
+    /// such as when the bot is kicked from the voice channel.
+    ///
+    /// Only applies to nodes added afterwards. The default is
+    /// [`CleanupPolicy::Keep`].
+    ///
+    /// # Examples
+    ///
+    /// Destroy players when their voice connection is closed:
+    ///
+    /// ```
+    /// use twilight_lavalink::{node::CleanupPolicy, Lavalink};
+    /// use twilight_model::id::Id;
+    ///
+    /// let lavalink = Lavalink::new(Id::new(1), 1).on_voice_close(CleanupPolicy::Destroy);
+    /// ```
+    #[must_use = "has no effect if not used"]
+    pub const fn on_voice_close(mut self, policy: CleanupPolicy) -> Self {
+        self.voice_close = policy;
+
+        self
+    }
+
     /// Process an event into the Lavalink client.
     ///
     /// **Note**: calling this method in your event loop is required. See the
@@ -278,8 +332,10 @@ impl Lavalink {
         let config = NodeConfig {
             address,
             authorization: authorization.into(),
+            backoff: self.backoff,
             resume: self.resume.clone(),
             user_id: self.user_id,
+            voice_close: self.voice_close,
         };
 
         let (node, rx) = Node::connect(config, self.players.clone()).await?;
@@ -311,38 +367,54 @@ impl Lavalink {
         self.nodes.remove(&address).is_some()
     }
 
+    /// Retrieve a snapshot of the status of every node.
+    ///
+    /// Refer to [`Node::status`] for more information.
+    pub fn node_statuses(&self) -> HashMap<SocketAddr, NodeStatus> {
+        self.nodes
+            .iter()
+            .map(|node| (*node.key(), node.value().status()))
+            .collect()
+    }
+
     /// Determine the "best" node for new players according to available nodes'
     /// penalty scores. Disconnected nodes will not be considered.
     ///
-    /// Refer to [`Node::penalty`] for how this is calculated.
+    /// Refer to [`Node::current_penalty`] for how this is calculated.
     ///
     /// # Errors
     ///
     /// Returns a [`ClientErrorType::NodesUnconfigured`] error type if there are
     /// no connected nodes available in the client.
     ///
-    /// [`Node::penalty`]: crate::node::Node::penalty
+    /// [`Node::current_penalty`]: crate::node::Node::current_penalty
+    #[allow(clippy::unused_async)]
+    #[deprecated(note = "use best_node instead")]
     pub async fn best(&self) -> Result<Arc<Node>, ClientError> {
-        let mut lowest = i32::MAX;
-        let mut best = None;
-
-        for node in &self.nodes {
-            if node.sender().is_closed() {
-                continue;
-            }
-
-            let penalty = node.value().penalty().await;
-
-            if penalty < lowest {
-                lowest = penalty;
-                best.replace(node.clone());
-            }
-        }
+        self.best_node()
+    }
 
-        best.ok_or(ClientError {
-            kind: ClientErrorType::NodesUnconfigured,
-            source: None,
-        })
+    /// Determine the "best" node for new players according to available nodes'
+    /// penalty scores. Disconnected nodes will not be considered.
+    ///
+    /// Refer to [`Node::current_penalty`] for how this is calculated.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientErrorType::NodesUnconfigured`] error type if there are
+    /// no connected nodes available in the client.
+    ///
+    /// [`Node::current_penalty`]: crate::node::Node::current_penalty
+    pub fn best_node(&self) -> Result<Arc<Node>, ClientError> {
+        self.nodes
+            .iter()
+            .filter(|node| !node.sender().is_closed())
+            .min_by_key(|node| node.current_penalty())
+            .map(|node| Arc::clone(node.value()))
+            .ok_or(ClientError {
+                kind: ClientErrorType::NodesUnconfigured,
+                source: None,
+            })
     }
 
     /// Retrieve an immutable reference to the player manager.
@@ -363,12 +435,14 @@ impl Lavalink {
     ///
     /// [`PlayerManager::get`]: crate::player::PlayerManager::get
     /// [`add`]: Self::add
+    // Kept async to not break callers.
+    #[allow(clippy::unused_async)]
     pub async fn player(&self, guild_id: Id<GuildMarker>) -> Result<Arc<Player>, ClientError> {
         if let Some(player) = self.players().get(&guild_id) {
             return Ok(player);
         }
 
-        let node = self.best().await?;
+        let node = self.best_node()?;
 
         Ok(self.players().get_or_insert(guild_id, node))
     }
@@ -394,10 +468,82 @@ impl Lavalink {
 #[cfg(test)]
 mod tests {
     use super::{ClientError, ClientErrorType, Lavalink};
+    use crate::{
+        model::{Opcode, Stats, StatsCpu, StatsMemory},
+        node::Node,
+    };
     use static_assertions::assert_impl_all;
-    use std::{error::Error, fmt::Debug};
+    use std::{
+        error::Error,
+        fmt::Debug,
+        net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+        sync::Arc,
+    };
+    use twilight_model::id::Id;
 
     assert_impl_all!(ClientErrorType: Debug, Send, Sync);
     assert_impl_all!(ClientError: Error, Send, Sync);
     assert_impl_all!(Lavalink: Debug, Send, Sync);
+
+    fn stats(playing_players: u64) -> Stats {
+        Stats {
+            cpu: StatsCpu {
+                cores: 4,
+                lavalink_load: 0.0,
+                system_load: 0.0,
+            },
+            frames: None,
+            memory: StatsMemory {
+                allocated: 0,
+                free: 0,
+                used: 0,
+                reservable: 0,
+            },
+            players: playing_players,
+            playing_players,
+            op: Opcode::Stats,
+            uptime: 1000,
+        }
+    }
+
+    #[test]
+    fn best_node() {
+        let lavalink = Lavalink::new(Id::new(1), 1);
+        assert!(matches!(
+            lavalink.best_node().unwrap_err().kind(),
+            ClientErrorType::NodesUnconfigured
+        ));
+
+        let first = SocketAddr::from(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 2333));
+        let second = SocketAddr::from(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 2334));
+        let mut receivers = Vec::new();
+
+        for address in [first, second] {
+            let (node, rx) = Node::unconnected(address, lavalink.players().clone());
+            lavalink.nodes.insert(address, Arc::new(node));
+            receivers.push(rx);
+        }
+
+        // Nodes without stats are ranked by their number of players.
+        lavalink.players().get_or_insert(
+            Id::new(1),
+            Arc::clone(lavalink.nodes.get(&first).unwrap().value()),
+        );
+        assert_eq!(second, lavalink.best_node().unwrap().config().address);
+
+        lavalink.nodes.get(&first).unwrap().receive_stats(&stats(2));
+        lavalink
+            .nodes
+            .get(&second)
+            .unwrap()
+            .receive_stats(&stats(5));
+        assert_eq!(first, lavalink.best_node().unwrap().config().address);
+
+        lavalink.nodes.get(&first).unwrap().receive_stats(&stats(8));
+        assert_eq!(second, lavalink.best_node().unwrap().config().address);
+
+        // Disconnected nodes aren't considered.
+        drop(receivers.pop());
+        assert_eq!(first, lavalink.best_node().unwrap().config().address);
+    }
 }
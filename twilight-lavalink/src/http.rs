@@ -1,42 +1,51 @@
 //! Models to deserialize responses into and functions to create `http` crate
 //! requests.
 
+use crate::model::outgoing::{
+    ChannelMix, Distortion, EqualizerBand, Filters, Karaoke, LowPass, Rotation, Timescale, Tremolo,
+    Vibrato,
+};
 use http::{
-    header::{HeaderValue, AUTHORIZATION},
+    header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE},
     Error as HttpError, Request,
 };
 use percent_encoding::NON_ALPHANUMERIC;
-use serde::{Deserialize, Deserializer, Serialize};
-use std::net::{IpAddr, SocketAddr};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+use twilight_model::id::{marker::GuildMarker, Id};
 
-/// The type of search result given.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// The type of load result given.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[non_exhaustive]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum LoadType {
-    /// Loading the results failed.
-    LoadFailed,
+#[serde(rename_all = "camelCase")]
+pub enum LoadResultType {
     /// There were no matches.
-    NoMatches,
+    Empty,
+    /// Loading the results failed.
+    Error,
     /// A playlist was found.
-    PlaylistLoaded,
+    Playlist,
     /// Some results were found.
-    SearchResult,
+    Search,
     /// A single track was found.
-    TrackLoaded,
+    Track,
 }
 
-/// A track within a search result.
+/// A track within a load result.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
 #[serde(rename_all = "camelCase")]
 pub struct Track {
-    /// Details about a track, such as the author and title.
-    pub info: TrackInfo,
     /// The base64 track string that you use in the [`Play`] event.
     ///
     /// [`Play`]: crate::model::outgoing::Play
-    pub track: String,
+    #[serde(alias = "track")]
+    pub encoded: String,
+    /// Details about a track, such as the author and title.
+    pub info: TrackInfo,
 }
 
 /// Additional information about a track, such as the author.
@@ -44,6 +53,9 @@ pub struct Track {
 #[non_exhaustive]
 #[serde(rename_all = "camelCase")]
 pub struct TrackInfo {
+    /// URL of the track's artwork, if provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artwork_url: Option<String>,
     /// The name of the author, if provided.
     pub author: Option<String>,
     /// The identifier of the source of the track.
@@ -52,14 +64,20 @@ pub struct TrackInfo {
     pub is_seekable: bool,
     /// Whether the source is a stream.
     pub is_stream: bool,
+    /// International Standard Recording Code of the track, if provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub isrc: Option<String>,
     /// The length of the audio in milliseconds.
     pub length: u64,
     /// The position of the audio.
     pub position: u64,
+    /// Name of the source manager that loaded the track, such as `youtube`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_name: Option<String>,
     /// The title, if provided.
     pub title: Option<String>,
-    /// The source URI of the track.
-    pub uri: String,
+    /// The source URI of the track, if provided.
+    pub uri: Option<String>,
 }
 
 /// Information about a playlist from a search result.
@@ -85,19 +103,84 @@ where
         .and_then(|selected| u64::try_from(selected).ok()))
 }
 
-/// Possible track results for a query.
+/// A playlist within a load result.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
 #[serde(rename_all = "camelCase")]
-pub struct LoadedTracks {
-    /// The type of search result, such as a list of tracks or a playlist.
-    pub load_type: LoadType,
-    /// Information about the playlist, if provided.
-    pub playlist_info: PlaylistInfo,
-    /// The list of tracks returned for the search query.
+pub struct Playlist {
+    /// Information about the playlist.
+    pub info: PlaylistInfo,
+    /// The tracks of the playlist.
     pub tracks: Vec<Track>,
 }
 
+/// How severe an [`Exception`] is.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+    /// The cause is known and expected, such as a track being unavailable.
+    Common,
+    /// The cause is unknown and may be a bug in Lavalink.
+    Fault,
+    /// The cause might not be exactly known, but is possibly caused by outside
+    /// factors, such as the source returning an unexpected response.
+    Suspicious,
+}
+
+/// Reason why loading tracks failed.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct Exception {
+    /// Cause of the exception.
+    pub cause: String,
+    /// Message of the exception, if provided.
+    pub message: Option<String>,
+    /// How severe the exception is.
+    pub severity: Severity,
+}
+
+/// Possible track results for a query, which vary by the type of result.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(content = "data", rename_all = "camelCase", tag = "loadType")]
+pub enum LoadedTracks {
+    /// There were no matches.
+    Empty {},
+    /// Loading the results failed.
+    Error(Exception),
+    /// A playlist was found.
+    Playlist(Playlist),
+    /// Some results were found, such as for a search query.
+    Search(Vec<Track>),
+    /// A single track was found.
+    Track(Track),
+}
+
+impl LoadedTracks {
+    /// Type of the load result.
+    pub const fn kind(&self) -> LoadResultType {
+        match self {
+            Self::Empty {} => LoadResultType::Empty,
+            Self::Error(_) => LoadResultType::Error,
+            Self::Playlist(_) => LoadResultType::Playlist,
+            Self::Search(_) => LoadResultType::Search,
+            Self::Track(_) => LoadResultType::Track,
+        }
+    }
+
+    /// Tracks that were found, in order.
+    pub fn tracks(&self) -> &[Track] {
+        match self {
+            Self::Playlist(playlist) => &playlist.tracks,
+            Self::Search(tracks) => tracks,
+            Self::Track(track) => std::slice::from_ref(track),
+            Self::Empty {} | Self::Error(_) => &[],
+        }
+    }
+}
+
 /// A failing IP address within the planner.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
@@ -243,10 +326,200 @@ pub struct RotatingNanoIpDetails {
     pub ip_block: IpBlock,
 }
 
-/// Get a list of tracks that match an identifier.
+/// Update a player via the REST API, setting any number of its properties at
+/// once.
 ///
-/// The response will include a body which can be deserialized into a
-/// [`LoadedTracks`].
+/// Fields that are `None` are left unchanged. Pass it to [`update_player`]
+/// to create the request.
+///
+/// # Examples
+///
+/// Set the volume and seek to 10 seconds:
+///
+/// ```
+/// use twilight_lavalink::http::UpdatePlayer;
+///
+/// let mut update = UpdatePlayer::new();
+/// update.position = Some(10_000);
+/// update.volume = Some(50);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePlayer {
+    /// Position in milliseconds to end the track at.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    /// Audio filters to apply, replacing all previously applied filters.
+    ///
+    /// The guild ID of the filters is ignored.
+    #[serde(
+        serialize_with = "serialize_filters",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filters: Option<Filters>,
+    /// Whether the player is paused.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paused: Option<bool>,
+    /// Position in milliseconds to seek the track to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u64>,
+    /// Track to play or stop playing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track: Option<UpdatePlayerTrack>,
+    /// Voice connection of the player.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<UpdatePlayerVoice>,
+    /// Volume of the player from 0 to 1000, where 100 is the default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume: Option<u16>,
+}
+
+impl UpdatePlayer {
+    /// Create a new update leaving the player unchanged.
+    pub const fn new() -> Self {
+        Self {
+            end_time: None,
+            filters: None,
+            paused: None,
+            position: None,
+            track: None,
+            voice: None,
+            volume: None,
+        }
+    }
+}
+
+/// Track to set via an [`UpdatePlayer`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct UpdatePlayerTrack {
+    /// The base64 track string to play, or `None` to stop the player.
+    pub encoded: Option<String>,
+}
+
+impl UpdatePlayerTrack {
+    /// Create a new track update playing a track.
+    pub fn play(encoded: impl Into<String>) -> Self {
+        Self {
+            encoded: Some(encoded.into()),
+        }
+    }
+
+    /// Create a new track update stopping the player.
+    pub const fn stop() -> Self {
+        Self { encoded: None }
+    }
+}
+
+/// Voice connection to set via an [`UpdatePlayer`].
+///
+/// The values are from Discord's voice server and voice state updates.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePlayerVoice {
+    /// Voice server endpoint.
+    pub endpoint: String,
+    /// Voice session ID.
+    pub session_id: String,
+    /// Voice connection token.
+    pub token: String,
+}
+
+impl UpdatePlayerVoice {
+    /// Create a new voice connection update.
+    pub fn new(
+        endpoint: impl Into<String>,
+        session_id: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            session_id: session_id.into(),
+            token: token.into(),
+        }
+    }
+}
+
+/// [`Filters`] as serialized in an [`UpdatePlayer`], without the websocket
+/// event's guild ID and opcode.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FiltersBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_mix: Option<&'a ChannelMix>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    distortion: Option<&'a Distortion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    equalizer: Option<&'a [EqualizerBand]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    karaoke: Option<&'a Karaoke>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    low_pass: Option<&'a LowPass>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rotation: Option<&'a Rotation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timescale: Option<&'a Timescale>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tremolo: Option<&'a Tremolo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vibrato: Option<&'a Vibrato>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    volume: Option<f64>,
+}
+
+/// Serialize the filters of an [`UpdatePlayer`].
+#[allow(clippy::ref_option)]
+fn serialize_filters<S: Serializer>(
+    filters: &Option<Filters>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    filters
+        .as_ref()
+        .map(|filters| FiltersBody {
+            channel_mix: filters.channel_mix.as_ref(),
+            distortion: filters.distortion.as_ref(),
+            equalizer: filters.equalizer.as_deref(),
+            karaoke: filters.karaoke.as_ref(),
+            low_pass: filters.low_pass.as_ref(),
+            rotation: filters.rotation.as_ref(),
+            timescale: filters.timescale.as_ref(),
+            tremolo: filters.tremolo.as_ref(),
+            vibrato: filters.vibrato.as_ref(),
+            volume: filters.volume,
+        })
+        .serialize(serializer)
+}
+
+/// Create an identifier searching YouTube for a query.
+///
+/// The identifier is to be passed to [`load_track`].
+pub fn youtube_search(query: impl AsRef<str>) -> String {
+    format!("ytsearch:{}", query.as_ref())
+}
+
+/// Create an identifier searching YouTube Music for a query.
+///
+/// The identifier is to be passed to [`load_track`].
+pub fn youtube_music_search(query: impl AsRef<str>) -> String {
+    format!("ytmsearch:{}", query.as_ref())
+}
+
+/// Create an identifier searching SoundCloud for a query.
+///
+/// The identifier is to be passed to [`load_track`].
+pub fn soundcloud_search(query: impl AsRef<str>) -> String {
+    format!("scsearch:{}", query.as_ref())
+}
+
+/// Get a list of tracks that match an identifier, such as a URL or a
+/// [search query].
+///
+/// The identifier is URL-encoded. The response will include a body which can
+/// be deserialized into a [`LoadedTracks`].
+///
+/// [search query]: youtube_search
 ///
 /// # Errors
 ///
@@ -258,7 +531,7 @@ pub fn load_track(
 ) -> Result<Request<&'static [u8]>, HttpError> {
     let identifier =
         percent_encoding::percent_encode(identifier.as_ref().as_bytes(), NON_ALPHANUMERIC);
-    let url = format!("http://{address}/loadtracks?identifier={identifier}");
+    let url = format!("http://{address}/v4/loadtracks?identifier={identifier}");
 
     let mut req = Request::get(url);
 
@@ -314,19 +587,103 @@ pub fn unmark_failed_address(
     )
 }
 
+/// Update a player of a session via the REST API, creating it if it doesn't
+/// exist.
+///
+/// If `no_replace` is `true`, the track of the update is ignored if a track
+/// is already playing. The response will include a body with the updated
+/// player.
+///
+/// # Errors
+///
+/// See the documentation for [`http::Error`].
+#[allow(clippy::missing_panics_doc)]
+pub fn update_player(
+    address: SocketAddr,
+    authorization: impl AsRef<str>,
+    session_id: impl AsRef<str>,
+    guild_id: Id<GuildMarker>,
+    update: &UpdatePlayer,
+    no_replace: bool,
+) -> Result<Request<Vec<u8>>, HttpError> {
+    let session_id =
+        percent_encoding::percent_encode(session_id.as_ref().as_bytes(), NON_ALPHANUMERIC);
+    let url = format!(
+        "http://{address}/v4/sessions/{session_id}/players/{guild_id}?noReplace={no_replace}"
+    );
+
+    let mut req = Request::patch(url);
+
+    let auth_value = HeaderValue::from_str(authorization.as_ref())?;
+    req = req.header(AUTHORIZATION, auth_value);
+    req = req.header(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    req.body(serde_json::to_vec(update).expect("valid json"))
+}
+
+/// Configure whether a session can be resumed via the REST API.
+///
+/// If `resuming` is `true`, the node keeps the session and its players for
+/// `timeout` after the connection is lost, until it is resumed by connecting
+/// with the session ID.
+///
+/// # Errors
+///
+/// See the documentation for [`http::Error`].
+#[allow(clippy::missing_panics_doc)]
+pub fn update_session(
+    address: SocketAddr,
+    authorization: impl AsRef<str>,
+    session_id: impl AsRef<str>,
+    resuming: bool,
+    timeout: Duration,
+) -> Result<Request<Vec<u8>>, HttpError> {
+    let session_id =
+        percent_encoding::percent_encode(session_id.as_ref().as_bytes(), NON_ALPHANUMERIC);
+    let url = format!("http://{address}/v4/sessions/{session_id}");
+
+    let mut req = Request::patch(url);
+
+    let auth_value = HeaderValue::from_str(authorization.as_ref())?;
+    req = req.header(AUTHORIZATION, auth_value);
+    req = req.header(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    req.body(
+        serde_json::to_vec(&serde_json::json!({
+            "resuming": resuming,
+            "timeout": timeout.as_secs(),
+        }))
+        .expect("valid json"),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        FailingAddress, IpBlock, IpBlockType, LoadType, LoadedTracks, NanoIpDetails,
-        NanoIpRoutePlanner, PlaylistInfo, RotatingIpDetails, RotatingIpRoutePlanner,
-        RotatingNanoIpDetails, RotatingNanoIpRoutePlanner, RoutePlanner, RoutePlannerType, Track,
-        TrackInfo,
+        Exception, FailingAddress, IpBlock, IpBlockType, LoadResultType, LoadedTracks,
+        NanoIpDetails, NanoIpRoutePlanner, Playlist, PlaylistInfo, RotatingIpDetails,
+        RotatingIpRoutePlanner, RotatingNanoIpDetails, RotatingNanoIpRoutePlanner, RoutePlanner,
+        RoutePlannerType, Severity, Track, TrackInfo, UpdatePlayer, UpdatePlayerTrack,
+        UpdatePlayerVoice,
     };
+    use crate::model::outgoing::{Filters, Timescale};
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::fmt::Debug;
+    use std::{fmt::Debug, time::Duration};
+    use twilight_model::id::Id;
 
+    assert_fields!(Exception: cause, message, severity);
+    assert_impl_all!(
+        Exception: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(FailingAddress: address, failing_timestamp, failing_time);
     assert_impl_all!(
         FailingAddress: Clone,
@@ -360,7 +717,8 @@ mod tests {
         Sync,
     );
     assert_impl_all!(
-        LoadType: Clone,
+        LoadResultType: Clone,
+        Copy,
         Debug,
         Deserialize<'static>,
         Eq,
@@ -369,7 +727,6 @@ mod tests {
         Serialize,
         Sync,
     );
-    assert_fields!(LoadedTracks: load_type, playlist_info, tracks);
     assert_impl_all!(
         LoadedTracks: Clone,
         Debug,
@@ -406,6 +763,17 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(Playlist: info, tracks);
+    assert_impl_all!(
+        Playlist: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(PlaylistInfo: name, selected_track);
     assert_impl_all!(
         PlaylistInfo: Clone,
@@ -482,6 +850,17 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_impl_all!(
+        Severity: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_impl_all!(
         RoutePlanner: Clone,
         Debug,
@@ -493,12 +872,15 @@ mod tests {
         Sync,
     );
     assert_fields!(
-        TrackInfo: author,
+        TrackInfo: artwork_url,
+        author,
         identifier,
         is_seekable,
         is_stream,
+        isrc,
         length,
         position,
+        source_name,
         title,
         uri
     );
@@ -512,7 +894,7 @@ mod tests {
         Serialize,
         Sync
     );
-    assert_fields!(Track: info, track);
+    assert_fields!(Track: encoded, info);
     assert_impl_all!(
         Track: Clone,
         Debug,
@@ -547,4 +929,232 @@ mod tests {
             ],
         );
     }
+
+    fn track_json() -> serde_json::Value {
+        serde_json::json!({
+            "encoded": "QAAAjQIAJVJpY2sgQXN0bGV5",
+            "info": {
+                "artworkUrl": null,
+                "author": "RickAstleyVEVO",
+                "identifier": "dQw4w9WgXcQ",
+                "isSeekable": true,
+                "isStream": false,
+                "isrc": null,
+                "length": 212_000,
+                "position": 0,
+                "sourceName": "youtube",
+                "title": "Rick Astley - Never Gonna Give You Up",
+                "uri": "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+            },
+            "pluginInfo": {},
+            "userData": {},
+        })
+    }
+
+    #[test]
+    fn loaded_tracks_track() {
+        let loaded = serde_json::from_value::<LoadedTracks>(serde_json::json!({
+            "loadType": "track",
+            "data": track_json(),
+        }))
+        .unwrap();
+
+        assert_eq!(LoadResultType::Track, loaded.kind());
+        let LoadedTracks::Track(track) = &loaded else {
+            panic!("not a track: {loaded:?}");
+        };
+        assert_eq!("QAAAjQIAJVJpY2sgQXN0bGV5", track.encoded);
+        assert_eq!(Some("youtube"), track.info.source_name.as_deref());
+        assert_eq!(212_000, track.info.length);
+        assert_eq!(1, loaded.tracks().len());
+    }
+
+    #[test]
+    fn loaded_tracks_playlist() {
+        let loaded = serde_json::from_value::<LoadedTracks>(serde_json::json!({
+            "loadType": "playlist",
+            "data": {
+                "info": {
+                    "name": "Example Playlist",
+                    "selectedTrack": 1,
+                },
+                "pluginInfo": {},
+                "tracks": [track_json(), track_json()],
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(LoadResultType::Playlist, loaded.kind());
+        let LoadedTracks::Playlist(playlist) = &loaded else {
+            panic!("not a playlist: {loaded:?}");
+        };
+        assert_eq!(Some("Example Playlist"), playlist.info.name.as_deref());
+        assert_eq!(Some(1), playlist.info.selected_track);
+        assert_eq!(2, loaded.tracks().len());
+    }
+
+    #[test]
+    fn loaded_tracks_search() {
+        let loaded = serde_json::from_value::<LoadedTracks>(serde_json::json!({
+            "loadType": "search",
+            "data": [track_json(), track_json(), track_json()],
+        }))
+        .unwrap();
+
+        assert_eq!(LoadResultType::Search, loaded.kind());
+        assert_eq!(3, loaded.tracks().len());
+    }
+
+    #[test]
+    fn loaded_tracks_empty() {
+        let loaded = serde_json::from_value::<LoadedTracks>(serde_json::json!({
+            "loadType": "empty",
+            "data": {},
+        }))
+        .unwrap();
+
+        assert_eq!(LoadedTracks::Empty {}, loaded);
+        assert_eq!(LoadResultType::Empty, loaded.kind());
+        assert!(loaded.tracks().is_empty());
+    }
+
+    #[test]
+    fn loaded_tracks_error() {
+        let loaded = serde_json::from_value::<LoadedTracks>(serde_json::json!({
+            "loadType": "error",
+            "data": {
+                "cause": "java.lang.RuntimeException: This video is unavailable",
+                "message": "This video is unavailable",
+                "severity": "common",
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(
+            LoadedTracks::Error(Exception {
+                cause: "java.lang.RuntimeException: This video is unavailable".to_owned(),
+                message: Some("This video is unavailable".to_owned()),
+                severity: Severity::Common,
+            }),
+            loaded
+        );
+        assert_eq!(LoadResultType::Error, loaded.kind());
+        assert!(loaded.tracks().is_empty());
+    }
+
+    #[test]
+    fn load_track_identifier() {
+        let request = super::load_track(
+            ([127, 0, 0, 1], 2333).into(),
+            super::youtube_search("never gonna & give"),
+            "youshallnotpass",
+        )
+        .unwrap();
+
+        assert_eq!(
+            "http://127.0.0.1:2333/v4/loadtracks?identifier=ytsearch%3Anever%20gonna%20%26%20give",
+            request.uri()
+        );
+        assert_eq!("ytmsearch:song", super::youtube_music_search("song"));
+        assert_eq!("scsearch:song", super::soundcloud_search("song"));
+    }
+
+    #[test]
+    fn update_player_volume_position() {
+        let mut update = UpdatePlayer::new();
+        update.position = Some(10_000);
+        update.volume = Some(50);
+
+        assert_eq!(
+            serde_json::json!({
+                "position": 10_000,
+                "volume": 50,
+            }),
+            serde_json::to_value(&update).unwrap()
+        );
+    }
+
+    #[test]
+    fn update_player_full() {
+        let mut filters = Filters::new(Id::new(1));
+        filters.timescale = Some(Timescale::new(1.5, 1.0, 1.0));
+
+        let mut update = UpdatePlayer::new();
+        update.end_time = Some(60_000);
+        update.filters = Some(filters);
+        update.paused = Some(false);
+        update.track = Some(UpdatePlayerTrack::play("track"));
+        update.voice = Some(UpdatePlayerVoice::new("endpoint", "session", "token"));
+
+        assert_eq!(
+            serde_json::json!({
+                "endTime": 60_000,
+                "filters": {
+                    "timescale": {
+                        "pitch": 1.0,
+                        "rate": 1.0,
+                        "speed": 1.5,
+                    },
+                },
+                "paused": false,
+                "track": {
+                    "encoded": "track",
+                },
+                "voice": {
+                    "endpoint": "endpoint",
+                    "sessionId": "session",
+                    "token": "token",
+                },
+            }),
+            serde_json::to_value(&update).unwrap()
+        );
+
+        update.track = Some(UpdatePlayerTrack::stop());
+        assert_eq!(
+            serde_json::json!({ "encoded": null }),
+            serde_json::to_value(&update).unwrap()["track"]
+        );
+    }
+
+    #[test]
+    fn update_player_request() {
+        let mut update = UpdatePlayer::new();
+        update.volume = Some(50);
+
+        let request = super::update_player(
+            ([127, 0, 0, 1], 2333).into(),
+            "youshallnotpass",
+            "session",
+            Id::new(1),
+            &update,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(http::Method::PATCH, request.method());
+        assert_eq!(
+            "http://127.0.0.1:2333/v4/sessions/session/players/1?noReplace=true",
+            request.uri()
+        );
+        assert_eq!(br#"{"volume":50}"#, request.body().as_slice());
+    }
+
+    #[test]
+    fn update_session_request() {
+        let request = super::update_session(
+            ([127, 0, 0, 1], 2333).into(),
+            "youshallnotpass",
+            "session",
+            true,
+            Duration::from_secs(60),
+        )
+        .unwrap();
+
+        assert_eq!(http::Method::PATCH, request.method());
+        assert_eq!("http://127.0.0.1:2333/v4/sessions/session", request.uri());
+        assert_eq!(
+            br#"{"resuming":true,"timeout":60}"#,
+            request.body().as_slice()
+        );
+    }
 }
@@ -17,6 +17,7 @@ pub mod client;
 pub mod model;
 pub mod node;
 pub mod player;
+pub mod queue;
 
 #[cfg(feature = "http-support")]
 pub mod http;
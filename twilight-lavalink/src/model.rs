@@ -14,12 +14,16 @@ pub enum Opcode {
     Equalizer,
     /// Meta information about a track starting or ending.
     Event,
+    /// Apply audio filters to a player.
+    Filters,
     /// Pause a player.
     Pause,
     /// Play a track.
     Play,
     /// An update about a player's current track.
     PlayerUpdate,
+    /// A connection to a node is ready.
+    Ready,
     /// Seek a player's active track to a new position.
     Seek,
     /// Updated statistics about a node.
@@ -51,6 +55,8 @@ pub mod outgoing {
         Destroy(Destroy),
         /// Equalize a player.
         Equalizer(Equalizer),
+        /// Apply audio filters to a player.
+        Filters(Box<Filters>),
         /// Pause or unpause a player.
         Pause(Pause),
         /// Play a track.
@@ -71,6 +77,12 @@ pub mod outgoing {
         }
     }
 
+    impl From<Filters> for OutgoingEvent {
+        fn from(event: Filters) -> OutgoingEvent {
+            Self::Filters(Box::new(event))
+        }
+    }
+
     impl From<Equalizer> for OutgoingEvent {
         fn from(event: Equalizer) -> OutgoingEvent {
             Self::Equalizer(event)
@@ -197,6 +209,309 @@ pub mod outgoing {
         }
     }
 
+    /// Apply audio filters to a player.
+    ///
+    /// Filters that are `None` are disabled, replacing any previously applied
+    /// filters.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Filters {
+        /// Mix both channels of the audio.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub channel_mix: Option<ChannelMix>,
+        /// Distort the audio.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub distortion: Option<Distortion>,
+        /// Bands to adjust the gain of.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub equalizer: Option<Vec<EqualizerBand>>,
+        /// The guild ID of the player.
+        pub guild_id: Id<GuildMarker>,
+        /// Eliminate part of a band, usually targeting vocals.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub karaoke: Option<Karaoke>,
+        /// Suppress higher frequencies.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub low_pass: Option<LowPass>,
+        /// The opcode of the event.
+        pub op: Opcode,
+        /// Rotate the audio around the stereo channels.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rotation: Option<Rotation>,
+        /// Change the speed, pitch, and rate.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub timescale: Option<Timescale>,
+        /// Oscillate the volume.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tremolo: Option<Tremolo>,
+        /// Oscillate the pitch.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub vibrato: Option<Vibrato>,
+        /// Volume multiplier from 0 to 5, where 1 is the default.
+        ///
+        /// Values above 1 may cause clipping.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub volume: Option<f64>,
+    }
+
+    impl Filters {
+        /// Create a new filters event without any filters.
+        ///
+        /// Sending it disables all filters.
+        pub const fn new(guild_id: Id<GuildMarker>) -> Self {
+            Self {
+                channel_mix: None,
+                distortion: None,
+                equalizer: None,
+                guild_id,
+                karaoke: None,
+                low_pass: None,
+                op: Opcode::Filters,
+                rotation: None,
+                timescale: None,
+                tremolo: None,
+                vibrato: None,
+                volume: None,
+            }
+        }
+    }
+
+    impl From<Id<GuildMarker>> for Filters {
+        fn from(guild_id: Id<GuildMarker>) -> Self {
+            Self::new(guild_id)
+        }
+    }
+
+    /// Mix both channels of the audio.
+    ///
+    /// Each factor is from 0 to 1. The default leaves the channels unchanged.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct ChannelMix {
+        /// Factor of the left channel mixed into the left channel.
+        pub left_to_left: f64,
+        /// Factor of the left channel mixed into the right channel.
+        pub left_to_right: f64,
+        /// Factor of the right channel mixed into the left channel.
+        pub right_to_left: f64,
+        /// Factor of the right channel mixed into the right channel.
+        pub right_to_right: f64,
+    }
+
+    impl ChannelMix {
+        /// Create a new channel mix filter.
+        pub const fn new(
+            left_to_left: f64,
+            left_to_right: f64,
+            right_to_left: f64,
+            right_to_right: f64,
+        ) -> Self {
+            Self {
+                left_to_left,
+                left_to_right,
+                right_to_left,
+                right_to_right,
+            }
+        }
+    }
+
+    impl Default for ChannelMix {
+        fn default() -> Self {
+            Self::new(1.0, 0.0, 0.0, 1.0)
+        }
+    }
+
+    /// Distort the audio.
+    ///
+    /// The default leaves the audio unchanged.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Distortion {
+        /// Offset of the cosine.
+        pub cos_offset: f64,
+        /// Scale of the cosine.
+        pub cos_scale: f64,
+        /// Offset of the output.
+        pub offset: f64,
+        /// Scale of the output.
+        pub scale: f64,
+        /// Offset of the sine.
+        pub sin_offset: f64,
+        /// Scale of the sine.
+        pub sin_scale: f64,
+        /// Offset of the tangent.
+        pub tan_offset: f64,
+        /// Scale of the tangent.
+        pub tan_scale: f64,
+    }
+
+    impl Default for Distortion {
+        fn default() -> Self {
+            Self {
+                cos_offset: 0.0,
+                cos_scale: 1.0,
+                offset: 0.0,
+                scale: 1.0,
+                sin_offset: 0.0,
+                sin_scale: 1.0,
+                tan_offset: 0.0,
+                tan_scale: 1.0,
+            }
+        }
+    }
+
+    /// Eliminate part of a band, usually targeting vocals.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Karaoke {
+        /// Band to filter, in Hz. The default is 220.
+        pub filter_band: f64,
+        /// Width of the filtered band. The default is 100.
+        pub filter_width: f64,
+        /// Effect level from 0 to 1. The default is 1.
+        pub level: f64,
+        /// Mono level from 0 to 1. The default is 1.
+        pub mono_level: f64,
+    }
+
+    impl Karaoke {
+        /// Create a new karaoke filter.
+        pub const fn new(level: f64, mono_level: f64, filter_band: f64, filter_width: f64) -> Self {
+            Self {
+                filter_band,
+                filter_width,
+                level,
+                mono_level,
+            }
+        }
+    }
+
+    impl Default for Karaoke {
+        fn default() -> Self {
+            Self::new(1.0, 1.0, 220.0, 100.0)
+        }
+    }
+
+    /// Suppress higher frequencies.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct LowPass {
+        /// Smoothing factor, where values of 1 or less disable the filter. The
+        /// default is 20.
+        pub smoothing: f64,
+    }
+
+    impl LowPass {
+        /// Create a new low pass filter.
+        pub const fn new(smoothing: f64) -> Self {
+            Self { smoothing }
+        }
+    }
+
+    impl Default for LowPass {
+        fn default() -> Self {
+            Self::new(20.0)
+        }
+    }
+
+    /// Rotate the audio around the stereo channels.
+    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Rotation {
+        /// Frequency of the rotation in Hz. The default is 0.
+        pub rotation_hz: f64,
+    }
+
+    impl Rotation {
+        /// Create a new rotation filter.
+        pub const fn new(rotation_hz: f64) -> Self {
+            Self { rotation_hz }
+        }
+    }
+
+    /// Change the speed, pitch, and rate.
+    ///
+    /// Each value is a multiplier of at least 0, where 1 is the default.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Timescale {
+        /// Pitch multiplier.
+        pub pitch: f64,
+        /// Rate multiplier, changing both the speed and pitch.
+        pub rate: f64,
+        /// Speed multiplier.
+        pub speed: f64,
+    }
+
+    impl Timescale {
+        /// Create a new timescale filter.
+        pub const fn new(speed: f64, pitch: f64, rate: f64) -> Self {
+            Self { pitch, rate, speed }
+        }
+    }
+
+    impl Default for Timescale {
+        fn default() -> Self {
+            Self::new(1.0, 1.0, 1.0)
+        }
+    }
+
+    /// Oscillate the volume.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Tremolo {
+        /// Depth of the oscillation from 0 to 1, exclusive. The default is 0.5.
+        pub depth: f64,
+        /// Frequency of the oscillation, greater than 0. The default is 2.
+        pub frequency: f64,
+    }
+
+    impl Tremolo {
+        /// Create a new tremolo filter.
+        pub const fn new(frequency: f64, depth: f64) -> Self {
+            Self { depth, frequency }
+        }
+    }
+
+    impl Default for Tremolo {
+        fn default() -> Self {
+            Self::new(2.0, 0.5)
+        }
+    }
+
+    /// Oscillate the pitch.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Vibrato {
+        /// Depth of the oscillation from 0 to 1, exclusive. The default is 0.5.
+        pub depth: f64,
+        /// Frequency of the oscillation from 0 to 14, exclusive of 0. The
+        /// default is 2.
+        pub frequency: f64,
+    }
+
+    impl Vibrato {
+        /// Create a new vibrato filter.
+        pub const fn new(frequency: f64, depth: f64) -> Self {
+            Self { depth, frequency }
+        }
+    }
+
+    impl Default for Vibrato {
+        fn default() -> Self {
+            Self::new(2.0, 0.5)
+        }
+    }
+
     /// Pause or unpause a player.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -442,6 +757,7 @@ pub mod incoming {
 
     use super::Opcode;
     use serde::{Deserialize, Serialize};
+    use std::borrow::Cow;
     use twilight_model::id::{marker::GuildMarker, Id};
 
     /// An incoming event from a Lavalink node.
@@ -451,6 +767,8 @@ pub mod incoming {
     pub enum IncomingEvent {
         /// An update about the information of a player.
         PlayerUpdate(PlayerUpdate),
+        /// The connection is ready, with the ID of its session.
+        Ready(Ready),
         /// New statistics about a node and its host.
         Stats(Stats),
         /// A track ended.
@@ -467,6 +785,12 @@ pub mod incoming {
         }
     }
 
+    impl From<Ready> for IncomingEvent {
+        fn from(event: Ready) -> IncomingEvent {
+            Self::Ready(event)
+        }
+    }
+
     impl From<Stats> for IncomingEvent {
         fn from(event: Stats) -> IncomingEvent {
             Self::Stats(event)
@@ -499,6 +823,21 @@ pub mod incoming {
         pub position: Option<i64>,
     }
 
+    /// The connection to a node is ready.
+    ///
+    /// Sent as the first event of every connection.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Ready {
+        /// The opcode of the event.
+        pub op: Opcode,
+        /// Whether the previous session was resumed.
+        pub resumed: bool,
+        /// The ID of the session, used to resume it and in REST requests.
+        pub session_id: String,
+    }
+
     /// Statistics about a node and its host.
     #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -591,13 +930,67 @@ pub mod incoming {
         /// The opcode of the event.
         pub op: Opcode,
         /// The reason that the track ended.
-        ///
-        /// For example, this may be `"FINISHED"`.
-        pub reason: String,
+        pub reason: TrackEndReason,
         /// The base64 track that was affected.
         pub track: String,
     }
 
+    /// The reason that a track ended.
+    #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(from = "String", into = "Cow<'static, str>")]
+    pub enum TrackEndReason {
+        /// The player was cleaned up by the node.
+        Cleanup,
+        /// The track finished playing.
+        Finished,
+        /// The track failed to start.
+        LoadFailed,
+        /// Another track started playing in its place.
+        Replaced,
+        /// The track was stopped.
+        Stopped,
+        /// Variant value is unknown to the library.
+        Unknown(String),
+    }
+
+    impl TrackEndReason {
+        /// Whether the next track may be started.
+        ///
+        /// This is the case if the track ended on its own rather than being
+        /// stopped or replaced.
+        pub const fn may_start_next(&self) -> bool {
+            matches!(self, Self::Finished | Self::LoadFailed)
+        }
+    }
+
+    impl From<TrackEndReason> for Cow<'static, str> {
+        fn from(value: TrackEndReason) -> Self {
+            match value {
+                TrackEndReason::Cleanup => "CLEANUP".into(),
+                TrackEndReason::Finished => "FINISHED".into(),
+                TrackEndReason::LoadFailed => "LOAD_FAILED".into(),
+                TrackEndReason::Replaced => "REPLACED".into(),
+                TrackEndReason::Stopped => "STOPPED".into(),
+                TrackEndReason::Unknown(unknown) => unknown.into(),
+            }
+        }
+    }
+
+    // Lavalink v4 sends camel cased reasons.
+    impl From<String> for TrackEndReason {
+        fn from(value: String) -> Self {
+            match value.as_str() {
+                "CLEANUP" | "cleanup" => Self::Cleanup,
+                "FINISHED" | "finished" => Self::Finished,
+                "LOAD_FAILED" | "loadFailed" => Self::LoadFailed,
+                "REPLACED" | "replaced" => Self::Replaced,
+                "STOPPED" | "stopped" => Self::Stopped,
+                _ => Self::Unknown(value),
+            }
+        }
+    }
+
     /// A track started.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -637,11 +1030,12 @@ pub mod incoming {
 
 pub use self::{
     incoming::{
-        IncomingEvent, PlayerUpdate, PlayerUpdateState, Stats, StatsCpu, StatsFrames, StatsMemory,
-        TrackEnd, TrackEventType, TrackStart, WebsocketClosed,
+        IncomingEvent, PlayerUpdate, PlayerUpdateState, Ready, Stats, StatsCpu, StatsFrames,
+        StatsMemory, TrackEnd, TrackEndReason, TrackEventType, TrackStart, WebsocketClosed,
     },
     outgoing::{
-        Destroy, Equalizer, EqualizerBand, OutgoingEvent, Pause, Play, Seek, Stop, VoiceUpdate,
+        ChannelMix, Destroy, Distortion, Equalizer, EqualizerBand, Filters, Karaoke, LowPass,
+        OutgoingEvent, Pause, Play, Rotation, Seek, Stop, Timescale, Tremolo, Vibrato, VoiceUpdate,
         Volume,
     },
 };
@@ -650,19 +1044,20 @@ pub use self::{
 mod tests {
     use super::{
         incoming::{
-            IncomingEvent, PlayerUpdate, PlayerUpdateState, Stats, StatsCpu, StatsFrames,
-            StatsMemory, TrackEnd, TrackEventType, TrackStart, WebsocketClosed,
+            IncomingEvent, PlayerUpdate, PlayerUpdateState, Ready, Stats, StatsCpu, StatsFrames,
+            StatsMemory, TrackEnd, TrackEndReason, TrackEventType, TrackStart, WebsocketClosed,
         },
         outgoing::{
-            Destroy, Equalizer, EqualizerBand, OutgoingEvent, Pause, Play, Seek, Stop, VoiceUpdate,
-            Volume,
+            ChannelMix, Destroy, Distortion, Equalizer, EqualizerBand, Filters, Karaoke, LowPass,
+            OutgoingEvent, Pause, Play, Rotation, Seek, Stop, Timescale, Tremolo, Vibrato,
+            VoiceUpdate, Volume,
         },
         Opcode,
     };
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::fmt::Debug;
+    use std::{fmt::Debug, hash::Hash};
     use twilight_model::{
         gateway::payload::incoming::VoiceServerUpdate,
         id::{marker::GuildMarker, Id},
@@ -702,6 +1097,112 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(
+        Filters: channel_mix,
+        distortion,
+        equalizer,
+        guild_id,
+        karaoke,
+        low_pass,
+        op,
+        rotation,
+        timescale,
+        tremolo,
+        vibrato,
+        volume
+    );
+    assert_impl_all!(
+        Filters: Clone,
+        Debug,
+        Deserialize<'static>,
+        From<Id<GuildMarker>>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_impl_all!(
+        ChannelMix: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_impl_all!(
+        Distortion: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Karaoke: filter_band, filter_width, level, mono_level);
+    assert_impl_all!(
+        Karaoke: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_impl_all!(
+        LowPass: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_impl_all!(
+        Rotation: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Timescale: pitch, rate, speed);
+    assert_impl_all!(
+        Timescale: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_impl_all!(
+        Tremolo: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_impl_all!(
+        Vibrato: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_impl_all!(
         IncomingEvent: Clone,
         Debug,
@@ -764,6 +1265,17 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(Ready: op, resumed, session_id);
+    assert_impl_all!(
+        Ready: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(Play: end_time, guild_id, no_replace, op, start_time, track);
     assert_impl_all!(
         Play: Clone,
@@ -868,6 +1380,17 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_impl_all!(
+        TrackEndReason: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_impl_all!(
         TrackEventType: Clone,
         Copy,
@@ -998,4 +1521,144 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn filters_timescale() {
+        let mut filters = Filters::new(Id::new(1));
+        filters.timescale = Some(Timescale::new(0.8, 1.2, 1.0));
+
+        assert_eq!(
+            serde_json::json!({
+                "guildId": "1",
+                "op": "filters",
+                "timescale": {
+                    "pitch": 1.2,
+                    "rate": 1.0,
+                    "speed": 0.8,
+                },
+            }),
+            serde_json::to_value(&filters).unwrap(),
+        );
+    }
+
+    #[test]
+    fn filters_karaoke() {
+        let mut filters = Filters::new(Id::new(1));
+        filters.karaoke = Some(Karaoke::default());
+        filters.volume = Some(1.0);
+
+        serde_test::assert_tokens(
+            &filters,
+            &[
+                Token::Struct {
+                    name: "Filters",
+                    len: 4,
+                },
+                Token::Str("guildId"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("karaoke"),
+                Token::Some,
+                Token::Struct {
+                    name: "Karaoke",
+                    len: 4,
+                },
+                Token::Str("filterBand"),
+                Token::F64(220.0),
+                Token::Str("filterWidth"),
+                Token::F64(100.0),
+                Token::Str("level"),
+                Token::F64(1.0),
+                Token::Str("monoLevel"),
+                Token::F64(1.0),
+                Token::StructEnd,
+                Token::Str("op"),
+                Token::UnitVariant {
+                    name: "Opcode",
+                    variant: "filters",
+                },
+                Token::Str("volume"),
+                Token::Some,
+                Token::F64(1.0),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn filters_deserialize() {
+        let filters = serde_json::from_str::<Filters>(
+            r#"{
+                "op": "filters",
+                "guildId": "1",
+                "timescale": {"speed": 1.1, "pitch": 0.9, "rate": 1.0},
+                "karaoke": {"level": 0.5, "monoLevel": 0.5, "filterBand": 220.0, "filterWidth": 100.0}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(Some(Timescale::new(1.1, 0.9, 1.0)), filters.timescale);
+        assert_eq!(Some(Karaoke::new(0.5, 0.5, 220.0, 100.0)), filters.karaoke);
+        assert!(filters.tremolo.is_none());
+    }
+
+    #[test]
+    fn ready() {
+        let ready = Ready {
+            op: Opcode::Ready,
+            resumed: false,
+            session_id: "session".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &ready,
+            &[
+                Token::Struct {
+                    name: "Ready",
+                    len: 3,
+                },
+                Token::Str("op"),
+                Token::UnitVariant {
+                    name: "Opcode",
+                    variant: "ready",
+                },
+                Token::Str("resumed"),
+                Token::Bool(false),
+                Token::Str("sessionId"),
+                Token::Str("session"),
+                Token::StructEnd,
+            ],
+        );
+
+        let event = serde_json::from_str::<IncomingEvent>(
+            r#"{"op":"ready","resumed":true,"sessionId":"session"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            IncomingEvent::Ready(Ready {
+                resumed: true,
+                ..ready
+            }),
+            event
+        );
+    }
+
+    #[test]
+    fn track_end_reason() {
+        serde_test::assert_tokens(&TrackEndReason::LoadFailed, &[Token::Str("LOAD_FAILED")]);
+
+        // Lavalink v4 sends camel cased reasons.
+        serde_test::assert_de_tokens(&TrackEndReason::LoadFailed, &[Token::Str("loadFailed")]);
+        serde_test::assert_tokens(
+            &TrackEndReason::Unknown("paused".to_owned()),
+            &[Token::Str("paused")],
+        );
+
+        assert!(TrackEndReason::Finished.may_start_next());
+        assert!(TrackEndReason::LoadFailed.may_start_next());
+        assert!(!TrackEndReason::Cleanup.may_start_next());
+        assert!(!TrackEndReason::Replaced.may_start_next());
+        assert!(!TrackEndReason::Stopped.may_start_next());
+        assert!(!TrackEndReason::Unknown("paused".to_owned()).may_start_next());
+    }
 }
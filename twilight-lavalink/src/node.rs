@@ -18,7 +18,10 @@
 //! [`Lavalink`]: crate::client::Lavalink
 
 use crate::{
-    model::{IncomingEvent, Opcode, OutgoingEvent, PlayerUpdate, Stats, StatsCpu, StatsMemory},
+    model::{
+        IncomingEvent, Opcode, OutgoingEvent, Play, PlayerUpdate, Ready, Stats, StatsCpu,
+        StatsMemory, TrackEnd, WebsocketClosed,
+    },
     player::PlayerManager,
 };
 use futures_util::{
@@ -26,18 +29,26 @@ use futures_util::{
     sink::SinkExt,
     stream::{Stream, StreamExt},
 };
-use http::header::{HeaderName, AUTHORIZATION};
+use http::header::{HeaderName, HeaderValue, AUTHORIZATION};
 use std::{
     error::Error,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     net::SocketAddr,
     pin::Pin,
+    sync::{
+        atomic::{AtomicI32, Ordering},
+        Arc, Mutex,
+    },
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::{
     net::TcpStream,
-    sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    sync::{
+        broadcast,
+        mpsc::{self, UnboundedReceiver, UnboundedSender},
+        watch,
+    },
     time as tokio_time,
 };
 use tokio_websockets::{
@@ -45,6 +56,12 @@ use tokio_websockets::{
 };
 use twilight_model::id::{marker::UserMarker, Id};
 
+/// Penalty score of a node that hasn't received stats yet.
+const PENALTY_UNKNOWN: i32 = i32::MIN;
+
+/// Number of status events buffered for each receiver.
+const STATUS_EVENTS_CAPACITY: usize = 16;
+
 /// An error occurred while either initializing a connection or while running
 /// its event loop.
 #[derive(Debug)]
@@ -230,12 +247,18 @@ pub struct NodeConfig {
     pub address: SocketAddr,
     /// The password to use when authenticating.
     pub authorization: String,
+    /// How to back off between attempts to (re)connect to the node.
+    pub backoff: Backoff,
     /// The details for resuming a Lavalink session, if any.
     ///
     /// Set this to `None` to disable resume capability.
     pub resume: Option<Resume>,
     /// The user ID of the bot.
     pub user_id: Id<UserMarker>,
+    /// What to do with a player when Discord closes its voice connection.
+    ///
+    /// The default is [`CleanupPolicy::Keep`].
+    pub voice_close: CleanupPolicy,
 }
 
 impl Debug for NodeConfig {
@@ -254,8 +277,10 @@ impl Debug for NodeConfig {
         f.debug_struct("NodeConfig")
             .field("address", &self.address)
             .field("authorization", &Redacted)
+            .field("backoff", &self.backoff)
             .field("resume", &self.resume)
             .field("user_id", &self.user_id)
+            .field("voice_close", &self.voice_close)
             .finish()
     }
 }
@@ -285,6 +310,144 @@ impl Default for Resume {
     }
 }
 
+/// Exponential backoff between attempts to (re)connect to a node.
+///
+/// The delay starts at [`initial`] and doubles after each failed attempt, up to
+/// [`maximum`].
+///
+/// [`initial`]: Self::initial
+/// [`maximum`]: Self::maximum
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Backoff {
+    /// Number of attempts to make before giving up, if limited.
+    ///
+    /// The default is 8.
+    pub attempts: Option<u32>,
+    /// Delay after the first failed attempt.
+    ///
+    /// The default is 1 second.
+    pub initial: Duration,
+    /// Maximum delay between attempts.
+    ///
+    /// The default is 64 seconds.
+    pub maximum: Duration,
+}
+
+impl Backoff {
+    /// Configure backoff, providing the initial and maximum delays between
+    /// attempts and the number of attempts to make before giving up.
+    ///
+    /// Pass `None` as the number of attempts to never give up.
+    pub const fn new(initial: Duration, maximum: Duration, attempts: Option<u32>) -> Self {
+        Self {
+            attempts,
+            initial,
+            maximum,
+        }
+    }
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1), Duration::from_secs(64), Some(8))
+    }
+}
+
+/// State of the connection to a node.
+///
+/// Retrieved via [`Node::connection_state`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ConnectionState {
+    /// Connected to the node.
+    Connected,
+    /// Connection was lost and is being re-established.
+    Reconnecting,
+    /// Connection was closed, either because the node was dropped or because
+    /// reconnecting failed.
+    Disconnected,
+}
+
+/// Snapshot of the status of a node.
+///
+/// Retrieved via [`Node::status`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct NodeStatus {
+    /// State of the connection to the node.
+    pub connection_state: ConnectionState,
+    /// Message of the last error of the connection, if any.
+    pub last_error: Option<String>,
+    /// Number of players linked to the node.
+    pub players: usize,
+    /// Time since the node last sent its stats, if it has.
+    pub stats_age: Option<Duration>,
+}
+
+/// Change of the status of the connection to a node.
+///
+/// Received via [`Node::status_events`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum NodeStatusEvent {
+    /// Connection was re-established after being lost.
+    Connected,
+    /// Connection was closed for good, because the node was dropped or
+    /// reconnecting failed.
+    ///
+    /// This is the last event of the node.
+    Disconnected {
+        /// Message of the error the connection was closed with, if any.
+        error: Option<String>,
+    },
+    /// Connection was lost and is being re-established.
+    Reconnecting {
+        /// Message of the error the connection was lost with, if any.
+        error: Option<String>,
+    },
+    /// Connection was re-established with resuming configured, after
+    /// [`Connected`].
+    ///
+    /// [`Connected`]: Self::Connected
+    Resumed {
+        /// Whether the previous session was resumed.
+        ///
+        /// If not, the voice connections and tracks of the node's players
+        /// are sent to the node again.
+        success: bool,
+    },
+}
+
+/// Status of a node shared with its connection.
+#[derive(Debug, Default)]
+struct StatusInner {
+    /// Message of the last error of the connection.
+    last_error: Option<String>,
+    /// ID of the session sent by the node when the connection is ready.
+    session_id: Option<String>,
+    /// When the node last sent its stats.
+    stats_received_at: Option<Instant>,
+}
+
+/// What to do with a player when Discord closes its voice connection.
+///
+/// Applies to [`WebsocketClosed`] events with a close code that ends the voice
+/// connection, such as when the bot is disconnected from the voice channel or
+/// the voice session is invalidated.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CleanupPolicy {
+    /// Destroy the player on the node and remove it from the
+    /// [`PlayerManager`].
+    Destroy,
+    /// Keep the player, marking it as [disconnected].
+    ///
+    /// [disconnected]: crate::player::Player::disconnected
+    #[default]
+    Keep,
+}
+
 impl NodeConfig {
     /// Create a new configuration for connecting to a node via
     /// [`Node::connect`].
@@ -302,7 +465,7 @@ impl NodeConfig {
         Self::_new(user_id, address.into(), authorization.into(), resume.into())
     }
 
-    const fn _new(
+    fn _new(
         user_id: Id<UserMarker>,
         address: SocketAddr,
         authorization: String,
@@ -311,8 +474,10 @@ impl NodeConfig {
         Self {
             address,
             authorization,
+            backoff: Backoff::default(),
             resume,
             user_id,
+            voice_close: CleanupPolicy::Keep,
         }
     }
 }
@@ -327,8 +492,14 @@ impl NodeConfig {
 pub struct Node {
     config: NodeConfig,
     lavalink_tx: UnboundedSender<OutgoingEvent>,
+    /// Penalty score calculated from the last received stats, or
+    /// [`PENALTY_UNKNOWN`] if none were received yet.
+    penalty: Arc<AtomicI32>,
     players: PlayerManager,
+    shared_status: Arc<Mutex<StatusInner>>,
+    state: watch::Receiver<ConnectionState>,
     stats: BiLock<Stats>,
+    status_tx: broadcast::Sender<NodeStatusEvent>,
 }
 
 impl Node {
@@ -381,8 +552,20 @@ impl Node {
 
         tracing::debug!("starting connection to {}", config.address);
 
-        let (conn_loop, lavalink_tx, lavalink_rx) =
-            Connection::connect(config.clone(), players.clone(), bilock_right).await?;
+        let penalty = Arc::new(AtomicI32::new(PENALTY_UNKNOWN));
+        let (state_tx, state) = watch::channel(ConnectionState::Connected);
+        let shared_status = Arc::new(Mutex::new(StatusInner::default()));
+        let (status_tx, _) = broadcast::channel(STATUS_EVENTS_CAPACITY);
+        let (conn_loop, lavalink_tx, lavalink_rx) = Connection::connect(
+            config.clone(),
+            Arc::clone(&penalty),
+            players.clone(),
+            Arc::clone(&shared_status),
+            state_tx,
+            bilock_right,
+            status_tx.clone(),
+        )
+        .await?;
 
         tracing::debug!("started connection to {}", config.address);
 
@@ -392,8 +575,12 @@ impl Node {
             Self {
                 config,
                 lavalink_tx,
+                penalty,
                 players,
+                shared_status,
+                state,
                 stats: bilock_left,
+                status_tx,
             },
             IncomingEvents { inner: lavalink_rx },
         ))
@@ -404,6 +591,16 @@ impl Node {
         &self.config
     }
 
+    /// Retrieve a receiver of the state of the connection to the node.
+    ///
+    /// When the connection is lost it's re-established, backing off as
+    /// configured by [`NodeConfig::backoff`]. If the session couldn't be
+    /// resumed the voice connections and tracks of the node's players are then
+    /// sent to the node again.
+    pub fn connection_state(&self) -> watch::Receiver<ConnectionState> {
+        self.state.clone()
+    }
+
     /// Retrieve an immutable reference to the player manager used by the node.
     pub const fn players(&self) -> &PlayerManager {
         &self.players
@@ -437,45 +634,179 @@ impl Node {
         (*self.stats.lock().await).clone()
     }
 
+    /// Retrieve a snapshot of the node's status.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn status(&self) -> NodeStatus {
+        let status = self.shared_status.lock().expect("status poisoned");
+
+        NodeStatus {
+            connection_state: *self.state.borrow(),
+            last_error: status.last_error.clone(),
+            players: self.player_count(),
+            stats_age: status
+                .stats_received_at
+                .map(|received_at| received_at.elapsed()),
+        }
+    }
+
+    /// Retrieve a receiver of changes of the status of the connection to the
+    /// node.
+    ///
+    /// Only changes after calling this are received. The events are separate
+    /// from the node's [`IncomingEvents`].
+    pub fn status_events(&self) -> broadcast::Receiver<NodeStatusEvent> {
+        self.status_tx.subscribe()
+    }
+
+    /// Retrieve the ID of the node's current session, if the node has sent
+    /// it.
+    ///
+    /// The ID is sent in a [`Ready`] event when connecting and is used to
+    /// resume the session when reconnecting.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn session_id(&self) -> Option<String> {
+        self.shared_status
+            .lock()
+            .expect("status poisoned")
+            .session_id
+            .clone()
+    }
+
+    /// Create a request configuring the node's current session to be
+    /// resumable for `timeout` after the connection is lost.
+    ///
+    /// The node keeps the session's players until the connection is
+    /// re-established, which resumes the session instead of re-sending the
+    /// voice connections and tracks of the players. The request has to be sent
+    /// with an HTTP client of your choice.
+    ///
+    /// Returns `None` if the node hasn't sent its [session ID] yet.
+    ///
+    /// [session ID]: Self::session_id
+    #[cfg(feature = "http-support")]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn configure_resume(&self, timeout: Duration) -> Option<http::Request<Vec<u8>>> {
+        let session_id = self.session_id()?;
+
+        Some(
+            crate::http::update_session(
+                self.config.address,
+                &self.config.authorization,
+                session_id,
+                true,
+                timeout,
+            )
+            .expect("authorization is a valid header value"),
+        )
+    }
+
     /// Retrieve the calculated penalty score of the node.
     ///
     /// This score can be used to calculate how loaded the server is. A higher
     /// number means it is more heavily loaded.
-    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    ///
+    /// The score is calculated from the number of playing players, the CPU
+    /// load, and the number of deficit and nulled audio frames, and is updated
+    /// whenever the node sends its stats. Until then, the score is the number
+    /// of players linked to the node.
+    pub fn current_penalty(&self) -> i32 {
+        let penalty = self.penalty.load(Ordering::Relaxed);
+
+        if penalty != PENALTY_UNKNOWN {
+            return penalty;
+        }
+
+        i32::try_from(self.player_count()).unwrap_or(i32::MAX)
+    }
+
+    /// Retrieve the calculated penalty score of the node.
+    #[allow(clippy::unused_async)]
+    #[deprecated(note = "use current_penalty instead")]
     pub async fn penalty(&self) -> i32 {
-        let stats = self.stats.lock().await;
-        let cpu = 1.05f64.powf(100f64 * stats.cpu.system_load) * 10f64 - 10f64;
-
-        let (deficit_frame, null_frame) = (
-            1.03f64
-                .powf(500f64 * (stats.frames.as_ref().map_or(0, |f| f.deficit) as f64 / 3000f64))
-                * 300f64
-                - 300f64,
-            (1.03f64
-                .powf(500f64 * (stats.frames.as_ref().map_or(0, |f| f.nulled) as f64 / 3000f64))
-                * 300f64
-                - 300f64)
-                * 2f64,
-        );
+        self.current_penalty()
+    }
 
-        stats.playing_players as i32 + cpu as i32 + deficit_frame as i32 + null_frame as i32
+    /// Number of players linked to the node.
+    fn player_count(&self) -> usize {
+        self.players
+            .players
+            .iter()
+            .filter(|player| player.node().config().address == self.config.address)
+            .count()
+    }
+}
+
+#[cfg(test)]
+impl Node {
+    /// Create a node that isn't connected, returning the receiver of the
+    /// node's events.
+    pub(crate) fn unconnected(
+        address: SocketAddr,
+        players: PlayerManager,
+    ) -> (Self, UnboundedReceiver<OutgoingEvent>) {
+        let (lavalink_tx, rx) = mpsc::unbounded_channel();
+        let (stats, _) = BiLock::new(Stats {
+            cpu: StatsCpu {
+                cores: 0,
+                lavalink_load: 0f64,
+                system_load: 0f64,
+            },
+            frames: None,
+            memory: StatsMemory {
+                allocated: 0,
+                free: 0,
+                used: 0,
+                reservable: 0,
+            },
+            players: 0,
+            playing_players: 0,
+            op: Opcode::Stats,
+            uptime: 0,
+        });
+        let node = Self {
+            config: NodeConfig::new(Id::new(2), address, "", None),
+            lavalink_tx,
+            penalty: Arc::new(AtomicI32::new(PENALTY_UNKNOWN)),
+            players,
+            shared_status: Arc::new(Mutex::new(StatusInner::default())),
+            state: watch::channel(ConnectionState::Connected).1,
+            stats,
+            status_tx: broadcast::channel(STATUS_EVENTS_CAPACITY).0,
+        };
+
+        (node, rx)
+    }
+
+    /// Update the penalty score as if the node sent its stats.
+    pub(crate) fn receive_stats(&self, stats: &Stats) {
+        self.penalty.store(penalty(stats), Ordering::Relaxed);
     }
 }
 
 struct Connection {
     config: NodeConfig,
+    /// Message of the error the event loop ended with, if any.
+    error: Option<String>,
+    penalty: Arc<AtomicI32>,
     stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
     node_from: UnboundedReceiver<OutgoingEvent>,
     node_to: UnboundedSender<IncomingEvent>,
     players: PlayerManager,
+    shared_status: Arc<Mutex<StatusInner>>,
+    state: watch::Sender<ConnectionState>,
     stats: BiLock<Stats>,
+    status_tx: broadcast::Sender<NodeStatusEvent>,
 }
 
 impl Connection {
     async fn connect(
         config: NodeConfig,
+        penalty: Arc<AtomicI32>,
         players: PlayerManager,
+        shared_status: Arc<Mutex<StatusInner>>,
+        state_tx: watch::Sender<ConnectionState>,
         stats: BiLock<Stats>,
+        status_tx: broadcast::Sender<NodeStatusEvent>,
     ) -> Result<
         (
             Self,
@@ -484,7 +815,7 @@ impl Connection {
         ),
         NodeError,
     > {
-        let stream = reconnect(&config).await?;
+        let (stream, _) = reconnect(&config, None).await?;
 
         let (to_node, from_lavalink) = mpsc::unbounded_channel();
         let (to_lavalink, from_node) = mpsc::unbounded_channel();
@@ -492,26 +823,49 @@ impl Connection {
         Ok((
             Self {
                 config,
+                error: None,
+                penalty,
                 stream,
                 node_from: from_node,
                 node_to: to_node,
                 players,
+                shared_status,
+                state: state_tx,
                 stats,
+                status_tx,
             },
             to_lavalink,
             from_lavalink,
         ))
     }
 
-    async fn run(mut self) -> Result<(), NodeError> {
+    async fn run(mut self) {
+        if let Err(source) = self.event_loop().await {
+            tracing::warn!(
+                "connection to node {} failed: {source}",
+                self.config.address
+            );
+
+            self.error = Some(source.to_string());
+        }
+    }
+
+    async fn event_loop(&mut self) -> Result<(), NodeError> {
         loop {
             tokio::select! {
                 incoming = self.stream.next() => {
-                    if let Some(Ok(incoming)) = incoming {
-                        self.incoming(incoming).await?;
-                    } else {
-                        tracing::debug!("connection to {} closed, reconnecting", self.config.address);
-                        self.stream = reconnect(&self.config).await?;
+                    match incoming {
+                        Some(Ok(incoming)) => {
+                            self.incoming(incoming).await?;
+                        }
+                        Some(Err(source)) => {
+                            tracing::debug!("connection to {} failed, reconnecting", self.config.address);
+                            self.reestablish(Some(source.to_string())).await?;
+                        }
+                        None => {
+                            tracing::debug!("connection to {} closed, reconnecting", self.config.address);
+                            self.reestablish(None).await?;
+                        }
                     }
                 }
                 outgoing = self.node_from.recv() => {
@@ -521,12 +875,9 @@ impl Connection {
                             self.config.address,
                         );
 
-                        let payload = serde_json::to_string(&outgoing).map_err(|source| NodeError {
-                            kind: NodeErrorType::SerializingMessage { message: outgoing },
-                            source: Some(Box::new(source)),
-                        })?;
-                        let msg = Message::text(payload);
-                        self.stream.send(msg).await.unwrap();
+                        if let Some(source) = self.send(outgoing).await? {
+                            self.reestablish(Some(source.to_string())).await?;
+                        }
                     } else {
                         tracing::debug!("node {} closed, ending connection", self.config.address);
 
@@ -539,6 +890,89 @@ impl Connection {
         Ok(())
     }
 
+    /// Serialize and send an event to the node, returning the error it
+    /// couldn't be sent with, if any.
+    async fn send(&mut self, event: OutgoingEvent) -> Result<Option<WebsocketError>, NodeError> {
+        let payload = serde_json::to_string(&event).map_err(|source| NodeError {
+            kind: NodeErrorType::SerializingMessage { message: event },
+            source: Some(Box::new(source)),
+        })?;
+
+        if let Err(source) = self.stream.send(Message::text(payload)).await {
+            tracing::warn!("failed to send to node {}: {source}", self.config.address);
+
+            return Ok(Some(source));
+        }
+
+        Ok(None)
+    }
+
+    /// Record the error the connection was lost or closed with, if any.
+    fn record_error(&self, error: Option<&String>) {
+        if let Some(error) = error {
+            self.shared_status
+                .lock()
+                .expect("status poisoned")
+                .last_error = Some(error.clone());
+        }
+    }
+
+    /// Reconnect to the node after the connection was lost with an error, if
+    /// any, re-syncing players if the session wasn't resumed.
+    async fn reestablish(&mut self, error: Option<String>) -> Result<(), NodeError> {
+        self.record_error(error.as_ref());
+        self.state.send_replace(ConnectionState::Reconnecting);
+        // It's fine if there are no receivers.
+        let _result = self.status_tx.send(NodeStatusEvent::Reconnecting { error });
+
+        let session_id = self
+            .shared_status
+            .lock()
+            .expect("status poisoned")
+            .session_id
+            .clone();
+        let (stream, resumed) = reconnect(&self.config, session_id.as_deref()).await?;
+        self.stream = stream;
+        self.state.send_replace(ConnectionState::Connected);
+        let _result = self.status_tx.send(NodeStatusEvent::Connected);
+
+        if self.config.resume.is_some() {
+            let _result = self
+                .status_tx
+                .send(NodeStatusEvent::Resumed { success: resumed });
+        }
+
+        if !resumed {
+            self.resync().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Send the voice connections and tracks of the node's players to the new
+    /// session.
+    async fn resync(&mut self) -> Result<(), NodeError> {
+        let events = self
+            .players
+            .players
+            .iter()
+            .filter(|player| player.node().config().address == self.config.address)
+            .flat_map(|player| player.sync_events())
+            .collect::<Vec<_>>();
+
+        for event in events {
+            tracing::debug!("re-syncing player on {}: {event:?}", self.config.address);
+
+            // The connection was lost again, which is picked up by the event
+            // loop.
+            if self.send(event).await?.is_some() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
     async fn incoming(&mut self, incoming: Message) -> Result<bool, NodeError> {
         tracing::debug!(
             "received message from {}: {incoming:?}",
@@ -565,7 +999,12 @@ impl Connection {
 
         match &event {
             IncomingEvent::PlayerUpdate(update) => self.player_update(update)?,
+            IncomingEvent::Ready(ready) => self.ready(ready),
             IncomingEvent::Stats(stats) => self.stats(stats).await?,
+            IncomingEvent::TrackEnd(end) => track_end(&self.players, end),
+            IncomingEvent::WeboscketClosed(closed) => {
+                websocket_closed(&self.players, self.config.voice_close, closed);
+            }
             _ => {}
         }
 
@@ -594,7 +1033,26 @@ impl Connection {
         Ok(())
     }
 
+    fn ready(&self, ready: &Ready) {
+        tracing::debug!(
+            "session {} of node {} is ready, resumed: {}",
+            ready.session_id,
+            self.config.address,
+            ready.resumed,
+        );
+
+        self.shared_status
+            .lock()
+            .expect("status poisoned")
+            .session_id = Some(ready.session_id.clone());
+    }
+
     async fn stats(&self, stats: &Stats) -> Result<(), NodeError> {
+        self.penalty.store(penalty(stats), Ordering::Relaxed);
+        self.shared_status
+            .lock()
+            .expect("status poisoned")
+            .stats_received_at = Some(Instant::now());
         *self.stats.lock().await = stats.clone();
 
         Ok(())
@@ -603,6 +1061,11 @@ impl Connection {
 
 impl Drop for Connection {
     fn drop(&mut self) {
+        self.state.send_replace(ConnectionState::Disconnected);
+        let error = self.error.take();
+        self.record_error(error.as_ref());
+        let _result = self.status_tx.send(NodeStatusEvent::Disconnected { error });
+
         // Cleanup local players associated with the node
         self.players
             .players
@@ -610,7 +1073,82 @@ impl Drop for Connection {
     }
 }
 
-fn connect_request(state: &NodeConfig) -> Result<ClientBuilder, NodeError> {
+/// Apply the cleanup policy to the player whose voice connection was closed.
+fn websocket_closed(players: &PlayerManager, policy: CleanupPolicy, event: &WebsocketClosed) {
+    // Close codes after which the voice connection can't be resumed.
+    //
+    // <https://discord.com/developers/docs/topics/opcodes-and-status-codes#voice-voice-close-event-codes>
+    if !matches!(event.code, 4004 | 4006 | 4009 | 4011 | 4012 | 4014 | 4016) {
+        return;
+    }
+
+    let Some(player) = players.get(&event.guild_id) else {
+        return;
+    };
+
+    tracing::debug!(
+        "voice connection for guild {} closed with code {}, applying {policy:?}",
+        event.guild_id,
+        event.code,
+    );
+
+    match policy {
+        CleanupPolicy::Destroy => {
+            if let Err(source) = players.destroy(event.guild_id) {
+                tracing::warn!(
+                    "failed to destroy player for guild {}: {source}",
+                    event.guild_id
+                );
+
+                players.players.remove(&event.guild_id);
+            }
+        }
+        CleanupPolicy::Keep => player.set_disconnected(true),
+    }
+}
+
+/// Calculate the penalty score of a node from its stats.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+fn penalty(stats: &Stats) -> i32 {
+    let cpu = 1.05f64.powf(100f64 * stats.cpu.system_load) * 10f64 - 10f64;
+
+    let (deficit_frame, null_frame) = (
+        1.03f64.powf(500f64 * (stats.frames.as_ref().map_or(0, |f| f.deficit) as f64 / 3000f64))
+            * 300f64
+            - 300f64,
+        (1.03f64.powf(500f64 * (stats.frames.as_ref().map_or(0, |f| f.nulled) as f64 / 3000f64))
+            * 300f64
+            - 300f64)
+            * 2f64,
+    );
+
+    stats.playing_players as i32 + cpu as i32 + deficit_frame as i32 + null_frame as i32
+}
+
+/// Clear the player's ended track and play its next queued track, if any.
+fn track_end(players: &PlayerManager, event: &TrackEnd) {
+    let Some(player) = players.get(&event.guild_id) else {
+        return;
+    };
+
+    let Some(track) = player.track_ended(event) else {
+        return;
+    };
+
+    tracing::debug!("playing next queued track for guild {}", event.guild_id);
+
+    if let Err(source) = player.send(Play::from((event.guild_id, track))) {
+        tracing::warn!(
+            "failed to play next queued track for guild {}: {source}",
+            event.guild_id
+        );
+    }
+}
+
+fn connect_request(
+    state: &NodeConfig,
+    session_id: Option<&str>,
+) -> Result<ClientBuilder<'static>, NodeError> {
     let mut builder = ClientBuilder::new()
         .uri(&format!("ws://{}", state.address))
         .map_err(|source| NodeError {
@@ -634,42 +1172,67 @@ fn connect_request(state: &NodeConfig) -> Result<ClientBuilder, NodeError> {
             .expect("allowed header");
     }
 
+    if let Some(session_id) = session_id {
+        let value = HeaderValue::from_str(session_id).map_err(|source| NodeError {
+            kind: NodeErrorType::BuildingConnectionRequest,
+            source: Some(Box::new(source)),
+        })?;
+
+        builder = builder
+            .add_header(HeaderName::from_static("session-id"), value)
+            .expect("allowed header");
+    }
+
     Ok(builder)
 }
 
+/// Connect to the node, resuming the session with the ID if any, and return
+/// whether the previous session was resumed.
 async fn reconnect(
     config: &NodeConfig,
-) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, NodeError> {
-    let (mut stream, res) = backoff(config).await?;
+    session_id: Option<&str>,
+) -> Result<(WebSocketStream<MaybeTlsStream<TcpStream>>, bool), NodeError> {
+    let (mut stream, res) = backoff(config, session_id).await?;
 
-    let headers = res.headers();
+    let Some(resume) = config.resume.as_ref() else {
+        return Ok((stream, false));
+    };
 
-    if let Some(resume) = config.resume.as_ref() {
-        let header = HeaderName::from_static("session-resumed");
+    let header = HeaderName::from_static("session-resumed");
 
-        if let Some(value) = headers.get(header) {
-            if value.as_bytes() == b"false" {
-                tracing::debug!("session to node {} didn't resume", config.address);
+    if res
+        .headers()
+        .get(header)
+        .is_some_and(|value| value.as_bytes() == b"true")
+    {
+        tracing::debug!("session to {} resumed", config.address);
 
-                let payload = serde_json::json!({
-                    "op": "configureResuming",
-                    "key": config.address,
-                    "timeout": resume.timeout,
-                });
-                let msg = Message::text(serde_json::to_string(&payload).unwrap());
+        return Ok((stream, true));
+    }
 
-                stream.send(msg).await.unwrap();
-            } else {
-                tracing::debug!("session to {} resumed", config.address);
-            }
-        }
+    tracing::debug!("session to node {} didn't resume", config.address);
+
+    let payload = serde_json::json!({
+        "op": "configureResuming",
+        "key": config.address,
+        "timeout": resume.timeout,
+    });
+    let msg = Message::text(serde_json::to_string(&payload).unwrap());
+
+    // A failure is picked up by the event loop, which reconnects again.
+    if let Err(source) = stream.send(msg).await {
+        tracing::warn!(
+            "failed to configure resuming on node {}: {source}",
+            config.address
+        );
     }
 
-    Ok(stream)
+    Ok((stream, false))
 }
 
 async fn backoff(
     config: &NodeConfig,
+    session_id: Option<&str>,
 ) -> Result<
     (
         WebSocketStream<MaybeTlsStream<TcpStream>>,
@@ -677,10 +1240,13 @@ async fn backoff(
     ),
     NodeError,
 > {
-    let mut seconds = 1;
+    let mut attempts = 0;
+    let mut delay = config.backoff.initial;
 
     loop {
-        let request = connect_request(config)?;
+        attempts += 1;
+
+        let request = connect_request(config, session_id)?;
 
         match request.connect().await {
             Ok((stream, response)) => return Ok((stream, response)),
@@ -700,7 +1266,11 @@ async fn backoff(
                     });
                 }
 
-                if seconds > 64 {
+                if config
+                    .backoff
+                    .attempts
+                    .is_some_and(|maximum| attempts >= maximum)
+                {
                     tracing::debug!("no longer trying to connect to node {}", config.address);
 
                     return Err(NodeError {
@@ -710,12 +1280,12 @@ async fn backoff(
                 }
 
                 tracing::debug!(
-                    "waiting {seconds} seconds before attempting to connect to node {} again",
+                    "waiting {delay:?} before attempting to connect to node {} again",
                     config.address,
                 );
-                tokio_time::sleep(Duration::from_secs(seconds)).await;
+                tokio_time::sleep(delay).await;
 
-                seconds *= 2;
+                delay = delay.saturating_mul(2).min(config.backoff.maximum);
 
                 continue;
             }
@@ -725,16 +1295,45 @@ async fn backoff(
 
 #[cfg(test)]
 mod tests {
-    use super::{Node, NodeConfig, NodeError, NodeErrorType, Resume};
+    use super::{
+        Backoff, CleanupPolicy, ConnectionState, Node, NodeConfig, NodeError, NodeErrorType,
+        NodeStatus, NodeStatusEvent, Resume,
+    };
+    use crate::{
+        model::{
+            Destroy, IncomingEvent, Opcode, OutgoingEvent, Play, Ready, Stats, StatsCpu,
+            StatsFrames, StatsMemory, TrackEnd, TrackEndReason, TrackEventType, VoiceUpdate,
+            WebsocketClosed,
+        },
+        player::PlayerManager,
+    };
+    use futures_util::{SinkExt, StreamExt};
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{
         error::Error,
         fmt::Debug,
         net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+        sync::Arc,
+        time::Duration,
+    };
+    use tokio::{
+        net::{TcpListener, TcpStream},
+        sync::{mpsc::UnboundedReceiver, oneshot},
+        time,
+    };
+    use tokio_websockets::{Message, ServerBuilder, WebSocketStream};
+    use twilight_model::{
+        gateway::payload::incoming::VoiceServerUpdate,
+        id::{marker::GuildMarker, Id},
     };
-    use twilight_model::id::Id;
 
-    assert_fields!(NodeConfig: address, authorization, resume, user_id);
+    assert_fields!(Backoff: attempts, initial, maximum);
+    assert_impl_all!(Backoff: Clone, Copy, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(CleanupPolicy: Clone, Copy, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ConnectionState: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(NodeStatus: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(NodeStatusEvent: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_fields!(NodeConfig: address, authorization, backoff, resume, user_id, voice_close);
     assert_impl_all!(NodeConfig: Clone, Debug, Send, Sync);
     assert_fields!(NodeErrorType::SerializingMessage: message);
     assert_fields!(NodeErrorType::Unauthorized: address, authorization);
@@ -749,10 +1348,444 @@ mod tests {
         let config = NodeConfig {
             address: SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 1312)),
             authorization: "some auth".to_owned(),
+            backoff: Backoff::default(),
             resume: None,
             user_id: Id::new(123),
+            voice_close: CleanupPolicy::Keep,
         };
 
         assert!(format!("{config:?}").contains("authorization: <redacted>"));
     }
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+
+    /// Create a player manager with a player for [`GUILD_ID`] linked to a node
+    /// that isn't connected, returning the receiver of the node's events.
+    fn players() -> (PlayerManager, UnboundedReceiver<OutgoingEvent>) {
+        let players = PlayerManager::new();
+        let (node, rx) = Node::unconnected(
+            SocketAddrV4::new(Ipv4Addr::LOCALHOST, 2333).into(),
+            players.clone(),
+        );
+        players.get_or_insert(GUILD_ID, Arc::new(node));
+
+        (players, rx)
+    }
+
+    fn closed(code: u64) -> WebsocketClosed {
+        WebsocketClosed {
+            guild_id: GUILD_ID,
+            kind: TrackEventType::WebsocketClosed,
+            op: Opcode::Event,
+            code,
+            by_remote: true,
+            reason: String::new(),
+        }
+    }
+
+    #[test]
+    fn penalty() {
+        let (players, _rx) = players();
+        let node = players.get(&GUILD_ID).unwrap().node().clone();
+
+        // Nodes without stats are ranked by their number of players.
+        assert_eq!(1, node.current_penalty());
+
+        let mut stats = Stats {
+            cpu: StatsCpu {
+                cores: 4,
+                lavalink_load: 0.1,
+                system_load: 0.0,
+            },
+            frames: None,
+            memory: StatsMemory {
+                allocated: 0,
+                free: 0,
+                used: 0,
+                reservable: 0,
+            },
+            players: 10,
+            playing_players: 5,
+            op: Opcode::Stats,
+            uptime: 1000,
+        };
+        node.receive_stats(&stats);
+        assert_eq!(5, node.current_penalty());
+
+        // 1.05^50 * 10 - 10
+        stats.cpu.system_load = 0.5;
+        node.receive_stats(&stats);
+        assert_eq!(5 + 104, node.current_penalty());
+
+        // 1.03^500 * 300 - 300, twice as much for nulled frames
+        stats.frames = Some(StatsFrames {
+            deficit: 3000,
+            nulled: 0,
+            sent: 0,
+        });
+        node.receive_stats(&stats);
+        assert_eq!(5 + 104 + 786_562_870, node.current_penalty());
+    }
+
+    fn ended(reason: TrackEndReason) -> TrackEnd {
+        TrackEnd {
+            guild_id: GUILD_ID,
+            kind: TrackEventType::End,
+            op: Opcode::Event,
+            reason,
+            track: "a".to_owned(),
+        }
+    }
+
+    #[test]
+    fn track_end_finished() {
+        let (players, mut rx) = players();
+        let player = players.get(&GUILD_ID).unwrap();
+
+        player.queue().push("a").unwrap();
+        player.queue().push("b").unwrap();
+        assert!(matches!(rx.try_recv(), Ok(OutgoingEvent::Play(play)) if play.track == "a"));
+        assert!(rx.try_recv().is_err());
+
+        super::track_end(&players, &ended(TrackEndReason::Finished));
+        assert!(matches!(rx.try_recv(), Ok(OutgoingEvent::Play(play)) if play.track == "b"));
+        assert_eq!(Some("b"), player.track().as_deref());
+        assert!(player.queue().is_empty());
+
+        // A duplicate event for a track that's no longer playing is ignored.
+        super::track_end(&players, &ended(TrackEndReason::Finished));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn track_end_load_failed() {
+        let (players, mut rx) = players();
+        let player = players.get(&GUILD_ID).unwrap();
+
+        player.send(Play::from((GUILD_ID, "a"))).unwrap();
+        player.queue().push("b").unwrap();
+        rx.try_recv().unwrap();
+
+        super::track_end(&players, &ended(TrackEndReason::LoadFailed));
+        assert!(matches!(rx.try_recv(), Ok(OutgoingEvent::Play(play)) if play.track == "b"));
+    }
+
+    /// Tracks ended on purpose neither advance the queue nor, if replaced,
+    /// clear the track playing in their place.
+    #[test]
+    fn track_end_on_purpose() {
+        for reason in [
+            TrackEndReason::Cleanup,
+            TrackEndReason::Replaced,
+            TrackEndReason::Stopped,
+        ] {
+            let (players, mut rx) = players();
+            let player = players.get(&GUILD_ID).unwrap();
+
+            player.send(Play::from((GUILD_ID, "a"))).unwrap();
+            player.queue().push("b").unwrap();
+            rx.try_recv().unwrap();
+
+            super::track_end(&players, &ended(reason.clone()));
+            assert!(rx.try_recv().is_err());
+            assert_eq!(["b"].as_slice(), player.queue().tracks());
+            assert_eq!(reason == TrackEndReason::Replaced, player.track().is_some());
+        }
+    }
+
+    /// Skipping replaces the current track, whose replaced event doesn't
+    /// advance the queue a second time.
+    #[test]
+    fn track_end_skip() {
+        let (players, mut rx) = players();
+        let player = players.get(&GUILD_ID).unwrap();
+
+        for track in ["a", "b", "c"] {
+            player.queue().push(track).unwrap();
+        }
+        rx.try_recv().unwrap();
+
+        assert_eq!(Some("b"), player.queue().skip().unwrap().as_deref());
+        assert!(matches!(rx.try_recv(), Ok(OutgoingEvent::Play(play)) if play.track == "b"));
+
+        super::track_end(&players, &ended(TrackEndReason::Replaced));
+        assert!(rx.try_recv().is_err());
+        assert_eq!(Some("b"), player.track().as_deref());
+        assert_eq!(["c"].as_slice(), player.queue().tracks());
+    }
+
+    /// Connect a node with a player for [`GUILD_ID`] and receive a
+    /// [`WebsocketClosed`] event with the close code, returning the player
+    /// manager and the node's connection to the mock server.
+    async fn websocket_closed(
+        voice_close: CleanupPolicy,
+        code: u64,
+    ) -> (PlayerManager, WebSocketStream<TcpStream>) {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let mut config = NodeConfig::new(Id::new(2), listener.local_addr().unwrap(), "", None);
+        config.voice_close = voice_close;
+        let players = PlayerManager::new();
+
+        let (mut stream, node) = tokio::join!(
+            async {
+                let (stream, _) = listener.accept().await.unwrap();
+
+                ServerBuilder::new().accept(stream).await.unwrap().1
+            },
+            Node::connect(config, players.clone())
+        );
+        let (node, mut events) = node.unwrap();
+        players.get_or_insert(GUILD_ID, Arc::new(node));
+
+        let payload = serde_json::to_string(&closed(code)).unwrap();
+        stream.send(Message::text(payload)).await.unwrap();
+
+        // Events are only forwarded once they've been handled.
+        assert!(matches!(
+            events.next().await,
+            Some(IncomingEvent::WeboscketClosed(_))
+        ));
+
+        (players, stream)
+    }
+
+    /// Assert that the node didn't send anything to the mock server.
+    async fn assert_nothing_sent(stream: &mut WebSocketStream<TcpStream>) {
+        assert!(time::timeout(Duration::from_millis(50), stream.next())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn websocket_closed_destroy() {
+        let (players, mut stream) = websocket_closed(CleanupPolicy::Destroy, 4014).await;
+
+        assert!(players.get(&GUILD_ID).is_none());
+        let message = stream.next().await.unwrap().unwrap();
+        let destroy = serde_json::from_str::<Destroy>(message.as_text().unwrap()).unwrap();
+        assert_eq!(GUILD_ID, destroy.guild_id);
+    }
+
+    #[tokio::test]
+    async fn websocket_closed_keep() {
+        let (players, mut stream) = websocket_closed(CleanupPolicy::Keep, 4014).await;
+
+        assert!(players.get(&GUILD_ID).unwrap().disconnected());
+        assert_nothing_sent(&mut stream).await;
+    }
+
+    #[tokio::test]
+    async fn websocket_closed_resumable() {
+        let (players, mut stream) = websocket_closed(CleanupPolicy::Destroy, 4015).await;
+
+        assert!(!players.get(&GUILD_ID).unwrap().disconnected());
+        assert_nothing_sent(&mut stream).await;
+    }
+
+    #[tokio::test]
+    async fn reconnect_resync() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        // Receive the voice update, drop the connection, and receive the
+        // voice update again on the new connection.
+        let server = tokio::spawn(async move {
+            let mut messages = Vec::new();
+
+            for _ in 0..2 {
+                let (stream, _) = listener.accept().await.unwrap();
+                let (_, mut stream) = ServerBuilder::new().accept(stream).await.unwrap();
+                let message = stream.next().await.unwrap().unwrap();
+                messages.push(message.as_text().unwrap().to_owned());
+            }
+
+            messages
+        });
+
+        let mut config = NodeConfig::new(Id::new(2), address, "", None);
+        config.backoff = Backoff::new(Duration::from_millis(10), Duration::from_millis(10), None);
+        let players = PlayerManager::new();
+        let (node, _events) = Node::connect(config, players.clone()).await.unwrap();
+        let mut state = node.connection_state();
+        assert_eq!(ConnectionState::Connected, *state.borrow_and_update());
+
+        let voice_update = VoiceUpdate::new(
+            GUILD_ID,
+            "session",
+            VoiceServerUpdate {
+                endpoint: Some("endpoint".to_owned()),
+                guild_id: GUILD_ID,
+                token: "token".to_owned(),
+            },
+        );
+        let player = players.get_or_insert(GUILD_ID, Arc::new(node));
+        player.send(voice_update.clone()).unwrap();
+
+        let messages = server.await.unwrap();
+        assert_eq!(2, messages.len());
+
+        for message in messages {
+            assert_eq!(
+                voice_update,
+                serde_json::from_str::<VoiceUpdate>(&message).unwrap()
+            );
+        }
+
+        assert!(state.has_changed().unwrap());
+        assert_eq!(ConnectionState::Connected, *state.borrow());
+    }
+
+    #[tokio::test]
+    async fn status_events() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (ready_tx, ready_rx) = oneshot::channel::<()>();
+
+        // Send stats and close the connection once the test is subscribed,
+        // then accept the reconnection.
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (_, mut stream) = ServerBuilder::new().accept(stream).await.unwrap();
+            ready_rx.await.unwrap();
+
+            let stats = Stats {
+                cpu: StatsCpu {
+                    cores: 4,
+                    lavalink_load: 0.1,
+                    system_load: 0.0,
+                },
+                frames: None,
+                memory: StatsMemory {
+                    allocated: 0,
+                    free: 0,
+                    used: 0,
+                    reservable: 0,
+                },
+                players: 0,
+                playing_players: 0,
+                op: Opcode::Stats,
+                uptime: 1000,
+            };
+            let payload = serde_json::to_string(&stats).unwrap();
+            stream.send(Message::text(payload)).await.unwrap();
+            stream.close().await.unwrap();
+
+            let (stream, _) = listener.accept().await.unwrap();
+            let (_, stream) = ServerBuilder::new().accept(stream).await.unwrap();
+
+            (listener, stream)
+        });
+
+        let mut config = NodeConfig::new(Id::new(2), address, "", Resume::new(60));
+        config.backoff = Backoff::new(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            Some(1),
+        );
+        let (node, _events) = Node::connect(config, PlayerManager::new()).await.unwrap();
+        let mut events = node.status_events();
+        assert_eq!(
+            NodeStatus {
+                connection_state: ConnectionState::Connected,
+                last_error: None,
+                players: 0,
+                stats_age: None,
+            },
+            node.status()
+        );
+        ready_tx.send(()).unwrap();
+
+        // A clean close isn't an error, and the node doesn't return a
+        // `Session-Resumed` header.
+        assert_eq!(
+            NodeStatusEvent::Reconnecting { error: None },
+            events.recv().await.unwrap()
+        );
+        assert_eq!(NodeStatusEvent::Connected, events.recv().await.unwrap());
+        assert_eq!(
+            NodeStatusEvent::Resumed { success: false },
+            events.recv().await.unwrap()
+        );
+
+        let status = node.status();
+        assert_eq!(ConnectionState::Connected, status.connection_state);
+        assert!(status.last_error.is_none());
+        assert!(status.stats_age.is_some());
+
+        // Reconnecting fails once the node is gone, closing the connection
+        // with the error.
+        let (listener, mut stream) = server.await.unwrap();
+        drop(listener);
+        stream.close().await.unwrap();
+
+        assert_eq!(
+            NodeStatusEvent::Reconnecting { error: None },
+            events.recv().await.unwrap()
+        );
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            NodeStatusEvent::Disconnected { error: Some(_) }
+        ));
+        // The connection is only closed once.
+        assert!(events.try_recv().is_err());
+
+        let status = node.status();
+        assert_eq!(ConnectionState::Disconnected, status.connection_state);
+        assert!(status.last_error.is_some());
+    }
+
+    #[tokio::test]
+    async fn session_resume() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        // Send the session ID and close the connection, returning the session
+        // ID header of the reconnection.
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (request, mut stream) = ServerBuilder::new().accept(stream).await.unwrap();
+            assert!(request.headers().get("session-id").is_none());
+
+            let ready = r#"{"op":"ready","resumed":false,"sessionId":"session"}"#;
+            stream.send(Message::text(ready)).await.unwrap();
+            stream.close().await.unwrap();
+
+            let (stream, _) = listener.accept().await.unwrap();
+            let (request, _) = ServerBuilder::new().accept(stream).await.unwrap();
+
+            request.headers().get("session-id").cloned()
+        });
+
+        let mut config = NodeConfig::new(Id::new(2), address, "", Resume::new(60));
+        config.backoff = Backoff::new(Duration::from_millis(10), Duration::from_millis(10), None);
+        let (node, mut events) = Node::connect(config, PlayerManager::new()).await.unwrap();
+        assert!(node.session_id().is_none());
+        #[cfg(feature = "http-support")]
+        assert!(node.configure_resume(Duration::from_secs(60)).is_none());
+
+        assert_eq!(
+            Some(IncomingEvent::Ready(Ready {
+                op: Opcode::Ready,
+                resumed: false,
+                session_id: "session".to_owned(),
+            })),
+            events.next().await
+        );
+        assert_eq!(Some("session"), node.session_id().as_deref());
+
+        #[cfg(feature = "http-support")]
+        {
+            let request = node.configure_resume(Duration::from_secs(60)).unwrap();
+            assert_eq!(
+                format!("http://{address}/v4/sessions/session"),
+                request.uri().to_string()
+            );
+        }
+
+        let header = server.await.unwrap();
+        assert_eq!(
+            Some("session"),
+            header.as_ref().map(|value| value.to_str().unwrap())
+        );
+    }
 }
@@ -10,15 +10,18 @@
 //! [read the position]: Player::position
 
 use crate::{
-    model::{Destroy, OutgoingEvent},
+    model::{
+        Destroy, Filters, OutgoingEvent, Pause, Play, TrackEnd, TrackEndReason, VoiceUpdate, Volume,
+    },
     node::{Node, NodeSenderError},
+    queue::{Queue, QueueState},
 };
 use dashmap::DashMap;
 use std::{
     fmt::Debug,
     sync::{
         atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
-        Arc,
+        Arc, Mutex, MutexGuard, PoisonError,
     },
 };
 use twilight_model::id::{
@@ -84,11 +87,16 @@ impl PlayerManager {
 #[derive(Debug)]
 pub struct Player {
     channel_id: AtomicU64,
+    disconnected: AtomicBool,
+    filters: Mutex<Option<Filters>>,
     guild_id: Id<GuildMarker>,
     node: Arc<Node>,
     paused: AtomicBool,
     position: AtomicI64,
+    queue: Mutex<QueueState>,
     time: AtomicI64,
+    track: Mutex<Option<String>>,
+    voice_update: Mutex<Option<VoiceUpdate>>,
     volume: AtomicI64,
 }
 
@@ -96,11 +104,16 @@ impl Player {
     pub(crate) const fn new(guild_id: Id<GuildMarker>, node: Arc<Node>) -> Self {
         Self {
             channel_id: AtomicU64::new(0),
+            disconnected: AtomicBool::new(false),
+            filters: Mutex::new(None),
             guild_id,
             node,
             paused: AtomicBool::new(false),
             position: AtomicI64::new(0),
+            queue: Mutex::new(QueueState::new()),
             time: AtomicI64::new(0),
+            track: Mutex::new(None),
+            voice_update: Mutex::new(None),
             volume: AtomicI64::new(100),
         }
     }
@@ -149,7 +162,37 @@ impl Player {
         tracing::debug!("sending event on guild player {}: {event:?}", self.guild_id);
 
         match &event {
+            OutgoingEvent::Destroy(_) | OutgoingEvent::Stop(_) => {
+                *self.track.lock().unwrap_or_else(PoisonError::into_inner) = None;
+            }
+            OutgoingEvent::Equalizer(event) => {
+                // The equalizer is one of the filters, so keep it when the
+                // other filters are restored.
+                self.filters
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .get_or_insert_with(|| Filters::new(self.guild_id))
+                    .equalizer = Some(event.bands.clone());
+            }
+            OutgoingEvent::Filters(event) => {
+                *self.filters.lock().unwrap_or_else(PoisonError::into_inner) =
+                    Some((**event).clone());
+            }
             OutgoingEvent::Pause(event) => self.paused.store(event.pause, Ordering::Release),
+            OutgoingEvent::Play(event) => {
+                let mut track = self.track.lock().unwrap_or_else(PoisonError::into_inner);
+
+                if !event.no_replace || track.is_none() {
+                    *track = Some(event.track.clone());
+                }
+            }
+            OutgoingEvent::VoiceUpdate(event) => {
+                self.set_disconnected(false);
+                *self
+                    .voice_update
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner) = Some(event.clone());
+            }
             OutgoingEvent::Volume(event) => {
                 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
                 self.volume.store(event.volume, Ordering::Release);
@@ -182,6 +225,29 @@ impl Player {
             .store(channel_id.map_or(0_u64, Id::get), Ordering::Release);
     }
 
+    /// Return whether Discord closed the player's voice connection.
+    ///
+    /// This is only set if the node's [`CleanupPolicy`] is to keep players and
+    /// is reset once a new voice update is sent.
+    ///
+    /// [`CleanupPolicy`]: crate::node::CleanupPolicy
+    pub fn disconnected(&self) -> bool {
+        self.disconnected.load(Ordering::Acquire)
+    }
+
+    /// Set whether Discord closed the player's voice connection.
+    pub(crate) fn set_disconnected(&self, disconnected: bool) {
+        self.disconnected.store(disconnected, Ordering::Release);
+    }
+
+    /// Return the filters last applied to the player, if any.
+    pub fn filters(&self) -> Option<Filters> {
+        self.filters
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
     /// Return the player's guild ID.
     pub const fn guild_id(&self) -> Id<GuildMarker> {
         self.guild_id
@@ -202,6 +268,16 @@ impl Player {
         self.position.store(position, Ordering::Release);
     }
 
+    /// Return the player's queue of tracks to play after the current track.
+    pub const fn queue(&self) -> Queue<'_> {
+        Queue::new(self)
+    }
+
+    /// Lock the player's queue.
+    pub(crate) fn queue_state(&self) -> MutexGuard<'_, QueueState> {
+        self.queue.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
     /// Return the player's time.
     pub fn time(&mut self) -> i64 {
         self.time.load(Ordering::Relaxed)
@@ -212,18 +288,161 @@ impl Player {
         self.time.store(time, Ordering::Release);
     }
 
+    /// Return the track the player is playing, if any.
+    pub fn track(&self) -> Option<String> {
+        self.track
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Clear the player's track if it's the track that ended, returning the
+    /// queued track to play next.
+    ///
+    /// Replaced tracks are ignored, as the player's track was already set to
+    /// the track playing in their place.
+    pub(crate) fn track_ended(&self, event: &TrackEnd) -> Option<String> {
+        if event.reason == TrackEndReason::Replaced {
+            return None;
+        }
+
+        {
+            let mut current = self.track.lock().unwrap_or_else(PoisonError::into_inner);
+
+            // Another track was started in the meantime.
+            if current.as_deref() != Some(event.track.as_str()) {
+                return None;
+            }
+
+            *current = None;
+        }
+
+        self.queue_state().track_ended(&event.track, &event.reason)
+    }
+
     /// Return the player's volume.
     pub fn volume(&self) -> i64 {
         self.volume.load(Ordering::Relaxed)
     }
+
+    /// Events restoring the player's state on a new node session.
+    ///
+    /// Consists of the last voice update and, if a track is playing, a play
+    /// event starting at the current position followed by the pause state,
+    /// followed by the last applied filters and the volume.
+    pub(crate) fn sync_events(&self) -> Vec<OutgoingEvent> {
+        let mut events = Vec::new();
+
+        if let Some(voice_update) = self
+            .voice_update
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+        {
+            events.push(OutgoingEvent::from(voice_update));
+        }
+
+        if let Some(track) = self.track() {
+            let position = u64::try_from(self.position())
+                .ok()
+                .filter(|position| *position > 0);
+            events.push(OutgoingEvent::from(Play::new(
+                self.guild_id,
+                track,
+                position,
+                None,
+                false,
+            )));
+
+            if self.paused() {
+                events.push(OutgoingEvent::from(Pause::new(self.guild_id, true)));
+            }
+        }
+
+        if let Some(filters) = self.filters() {
+            events.push(OutgoingEvent::from(filters));
+        }
+
+        if self.volume() != 100 {
+            events.push(OutgoingEvent::from(Volume::new(
+                self.guild_id,
+                self.volume(),
+            )));
+        }
+
+        events
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{Player, PlayerManager};
+    use crate::{
+        model::{Equalizer, EqualizerBand, Filters, OutgoingEvent, Timescale},
+        node::Node,
+    };
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{
+        fmt::Debug,
+        net::{Ipv4Addr, SocketAddrV4},
+        sync::Arc,
+    };
+    use twilight_model::id::{marker::GuildMarker, Id};
 
     assert_impl_all!(PlayerManager: Debug, Default, Send, Sync);
     assert_impl_all!(Player: Debug, Send, Sync);
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+
+    /// Create a player linked to a node that isn't connected.
+    fn player() -> Arc<Player> {
+        let players = PlayerManager::new();
+        let (node, _rx) = Node::unconnected(
+            SocketAddrV4::new(Ipv4Addr::LOCALHOST, 2333).into(),
+            players.clone(),
+        );
+
+        players.get_or_insert(GUILD_ID, Arc::new(node))
+    }
+
+    /// Filters events among the player's sync events.
+    fn synced_filters(player: &Player) -> Vec<Filters> {
+        player
+            .sync_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                OutgoingEvent::Filters(filters) => Some(*filters),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sync_filters() {
+        let player = player();
+        assert!(synced_filters(&player).is_empty());
+
+        let mut filters = Filters::new(GUILD_ID);
+        filters.timescale = Some(Timescale::new(1.2, 1.0, 1.0));
+        _ = player.send(filters.clone());
+        assert_eq!(vec![filters.clone()], synced_filters(&player));
+
+        // The equalizer is restored along with the other filters.
+        let bands = vec![EqualizerBand::new(0, 0.25)];
+        _ = player.send(Equalizer::new(GUILD_ID, bands.clone()));
+        filters.equalizer = Some(bands);
+        assert_eq!(Some(filters.clone()), player.filters());
+        assert_eq!(vec![filters], synced_filters(&player));
+    }
+
+    #[test]
+    fn sync_equalizer() {
+        let player = player();
+        let bands = vec![EqualizerBand::new(1, -0.1)];
+        _ = player.send(Equalizer::new(GUILD_ID, bands.clone()));
+
+        let mut filters = Filters::new(GUILD_ID);
+        filters.equalizer = Some(bands);
+        assert_eq!(vec![filters], synced_filters(&player));
+    }
 }
@@ -0,0 +1,342 @@
+//! Queues of tracks for players, advancing when tracks finish.
+//!
+//! Each [`Player`] has a queue of tracks to play after the current one,
+//! accessible via [`Player::queue`]. When the current track finishes, the node
+//! plays the next queued track according to the queue's [`LoopMode`]. Players
+//! that never queue tracks are unaffected.
+//!
+//! # Examples
+//!
+//! Queue two tracks, repeating the queue once both were played:
+//!
+//! ```
+//! use twilight_lavalink::{queue::LoopMode, Lavalink};
+//! # use twilight_model::id::Id;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let (guild_id, user_id) = (Id::new(1), Id::new(2));
+//! # let (first, second) = (String::new(), String::new());
+//!
+//! let lavalink = Lavalink::new(user_id, 10);
+//!
+//! if let Some(player) = lavalink.players().get(&guild_id) {
+//!     let queue = player.queue();
+//!     queue.set_loop_mode(LoopMode::Queue);
+//!     // Starts playing the track if the player is idle.
+//!     queue.push(first)?;
+//!     queue.push(second)?;
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! [`Player`]: crate::player::Player
+
+use crate::{
+    model::{OutgoingEvent, Play, Stop, TrackEndReason},
+    node::NodeSenderError,
+    player::Player,
+};
+use std::collections::VecDeque;
+
+/// How a queue advances once the current track finished.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum LoopMode {
+    /// Play the queued tracks once.
+    #[default]
+    Off,
+    /// Move finished tracks to the back of the queue.
+    Queue,
+    /// Repeat the current track.
+    Track,
+}
+
+/// Queued tracks of a player.
+#[derive(Debug)]
+pub(crate) struct QueueState {
+    /// How the queue advances.
+    loop_mode: LoopMode,
+    /// Base64 tracks to play after the current track.
+    tracks: VecDeque<String>,
+}
+
+impl QueueState {
+    /// Create an empty queue that doesn't loop.
+    pub(crate) const fn new() -> Self {
+        Self {
+            loop_mode: LoopMode::Off,
+            tracks: VecDeque::new(),
+        }
+    }
+
+    /// Track to play after the current track ended, if any.
+    ///
+    /// Tracks that were stopped, replaced, or cleaned up were ended on purpose,
+    /// possibly by the track already playing in their place, so the queue
+    /// doesn't advance.
+    pub(crate) fn track_ended(&mut self, track: &str, reason: &TrackEndReason) -> Option<String> {
+        match reason {
+            TrackEndReason::Finished => match self.loop_mode {
+                LoopMode::Off => self.tracks.pop_front(),
+                LoopMode::Queue => {
+                    self.tracks.push_back(track.to_owned());
+
+                    self.tracks.pop_front()
+                }
+                LoopMode::Track => Some(track.to_owned()),
+            },
+            // Neither repeat nor requeue a track that can't be played.
+            TrackEndReason::LoadFailed => self.tracks.pop_front(),
+            _ => None,
+        }
+    }
+
+    /// Track to play in place of the current track, if any.
+    fn skip(&mut self, current: Option<String>) -> Option<String> {
+        if self.loop_mode == LoopMode::Queue {
+            self.tracks.extend(current);
+        }
+
+        self.tracks.pop_front()
+    }
+}
+
+/// Queue of tracks to play after a player's current track.
+///
+/// Retrieved via [`Player::queue`].
+#[derive(Debug)]
+pub struct Queue<'a> {
+    player: &'a Player,
+}
+
+impl<'a> Queue<'a> {
+    pub(crate) const fn new(player: &'a Player) -> Self {
+        Self { player }
+    }
+
+    /// Remove all queued tracks.
+    ///
+    /// The current track keeps playing.
+    pub fn clear(&self) {
+        self.player.queue_state().tracks.clear();
+    }
+
+    /// Return whether no tracks are queued.
+    pub fn is_empty(&self) -> bool {
+        self.player.queue_state().tracks.is_empty()
+    }
+
+    /// Return the number of queued tracks.
+    pub fn len(&self) -> usize {
+        self.player.queue_state().tracks.len()
+    }
+
+    /// Return how the queue advances once the current track finished.
+    pub fn loop_mode(&self) -> LoopMode {
+        self.player.queue_state().loop_mode
+    }
+
+    /// Set how the queue advances once the current track finished.
+    pub fn set_loop_mode(&self, loop_mode: LoopMode) {
+        self.player.queue_state().loop_mode = loop_mode;
+    }
+
+    /// Add a base64 track to the back of the queue.
+    ///
+    /// The track is played immediately if the player isn't playing a track.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NodeSenderErrorType::Sending`] error type if the track was
+    /// to be played but the node is no longer connected.
+    ///
+    /// [`NodeSenderErrorType::Sending`]: crate::node::NodeSenderErrorType::Sending
+    pub fn push(&self, track: impl Into<String>) -> Result<(), NodeSenderError> {
+        let track = track.into();
+        // Hold the lock while sending, so that concurrent pushes see the
+        // track as playing instead of both playing their track.
+        let mut state = self.player.queue_state();
+
+        if self.player.track().is_some() || !state.tracks.is_empty() {
+            state.tracks.push_back(track);
+
+            return Ok(());
+        }
+
+        self.player
+            .send(Play::from((self.player.guild_id(), track)))
+    }
+
+    /// Randomly reorder the queued tracks.
+    pub fn shuffle(&self) {
+        fastrand::shuffle(self.player.queue_state().tracks.make_contiguous());
+    }
+
+    /// Play the next queued track in place of the current track, returning
+    /// it.
+    ///
+    /// If the queue is empty the current track is stopped instead. Skipping
+    /// ignores [`LoopMode::Track`], whereas [`LoopMode::Queue`] moves the
+    /// current track to the back of the queue.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NodeSenderErrorType::Sending`] error type if the node is no
+    /// longer connected.
+    ///
+    /// [`NodeSenderErrorType::Sending`]: crate::node::NodeSenderErrorType::Sending
+    pub fn skip(&self) -> Result<Option<String>, NodeSenderError> {
+        // Hold the lock while sending, like when pushing, so that concurrent
+        // pushes and skips see the next track as playing.
+        let mut state = self.player.queue_state();
+        let next = state.skip(self.player.track());
+        let guild_id = self.player.guild_id();
+
+        let event = match &next {
+            // Replace the current track instead of playing after it.
+            Some(track) => {
+                OutgoingEvent::from(Play::from((guild_id, track.clone(), None, None, false)))
+            }
+            None => OutgoingEvent::from(Stop::from(guild_id)),
+        };
+        self.player.send(event)?;
+        drop(state);
+
+        Ok(next)
+    }
+
+    /// Return a copy of the queued tracks, starting with the next track.
+    pub fn tracks(&self) -> Vec<String> {
+        self.player.queue_state().tracks.iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LoopMode, Queue, QueueState};
+    use crate::{
+        model::{OutgoingEvent, TrackEndReason},
+        node::Node,
+        player::PlayerManager,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        hash::Hash,
+        net::{Ipv4Addr, SocketAddrV4},
+        sync::Arc,
+        thread,
+    };
+    use twilight_model::id::Id;
+
+    assert_impl_all!(LoopMode: Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(Queue<'_>: Debug, Send, Sync);
+
+    fn state(loop_mode: LoopMode) -> QueueState {
+        QueueState {
+            loop_mode,
+            tracks: ["b".to_owned(), "c".to_owned()].into(),
+        }
+    }
+
+    #[test]
+    fn finished() {
+        let mut off = state(LoopMode::Off);
+        assert_eq!(
+            Some("b"),
+            off.track_ended("a", &TrackEndReason::Finished).as_deref()
+        );
+        assert_eq!(["c"].as_slice(), off.tracks.make_contiguous());
+
+        let mut queue = state(LoopMode::Queue);
+        assert_eq!(
+            Some("b"),
+            queue.track_ended("a", &TrackEndReason::Finished).as_deref()
+        );
+        assert_eq!(["c", "a"].as_slice(), queue.tracks.make_contiguous());
+
+        let mut track = state(LoopMode::Track);
+        assert_eq!(
+            Some("a"),
+            track.track_ended("a", &TrackEndReason::Finished).as_deref()
+        );
+        assert_eq!(["b", "c"].as_slice(), track.tracks.make_contiguous());
+
+        let mut empty = QueueState::new();
+        assert!(empty.track_ended("a", &TrackEndReason::Finished).is_none());
+    }
+
+    #[test]
+    fn load_failed() {
+        for loop_mode in [LoopMode::Off, LoopMode::Queue, LoopMode::Track] {
+            let mut state = state(loop_mode);
+            assert_eq!(
+                Some("b"),
+                state
+                    .track_ended("a", &TrackEndReason::LoadFailed)
+                    .as_deref()
+            );
+            assert_eq!(["c"].as_slice(), state.tracks.make_contiguous());
+        }
+    }
+
+    /// Tracks ended on purpose don't advance the queue, which would otherwise
+    /// skip the track playing in their place.
+    #[test]
+    fn ended_on_purpose() {
+        for reason in [
+            TrackEndReason::Cleanup,
+            TrackEndReason::Replaced,
+            TrackEndReason::Stopped,
+            TrackEndReason::Unknown("paused".to_owned()),
+        ] {
+            for loop_mode in [LoopMode::Off, LoopMode::Queue, LoopMode::Track] {
+                let mut state = state(loop_mode);
+                assert!(state.track_ended("a", &reason).is_none());
+                assert_eq!(["b", "c"].as_slice(), state.tracks.make_contiguous());
+            }
+        }
+    }
+
+    /// Only one of concurrently pushed tracks to an idle player is played.
+    #[test]
+    fn push_concurrently() {
+        let players = PlayerManager::new();
+        let (node, mut rx) = Node::unconnected(
+            SocketAddrV4::new(Ipv4Addr::LOCALHOST, 2333).into(),
+            players.clone(),
+        );
+        let player = players.get_or_insert(Id::new(1), Arc::new(node));
+
+        thread::scope(|scope| {
+            for track in 0..8 {
+                let player = &player;
+                scope.spawn(move || player.queue().push(track.to_string()).unwrap());
+            }
+        });
+
+        let mut plays = 0;
+
+        while let Ok(event) = rx.try_recv() {
+            assert!(matches!(event, OutgoingEvent::Play(_)));
+            plays += 1;
+        }
+
+        assert_eq!(1, plays);
+        assert_eq!(7, player.queue().len());
+    }
+
+    #[test]
+    fn skip() {
+        let mut off = state(LoopMode::Off);
+        assert_eq!(Some("b"), off.skip(Some("a".to_owned())).as_deref());
+        assert_eq!(Some("c"), off.skip(Some("b".to_owned())).as_deref());
+        assert!(off.skip(Some("c".to_owned())).is_none());
+
+        let mut queue = state(LoopMode::Queue);
+        assert_eq!(Some("b"), queue.skip(Some("a".to_owned())).as_deref());
+        assert_eq!(["c", "a"].as_slice(), queue.tracks.make_contiguous());
+
+        let mut track = state(LoopMode::Track);
+        assert_eq!(Some("b"), track.skip(Some("a".to_owned())).as_deref());
+    }
+}
@@ -0,0 +1,78 @@
+//! Count the allocations made deserializing owned and borrowed payloads.
+//!
+//! Allocation counts are deterministic, so each payload is deserialized once
+//! rather than timed.
+
+use serde::Deserialize;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use twilight_model::gateway::payload::incoming::{MessageCreate, MessageCreateRef};
+
+/// System allocator counting the number of allocations.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const MESSAGE_CREATE: &str = r#"{
+    "attachments": [],
+    "author": {
+        "avatar": null,
+        "discriminator": "0001",
+        "id": "3",
+        "username": "test"
+    },
+    "channel_id": "2",
+    "content": "the quick brown fox jumps over the lazy dog",
+    "edited_timestamp": null,
+    "embeds": [],
+    "guild_id": "1",
+    "id": "4",
+    "mention_everyone": false,
+    "mention_roles": [],
+    "mentions": [],
+    "pinned": false,
+    "timestamp": "2021-09-19T17:30:45.000000+00:00",
+    "tts": false,
+    "type": 0
+}"#;
+
+/// Number of allocations made deserializing `input` into `T`.
+fn allocations<'a, T: Deserialize<'a>>(input: &'a str) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let value = serde_json::from_str::<T>(input).unwrap();
+    let after = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(value);
+
+    after - before
+}
+
+fn main() {
+    let owned = allocations::<MessageCreate>(MESSAGE_CREATE);
+    let borrowed = allocations::<MessageCreateRef<'_>>(MESSAGE_CREATE);
+
+    println!("message create: {owned} allocations");
+    println!("message create ref: {borrowed} allocations");
+}
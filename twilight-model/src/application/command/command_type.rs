@@ -22,7 +22,17 @@ pub enum CommandType {
 }
 
 impl CommandType {
+    /// Name of the variant as a string slice.
+    ///
+    /// This is the same as [`name`].
+    ///
+    /// [`name`]: Self::name
     pub const fn kind(self) -> &'static str {
+        self.name()
+    }
+
+    /// Name of the variant as a string slice.
+    pub const fn name(self) -> &'static str {
         match self {
             Self::ChatInput => "ChatInput",
             Self::User => "User",
@@ -90,4 +100,12 @@ mod tests {
         assert_eq!("Message", CommandType::Message.kind());
         assert_eq!("Unknown", CommandType::Unknown(99).kind());
     }
+
+    #[test]
+    fn names() {
+        assert_eq!("ChatInput", CommandType::ChatInput.name());
+        assert_eq!("User", CommandType::User.name());
+        assert_eq!("Message", CommandType::Message.name());
+        assert_eq!("Unknown", CommandType::Unknown(99).name());
+    }
 }
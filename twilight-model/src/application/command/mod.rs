@@ -376,7 +376,7 @@ mod tests {
                 Token::Str("description"),
                 Token::Str("sub command group desc"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::SubCommandGroup as u8),
+                Token::U8(u8::from(CommandOptionType::SubCommandGroup)),
                 Token::Str("name"),
                 Token::Str("sub command group name"),
                 Token::Str("options"),
@@ -389,7 +389,7 @@ mod tests {
                 Token::Str("description"),
                 Token::Str("sub command desc"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::SubCommand as u8),
+                Token::U8(u8::from(CommandOptionType::SubCommand)),
                 Token::Str("name"),
                 Token::Str("sub command name"),
                 Token::Str("options"),
@@ -402,7 +402,7 @@ mod tests {
                 Token::Str("description"),
                 Token::Str("attachment desc"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Attachment as u8),
+                Token::U8(u8::from(CommandOptionType::Attachment)),
                 Token::Str("name"),
                 Token::Str("attachment name"),
                 Token::StructEnd,
@@ -413,7 +413,7 @@ mod tests {
                 Token::Str("description"),
                 Token::Str("boolean desc"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Boolean as u8),
+                Token::U8(u8::from(CommandOptionType::Boolean)),
                 Token::Str("name"),
                 Token::Str("boolean name"),
                 Token::Str("required"),
@@ -431,7 +431,7 @@ mod tests {
                 Token::Str("description"),
                 Token::Str("channel desc"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Channel as u8),
+                Token::U8(u8::from(CommandOptionType::Channel)),
                 Token::Str("name"),
                 Token::Str("channel name"),
                 Token::StructEnd,
@@ -447,7 +447,7 @@ mod tests {
                 Token::Str("description"),
                 Token::Str("channel desc"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Channel as u8),
+                Token::U8(u8::from(CommandOptionType::Channel)),
                 Token::Str("name"),
                 Token::Str("channel name"),
                 Token::StructEnd,
@@ -465,7 +465,7 @@ mod tests {
                 Token::Str("description"),
                 Token::Str("integer desc"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Integer as u8),
+                Token::U8(u8::from(CommandOptionType::Integer)),
                 Token::Str("max_value"),
                 Token::Some,
                 Token::I64(100),
@@ -488,7 +488,7 @@ mod tests {
                 Token::Str("mentionable desc (but british)"),
                 Token::MapEnd,
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Mentionable as u8),
+                Token::U8(u8::from(CommandOptionType::Mentionable)),
                 Token::Str("name"),
                 Token::Str("mentionable name"),
                 Token::StructEnd,
@@ -521,7 +521,7 @@ mod tests {
                 Token::Str("description"),
                 Token::Str("number desc"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Number as u8),
+                Token::U8(u8::from(CommandOptionType::Number)),
                 Token::Str("name"),
                 Token::Str("number name"),
                 Token::StructEnd,
@@ -532,7 +532,7 @@ mod tests {
                 Token::Str("description"),
                 Token::Str("role desc"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Role as u8),
+                Token::U8(u8::from(CommandOptionType::Role)),
                 Token::Str("name"),
                 Token::Str("role name"),
                 Token::Str("name_localizations"),
@@ -549,7 +549,7 @@ mod tests {
                 Token::Str("description"),
                 Token::Str("string desc"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::String as u8),
+                Token::U8(u8::from(CommandOptionType::String)),
                 Token::Str("max_length"),
                 Token::Some,
                 Token::U16(6000),
@@ -1,6 +1,5 @@
 use crate::channel::ChannelType;
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::{cmp::Eq, collections::HashMap};
 
 /// Option for a [`Command`].
@@ -200,21 +199,23 @@ pub enum CommandOptionValue {
 }
 
 /// Type of a [`CommandOption`].
-#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[non_exhaustive]
-#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
 pub enum CommandOptionType {
-    SubCommand = 1,
-    SubCommandGroup = 2,
-    String = 3,
-    Integer = 4,
-    Boolean = 5,
-    User = 6,
-    Channel = 7,
-    Role = 8,
-    Mentionable = 9,
-    Number = 10,
-    Attachment = 11,
+    SubCommand,
+    SubCommandGroup,
+    String,
+    Integer,
+    Boolean,
+    User,
+    Channel,
+    Role,
+    Mentionable,
+    Number,
+    Attachment,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
 impl CommandOptionType {
@@ -231,6 +232,98 @@ impl CommandOptionType {
             CommandOptionType::Mentionable => "Mentionable",
             CommandOptionType::Number => "Number",
             CommandOptionType::Attachment => "Attachment",
+            CommandOptionType::Unknown(_) => "Unknown",
         }
     }
 }
+
+impl From<u8> for CommandOptionType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::SubCommand,
+            2 => Self::SubCommandGroup,
+            3 => Self::String,
+            4 => Self::Integer,
+            5 => Self::Boolean,
+            6 => Self::User,
+            7 => Self::Channel,
+            8 => Self::Role,
+            9 => Self::Mentionable,
+            10 => Self::Number,
+            11 => Self::Attachment,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<CommandOptionType> for u8 {
+    fn from(value: CommandOptionType) -> Self {
+        match value {
+            CommandOptionType::SubCommand => 1,
+            CommandOptionType::SubCommandGroup => 2,
+            CommandOptionType::String => 3,
+            CommandOptionType::Integer => 4,
+            CommandOptionType::Boolean => 5,
+            CommandOptionType::User => 6,
+            CommandOptionType::Channel => 7,
+            CommandOptionType::Role => 8,
+            CommandOptionType::Mentionable => 9,
+            CommandOptionType::Number => 10,
+            CommandOptionType::Attachment => 11,
+            CommandOptionType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandOptionType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        CommandOptionType: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn variants() {
+        const MAP: &[(CommandOptionType, u8)] = &[
+            (CommandOptionType::SubCommand, 1),
+            (CommandOptionType::SubCommandGroup, 2),
+            (CommandOptionType::String, 3),
+            (CommandOptionType::Integer, 4),
+            (CommandOptionType::Boolean, 5),
+            (CommandOptionType::User, 6),
+            (CommandOptionType::Channel, 7),
+            (CommandOptionType::Role, 8),
+            (CommandOptionType::Mentionable, 9),
+            (CommandOptionType::Number, 10),
+            (CommandOptionType::Attachment, 11),
+            (CommandOptionType::Unknown(99), 99),
+        ];
+
+        for (kind, num) in MAP {
+            serde_test::assert_tokens(kind, &[Token::U8(*num)]);
+            assert_eq!(*kind, CommandOptionType::from(*num));
+            assert_eq!(*num, u8::from(*kind));
+        }
+    }
+
+    #[test]
+    fn kinds() {
+        assert_eq!("SubCommand", CommandOptionType::SubCommand.kind());
+        assert_eq!("Attachment", CommandOptionType::Attachment.kind());
+        assert_eq!("Unknown", CommandOptionType::Unknown(99).kind());
+    }
+}
@@ -310,6 +310,12 @@ impl<'de> Deserialize<'de> for CommandDataOption {
                                 return Err(DeError::invalid_type(val.as_unexpected(), &"user id"));
                             }
                         }
+                        CommandOptionType::Unknown(unknown) => {
+                            return Err(DeError::invalid_value(
+                                Unexpected::Unsigned(unknown.into()),
+                                &"a known command option type",
+                            ));
+                        }
                     }
                 };
 
@@ -468,7 +474,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("cat"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Integer as u8),
+                Token::U8(u8::from(CommandOptionType::Integer)),
                 Token::Str("value"),
                 Token::I64(42),
                 Token::StructEnd,
@@ -529,7 +535,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("cat"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Integer as u8),
+                Token::U8(u8::from(CommandOptionType::Integer)),
                 Token::Str("value"),
                 Token::I64(42),
                 Token::StructEnd,
@@ -543,7 +549,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("dog"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::String as u8),
+                Token::U8(u8::from(CommandOptionType::String)),
                 Token::Str("value"),
                 Token::String("Shiba"),
                 Token::StructEnd,
@@ -591,7 +597,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("cat"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::SubCommand as u8),
+                Token::U8(u8::from(CommandOptionType::SubCommand)),
                 Token::StructEnd,
                 Token::SeqEnd,
                 Token::StructEnd,
@@ -616,7 +622,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("opt"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Number as u8),
+                Token::U8(u8::from(CommandOptionType::Number)),
                 Token::Str("value"),
                 Token::I64(5),
                 Token::StructEnd,
@@ -647,7 +653,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("opt"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Number as u8),
+                Token::U8(u8::from(CommandOptionType::Number)),
                 Token::Str("value"),
                 Token::String("not a number"),
                 Token::StructEnd,
@@ -675,7 +681,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("opt"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::Number as u8),
+                Token::U8(u8::from(CommandOptionType::Number)),
                 Token::Str("value"),
                 Token::String("1"),
                 Token::StructEnd,
@@ -700,7 +706,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("opt"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::String as u8),
+                Token::U8(u8::from(CommandOptionType::String)),
                 Token::Str("value"),
                 Token::String("0001"),
                 Token::StructEnd,
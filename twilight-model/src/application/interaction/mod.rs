@@ -545,6 +545,7 @@ mod tests {
                 recipients: None,
                 rtc_region: None,
                 topic: None,
+                total_message_sent: None,
                 user_limit: None,
                 application_id: None,
                 applied_tags: None,
@@ -736,7 +737,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("member"),
                 Token::Str("type"),
-                Token::U8(CommandOptionType::User as u8),
+                Token::U8(u8::from(CommandOptionType::User)),
                 Token::Str("value"),
                 Token::NewtypeStruct { name: "Id" },
                 Token::Str("600"),
@@ -229,6 +229,7 @@ mod tests {
                     message_snapshots: Vec::new(),
                     pinned: false,
                     poll: None,
+                    position: None,
                     reactions: Vec::new(),
                     reference: None,
                     referenced_message: None,
@@ -80,6 +80,7 @@ impl ChannelType {
     /// - [`GuildAnnouncement`][`Self::GuildAnnouncement`]
     /// - [`GuildCategory`][`Self::GuildCategory`]
     /// - [`GuildDirectory`][`Self::GuildDirectory`]
+    /// - [`GuildForum`][`Self::GuildForum`]
     /// - [`GuildStageVoice`][`Self::GuildStageVoice`]
     /// - [`GuildText`][`Self::GuildText`]
     /// - [`GuildVoice`][`Self::GuildVoice`]
@@ -91,6 +92,7 @@ impl ChannelType {
             self,
             Self::GuildCategory
                 | Self::GuildDirectory
+                | Self::GuildForum
                 | Self::GuildAnnouncement
                 | Self::AnnouncementThread
                 | Self::PublicThread
@@ -104,7 +106,7 @@ impl ChannelType {
 
     /// Whether the channel type is a thread.
     ///
-    /// The following channel types are considered guild channel types:
+    /// The following channel types are considered thread channel types:
     ///
     /// - [`AnnouncementThread`][`Self::AnnouncementThread`]
     /// - [`PrivateThread`][`Self::PrivateThread`]
@@ -116,6 +118,16 @@ impl ChannelType {
         )
     }
 
+    /// Whether the channel type is a voice channel.
+    ///
+    /// The following channel types are considered voice channel types:
+    ///
+    /// - [`GuildStageVoice`][`Self::GuildStageVoice`]
+    /// - [`GuildVoice`][`Self::GuildVoice`]
+    pub const fn is_voice(self) -> bool {
+        matches!(self, Self::GuildStageVoice | Self::GuildVoice)
+    }
+
     /// Name of the variant as a string slice.
     pub const fn name(self) -> &'static str {
         match self {
@@ -145,6 +157,7 @@ mod tests {
 
     const_assert!(ChannelType::GuildCategory.is_guild());
     const_assert!(ChannelType::GuildDirectory.is_guild());
+    const_assert!(ChannelType::GuildForum.is_guild());
     const_assert!(ChannelType::GuildAnnouncement.is_guild());
     const_assert!(ChannelType::AnnouncementThread.is_guild());
     const_assert!(ChannelType::PublicThread.is_guild());
@@ -158,6 +171,37 @@ mod tests {
     const_assert!(ChannelType::PublicThread.is_thread());
     const_assert!(ChannelType::PrivateThread.is_thread());
 
+    const_assert!(ChannelType::GuildStageVoice.is_voice());
+    const_assert!(ChannelType::GuildVoice.is_voice());
+
+    /// Assert the classification of every known variant and an unknown one.
+    #[test]
+    fn classifications() {
+        // (variant, is_guild, is_thread, is_voice)
+        let cases = [
+            (ChannelType::GuildText, true, false, false),
+            (ChannelType::Private, false, false, false),
+            (ChannelType::GuildVoice, true, false, true),
+            (ChannelType::Group, false, false, false),
+            (ChannelType::GuildCategory, true, false, false),
+            (ChannelType::GuildAnnouncement, true, false, false),
+            (ChannelType::AnnouncementThread, true, true, false),
+            (ChannelType::PublicThread, true, true, false),
+            (ChannelType::PrivateThread, true, true, false),
+            (ChannelType::GuildStageVoice, true, false, true),
+            (ChannelType::GuildDirectory, true, false, false),
+            (ChannelType::GuildForum, true, false, false),
+            (ChannelType::GuildMedia, true, false, false),
+            (ChannelType::Unknown(99), false, false, false),
+        ];
+
+        for (kind, guild, thread, voice) in cases {
+            assert_eq!(guild, kind.is_guild(), "{kind:?}");
+            assert_eq!(thread, kind.is_thread(), "{kind:?}");
+            assert_eq!(voice, kind.is_voice(), "{kind:?}");
+        }
+    }
+
     #[test]
     fn variants() {
         serde_test::assert_tokens(&ChannelType::GuildText, &[Token::U8(0)]);
@@ -1080,7 +1080,7 @@ mod tests {
                 Token::Some,
                 Token::Bool(true),
                 Token::String("style"),
-                Token::U8(TextInputStyle::Short as u8),
+                Token::U8(1),
                 Token::String("value"),
                 Token::Some,
                 Token::String("Hello World!"),
@@ -1,4 +1,4 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use serde::{Deserialize, Serialize};
 
 /// Pop-up [`Component`] that renders on modals.
 ///
@@ -28,22 +28,43 @@ pub struct TextInput {
 }
 
 /// Style of an [`TextInput`].
-#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[non_exhaustive]
-#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
 pub enum TextInputStyle {
     /// Intended for short single-line text.
-    Short = 1,
+    Short,
     /// Intended for much longer inputs.
-    Paragraph = 2,
+    Paragraph,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for TextInputStyle {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Short,
+            2 => Self::Paragraph,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<TextInputStyle> for u8 {
+    fn from(value: TextInputStyle) -> Self {
+        match value {
+            TextInputStyle::Short => 1,
+            TextInputStyle::Paragraph => 2,
+            TextInputStyle::Unknown(unknown) => unknown,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde::{Deserialize, Serialize};
     use serde_test::Token;
-    use static_assertions::{assert_fields, assert_impl_all, const_assert_eq};
+    use static_assertions::{assert_fields, assert_impl_all};
     use std::{fmt::Debug, hash::Hash};
 
     assert_fields!(
@@ -69,12 +90,11 @@ mod tests {
         Serialize,
         Sync
     );
-    const_assert_eq!(1, TextInputStyle::Short as u8);
-    const_assert_eq!(2, TextInputStyle::Paragraph as u8);
 
     #[test]
     fn text_input_style() {
         serde_test::assert_tokens(&TextInputStyle::Short, &[Token::U8(1)]);
         serde_test::assert_tokens(&TextInputStyle::Paragraph, &[Token::U8(2)]);
+        serde_test::assert_tokens(&TextInputStyle::Unknown(200), &[Token::U8(200)]);
     }
 }
@@ -47,6 +47,32 @@ pub struct Embed {
     pub video: Option<EmbedVideo>,
 }
 
+impl Embed {
+    /// Total length of the embed's text counted towards Discord's combined
+    /// embed length limit.
+    ///
+    /// This is the sum of the lengths of the author name, description, field
+    /// names and values, footer text, and title, in UTF-16 code units.
+    pub fn total_length(&self) -> usize {
+        /// Length of a string in UTF-16 code units.
+        fn len(value: &str) -> usize {
+            value.encode_utf16().count()
+        }
+
+        let author = self.author.as_ref().map_or(0, |author| len(&author.name));
+        let description = self.description.as_deref().map_or(0, len);
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| len(&field.name) + len(&field.value))
+            .sum::<usize>();
+        let footer = self.footer.as_ref().map_or(0, |footer| len(&footer.text));
+        let title = self.title.as_deref().map_or(0, len);
+
+        author + description + fields + footer + title
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -305,4 +331,57 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn total_length() {
+        let mut embed = Embed {
+            author: Some(EmbedAuthor {
+                icon_url: None,
+                name: "author".to_owned(),
+                proxy_icon_url: None,
+                url: None,
+            }),
+            color: None,
+            description: Some("description".to_owned()),
+            fields: Vec::from([
+                EmbedField {
+                    inline: false,
+                    name: "name".to_owned(),
+                    value: "value".to_owned(),
+                },
+                EmbedField {
+                    inline: true,
+                    name: "name 2".to_owned(),
+                    value: "value 2".to_owned(),
+                },
+            ]),
+            footer: Some(EmbedFooter {
+                icon_url: None,
+                proxy_icon_url: None,
+                text: "footer".to_owned(),
+            }),
+            image: None,
+            kind: "rich".to_owned(),
+            provider: None,
+            thumbnail: None,
+            timestamp: None,
+            title: Some("title".to_owned()),
+            url: Some("https://example.com".to_owned()),
+            video: None,
+        };
+
+        assert_eq!(embed.total_length(), 6 + 11 + 4 + 5 + 6 + 7 + 6 + 5);
+
+        // Characters outside the basic multilingual plane are two UTF-16 code
+        // units long.
+        embed.title = Some("🦀é".to_owned());
+        assert_eq!(embed.total_length(), 6 + 11 + 4 + 5 + 6 + 7 + 6 + 3);
+
+        embed.author = None;
+        embed.description = None;
+        embed.fields.clear();
+        embed.footer = None;
+        embed.title = None;
+        assert_eq!(embed.total_length(), 0);
+    }
 }
@@ -150,6 +150,70 @@ impl MessageType {
 
         self.deletable()
     }
+
+    /// Whether the message was generated by Discord rather than sent by a user
+    /// or application.
+    ///
+    /// Every type is a system type except for [`Regular`], [`Reply`],
+    /// [`ChatInputCommand`], and [`ContextMenuCommand`]. [`Unknown`] types are
+    /// considered system types, as every type added by Discord in recent years
+    /// has been one.
+    ///
+    /// [`ChatInputCommand`]: Self::ChatInputCommand
+    /// [`ContextMenuCommand`]: Self::ContextMenuCommand
+    /// [`Regular`]: Self::Regular
+    /// [`Reply`]: Self::Reply
+    /// [`Unknown`]: Self::Unknown
+    pub const fn is_system(self) -> bool {
+        !matches!(
+            self,
+            Self::Regular | Self::Reply | Self::ChatInputCommand | Self::ContextMenuCommand
+        )
+    }
+
+    /// Name of the variant as a string slice.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Regular => "Regular",
+            Self::RecipientAdd => "RecipientAdd",
+            Self::RecipientRemove => "RecipientRemove",
+            Self::Call => "Call",
+            Self::ChannelNameChange => "ChannelNameChange",
+            Self::ChannelIconChange => "ChannelIconChange",
+            Self::ChannelMessagePinned => "ChannelMessagePinned",
+            Self::UserJoin => "UserJoin",
+            Self::GuildBoost => "GuildBoost",
+            Self::GuildBoostTier1 => "GuildBoostTier1",
+            Self::GuildBoostTier2 => "GuildBoostTier2",
+            Self::GuildBoostTier3 => "GuildBoostTier3",
+            Self::ChannelFollowAdd => "ChannelFollowAdd",
+            Self::GuildDiscoveryDisqualified => "GuildDiscoveryDisqualified",
+            Self::GuildDiscoveryRequalified => "GuildDiscoveryRequalified",
+            Self::GuildDiscoveryGracePeriodInitialWarning => {
+                "GuildDiscoveryGracePeriodInitialWarning"
+            }
+            Self::GuildDiscoveryGracePeriodFinalWarning => "GuildDiscoveryGracePeriodFinalWarning",
+            Self::ThreadCreated => "ThreadCreated",
+            Self::Reply => "Reply",
+            Self::ChatInputCommand => "ChatInputCommand",
+            Self::ThreadStarterMessage => "ThreadStarterMessage",
+            Self::GuildInviteReminder => "GuildInviteReminder",
+            Self::ContextMenuCommand => "ContextMenuCommand",
+            Self::AutoModerationAction => "AutoModerationAction",
+            Self::RoleSubscriptionPurchase => "RoleSubscriptionPurchase",
+            Self::InteractionPremiumUpsell => "InteractionPremiumUpsell",
+            Self::StageStart => "StageStart",
+            Self::StageEnd => "StageEnd",
+            Self::StageSpeaker => "StageSpeaker",
+            Self::StageTopic => "StageTopic",
+            Self::GuildApplicationPremiumSubscription => "GuildApplicationPremiumSubscription",
+            Self::GuildIncidentAlertModeEnabled => "GuildIncidentAlertModeEnabled",
+            Self::GuildIncidentAlertModeDisabled => "GuildIncidentAlertModeDisabled",
+            Self::GuildIncidentReportRaid => "GuildIncidentReportRaid",
+            Self::GuildIncidentReportRaidFalseAlarm => "GuildIncidentReportRaidFalseAlarm",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
 }
 
 impl From<u8> for MessageType {
@@ -316,10 +380,107 @@ mod tests {
         }
     }
 
+    /// Message types added by Discord after this release must not fail
+    /// deserialization.
+    #[test]
+    fn unknown() {
+        let message_type = serde_json::from_str::<MessageType>("200").unwrap();
+
+        assert_eq!(MessageType::Unknown(200), message_type);
+        assert_eq!(200, u8::from(message_type));
+        assert_eq!("200", serde_json::to_string(&message_type).unwrap());
+        serde_test::assert_tokens(&message_type, &[Token::U8(200)]);
+    }
+
     #[test]
     fn deletable_with_permissions() {
         assert!(MessageType::AutoModerationAction
             .deletable_with_permissions(Permissions::MANAGE_MESSAGES));
         assert!(!MessageType::AutoModerationAction.deletable_with_permissions(Permissions::empty()));
     }
+
+    #[test]
+    fn names() {
+        assert_eq!("Regular", MessageType::Regular.name());
+        assert_eq!("RecipientAdd", MessageType::RecipientAdd.name());
+        assert_eq!("RecipientRemove", MessageType::RecipientRemove.name());
+        assert_eq!("Call", MessageType::Call.name());
+        assert_eq!("ChannelNameChange", MessageType::ChannelNameChange.name());
+        assert_eq!("ChannelIconChange", MessageType::ChannelIconChange.name());
+        assert_eq!(
+            "ChannelMessagePinned",
+            MessageType::ChannelMessagePinned.name()
+        );
+        assert_eq!("UserJoin", MessageType::UserJoin.name());
+        assert_eq!("GuildBoost", MessageType::GuildBoost.name());
+        assert_eq!("GuildBoostTier1", MessageType::GuildBoostTier1.name());
+        assert_eq!("GuildBoostTier2", MessageType::GuildBoostTier2.name());
+        assert_eq!("GuildBoostTier3", MessageType::GuildBoostTier3.name());
+        assert_eq!("ChannelFollowAdd", MessageType::ChannelFollowAdd.name());
+        assert_eq!(
+            "GuildDiscoveryDisqualified",
+            MessageType::GuildDiscoveryDisqualified.name()
+        );
+        assert_eq!(
+            "GuildDiscoveryRequalified",
+            MessageType::GuildDiscoveryRequalified.name()
+        );
+        assert_eq!(
+            "GuildDiscoveryGracePeriodInitialWarning",
+            MessageType::GuildDiscoveryGracePeriodInitialWarning.name()
+        );
+        assert_eq!(
+            "GuildDiscoveryGracePeriodFinalWarning",
+            MessageType::GuildDiscoveryGracePeriodFinalWarning.name()
+        );
+        assert_eq!("ThreadCreated", MessageType::ThreadCreated.name());
+        assert_eq!("Reply", MessageType::Reply.name());
+        assert_eq!("ChatInputCommand", MessageType::ChatInputCommand.name());
+        assert_eq!(
+            "ThreadStarterMessage",
+            MessageType::ThreadStarterMessage.name()
+        );
+        assert_eq!(
+            "GuildInviteReminder",
+            MessageType::GuildInviteReminder.name()
+        );
+        assert_eq!("ContextMenuCommand", MessageType::ContextMenuCommand.name());
+        assert_eq!(
+            "AutoModerationAction",
+            MessageType::AutoModerationAction.name()
+        );
+        assert_eq!(
+            "RoleSubscriptionPurchase",
+            MessageType::RoleSubscriptionPurchase.name()
+        );
+        assert_eq!(
+            "InteractionPremiumUpsell",
+            MessageType::InteractionPremiumUpsell.name()
+        );
+        assert_eq!("StageStart", MessageType::StageStart.name());
+        assert_eq!("StageEnd", MessageType::StageEnd.name());
+        assert_eq!("StageSpeaker", MessageType::StageSpeaker.name());
+        assert_eq!("StageTopic", MessageType::StageTopic.name());
+        assert_eq!(
+            "GuildApplicationPremiumSubscription",
+            MessageType::GuildApplicationPremiumSubscription.name()
+        );
+        assert_eq!(
+            "GuildIncidentAlertModeEnabled",
+            MessageType::GuildIncidentAlertModeEnabled.name()
+        );
+        assert_eq!(
+            "GuildIncidentAlertModeDisabled",
+            MessageType::GuildIncidentAlertModeDisabled.name()
+        );
+        assert_eq!(
+            "GuildIncidentReportRaid",
+            MessageType::GuildIncidentReportRaid.name()
+        );
+        assert_eq!(
+            "GuildIncidentReportRaidFalseAlarm",
+            MessageType::GuildIncidentReportRaidFalseAlarm.name()
+        );
+        assert_eq!("Unknown", MessageType::Unknown(99).name());
+    }
 }
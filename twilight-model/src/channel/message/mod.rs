@@ -31,7 +31,7 @@ pub use self::{
     interaction::MessageInteraction,
     kind::MessageType,
     mention::Mention,
-    reaction::{EmojiReactionType, Reaction, ReactionCountDetails},
+    reaction::{EmojiReactionType, Reaction, ReactionCountDetails, ReactionKey},
     reaction_type::ReactionType,
     reference::MessageReference,
     reference_type::MessageReferenceType,
@@ -182,6 +182,12 @@ pub struct Message {
     /// The poll associated with the message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub poll: Option<Poll>,
+    /// Approximate position of the message in a thread.
+    ///
+    /// Starts at 0 for the first message sent in the thread after its creation
+    /// and may have gaps, such as for deleted messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u64>,
     /// List of reactions to the message.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub reactions: Vec<Reaction>,
@@ -216,6 +222,65 @@ pub struct Message {
     pub webhook_id: Option<Id<WebhookMarker>>,
 }
 
+impl Message {
+    /// Whether the message's author is a bot or an official Discord system
+    /// user.
+    ///
+    /// This is the case if the author's [`User::bot`] is `true` or their
+    /// [`User::system`] is `Some(true)`.
+    pub const fn author_is_bot_or_system(&self) -> bool {
+        self.author.bot || matches!(self.author.system, Some(true))
+    }
+
+    /// Whether the message is only visible to the user who invoked an
+    /// interaction.
+    ///
+    /// This is the case if the message's [`flags`] contain
+    /// [`MessageFlags::EPHEMERAL`].
+    ///
+    /// [`flags`]: Self::flags
+    pub fn is_ephemeral(&self) -> bool {
+        self.flags
+            .is_some_and(|flags| flags.contains(MessageFlags::EPHEMERAL))
+    }
+
+    /// Whether the message was sent by a webhook.
+    ///
+    /// This is the case if the message has a [`webhook_id`] and isn't an
+    /// [interaction response], which are sent via the application's webhook
+    /// but are owned by the application.
+    ///
+    /// [`webhook_id`]: Self::webhook_id
+    /// [interaction response]: Self::is_interaction_response
+    pub const fn is_from_webhook(&self) -> bool {
+        self.webhook_id.is_some() && !self.is_interaction_response()
+    }
+
+    /// Whether the message is a response to an interaction.
+    ///
+    /// This is the case if the message has [`interaction_metadata`] or its
+    /// [`kind`] is [`MessageType::ChatInputCommand`] or
+    /// [`MessageType::ContextMenuCommand`].
+    ///
+    /// [`interaction_metadata`]: Self::interaction_metadata
+    /// [`kind`]: Self::kind
+    pub const fn is_interaction_response(&self) -> bool {
+        self.interaction_metadata.is_some()
+            || matches!(
+                self.kind,
+                MessageType::ChatInputCommand | MessageType::ContextMenuCommand
+            )
+    }
+
+    /// Whether the message was generated by Discord rather than sent by a user
+    /// or application.
+    ///
+    /// Refer to [`MessageType::is_system`] for which types are system types.
+    pub const fn is_system(&self) -> bool {
+        self.kind.is_system()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -226,6 +291,7 @@ mod tests {
         MessageCall, MessageFlags, MessageReference, MessageType, Reaction,
     };
     use crate::{
+        application::interaction::InteractionType,
         channel::{ChannelMention, ChannelType},
         guild::{MemberFlags, PartialMember},
         id::Id,
@@ -302,6 +368,7 @@ mod tests {
             message_snapshots: Vec::new(),
             pinned: false,
             poll: None,
+            position: None,
             reactions: Vec::new(),
             reference: None,
             role_subscription_data: None,
@@ -529,6 +596,7 @@ mod tests {
             message_snapshots: Vec::new(),
             pinned: false,
             poll: None,
+            position: Some(2),
             reactions: vec![Reaction {
                 burst_colors: Vec::new(),
                 count: 7,
@@ -568,7 +636,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Message",
-                    len: 26,
+                    len: 27,
                 },
                 Token::Str("activity"),
                 Token::Some,
@@ -714,6 +782,9 @@ mod tests {
                 Token::SeqEnd,
                 Token::Str("pinned"),
                 Token::Bool(false),
+                Token::Str("position"),
+                Token::Some,
+                Token::U64(2),
                 Token::Str("reactions"),
                 Token::Seq { len: Some(1) },
                 Token::Struct {
@@ -791,4 +862,263 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn thread_message() {
+        let input = r#"{
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "discriminator": "0001",
+                "id": "3",
+                "username": "test"
+            },
+            "channel_id": "5",
+            "content": "ping",
+            "edited_timestamp": null,
+            "embeds": [],
+            "guild_id": "1",
+            "id": "4",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "position": 3,
+            "timestamp": "2020-02-02T02:02:02.020000+00:00",
+            "tts": false,
+            "type": 0
+        }"#;
+
+        let message = serde_json::from_str::<Message>(input).unwrap();
+        assert_eq!(message.position, Some(3));
+
+        let value = serde_json::to_value(&message).unwrap();
+        assert_eq!(value["position"], 3);
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn interaction_metadata() {
+        let input = r#"{
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "bot": true,
+                "discriminator": "0001",
+                "id": "3",
+                "username": "bot"
+            },
+            "channel_id": "2",
+            "content": "pong",
+            "edited_timestamp": null,
+            "embeds": [],
+            "id": "4",
+            "interaction": {
+                "id": "5",
+                "name": "ping",
+                "type": 2,
+                "user": {
+                    "avatar": null,
+                    "discriminator": "0001",
+                    "id": "6",
+                    "username": "test"
+                }
+            },
+            "interaction_metadata": {
+                "authorizing_integration_owners": {
+                    "1": "6"
+                },
+                "id": "5",
+                "interacted_message_id": "7",
+                "type": 3,
+                "user": {
+                    "avatar": null,
+                    "discriminator": "0001",
+                    "id": "6",
+                    "username": "test"
+                }
+            },
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "timestamp": "2020-02-02T02:02:02.020000+00:00",
+            "tts": false,
+            "type": 20
+        }"#;
+
+        let message = serde_json::from_str::<Message>(input).unwrap();
+
+        let interaction = message.interaction.as_ref().unwrap();
+        assert_eq!(interaction.id, Id::new(5));
+        assert_eq!(interaction.user.id, Id::new(6));
+
+        let metadata = message.interaction_metadata.as_deref().unwrap();
+        assert_eq!(metadata.id, Id::new(5));
+        assert_eq!(metadata.interacted_message_id, Some(Id::new(7)));
+        assert_eq!(metadata.kind, InteractionType::MessageComponent);
+        assert_eq!(
+            metadata.authorizing_integration_owners.user,
+            Some(Id::new(6))
+        );
+        assert_eq!(metadata.user.id, Id::new(6));
+
+        let serialized = serde_json::to_string(&message).unwrap();
+        assert_eq!(message, serde_json::from_str(&serialized).unwrap());
+    }
+
+    /// Minimal message sent by a user.
+    fn minimal_message() -> Message {
+        let input = r#"{
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "discriminator": "0001",
+                "id": "3",
+                "username": "test"
+            },
+            "channel_id": "2",
+            "content": "ping",
+            "edited_timestamp": null,
+            "embeds": [],
+            "id": "4",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "timestamp": "2020-02-02T02:02:02.020000+00:00",
+            "tts": false,
+            "type": 0
+        }"#;
+
+        serde_json::from_str(input).unwrap()
+    }
+
+    #[test]
+    fn classification() {
+        // Message type, whether it's a system message, and whether it's an
+        // interaction response.
+        const MAP: &[(MessageType, bool, bool)] = &[
+            (MessageType::Regular, false, false),
+            (MessageType::RecipientAdd, true, false),
+            (MessageType::RecipientRemove, true, false),
+            (MessageType::Call, true, false),
+            (MessageType::ChannelNameChange, true, false),
+            (MessageType::ChannelIconChange, true, false),
+            (MessageType::ChannelMessagePinned, true, false),
+            (MessageType::UserJoin, true, false),
+            (MessageType::GuildBoost, true, false),
+            (MessageType::GuildBoostTier1, true, false),
+            (MessageType::GuildBoostTier2, true, false),
+            (MessageType::GuildBoostTier3, true, false),
+            (MessageType::ChannelFollowAdd, true, false),
+            (MessageType::GuildDiscoveryDisqualified, true, false),
+            (MessageType::GuildDiscoveryRequalified, true, false),
+            (
+                MessageType::GuildDiscoveryGracePeriodInitialWarning,
+                true,
+                false,
+            ),
+            (
+                MessageType::GuildDiscoveryGracePeriodFinalWarning,
+                true,
+                false,
+            ),
+            (MessageType::ThreadCreated, true, false),
+            (MessageType::Reply, false, false),
+            (MessageType::ChatInputCommand, false, true),
+            (MessageType::ThreadStarterMessage, true, false),
+            (MessageType::GuildInviteReminder, true, false),
+            (MessageType::ContextMenuCommand, false, true),
+            (MessageType::AutoModerationAction, true, false),
+            (MessageType::RoleSubscriptionPurchase, true, false),
+            (MessageType::InteractionPremiumUpsell, true, false),
+            (MessageType::StageStart, true, false),
+            (MessageType::StageEnd, true, false),
+            (MessageType::StageSpeaker, true, false),
+            (MessageType::StageTopic, true, false),
+            (
+                MessageType::GuildApplicationPremiumSubscription,
+                true,
+                false,
+            ),
+            (MessageType::GuildIncidentAlertModeEnabled, true, false),
+            (MessageType::GuildIncidentAlertModeDisabled, true, false),
+            (MessageType::GuildIncidentReportRaid, true, false),
+            (MessageType::GuildIncidentReportRaidFalseAlarm, true, false),
+            (MessageType::Unknown(u8::MAX), true, false),
+        ];
+
+        for (kind, system, interaction_response) in MAP {
+            let mut message = minimal_message();
+            message.kind = *kind;
+            assert_eq!(*system, message.is_system(), "{kind:?}");
+            assert_eq!(
+                *interaction_response,
+                message.is_interaction_response(),
+                "{kind:?}"
+            );
+            assert!(!message.is_from_webhook(), "{kind:?}");
+
+            // Interaction responses are sent via the application's webhook.
+            message.webhook_id = Some(Id::new(5));
+            assert_eq!(!interaction_response, message.is_from_webhook(), "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn interaction_response() {
+        let mut message = minimal_message();
+        message.webhook_id = Some(Id::new(5));
+        message.interaction_metadata = Some(Box::new(
+            serde_json::from_str(
+                r#"{
+                    "authorizing_integration_owners": {},
+                    "id": "6",
+                    "interacted_message_id": "7",
+                    "type": 3,
+                    "user": {
+                        "avatar": null,
+                        "discriminator": "0001",
+                        "id": "8",
+                        "username": "test"
+                    }
+                }"#,
+            )
+            .unwrap(),
+        ));
+
+        // Responses to component interactions are regular messages.
+        assert!(!message.is_system());
+        assert!(message.is_interaction_response());
+        assert!(!message.is_from_webhook());
+    }
+
+    #[test]
+    fn ephemeral() {
+        let mut message = minimal_message();
+        assert!(!message.is_ephemeral());
+
+        message.flags = Some(MessageFlags::LOADING);
+        assert!(!message.is_ephemeral());
+
+        message.flags = Some(MessageFlags::EPHEMERAL | MessageFlags::LOADING);
+        assert!(message.is_ephemeral());
+    }
+
+    #[test]
+    fn author_is_bot_or_system() {
+        let mut message = minimal_message();
+        assert!(!message.author_is_bot_or_system());
+
+        message.author.system = Some(false);
+        assert!(!message.author_is_bot_or_system());
+
+        message.author.system = Some(true);
+        assert!(message.author_is_bot_or_system());
+
+        message.author.system = None;
+        message.author.bot = true;
+        assert!(message.author_is_bot_or_system());
+    }
 }
@@ -55,6 +55,70 @@ pub enum EmojiReactionType {
     },
 }
 
+impl EmojiReactionType {
+    /// Create a reaction of a custom emoji.
+    pub const fn custom(id: Id<EmojiMarker>, name: Option<String>, animated: bool) -> Self {
+        Self::Custom { animated, id, name }
+    }
+
+    /// Create a reaction of a unicode emoji, such as "🌈".
+    pub fn unicode(name: impl Into<String>) -> Self {
+        Self::Unicode { name: name.into() }
+    }
+
+    /// Whether the emoji has the same identity as another.
+    ///
+    /// Like [`ReactionKey`], custom emojis are compared by their ID only, so a
+    /// renamed custom emoji is the same, but without allocating keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::{channel::message::EmojiReactionType, id::Id};
+    ///
+    /// let emoji = EmojiReactionType::custom(Id::new(1), Some("old".to_owned()), false);
+    /// let renamed = EmojiReactionType::custom(Id::new(1), Some("new".to_owned()), false);
+    ///
+    /// assert_ne!(emoji, renamed);
+    /// assert!(emoji.same_emoji(&renamed));
+    /// ```
+    pub fn same_emoji(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+
+    /// Borrowed identity of the emoji.
+    fn key(&self) -> ReactionKeyRef<'_> {
+        match self {
+            Self::Custom { id, .. } => ReactionKeyRef::Custom(*id),
+            Self::Unicode { name } => ReactionKeyRef::Unicode(name),
+        }
+    }
+
+    /// Format the emoji as Discord's reaction endpoints expect.
+    ///
+    /// Custom emojis are formatted as `name:id`, using `e` as the name if it's
+    /// unavailable, while unicode emojis are their value. The value isn't
+    /// percent-encoded for use in URLs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::{channel::message::EmojiReactionType, id::Id};
+    ///
+    /// let custom = EmojiReactionType::custom(Id::new(123), Some("rarity".to_owned()), true);
+    /// assert_eq!("rarity:123", custom.to_reaction_string());
+    ///
+    /// let unicode = EmojiReactionType::unicode("🌈");
+    /// assert_eq!("🌈", unicode.to_reaction_string());
+    /// ```
+    pub fn to_reaction_string(&self) -> String {
+        match self {
+            Self::Custom { id, name, .. } => format!("{}:{id}", name.as_deref().unwrap_or("e")),
+            Self::Unicode { name } => name.clone(),
+        }
+    }
+}
+
 /// Breakdown of normal and super reaction counts for the associated emoji.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub struct ReactionCountDetails {
@@ -64,9 +128,89 @@ pub struct ReactionCountDetails {
     pub normal: u64,
 }
 
+/// Identity of a reaction's emoji, for use as a key in maps and sets.
+///
+/// Custom emojis are identified by their ID only, so a renamed or animated
+/// custom emoji has the same key. Unicode emojis are identified by their exact
+/// value, so skin tone variants have different keys.
+///
+/// Keys compare equal to [`EmojiReactionType`]s with the same identity.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::{
+///     channel::message::{EmojiReactionType, ReactionKey},
+///     id::Id,
+/// };
+///
+/// let emoji = EmojiReactionType::Custom {
+///     animated: false,
+///     id: Id::new(1),
+///     name: Some("old".to_owned()),
+/// };
+/// let renamed = EmojiReactionType::Custom {
+///     animated: false,
+///     id: Id::new(1),
+///     name: Some("new".to_owned()),
+/// };
+///
+/// assert_eq!(ReactionKey::from(&emoji), ReactionKey::from(&renamed));
+/// assert_eq!(ReactionKey::Custom(Id::new(1)), renamed);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ReactionKey {
+    /// ID of a custom emoji.
+    Custom(Id<EmojiMarker>),
+    /// Value of a unicode emoji.
+    Unicode(String),
+}
+
+impl From<&EmojiReactionType> for ReactionKey {
+    fn from(emoji: &EmojiReactionType) -> Self {
+        match emoji {
+            EmojiReactionType::Custom { id, .. } => Self::Custom(*id),
+            EmojiReactionType::Unicode { name } => Self::Unicode(name.clone()),
+        }
+    }
+}
+
+impl From<EmojiReactionType> for ReactionKey {
+    fn from(emoji: EmojiReactionType) -> Self {
+        match emoji {
+            EmojiReactionType::Custom { id, .. } => Self::Custom(id),
+            EmojiReactionType::Unicode { name } => Self::Unicode(name),
+        }
+    }
+}
+
+impl PartialEq<EmojiReactionType> for ReactionKey {
+    fn eq(&self, other: &EmojiReactionType) -> bool {
+        let key = match self {
+            Self::Custom(id) => ReactionKeyRef::Custom(*id),
+            Self::Unicode(name) => ReactionKeyRef::Unicode(name),
+        };
+
+        key == other.key()
+    }
+}
+
+impl PartialEq<ReactionKey> for EmojiReactionType {
+    fn eq(&self, other: &ReactionKey) -> bool {
+        other == self
+    }
+}
+
+/// Borrowed [`ReactionKey`], for comparing emojis without allocating.
+#[derive(Eq, PartialEq)]
+enum ReactionKeyRef<'a> {
+    Custom(Id<EmojiMarker>),
+    Unicode(&'a str),
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{EmojiReactionType, Reaction, ReactionCountDetails};
+    use super::{EmojiReactionType, Reaction, ReactionCountDetails, ReactionKey};
     use crate::{id::Id, util::HexColor};
     use serde_test::Token;
 
@@ -192,4 +336,77 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn constructors() {
+        let custom = EmojiReactionType::custom(Id::new(123), Some("party".to_owned()), true);
+        assert_eq!(
+            EmojiReactionType::Custom {
+                animated: true,
+                id: Id::new(123),
+                name: Some("party".to_owned()),
+            },
+            custom
+        );
+        assert_eq!("party:123", custom.to_reaction_string());
+        assert_eq!(
+            "e:123",
+            EmojiReactionType::custom(Id::new(123), None, false).to_reaction_string()
+        );
+
+        let unicode = EmojiReactionType::unicode("\u{1f44d}\u{1f3fd}");
+        assert_eq!(
+            EmojiReactionType::Unicode {
+                name: "\u{1f44d}\u{1f3fd}".to_owned(),
+            },
+            unicode
+        );
+        assert_eq!("\u{1f44d}\u{1f3fd}", unicode.to_reaction_string());
+    }
+
+    #[test]
+    fn key() {
+        let custom = EmojiReactionType::Custom {
+            animated: false,
+            id: Id::new(1),
+            name: Some("old".to_owned()),
+        };
+        let renamed = EmojiReactionType::Custom {
+            animated: true,
+            id: Id::new(1),
+            name: None,
+        };
+        let other = EmojiReactionType::Custom {
+            animated: false,
+            id: Id::new(2),
+            name: Some("old".to_owned()),
+        };
+
+        assert_eq!(ReactionKey::from(&custom), ReactionKey::from(&renamed));
+        assert_eq!(
+            ReactionKey::from(custom.clone()),
+            ReactionKey::Custom(Id::new(1))
+        );
+        assert_ne!(ReactionKey::from(&custom), ReactionKey::from(&other));
+        assert_eq!(ReactionKey::Custom(Id::new(1)), renamed);
+        assert_eq!(renamed, ReactionKey::Custom(Id::new(1)));
+        assert_ne!(ReactionKey::Custom(Id::new(1)), other);
+
+        let thumbs_up = EmojiReactionType::Unicode {
+            name: "\u{1f44d}".to_owned(),
+        };
+        let thumbs_up_medium = EmojiReactionType::Unicode {
+            name: "\u{1f44d}\u{1f3fd}".to_owned(),
+        };
+
+        assert_eq!(
+            ReactionKey::Unicode("\u{1f44d}".to_owned()),
+            ReactionKey::from(&thumbs_up)
+        );
+        assert_ne!(
+            ReactionKey::from(&thumbs_up),
+            ReactionKey::from(&thumbs_up_medium)
+        );
+        assert_ne!(ReactionKey::from(&thumbs_up), custom);
+    }
 }
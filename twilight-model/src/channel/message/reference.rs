@@ -34,12 +34,74 @@ pub struct MessageReference {
     pub fail_if_not_exists: Option<bool>,
 }
 
+impl MessageReference {
+    /// Create a reference replying to a message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::{channel::message::MessageReference, id::Id};
+    ///
+    /// let reference = MessageReference::reply(Id::new(1), Id::new(2));
+    ///
+    /// assert_eq!(Some(Id::new(1)), reference.channel_id);
+    /// assert_eq!(Some(Id::new(2)), reference.message_id);
+    /// ```
+    pub const fn reply(channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker>) -> Self {
+        Self {
+            channel_id: Some(channel_id),
+            guild_id: None,
+            kind: MessageReferenceType::Default,
+            message_id: Some(message_id),
+            fail_if_not_exists: None,
+        }
+    }
+
+    /// Create a reference forwarding a message.
+    ///
+    /// Unlike replies, the channel of the forwarded message may differ from
+    /// the channel the message is sent to.
+    pub const fn forward(channel_id: Id<ChannelMarker>, message_id: Id<MessageMarker>) -> Self {
+        Self {
+            channel_id: Some(channel_id),
+            guild_id: None,
+            kind: MessageReferenceType::Forward,
+            message_id: Some(message_id),
+            fail_if_not_exists: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::MessageReference;
     use crate::{channel::message::reference_type::MessageReferenceType, id::Id};
     use serde_test::Token;
 
+    #[test]
+    fn constructors() {
+        let reply = MessageReference::reply(Id::new(1), Id::new(2));
+        assert_eq!(
+            MessageReference {
+                channel_id: Some(Id::new(1)),
+                guild_id: None,
+                kind: MessageReferenceType::Default,
+                message_id: Some(Id::new(2)),
+                fail_if_not_exists: None,
+            },
+            reply
+        );
+
+        let forward = MessageReference::forward(Id::new(1), Id::new(2));
+        assert_eq!(
+            MessageReference {
+                kind: MessageReferenceType::Forward,
+                ..reply
+            },
+            forward
+        );
+    }
+
     #[test]
     fn minimal() {
         let value = MessageReference {
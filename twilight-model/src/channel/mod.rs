@@ -29,7 +29,7 @@ pub use self::{
 use crate::{
     channel::{
         forum::{DefaultReaction, ForumLayout, ForumSortOrder, ForumTag},
-        permission_overwrite::PermissionOverwrite,
+        permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
         thread::{AutoArchiveDuration, ThreadMember, ThreadMetadata},
     },
     id::{
@@ -132,6 +132,12 @@ pub struct Channel {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub member_count: Option<i8>,
     /// Number of messages in the channel.
+    ///
+    /// For threads, this doesn't include the initial message or deleted
+    /// messages. Refer to [`total_message_sent`] for a count that includes
+    /// deleted messages.
+    ///
+    /// [`total_message_sent`]: Self::total_message_sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_count: Option<u32>,
     /// Name of the channel.
@@ -176,6 +182,14 @@ pub struct Channel {
     /// Topic of the channel.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<String>,
+    /// Number of messages ever sent in a thread.
+    ///
+    /// Similar to [`message_count`] but isn't decremented when messages are
+    /// deleted.
+    ///
+    /// [`message_count`]: Self::message_count
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_message_sent: Option<u32>,
     /// Number of users that may be in the channel.
     ///
     /// Zero refers to no limit.
@@ -188,11 +202,36 @@ pub struct Channel {
     pub video_quality_mode: Option<VideoQualityMode>,
 }
 
+impl Channel {
+    /// Permission overwrites of the channel.
+    ///
+    /// Empty if the channel can't have permission overwrites, such as private
+    /// channels and threads.
+    pub fn permission_overwrites(&self) -> &[PermissionOverwrite] {
+        self.permission_overwrites.as_deref().unwrap_or_default()
+    }
+
+    /// Permission overwrite of the channel for a member or role, if any.
+    pub fn overwrite_for(
+        &self,
+        target_id: Id<GenericMarker>,
+        kind: PermissionOverwriteType,
+    ) -> Option<&PermissionOverwrite> {
+        self.permission_overwrites()
+            .iter()
+            .find(|overwrite| overwrite.id == target_id && overwrite.kind == kind)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{AutoArchiveDuration, Channel, ChannelType, ThreadMember, ThreadMetadata};
     use crate::{
-        channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        channel::{
+            forum::{DefaultReaction, ForumLayout, ForumSortOrder, ForumTag},
+            permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+            ChannelFlags,
+        },
         guild::Permissions,
         id::Id,
         util::Timestamp,
@@ -263,6 +302,7 @@ mod tests {
             rtc_region: None,
             thread_metadata: None,
             topic: Some("a".to_owned()),
+            total_message_sent: None,
             user_limit: None,
             video_quality_mode: None,
         };
@@ -306,6 +346,7 @@ mod tests {
             rtc_region: None,
             thread_metadata: None,
             topic: None,
+            total_message_sent: None,
             user_limit: None,
             video_quality_mode: None,
         };
@@ -325,6 +366,161 @@ mod tests {
         );
     }
 
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn guild_forum_channel_deserialization() {
+        let value = Channel {
+            application_id: None,
+            applied_tags: None,
+            available_tags: Some(Vec::from([
+                ForumTag {
+                    emoji_id: Some(Id::new(4)),
+                    emoji_name: None,
+                    id: Id::new(3),
+                    moderated: true,
+                    name: "solved".to_owned(),
+                },
+                ForumTag {
+                    emoji_id: None,
+                    emoji_name: Some("🦀".to_owned()),
+                    id: Id::new(5),
+                    moderated: false,
+                    name: "rust".to_owned(),
+                },
+            ])),
+            bitrate: None,
+            default_auto_archive_duration: Some(AutoArchiveDuration::Day),
+            default_forum_layout: Some(ForumLayout::GalleryView),
+            default_reaction_emoji: Some(DefaultReaction {
+                emoji_id: None,
+                emoji_name: Some("👍".to_owned()),
+            }),
+            default_sort_order: Some(ForumSortOrder::CreationDate),
+            default_thread_rate_limit_per_user: Some(60),
+            flags: Some(ChannelFlags::REQUIRE_TAG),
+            guild_id: Some(Id::new(2)),
+            icon: None,
+            id: Id::new(1),
+            invitable: None,
+            kind: ChannelType::GuildForum,
+            last_message_id: Some(Id::new(6)),
+            last_pin_timestamp: None,
+            managed: None,
+            member: None,
+            member_count: None,
+            message_count: None,
+            name: Some("help".to_owned()),
+            newly_created: None,
+            nsfw: Some(false),
+            owner_id: None,
+            parent_id: None,
+            permission_overwrites: Some(Vec::new()),
+            position: Some(0),
+            rate_limit_per_user: Some(0),
+            recipients: None,
+            rtc_region: None,
+            thread_metadata: None,
+            topic: Some("ask questions here".to_owned()),
+            total_message_sent: None,
+            user_limit: None,
+            video_quality_mode: None,
+        };
+        let input = serde_json::json!({
+            "available_tags": [
+                {
+                    "emoji_id": "4",
+                    "emoji_name": null,
+                    "id": "3",
+                    "moderated": true,
+                    "name": "solved",
+                },
+                {
+                    "emoji_id": null,
+                    "emoji_name": "🦀",
+                    "id": "5",
+                    "moderated": false,
+                    "name": "rust",
+                },
+            ],
+            "default_auto_archive_duration": 1440,
+            "default_forum_layout": 2,
+            "default_reaction_emoji": {
+                "emoji_id": null,
+                "emoji_name": "👍",
+            },
+            "default_sort_order": 1,
+            "default_thread_rate_limit_per_user": 60,
+            "flags": 16,
+            "guild_id": "2",
+            "id": "1",
+            "last_message_id": "6",
+            "name": "help",
+            "nsfw": false,
+            "parent_id": null,
+            "permission_overwrites": [],
+            "position": 0,
+            "rate_limit_per_user": 0,
+            "topic": "ask questions here",
+            "type": 15,
+        });
+
+        assert_eq!(value, serde_json::from_value(input).unwrap());
+        assert_eq!(
+            value,
+            serde_json::from_value(serde_json::to_value(&value).unwrap()).unwrap()
+        );
+
+        let thread: Channel = serde_json::from_value(serde_json::json!({
+            "applied_tags": ["3", "5"],
+            "id": "7",
+            "parent_id": "1",
+            "type": 11,
+        }))
+        .unwrap();
+        assert_eq!(
+            Some(Vec::from([Id::new(3), Id::new(5)])),
+            thread.applied_tags
+        );
+    }
+
+    #[test]
+    fn permission_overwrites() {
+        let overwrite = |id, kind| PermissionOverwrite {
+            allow: Permissions::SEND_MESSAGES,
+            deny: Permissions::empty(),
+            id: Id::new(id),
+            kind,
+        };
+
+        let mut channel: Channel = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "recipients": [],
+            "type": 1,
+        }))
+        .unwrap();
+        assert!(channel.permission_overwrites().is_empty());
+        assert!(channel
+            .overwrite_for(Id::new(2), PermissionOverwriteType::Role)
+            .is_none());
+
+        channel.permission_overwrites = Some(Vec::from([
+            overwrite(2, PermissionOverwriteType::Role),
+            overwrite(3, PermissionOverwriteType::Member),
+        ]));
+        assert_eq!(channel.permission_overwrites().len(), 2);
+        assert_eq!(
+            channel.overwrite_for(Id::new(2), PermissionOverwriteType::Role),
+            Some(&overwrite(2, PermissionOverwriteType::Role))
+        );
+        assert_eq!(
+            channel.overwrite_for(Id::new(3), PermissionOverwriteType::Member),
+            Some(&overwrite(3, PermissionOverwriteType::Member))
+        );
+        assert!(channel
+            .overwrite_for(Id::new(2), PermissionOverwriteType::Member)
+            .is_none());
+    }
+
     #[test]
     fn guild_announcement_channel_deserialization() {
         let value = Channel {
@@ -361,6 +557,7 @@ mod tests {
             rtc_region: None,
             thread_metadata: None,
             topic: Some("a news channel".to_owned()),
+            total_message_sent: None,
             user_limit: None,
             video_quality_mode: None,
         };
@@ -437,6 +634,7 @@ mod tests {
                 locked: false,
             }),
             topic: None,
+            total_message_sent: None,
             user_limit: None,
             video_quality_mode: None,
         };
@@ -527,6 +725,7 @@ mod tests {
                 locked: false,
             }),
             topic: None,
+            total_message_sent: Some(60),
             user_limit: None,
             video_quality_mode: None,
         };
@@ -559,7 +758,8 @@ mod tests {
                     "auto_archive_duration": AutoArchiveDuration::Day,
                     "create_timestamp": timestamp,
                     "locked": false
-                }
+                },
+                "total_message_sent": 60
             }))
             .unwrap()
         )
@@ -625,6 +825,7 @@ mod tests {
                 locked: false,
             }),
             topic: None,
+            total_message_sent: None,
             user_limit: None,
             video_quality_mode: None,
         };
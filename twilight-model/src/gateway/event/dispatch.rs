@@ -1,8 +1,9 @@
 use super::{super::payload::incoming::*, Event, EventConversionError, EventType};
 use serde::{
-    de::{Deserialize, DeserializeSeed, Deserializer, Error as DeError, IgnoredAny},
+    de::{Deserialize, DeserializeSeed, Deserializer, IgnoredAny},
     Serialize,
 };
+use serde_value::Value;
 
 /// A dispatch event, containing information about a created guild, a member
 /// added, etc.
@@ -77,6 +78,7 @@ pub enum DispatchEvent {
     ThreadUpdate(Box<ThreadUpdate>),
     TypingStart(Box<TypingStart>),
     UnavailableGuild(UnavailableGuild),
+    Unknown(UnknownEvent),
     UserUpdate(UserUpdate),
     VoiceServerUpdate(VoiceServerUpdate),
     VoiceStateUpdate(Box<VoiceStateUpdate>),
@@ -150,6 +152,7 @@ impl DispatchEvent {
             Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
+            Self::Unknown(_) => EventType::Unknown,
             Self::UserUpdate(_) => EventType::UserUpdate,
             Self::VoiceServerUpdate(_) => EventType::VoiceServerUpdate,
             Self::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
@@ -222,6 +225,7 @@ impl TryFrom<Event> for DispatchEvent {
             Event::ThreadUpdate(v) => Self::ThreadUpdate(v),
             Event::TypingStart(v) => Self::TypingStart(v),
             Event::UnavailableGuild(v) => Self::UnavailableGuild(v),
+            Event::Unknown(v) => Self::Unknown(v),
             Event::UserUpdate(v) => Self::UserUpdate(v),
             Event::VoiceServerUpdate(v) => Self::VoiceServerUpdate(v),
             Event::VoiceStateUpdate(v) => Self::VoiceStateUpdate(v),
@@ -235,6 +239,8 @@ impl TryFrom<Event> for DispatchEvent {
 /// Deserialize into a [`DispatchEvent`] by knowing its event name.
 ///
 /// An event name is something like `"CHANNEL_CREATE"` or `"GUILD_MEMBER_ADD"`.
+/// Events with a name unknown to twilight deserialize into
+/// [`DispatchEvent::Unknown`], preserving their data.
 #[derive(PartialEq, Eq)]
 pub struct DispatchEventWithTypeDeserializer<'a>(&'a str);
 
@@ -435,7 +441,10 @@ impl<'de> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'_> {
             "WEBHOOKS_UPDATE" => {
                 DispatchEvent::WebhooksUpdate(WebhooksUpdate::deserialize(deserializer)?)
             }
-            other => return Err(DeError::unknown_variant(other, &[])),
+            other => DispatchEvent::Unknown(UnknownEvent {
+                data: Value::deserialize(deserializer)?,
+                kind: other.to_owned(),
+            }),
         })
     }
 }
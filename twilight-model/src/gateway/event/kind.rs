@@ -87,6 +87,8 @@ pub enum EventType {
     ThreadUpdate,
     TypingStart,
     UnavailableGuild,
+    /// Dispatch event of a type unknown to twilight.
+    Unknown,
     UserUpdate,
     VoiceServerUpdate,
     VoiceStateUpdate,
@@ -168,7 +170,8 @@ impl EventType {
             | Self::GatewayHeartbeatAck
             | Self::GatewayHello
             | Self::GatewayInvalidateSession
-            | Self::GatewayReconnect => None,
+            | Self::GatewayReconnect
+            | Self::Unknown => None,
         }
     }
 }
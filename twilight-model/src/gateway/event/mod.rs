@@ -171,6 +171,8 @@ pub enum Event {
     TypingStart(Box<TypingStart>),
     /// A guild is now unavailable.
     UnavailableGuild(UnavailableGuild),
+    /// A dispatch event of a type unknown to twilight was received.
+    Unknown(UnknownEvent),
     /// The current user was updated.
     UserUpdate(UserUpdate),
     /// A voice server update was sent.
@@ -257,6 +259,7 @@ impl Event {
             | Event::GatewayReconnect
             | Event::Ready(_)
             | Event::Resumed
+            | Event::Unknown(_)
             | Event::UserUpdate(_) => None,
         }
     }
@@ -332,6 +335,7 @@ impl Event {
             Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
+            Self::Unknown(_) => EventType::Unknown,
             Self::UserUpdate(_) => EventType::UserUpdate,
             Self::VoiceServerUpdate(_) => EventType::VoiceServerUpdate,
             Self::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
@@ -410,6 +414,7 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::ThreadUpdate(v) => Self::ThreadUpdate(v),
             DispatchEvent::TypingStart(v) => Self::TypingStart(v),
             DispatchEvent::UnavailableGuild(v) => Self::UnavailableGuild(v),
+            DispatchEvent::Unknown(v) => Self::Unknown(v),
             DispatchEvent::UserUpdate(v) => Self::UserUpdate(v),
             DispatchEvent::VoiceServerUpdate(v) => Self::VoiceServerUpdate(v),
             DispatchEvent::VoiceStateUpdate(v) => Self::VoiceStateUpdate(v),
@@ -547,6 +552,7 @@ mod tests {
     const_assert!(mem::size_of::<ThreadListSync>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<ThreadMembersUpdate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<UnavailableGuild>() <= EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<UnknownEvent>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<UserUpdate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<VoiceServerUpdate>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<WebhooksUpdate>() <= EVENT_THRESHOLD);
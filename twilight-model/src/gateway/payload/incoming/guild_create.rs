@@ -2,18 +2,35 @@ use crate::{
     guild::{Guild, UnavailableGuild},
     id::{marker::GuildMarker, Id},
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_value::{DeserializerError, Value};
 
-// Developer note: Do not change order as we want unavailable to fail
-// first.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// Guild became available, or is unavailable due to an outage.
+///
+/// Deserialized as [`Unavailable`] if the payload's `unavailable` field is
+/// `true`, and as [`Available`] otherwise.
+///
+/// [`Available`]: Self::Available
+/// [`Unavailable`]: Self::Unavailable
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum GuildCreate {
+    /// Stub of a guild that is unavailable due to an outage.
     Unavailable(UnavailableGuild),
+    /// Full guild.
     Available(Guild),
 }
 
 impl GuildCreate {
+    /// Full guild, if it's available.
+    #[deprecated(note = "match on `GuildCreate::Available` instead")]
+    pub const fn guild(&self) -> Option<&Guild> {
+        match self {
+            GuildCreate::Available(g) => Some(g),
+            GuildCreate::Unavailable(_) => None,
+        }
+    }
+
     /// ID of the guild.
     pub const fn id(&self) -> Id<GuildMarker> {
         match self {
@@ -23,6 +40,27 @@ impl GuildCreate {
     }
 }
 
+impl<'de> Deserialize<'de> for GuildCreate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        let unavailable = match &value {
+            Value::Map(map) => matches!(
+                map.get(&Value::String("unavailable".to_owned())),
+                Some(Value::Bool(true))
+            ),
+            _ => false,
+        };
+
+        if unavailable {
+            value.deserialize_into().map(Self::Unavailable)
+        } else {
+            value.deserialize_into().map(Self::Available)
+        }
+        .map_err(DeserializerError::into_error)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_test::Token;
@@ -31,6 +69,84 @@ mod tests {
 
     use super::GuildCreate;
 
+    /// Minimal JSON of an available guild, with its `unavailable` field.
+    fn available_guild(unavailable: &str) -> String {
+        format!(
+            r#"{{
+                "afk_channel_id": null,
+                "afk_timeout": 300,
+                "application_id": null,
+                "banner": null,
+                "default_message_notifications": 0,
+                "description": null,
+                "discovery_splash": null,
+                "emojis": [],
+                "explicit_content_filter": 0,
+                "features": [],
+                "icon": null,
+                "id": "1234",
+                "mfa_level": 0,
+                "name": "guild",
+                "nsfw_level": 0,
+                "owner_id": "5678",
+                "preferred_locale": "en-US",
+                "premium_progress_bar_enabled": false,
+                "premium_tier": 0,
+                "roles": [],
+                "rules_channel_id": null,
+                "splash": null,
+                "system_channel_flags": 0,
+                "system_channel_id": null,
+                {unavailable}
+                "vanity_url_code": null,
+                "verification_level": 0
+            }}"#
+        )
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn available_guild_json() {
+        for unavailable in ["", r#""unavailable": false,"#] {
+            let guild_create =
+                serde_json::from_str::<GuildCreate>(&available_guild(unavailable)).unwrap();
+
+            let GuildCreate::Available(guild) = &guild_create else {
+                panic!("expected available guild: {guild_create:?}");
+            };
+            assert_eq!(Id::new(1234), guild.id);
+            assert_eq!("guild", guild.name);
+            assert_eq!(Some(guild), guild_create.guild());
+            assert_eq!(Id::new(1234), guild_create.id());
+        }
+    }
+
+    /// Test that malformed available guilds report why they're malformed.
+    #[test]
+    fn available_guild_error() {
+        let input = available_guild("").replace(r#""name": "guild","#, "");
+        let error = serde_json::from_str::<GuildCreate>(&input).unwrap_err();
+
+        assert!(error.to_string().contains("name"), "{error}");
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn unavailable_guild_json() {
+        let input = r#"{"id": "1234", "unavailable": true}"#;
+        let guild_create = serde_json::from_str::<GuildCreate>(input).unwrap();
+
+        assert_eq!(
+            GuildCreate::Unavailable(UnavailableGuild {
+                id: Id::new(1234),
+                unavailable: true,
+            }),
+            guild_create
+        );
+        assert!(guild_create.guild().is_none());
+        assert_eq!(Id::new(1234), guild_create.id());
+    }
+
     #[test]
     fn unavailable_guild() {
         let expected = GuildCreate::Unavailable(UnavailableGuild {
@@ -21,3 +21,68 @@ impl DerefMut for GuildScheduledEventCreate {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GuildScheduledEventCreate;
+    use crate::{
+        gateway::event::{DispatchEvent, DispatchEventWithTypeDeserializer},
+        guild::scheduled_event::{
+            EntityMetadata, EntityType, GuildScheduledEvent, PrivacyLevel, Status,
+        },
+        id::Id,
+        util::Timestamp,
+    };
+    use serde::de::DeserializeSeed;
+    use std::error::Error;
+
+    #[test]
+    fn external_location() -> Result<(), Box<dyn Error>> {
+        let value = GuildScheduledEventCreate(GuildScheduledEvent {
+            channel_id: None,
+            creator: None,
+            creator_id: Some(Id::new(4)),
+            description: None,
+            entity_id: None,
+            entity_metadata: Some(EntityMetadata {
+                location: Some("the park".to_owned()),
+            }),
+            entity_type: EntityType::External,
+            guild_id: Id::new(1),
+            id: Id::new(2),
+            image: None,
+            name: "picnic".to_owned(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            scheduled_end_time: Some(Timestamp::parse("2022-01-01T02:00:00.000000+00:00")?),
+            scheduled_start_time: Timestamp::parse("2022-01-01T00:00:00.000000+00:00")?,
+            status: Status::Scheduled,
+            user_count: None,
+        });
+        let json = serde_json::json!({
+            "creator_id": "4",
+            "entity_metadata": {
+                "location": "the park"
+            },
+            "entity_type": 3,
+            "guild_id": "1",
+            "id": "2",
+            "name": "picnic",
+            "privacy_level": 2,
+            "scheduled_end_time": "2022-01-01T02:00:00.000000+00:00",
+            "scheduled_start_time": "2022-01-01T00:00:00.000000+00:00",
+            "status": 1
+        });
+
+        assert_eq!(value, serde_json::from_value(json.clone())?);
+        assert_eq!(json, serde_json::to_value(&value)?);
+
+        let deserializer = DispatchEventWithTypeDeserializer::new("GUILD_SCHEDULED_EVENT_CREATE");
+        let event = deserializer.deserialize(json)?;
+        assert!(matches!(
+            event,
+            DispatchEvent::GuildScheduledEventCreate(create) if *create == value
+        ));
+
+        Ok(())
+    }
+}
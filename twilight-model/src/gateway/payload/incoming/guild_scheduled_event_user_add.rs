@@ -14,3 +14,53 @@ pub struct GuildScheduledEventUserAdd {
     /// ID of the user who has subscribed to the guild scheduled event.
     pub user_id: Id<UserMarker>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GuildScheduledEventUserAdd;
+    use crate::{
+        gateway::event::{DispatchEvent, DispatchEventWithTypeDeserializer},
+        id::Id,
+    };
+    use serde::de::DeserializeSeed;
+    use serde_test::Token;
+
+    #[test]
+    fn user_add() {
+        let value = GuildScheduledEventUserAdd {
+            guild_id: Id::new(1),
+            guild_scheduled_event_id: Id::new(2),
+            user_id: Id::new(3),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildScheduledEventUserAdd",
+                    len: 3,
+                },
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("guild_scheduled_event_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("user_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::StructEnd,
+            ],
+        );
+
+        let deserializer = DispatchEventWithTypeDeserializer::new("GUILD_SCHEDULED_EVENT_USER_ADD");
+        let event = deserializer
+            .deserialize(serde_json::json!({
+                "guild_id": "1",
+                "guild_scheduled_event_id": "2",
+                "user_id": "3"
+            }))
+            .unwrap();
+        assert_eq!(DispatchEvent::GuildScheduledEventUserAdd(value), event);
+    }
+}
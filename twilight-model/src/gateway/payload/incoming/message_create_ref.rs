@@ -0,0 +1,142 @@
+use crate::{
+    channel::message::MessageType,
+    id::{
+        marker::{
+            ChannelMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker, WebhookMarker,
+        },
+        Id,
+    },
+    util::Timestamp,
+};
+use serde::Deserialize;
+use std::borrow::Cow;
+
+/// Subset of a [`MessageCreate`] borrowing its strings from the event's JSON.
+///
+/// Strings are only allocated if they contain escape sequences, avoiding
+/// allocations for most messages. Fields not present here must be
+/// deserialized via the owned [`MessageCreate`].
+///
+/// [`MessageCreate`]: super::MessageCreate
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct MessageCreateRef<'a> {
+    /// Author of the message.
+    #[serde(borrow)]
+    pub author: MessageCreateAuthorRef<'a>,
+    /// ID of the channel the message was sent in.
+    pub channel_id: Id<ChannelMarker>,
+    /// Content of the message.
+    #[serde(borrow)]
+    pub content: Cow<'a, str>,
+    /// ID of the guild the message was sent in.
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// ID of the message.
+    pub id: Id<MessageMarker>,
+    /// Type of message.
+    #[serde(rename = "type")]
+    pub kind: MessageType,
+    /// Whether the message mentions everyone.
+    #[serde(default)]
+    pub mention_everyone: bool,
+    /// IDs of the roles mentioned in the message.
+    #[serde(default)]
+    pub mention_roles: Vec<Id<RoleMarker>>,
+    /// When the message was sent.
+    pub timestamp: Timestamp,
+    /// ID of the webhook that sent the message.
+    pub webhook_id: Option<Id<WebhookMarker>>,
+}
+
+/// Author of a [`MessageCreateRef`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct MessageCreateAuthorRef<'a> {
+    /// Whether the author is a bot.
+    #[serde(default)]
+    pub bot: bool,
+    /// ID of the author.
+    pub id: Id<UserMarker>,
+    /// Username of the author.
+    #[serde(borrow, rename = "username")]
+    pub name: Cow<'a, str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MessageCreateAuthorRef, MessageCreateRef};
+    use crate::{channel::message::MessageType, id::Id, util::Timestamp};
+    use static_assertions::assert_impl_all;
+    use std::{borrow::Cow, fmt::Debug};
+
+    assert_impl_all!(MessageCreateRef<'_>: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MessageCreateAuthorRef<'_>: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn message_create_ref() {
+        let input = r#"{
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "discriminator": "0001",
+                "id": "3",
+                "username": "test"
+            },
+            "channel_id": "2",
+            "content": "ping",
+            "edited_timestamp": null,
+            "embeds": [],
+            "guild_id": "1",
+            "id": "4",
+            "mention_everyone": false,
+            "mention_roles": ["5"],
+            "mentions": [],
+            "pinned": false,
+            "timestamp": "2021-09-19T17:30:45.000000+00:00",
+            "tts": false,
+            "type": 0
+        }"#;
+
+        let value = serde_json::from_str::<MessageCreateRef<'_>>(input).unwrap();
+
+        assert_eq!(
+            value,
+            MessageCreateRef {
+                author: MessageCreateAuthorRef {
+                    bot: false,
+                    id: Id::new(3),
+                    name: Cow::Borrowed("test"),
+                },
+                channel_id: Id::new(2),
+                content: Cow::Borrowed("ping"),
+                guild_id: Some(Id::new(1)),
+                id: Id::new(4),
+                kind: MessageType::Regular,
+                mention_everyone: false,
+                mention_roles: vec![Id::new(5)],
+                timestamp: Timestamp::from_secs(1_632_072_645).unwrap(),
+                webhook_id: None,
+            }
+        );
+        assert!(matches!(value.content, Cow::Borrowed(_)));
+        assert!(matches!(value.author.name, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn escaped_content() {
+        let input = r#"{
+            "author": {
+                "id": "3",
+                "username": "test"
+            },
+            "channel_id": "2",
+            "content": "line\nbreak",
+            "id": "4",
+            "timestamp": "2021-09-19T17:30:45.000000+00:00",
+            "type": 0
+        }"#;
+
+        let value = serde_json::from_str::<MessageCreateRef<'_>>(input).unwrap();
+
+        assert_eq!(value.content, "line\nbreak");
+        assert!(matches!(value.content, Cow::Owned(_)));
+    }
+}
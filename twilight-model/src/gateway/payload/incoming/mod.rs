@@ -51,6 +51,7 @@ mod member_chunk;
 mod member_remove;
 mod member_update;
 mod message_create;
+mod message_create_ref;
 mod message_delete;
 mod message_delete_bulk;
 mod message_poll_vote_add;
@@ -75,6 +76,7 @@ mod thread_members_update;
 mod thread_update;
 mod typing_start;
 mod unavailable_guild;
+mod unknown_event;
 mod user_update;
 mod voice_server_update;
 mod voice_state_update;
@@ -84,35 +86,70 @@ pub use self::{
     auto_moderation_action_execution::AutoModerationActionExecution,
     auto_moderation_rule_create::AutoModerationRuleCreate,
     auto_moderation_rule_delete::AutoModerationRuleDelete,
-    auto_moderation_rule_update::AutoModerationRuleUpdate, ban_add::BanAdd, ban_remove::BanRemove,
-    channel_create::ChannelCreate, channel_delete::ChannelDelete,
-    channel_pins_update::ChannelPinsUpdate, channel_update::ChannelUpdate,
-    command_permissions_update::CommandPermissionsUpdate, entitlement_create::EntitlementCreate,
-    entitlement_delete::EntitlementDelete, entitlement_update::EntitlementUpdate,
-    guild_audit_log_entry_create::GuildAuditLogEntryCreate, guild_create::GuildCreate,
-    guild_delete::GuildDelete, guild_emojis_update::GuildEmojisUpdate,
+    auto_moderation_rule_update::AutoModerationRuleUpdate,
+    ban_add::BanAdd,
+    ban_remove::BanRemove,
+    channel_create::ChannelCreate,
+    channel_delete::ChannelDelete,
+    channel_pins_update::ChannelPinsUpdate,
+    channel_update::ChannelUpdate,
+    command_permissions_update::CommandPermissionsUpdate,
+    entitlement_create::EntitlementCreate,
+    entitlement_delete::EntitlementDelete,
+    entitlement_update::EntitlementUpdate,
+    guild_audit_log_entry_create::GuildAuditLogEntryCreate,
+    guild_create::GuildCreate,
+    guild_delete::GuildDelete,
+    guild_emojis_update::GuildEmojisUpdate,
     guild_integrations_update::GuildIntegrationsUpdate,
     guild_scheduled_event_create::GuildScheduledEventCreate,
     guild_scheduled_event_delete::GuildScheduledEventDelete,
     guild_scheduled_event_update::GuildScheduledEventUpdate,
     guild_scheduled_event_user_add::GuildScheduledEventUserAdd,
     guild_scheduled_event_user_remove::GuildScheduledEventUserRemove,
-    guild_stickers_update::GuildStickersUpdate, guild_update::GuildUpdate, hello::Hello,
-    integration_create::IntegrationCreate, integration_delete::IntegrationDelete,
-    integration_update::IntegrationUpdate, interaction_create::InteractionCreate,
-    invite_create::InviteCreate, invite_delete::InviteDelete, member_add::MemberAdd,
-    member_chunk::MemberChunk, member_remove::MemberRemove, member_update::MemberUpdate,
-    message_create::MessageCreate, message_delete::MessageDelete,
-    message_delete_bulk::MessageDeleteBulk, message_poll_vote_add::MessagePollVoteAdd,
-    message_poll_vote_remove::MessagePollVoteRemove, message_update::MessageUpdate,
-    presence_update::PresenceUpdate, reaction_add::ReactionAdd, reaction_remove::ReactionRemove,
-    reaction_remove_all::ReactionRemoveAll, reaction_remove_emoji::ReactionRemoveEmoji,
-    ready::Ready, role_create::RoleCreate, role_delete::RoleDelete, role_update::RoleUpdate,
-    stage_instance_create::StageInstanceCreate, stage_instance_delete::StageInstanceDelete,
-    stage_instance_update::StageInstanceUpdate, thread_create::ThreadCreate,
-    thread_delete::ThreadDelete, thread_list_sync::ThreadListSync,
-    thread_member_update::ThreadMemberUpdate, thread_members_update::ThreadMembersUpdate,
-    thread_update::ThreadUpdate, typing_start::TypingStart, unavailable_guild::UnavailableGuild,
-    user_update::UserUpdate, voice_server_update::VoiceServerUpdate,
-    voice_state_update::VoiceStateUpdate, webhooks_update::WebhooksUpdate,
+    guild_stickers_update::GuildStickersUpdate,
+    guild_update::GuildUpdate,
+    hello::Hello,
+    integration_create::IntegrationCreate,
+    integration_delete::IntegrationDelete,
+    integration_update::IntegrationUpdate,
+    interaction_create::InteractionCreate,
+    invite_create::InviteCreate,
+    invite_delete::InviteDelete,
+    member_add::MemberAdd,
+    member_chunk::MemberChunk,
+    member_remove::MemberRemove,
+    member_update::MemberUpdate,
+    message_create::MessageCreate,
+    message_create_ref::{MessageCreateAuthorRef, MessageCreateRef},
+    message_delete::MessageDelete,
+    message_delete_bulk::MessageDeleteBulk,
+    message_poll_vote_add::MessagePollVoteAdd,
+    message_poll_vote_remove::MessagePollVoteRemove,
+    message_update::MessageUpdate,
+    presence_update::PresenceUpdate,
+    reaction_add::ReactionAdd,
+    reaction_remove::ReactionRemove,
+    reaction_remove_all::ReactionRemoveAll,
+    reaction_remove_emoji::ReactionRemoveEmoji,
+    ready::Ready,
+    role_create::RoleCreate,
+    role_delete::RoleDelete,
+    role_update::RoleUpdate,
+    stage_instance_create::StageInstanceCreate,
+    stage_instance_delete::StageInstanceDelete,
+    stage_instance_update::StageInstanceUpdate,
+    thread_create::ThreadCreate,
+    thread_delete::ThreadDelete,
+    thread_list_sync::ThreadListSync,
+    thread_member_update::ThreadMemberUpdate,
+    thread_members_update::ThreadMembersUpdate,
+    thread_update::ThreadUpdate,
+    typing_start::TypingStart,
+    unavailable_guild::UnavailableGuild,
+    unknown_event::UnknownEvent,
+    user_update::UserUpdate,
+    voice_server_update::VoiceServerUpdate,
+    voice_state_update::VoiceStateUpdate,
+    webhooks_update::WebhooksUpdate,
 };
@@ -18,3 +18,113 @@ impl DerefMut for ReactionAdd {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ReactionAdd;
+    use crate::{
+        channel::message::EmojiReactionType,
+        gateway::{
+            event::{DispatchEvent, DispatchEventWithTypeDeserializer},
+            GatewayReaction,
+        },
+        id::Id,
+        util::HexColor,
+    };
+    use serde::de::DeserializeSeed;
+    use std::str::FromStr;
+
+    fn reaction_add(payload: &str) -> ReactionAdd {
+        let deserializer = DispatchEventWithTypeDeserializer::new("MESSAGE_REACTION_ADD");
+        let mut json = serde_json::Deserializer::from_str(payload);
+
+        match deserializer.deserialize(&mut json).unwrap() {
+            DispatchEvent::ReactionAdd(reaction) => *reaction,
+            event => panic!("unexpected event: {event:?}"),
+        }
+    }
+
+    #[test]
+    fn guild() {
+        let reaction = reaction_add(
+            r##"{
+                "burst": true,
+                "burst_colors": ["#f200ff"],
+                "channel_id": "2",
+                "emoji": {"id": null, "name": "🦀"},
+                "guild_id": "1",
+                "member": {
+                    "communication_disabled_until": null,
+                    "deaf": false,
+                    "flags": 0,
+                    "joined_at": "2020-01-01T00:00:00.000000+00:00",
+                    "mute": false,
+                    "nick": null,
+                    "roles": ["5"],
+                    "user": {
+                        "avatar": null,
+                        "discriminator": "0",
+                        "id": "3",
+                        "username": "twilight"
+                    }
+                },
+                "message_author_id": "4",
+                "message_id": "6",
+                "type": 1,
+                "user_id": "3"
+            }"##,
+        );
+
+        assert!(reaction.burst);
+        assert_eq!(
+            Vec::from([HexColor::from_str("#F200FF").unwrap()]),
+            reaction.burst_colors
+        );
+        assert_eq!(Some(Id::new(1)), reaction.guild_id);
+        assert_eq!(
+            EmojiReactionType::Unicode {
+                name: "🦀".to_owned()
+            },
+            reaction.emoji
+        );
+        assert_eq!(Some(Id::new(4)), reaction.message_author_id);
+
+        let member = reaction.member.as_ref().unwrap();
+        assert_eq!(Vec::from([Id::new(5)]), member.roles);
+        assert_eq!(reaction.user_id, member.user.id);
+    }
+
+    #[test]
+    fn private() {
+        let reaction = reaction_add(
+            r#"{
+                "burst": false,
+                "channel_id": "2",
+                "emoji": {"animated": false, "id": "7", "name": "twilight"},
+                "message_author_id": "4",
+                "message_id": "6",
+                "type": 0,
+                "user_id": "3"
+            }"#,
+        );
+
+        assert_eq!(
+            ReactionAdd(GatewayReaction {
+                burst: false,
+                burst_colors: Vec::new(),
+                channel_id: Id::new(2),
+                emoji: EmojiReactionType::Custom {
+                    animated: false,
+                    id: Id::new(7),
+                    name: Some("twilight".to_owned()),
+                },
+                guild_id: None,
+                member: None,
+                message_author_id: Some(Id::new(4)),
+                message_id: Id::new(6),
+                user_id: Id::new(3),
+            }),
+            reaction
+        );
+    }
+}
@@ -5,6 +5,17 @@ use std::ops::{Deref, DerefMut};
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ThreadCreate(pub Channel);
 
+impl ThreadCreate {
+    /// Whether the thread was just created.
+    ///
+    /// Thread creates are also sent when the current user gains access to an
+    /// existing thread, in which case Discord omits [`Channel::newly_created`]
+    /// and this is `false`.
+    pub fn is_newly_created(&self) -> bool {
+        self.0.newly_created.unwrap_or_default()
+    }
+}
+
 impl Deref for ThreadCreate {
     type Target = Channel;
 
@@ -18,3 +29,56 @@ impl DerefMut for ThreadCreate {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ThreadCreate;
+    use crate::{channel::ChannelType, util::Timestamp};
+
+    fn payload(newly_created: Option<bool>) -> serde_json::Value {
+        let mut value = serde_json::json!({
+            "id": "3",
+            "guild_id": "1",
+            "type": ChannelType::PublicThread,
+            "name": "thread",
+            "owner_id": "4",
+            "parent_id": "2",
+            "thread_metadata": {
+                "archive_timestamp": "2021-09-19T14:17:32.000000+00:00",
+                "archived": false,
+                "auto_archive_duration": 1440,
+                "create_timestamp": "2021-09-19T14:17:32.000000+00:00",
+                "locked": false
+            }
+        });
+
+        if let Some(newly_created) = newly_created {
+            value["newly_created"] = newly_created.into();
+        }
+
+        value
+    }
+
+    #[test]
+    fn newly_created() {
+        let event: ThreadCreate = serde_json::from_value(payload(Some(true))).unwrap();
+
+        assert!(event.is_newly_created());
+        assert_eq!(
+            Some(Timestamp::from_secs(1_632_061_052).unwrap()),
+            event
+                .thread_metadata
+                .as_ref()
+                .and_then(|metadata| metadata.create_timestamp)
+        );
+    }
+
+    /// Threads the current user gained access to don't have the field.
+    #[test]
+    fn gained_access() {
+        let event: ThreadCreate = serde_json::from_value(payload(None)).unwrap();
+
+        assert!(event.newly_created.is_none());
+        assert!(!event.is_newly_created());
+    }
+}
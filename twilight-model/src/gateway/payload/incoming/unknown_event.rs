@@ -0,0 +1,56 @@
+use serde::Serialize;
+use serde_value::Value;
+
+/// Dispatch event of a type unknown to twilight, such as an event newly added
+/// by Discord.
+///
+/// Its data is preserved so that it can be inspected or deserialized into a
+/// user-defined type.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct UnknownEvent {
+    /// Raw data of the event.
+    pub data: Value,
+    /// Name of the event, such as `"GUILD_SOUNDBOARD_SOUND_CREATE"`.
+    pub kind: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnknownEvent;
+    use crate::gateway::event::{DispatchEvent, DispatchEventWithTypeDeserializer, EventType};
+    use serde::de::DeserializeSeed;
+    use serde_value::Value;
+    use static_assertions::assert_impl_all;
+    use std::{collections::BTreeMap, fmt::Debug};
+
+    assert_impl_all!(UnknownEvent: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn unknown_event() {
+        let input = r#"{"guild_id":"1","nested":{"values":[1,true,null]}}"#;
+
+        let mut deserializer = serde_json::Deserializer::from_str(input);
+        let event = DispatchEventWithTypeDeserializer::new("MADE_UP_EVENT")
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(EventType::Unknown, event.kind());
+
+        let DispatchEvent::Unknown(event) = event else {
+            panic!("event is not unknown: {event:?}");
+        };
+        assert_eq!("MADE_UP_EVENT", event.kind);
+
+        let nested = BTreeMap::from([(
+            Value::String("values".to_owned()),
+            Value::Seq(vec![Value::U64(1), Value::Bool(true), Value::Unit]),
+        )]);
+        let data = BTreeMap::from([
+            (
+                Value::String("guild_id".to_owned()),
+                Value::String("1".to_owned()),
+            ),
+            (Value::String("nested".to_owned()), Value::Map(nested)),
+        ]);
+        assert_eq!(Value::Map(data), event.data);
+    }
+}
@@ -1,7 +1,8 @@
 //! Mostly internal custom serde deserializers.
 
-use super::MemberFlags;
+use super::{MemberConversionError, MemberFlags, PartialMember};
 use crate::{
+    application::interaction::InteractionMember,
     id::{marker::RoleMarker, Id},
     user::User,
     util::{ImageHash, Timestamp},
@@ -36,18 +37,191 @@ pub struct Member {
     pub user: User,
 }
 
+/// Convert an interaction member and its user, resolved separately, into a
+/// member.
+///
+/// Interaction members don't include whether the member is deafened or muted,
+/// so both are set to `false`. The member's permissions are lost.
+impl From<(InteractionMember, User)> for Member {
+    fn from((member, user): (InteractionMember, User)) -> Self {
+        Self {
+            avatar: member.avatar,
+            communication_disabled_until: member.communication_disabled_until,
+            deaf: false,
+            flags: member.flags,
+            joined_at: member.joined_at,
+            mute: false,
+            nick: member.nick,
+            pending: member.pending,
+            premium_since: member.premium_since,
+            roles: member.roles,
+            user,
+        }
+    }
+}
+
+/// Convert a partial member with a user into a member.
+///
+/// Partial members don't include whether the member is pending, so it's set
+/// to `false`. The member's permissions are lost.
+///
+/// # Errors
+///
+/// Returns a [`MemberConversionError`] if the partial member has no user.
+impl TryFrom<PartialMember> for Member {
+    type Error = MemberConversionError;
+
+    fn try_from(member: PartialMember) -> Result<Self, Self::Error> {
+        match member.user {
+            Some(user) => Ok(Self {
+                avatar: member.avatar,
+                communication_disabled_until: member.communication_disabled_until,
+                deaf: member.deaf,
+                flags: member.flags,
+                joined_at: member.joined_at,
+                mute: member.mute,
+                nick: member.nick,
+                pending: false,
+                premium_since: member.premium_since,
+                roles: member.roles,
+                user,
+            }),
+            None => Err(MemberConversionError::new(member)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Member;
     use crate::{
-        guild::MemberFlags,
+        application::interaction::InteractionMember,
+        guild::{MemberConversionError, MemberFlags, MemberLike, PartialMember, Permissions},
         id::Id,
         test::image_hash,
         user::User,
         util::datetime::{Timestamp, TimestampParseError},
     };
     use serde_test::Token;
-    use std::str::FromStr;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, str::FromStr};
+
+    assert_impl_all!(Member: From<(InteractionMember, User)>, MemberLike, TryFrom<PartialMember>);
+    assert_impl_all!(MemberConversionError: Debug, Error, Send, Sync);
+
+    fn user() -> User {
+        User {
+            accent_color: None,
+            avatar: None,
+            avatar_decoration: None,
+            avatar_decoration_data: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            global_name: None,
+            id: Id::new(3),
+            locale: None,
+            mfa_enabled: None,
+            name: "twilight".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    /// Discord omits `joined_at` for some members, such as lurkers of a stage.
+    #[test]
+    fn member_without_joined_at() -> Result<(), serde_json::Error> {
+        let member = serde_json::from_str::<Member>(
+            r#"{
+                "communication_disabled_until": null,
+                "deaf": false,
+                "flags": 0,
+                "mute": false,
+                "nick": null,
+                "roles": ["1"],
+                "user": {
+                    "avatar": null,
+                    "discriminator": "0001",
+                    "id": "3",
+                    "username": "twilight"
+                }
+            }"#,
+        )?;
+
+        assert!(member.joined_at.is_none());
+        assert!(MemberLike::joined_at(&member).is_none());
+        assert_eq!([Id::new(1)], member.roles());
+        assert_eq!(user(), member.user);
+
+        Ok(())
+    }
+
+    #[test]
+    fn member_conversions() {
+        let member = Member {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: true,
+            flags: MemberFlags::empty(),
+            joined_at: None,
+            mute: true,
+            nick: Some("twilight".to_owned()),
+            pending: true,
+            premium_since: None,
+            roles: Vec::from([Id::new(1)]),
+            user: user(),
+        };
+
+        let partial_member = PartialMember::from(member.clone());
+        assert_eq!(Some(&member.user), partial_member.user.as_ref());
+        assert_eq!(member.nick(), partial_member.nick());
+        assert!(partial_member.permissions.is_none());
+
+        // Whether the member is pending is lost.
+        let converted = Member::try_from(partial_member).unwrap();
+        assert_eq!(
+            Member {
+                pending: false,
+                ..member.clone()
+            },
+            converted
+        );
+
+        let interaction_member = InteractionMember {
+            avatar: None,
+            communication_disabled_until: None,
+            flags: MemberFlags::empty(),
+            joined_at: None,
+            nick: Some("twilight".to_owned()),
+            pending: true,
+            permissions: Permissions::SEND_MESSAGES,
+            premium_since: None,
+            roles: Vec::from([Id::new(1)]),
+        };
+
+        // Whether the member is deafened or muted is unknown.
+        let converted = Member::from((interaction_member.clone(), user()));
+        assert_eq!(
+            Member {
+                deaf: false,
+                mute: false,
+                ..member
+            },
+            converted
+        );
+
+        let partial_member = PartialMember::from(interaction_member);
+        assert_eq!(Some(Permissions::SEND_MESSAGES), partial_member.permissions);
+        assert!(partial_member.user.is_none());
+
+        let error = Member::try_from(partial_member.clone()).unwrap_err();
+        assert_eq!(&partial_member, error.member_ref());
+        assert_eq!(partial_member, error.into_member());
+    }
 
     #[test]
     fn member_deserializer() -> Result<(), TimestampParseError> {
@@ -0,0 +1,83 @@
+use super::{Member, PartialMember};
+use crate::{
+    application::interaction::InteractionMember,
+    id::{marker::RoleMarker, Id},
+    util::Timestamp,
+};
+
+/// Common accessors of the member types.
+///
+/// Members are sent in different shapes depending on where they come from,
+/// such as [`Member`] in guilds, [`PartialMember`] in messages, and
+/// [`InteractionMember`] in interactions. This allows working with any of them,
+/// such as when calculating permissions or caching members.
+pub trait MemberLike {
+    /// When the member joined the guild.
+    ///
+    /// Not sent for members who haven't joined the guild, such as lurkers of
+    /// a stage.
+    fn joined_at(&self) -> Option<Timestamp>;
+
+    /// Nickname of the member in the guild.
+    fn nick(&self) -> Option<&str>;
+
+    /// When the member started boosting the guild, if they're boosting it.
+    fn premium_since(&self) -> Option<Timestamp>;
+
+    /// IDs of the roles of the member.
+    fn roles(&self) -> &[Id<RoleMarker>];
+}
+
+impl MemberLike for InteractionMember {
+    fn joined_at(&self) -> Option<Timestamp> {
+        self.joined_at
+    }
+
+    fn nick(&self) -> Option<&str> {
+        self.nick.as_deref()
+    }
+
+    fn premium_since(&self) -> Option<Timestamp> {
+        self.premium_since
+    }
+
+    fn roles(&self) -> &[Id<RoleMarker>] {
+        &self.roles
+    }
+}
+
+impl MemberLike for Member {
+    fn joined_at(&self) -> Option<Timestamp> {
+        self.joined_at
+    }
+
+    fn nick(&self) -> Option<&str> {
+        self.nick.as_deref()
+    }
+
+    fn premium_since(&self) -> Option<Timestamp> {
+        self.premium_since
+    }
+
+    fn roles(&self) -> &[Id<RoleMarker>] {
+        &self.roles
+    }
+}
+
+impl MemberLike for PartialMember {
+    fn joined_at(&self) -> Option<Timestamp> {
+        self.joined_at
+    }
+
+    fn nick(&self) -> Option<&str> {
+        self.nick.as_deref()
+    }
+
+    fn premium_since(&self) -> Option<Timestamp> {
+        self.premium_since
+    }
+
+    fn roles(&self) -> &[Id<RoleMarker>] {
+        &self.roles
+    }
+}
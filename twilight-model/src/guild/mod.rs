@@ -27,6 +27,7 @@ mod integration_expire_behavior;
 mod integration_type;
 mod member;
 mod member_flags;
+mod member_like;
 mod mfa_level;
 mod nsfw_level;
 mod partial_guild;
@@ -47,18 +48,36 @@ mod verification_level;
 pub use self::nsfw_level::NSFWLevel;
 pub use self::permissions::Permissions;
 pub use self::{
-    afk_timeout::AfkTimeout, ban::Ban,
-    default_message_notification_level::DefaultMessageNotificationLevel, emoji::Emoji,
-    explicit_content_filter::ExplicitContentFilter, feature::GuildFeature, info::GuildInfo,
-    integration::GuildIntegration, integration_account::IntegrationAccount,
+    afk_timeout::AfkTimeout,
+    ban::Ban,
+    default_message_notification_level::DefaultMessageNotificationLevel,
+    emoji::Emoji,
+    explicit_content_filter::ExplicitContentFilter,
+    feature::GuildFeature,
+    info::GuildInfo,
+    integration::GuildIntegration,
+    integration_account::IntegrationAccount,
     integration_application::IntegrationApplication,
-    integration_expire_behavior::IntegrationExpireBehavior, integration_type::GuildIntegrationType,
-    member::Member, member_flags::MemberFlags, mfa_level::MfaLevel, partial_guild::PartialGuild,
-    partial_member::PartialMember, premium_tier::PremiumTier, preview::GuildPreview,
-    prune::GuildPrune, role::Role, role_flags::RoleFlags, role_position::RolePosition,
-    role_tags::RoleTags, system_channel_flags::SystemChannelFlags,
-    unavailable_guild::UnavailableGuild, vanity_url::VanityUrl,
-    verification_level::VerificationLevel, widget::GuildWidget,
+    integration_expire_behavior::IntegrationExpireBehavior,
+    integration_type::GuildIntegrationType,
+    member::Member,
+    member_flags::MemberFlags,
+    member_like::MemberLike,
+    mfa_level::MfaLevel,
+    partial_guild::PartialGuild,
+    partial_member::{MemberConversionError, PartialMember},
+    premium_tier::PremiumTier,
+    preview::GuildPreview,
+    prune::GuildPrune,
+    role::Role,
+    role_flags::RoleFlags,
+    role_position::RolePosition,
+    role_tags::RoleTags,
+    system_channel_flags::SystemChannelFlags,
+    unavailable_guild::UnavailableGuild,
+    vanity_url::VanityUrl,
+    verification_level::VerificationLevel,
+    widget::GuildWidget,
 };
 
 use super::gateway::presence::PresenceListDeserializer;
@@ -171,6 +190,28 @@ pub struct Guild {
     pub widget_enabled: Option<bool>,
 }
 
+impl Guild {
+    /// Whether the guild has a feature.
+    pub fn has_feature(&self, feature: &GuildFeature) -> bool {
+        self.features.contains(feature)
+    }
+
+    /// Whether the guild has a vanity invite URL, via the
+    /// [`GuildFeature::VanityUrl`] feature.
+    pub fn has_vanity_url(&self) -> bool {
+        self.has_feature(&GuildFeature::VanityUrl)
+    }
+
+    /// Whether the guild is a community guild, via the
+    /// [`GuildFeature::Community`] feature.
+    ///
+    /// Community guilds can enable features such as welcome screens,
+    /// membership screening and announcement channels.
+    pub fn is_community(&self) -> bool {
+        self.has_feature(&GuildFeature::Community)
+    }
+}
+
 impl<'de> Deserialize<'de> for Guild {
     #[allow(clippy::too_many_lines)]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
@@ -1080,4 +1121,75 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn features() -> Result<(), TimestampParseError> {
+        let joined_at = Some(Timestamp::from_str("2015-04-26T06:26:56.936000+00:00")?);
+
+        let mut value = Guild {
+            afk_channel_id: Some(Id::new(2)),
+            afk_timeout: AfkTimeout::FIFTEEN_MINUTES,
+            application_id: Some(Id::new(3)),
+            approximate_member_count: Some(1_200),
+            approximate_presence_count: Some(900),
+            banner: Some(image_hash::BANNER),
+            channels: Vec::new(),
+            default_message_notifications: DefaultMessageNotificationLevel::Mentions,
+            description: Some("a description".to_owned()),
+            discovery_splash: Some(image_hash::SPLASH),
+            emojis: Vec::new(),
+            explicit_content_filter: ExplicitContentFilter::MembersWithoutRole,
+            features: Vec::from([GuildFeature::Banner]),
+            guild_scheduled_events: Vec::new(),
+            icon: Some(image_hash::ICON),
+            id: Id::new(1),
+            joined_at,
+            large: true,
+            max_members: Some(25_000),
+            max_presences: Some(10_000),
+            max_stage_video_channel_users: Some(10),
+            max_video_channel_users: Some(10),
+            member_count: Some(12_000),
+            members: Vec::new(),
+            mfa_level: MfaLevel::Elevated,
+            name: "the name".to_owned(),
+            nsfw_level: NSFWLevel::Default,
+            owner_id: Id::new(5),
+            owner: Some(false),
+            permissions: Some(Permissions::SEND_MESSAGES),
+            preferred_locale: "en-us".to_owned(),
+            premium_progress_bar_enabled: false,
+            premium_subscription_count: Some(3),
+            premium_tier: PremiumTier::Tier1,
+            presences: Vec::new(),
+            public_updates_channel_id: None,
+            roles: Vec::new(),
+            rules_channel_id: Some(Id::new(6)),
+            safety_alerts_channel_id: Some(Id::new(9)),
+            splash: Some(image_hash::SPLASH),
+            stage_instances: Vec::new(),
+            stickers: Vec::new(),
+            system_channel_flags: SystemChannelFlags::SUPPRESS_PREMIUM_SUBSCRIPTIONS,
+            system_channel_id: Some(Id::new(7)),
+            threads: Vec::new(),
+            unavailable: None,
+            vanity_url_code: Some("twilight".to_owned()),
+            verification_level: VerificationLevel::Medium,
+            voice_states: Vec::new(),
+            widget_channel_id: Some(Id::new(8)),
+            widget_enabled: Some(true),
+        };
+
+        assert!(value.has_feature(&GuildFeature::Banner));
+        assert!(!value.has_feature(&GuildFeature::AnimatedBanner));
+        assert!(!value.is_community());
+        assert!(!value.has_vanity_url());
+
+        value.features.push(GuildFeature::Community);
+        value.features.push(GuildFeature::VanityUrl);
+        assert!(value.is_community());
+        assert!(value.has_vanity_url());
+
+        Ok(())
+    }
 }
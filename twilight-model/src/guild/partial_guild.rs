@@ -61,6 +61,28 @@ pub struct PartialGuild {
     pub widget_enabled: Option<bool>,
 }
 
+impl PartialGuild {
+    /// Whether the guild has a feature.
+    pub fn has_feature(&self, feature: &GuildFeature) -> bool {
+        self.features.contains(feature)
+    }
+
+    /// Whether the guild has a vanity invite URL, via the
+    /// [`GuildFeature::VanityUrl`] feature.
+    pub fn has_vanity_url(&self) -> bool {
+        self.has_feature(&GuildFeature::VanityUrl)
+    }
+
+    /// Whether the guild is a community guild.
+    ///
+    /// Refer to [`Guild::is_community`] for more information.
+    ///
+    /// [`Guild::is_community`]: super::Guild::is_community
+    pub fn is_community(&self) -> bool {
+        self.has_feature(&GuildFeature::Community)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -227,4 +249,55 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn features() {
+        let mut value = PartialGuild {
+            afk_channel_id: Some(Id::new(2)),
+            afk_timeout: AfkTimeout::FIFTEEN_MINUTES,
+            application_id: Some(Id::new(3)),
+            banner: Some(image_hash::BANNER),
+            default_message_notifications: DefaultMessageNotificationLevel::Mentions,
+            description: Some("a description".to_owned()),
+            discovery_splash: Some(image_hash::SPLASH),
+            emojis: Vec::new(),
+            explicit_content_filter: ExplicitContentFilter::MembersWithoutRole,
+            features: Vec::from([GuildFeature::AnimatedBanner]),
+            icon: Some(image_hash::ICON),
+            id: Id::new(1),
+            max_members: Some(25_000),
+            max_presences: Some(10_000),
+            member_count: Some(12_000),
+            mfa_level: MfaLevel::Elevated,
+            name: "the name".to_owned(),
+            nsfw_level: NSFWLevel::Default,
+            owner_id: Id::new(5),
+            owner: Some(false),
+            permissions: Some(Permissions::SEND_MESSAGES),
+            preferred_locale: "en-us".to_owned(),
+            premium_progress_bar_enabled: true,
+            premium_subscription_count: Some(3),
+            premium_tier: PremiumTier::Tier1,
+            public_updates_channel_id: None,
+            roles: Vec::new(),
+            rules_channel_id: Some(Id::new(6)),
+            splash: Some(image_hash::SPLASH),
+            system_channel_flags: SystemChannelFlags::SUPPRESS_PREMIUM_SUBSCRIPTIONS,
+            system_channel_id: Some(Id::new(7)),
+            verification_level: VerificationLevel::Medium,
+            vanity_url_code: Some("twilight".to_owned()),
+            widget_channel_id: Some(Id::new(8)),
+            widget_enabled: Some(true),
+        };
+
+        assert!(value.has_feature(&GuildFeature::AnimatedBanner));
+        assert!(!value.has_feature(&GuildFeature::Banner));
+        assert!(!value.is_community());
+        assert!(!value.has_vanity_url());
+
+        value.features.push(GuildFeature::Community);
+        value.features.push(GuildFeature::VanityUrl);
+        assert!(value.is_community());
+        assert!(value.has_vanity_url());
+    }
 }
@@ -1,10 +1,15 @@
 use crate::{
-    guild::Permissions,
+    application::interaction::InteractionMember,
+    guild::{Member, Permissions},
     id::{marker::RoleMarker, Id},
     user::User,
     util::{ImageHash, Timestamp},
 };
 use serde::{Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 
 use super::MemberFlags;
 
@@ -32,6 +37,81 @@ pub struct PartialMember {
     pub user: Option<User>,
 }
 
+/// Convert an interaction member into a partial member without a user.
+///
+/// Interaction members don't include whether the member is deafened or muted,
+/// so both are set to `false`. Whether the member is pending is lost.
+impl From<InteractionMember> for PartialMember {
+    fn from(member: InteractionMember) -> Self {
+        Self {
+            avatar: member.avatar,
+            communication_disabled_until: member.communication_disabled_until,
+            deaf: false,
+            flags: member.flags,
+            joined_at: member.joined_at,
+            mute: false,
+            nick: member.nick,
+            permissions: Some(member.permissions),
+            premium_since: member.premium_since,
+            roles: member.roles,
+            user: None,
+        }
+    }
+}
+
+/// Convert a member into a partial member with its user.
+///
+/// Whether the member is pending is lost.
+impl From<Member> for PartialMember {
+    fn from(member: Member) -> Self {
+        Self {
+            avatar: member.avatar,
+            communication_disabled_until: member.communication_disabled_until,
+            deaf: member.deaf,
+            flags: member.flags,
+            joined_at: member.joined_at,
+            mute: member.mute,
+            nick: member.nick,
+            permissions: None,
+            premium_since: member.premium_since,
+            roles: member.roles,
+            user: Some(member.user),
+        }
+    }
+}
+
+/// Converting a [`PartialMember`] into a [`Member`] failed because it has no
+/// user.
+#[derive(Debug)]
+pub struct MemberConversionError {
+    member: PartialMember,
+}
+
+impl MemberConversionError {
+    pub(crate) const fn new(member: PartialMember) -> Self {
+        Self { member }
+    }
+
+    /// Return an immutable reference to the original partial member.
+    pub const fn member_ref(&self) -> &PartialMember {
+        &self.member
+    }
+
+    /// Consume the error, returning the original partial member.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_member(self) -> PartialMember {
+        self.member
+    }
+}
+
+impl Display for MemberConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("partial member has no user")
+    }
+}
+
+impl Error for MemberConversionError {}
+
 #[cfg(test)]
 mod tests {
     use super::PartialMember;
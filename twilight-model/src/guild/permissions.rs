@@ -1,3 +1,10 @@
+use crate::{
+    channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+    id::{
+        marker::{GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
 use bitflags::bitflags;
 use serde::{
     de::{Deserialize, Deserializer, Error as DeError, Visitor},
@@ -87,6 +94,118 @@ bitflags! {
     }
 }
 
+impl Permissions {
+    /// Compute the effective permissions of a member in a channel.
+    ///
+    /// The base permissions are those of the `@everyone` role combined with
+    /// the permissions of each of the member's roles found in `roles`. The
+    /// channel's overwrites are then applied in order: the `@everyone` role's
+    /// overwrite, the overwrites of the member's roles, and finally the
+    /// member's overwrite. Denied permissions of each step are removed before
+    /// its allowed permissions are added.
+    ///
+    /// Returns [`Permissions::all`] if the base permissions contain
+    /// [`ADMINISTRATOR`]. Guild ownership is not taken into account.
+    ///
+    /// Unlike `twilight_util`'s `PermissionCalculator`, permissions are not
+    /// removed based on the type of channel.
+    ///
+    /// # Examples
+    ///
+    /// A member whose role allows sending messages in a channel that denies it
+    /// for `@everyone`:
+    ///
+    /// ```
+    /// use twilight_model::{
+    ///     channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+    ///     guild::Permissions,
+    ///     id::Id,
+    /// };
+    ///
+    /// let guild_id = Id::new(1);
+    /// let role_id = Id::new(2);
+    ///
+    /// let overwrites = [
+    ///     PermissionOverwrite {
+    ///         allow: Permissions::empty(),
+    ///         deny: Permissions::SEND_MESSAGES,
+    ///         id: guild_id.cast(),
+    ///         kind: PermissionOverwriteType::Role,
+    ///     },
+    ///     PermissionOverwrite {
+    ///         allow: Permissions::SEND_MESSAGES,
+    ///         deny: Permissions::empty(),
+    ///         id: role_id.cast(),
+    ///         kind: PermissionOverwriteType::Role,
+    ///     },
+    /// ];
+    ///
+    /// let permissions = Permissions::compute(
+    ///     guild_id,
+    ///     Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES,
+    ///     &[(role_id, Permissions::empty())],
+    ///     &[role_id],
+    ///     &overwrites,
+    ///     Id::new(3),
+    /// );
+    ///
+    /// assert!(permissions.contains(Permissions::SEND_MESSAGES));
+    /// ```
+    ///
+    /// [`ADMINISTRATOR`]: Self::ADMINISTRATOR
+    #[must_use = "computing permissions is only useful if they're used"]
+    pub fn compute(
+        guild_id: Id<GuildMarker>,
+        everyone: Self,
+        roles: &[(Id<RoleMarker>, Self)],
+        member_roles: &[Id<RoleMarker>],
+        overwrites: &[PermissionOverwrite],
+        member_id: Id<UserMarker>,
+    ) -> Self {
+        let mut permissions = roles
+            .iter()
+            .filter(|(id, _)| member_roles.contains(id))
+            .fold(everyone, |permissions, (_, role)| permissions | *role);
+
+        if permissions.contains(Self::ADMINISTRATOR) {
+            return Self::all();
+        }
+
+        let everyone_id = guild_id.cast();
+
+        if let Some(overwrite) = overwrites.iter().find(|overwrite| {
+            overwrite.kind == PermissionOverwriteType::Role && overwrite.id == everyone_id
+        }) {
+            permissions.remove(overwrite.deny);
+            permissions.insert(overwrite.allow);
+        }
+
+        let (allow, deny) = overwrites
+            .iter()
+            .filter(|overwrite| {
+                overwrite.kind == PermissionOverwriteType::Role
+                    && overwrite.id != everyone_id
+                    && member_roles.contains(&overwrite.id.cast())
+            })
+            .fold(
+                (Self::empty(), Self::empty()),
+                |(allow, deny), overwrite| (allow | overwrite.allow, deny | overwrite.deny),
+            );
+
+        permissions.remove(deny);
+        permissions.insert(allow);
+
+        if let Some(overwrite) = overwrites.iter().find(|overwrite| {
+            overwrite.kind == PermissionOverwriteType::Member && overwrite.id == member_id.cast()
+        }) {
+            permissions.remove(overwrite.deny);
+            permissions.insert(overwrite.allow);
+        }
+
+        permissions
+    }
+}
+
 struct PermissionsVisitor;
 
 impl Visitor<'_> for PermissionsVisitor {
@@ -125,6 +244,13 @@ impl Serialize for Permissions {
 #[cfg(test)]
 mod tests {
     use super::Permissions;
+    use crate::{
+        channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        id::{
+            marker::{GuildMarker, RoleMarker, UserMarker},
+            Id,
+        },
+    };
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::{assert_impl_all, const_assert_eq};
@@ -220,4 +346,126 @@ mod tests {
         // Deserialization truncates unknown bits.
         serde_test::assert_de_tokens(&Permissions::empty(), &[Token::Str("9223372036854775808")]);
     }
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+    const ROLE_ID: Id<RoleMarker> = Id::new(2);
+    const OTHER_ROLE_ID: Id<RoleMarker> = Id::new(3);
+    const USER_ID: Id<UserMarker> = Id::new(4);
+
+    const fn overwrite(
+        id: Id<RoleMarker>,
+        kind: PermissionOverwriteType,
+        allow: Permissions,
+        deny: Permissions,
+    ) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow,
+            deny,
+            id: id.cast(),
+            kind,
+        }
+    }
+
+    fn compute(everyone: Permissions, overwrites: &[PermissionOverwrite]) -> Permissions {
+        Permissions::compute(
+            GUILD_ID,
+            everyone,
+            &[
+                (ROLE_ID, Permissions::empty()),
+                (OTHER_ROLE_ID, Permissions::MANAGE_MESSAGES),
+            ],
+            &[ROLE_ID],
+            overwrites,
+            USER_ID,
+        )
+    }
+
+    #[test]
+    fn compute_roles() {
+        assert_eq!(
+            Permissions::VIEW_CHANNEL,
+            compute(Permissions::VIEW_CHANNEL, &[])
+        );
+        assert_eq!(
+            Permissions::VIEW_CHANNEL | Permissions::MANAGE_MESSAGES,
+            Permissions::compute(
+                GUILD_ID,
+                Permissions::VIEW_CHANNEL,
+                &[
+                    (ROLE_ID, Permissions::empty()),
+                    (OTHER_ROLE_ID, Permissions::MANAGE_MESSAGES),
+                ],
+                &[ROLE_ID, OTHER_ROLE_ID],
+                &[],
+                USER_ID,
+            )
+        );
+    }
+
+    #[test]
+    fn compute_administrator() {
+        let overwrites = [overwrite(
+            USER_ID.cast(),
+            PermissionOverwriteType::Member,
+            Permissions::empty(),
+            Permissions::VIEW_CHANNEL,
+        )];
+
+        assert_eq!(
+            Permissions::all(),
+            compute(Permissions::ADMINISTRATOR, &overwrites)
+        );
+    }
+
+    /// Examples from Discord's documentation: overwrites of the member's roles
+    /// take precedence over the `@everyone` overwrite, and the member's
+    /// overwrite takes precedence over both.
+    #[test]
+    fn compute_overwrites() {
+        let everyone = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+        let deny_everyone = overwrite(
+            GUILD_ID.cast(),
+            PermissionOverwriteType::Role,
+            Permissions::empty(),
+            Permissions::SEND_MESSAGES,
+        );
+        let allow_role = overwrite(
+            ROLE_ID,
+            PermissionOverwriteType::Role,
+            Permissions::SEND_MESSAGES,
+            Permissions::empty(),
+        );
+        let deny_role = overwrite(
+            ROLE_ID,
+            PermissionOverwriteType::Role,
+            Permissions::empty(),
+            Permissions::SEND_MESSAGES,
+        );
+        let allow_member = overwrite(
+            USER_ID.cast(),
+            PermissionOverwriteType::Member,
+            Permissions::SEND_MESSAGES,
+            Permissions::empty(),
+        );
+        let allow_other_role = overwrite(
+            OTHER_ROLE_ID,
+            PermissionOverwriteType::Role,
+            Permissions::SEND_MESSAGES,
+            Permissions::empty(),
+        );
+
+        assert_eq!(
+            Permissions::VIEW_CHANNEL,
+            compute(everyone, &[deny_everyone])
+        );
+        assert_eq!(everyone, compute(everyone, &[deny_everyone, allow_role]));
+        assert_eq!(everyone, compute(everyone, &[allow_role, deny_everyone]));
+        assert_eq!(Permissions::VIEW_CHANNEL, compute(everyone, &[deny_role]));
+        assert_eq!(everyone, compute(everyone, &[deny_role, allow_member]));
+        // Roles the member doesn't have are ignored.
+        assert_eq!(
+            Permissions::VIEW_CHANNEL,
+            compute(everyone, &[deny_everyone, allow_other_role])
+        );
+    }
 }
@@ -241,6 +241,7 @@ mod tests {
                         rtc_region: None,
                         thread_metadata: None,
                         topic: None,
+                        total_message_sent: None,
                         user_limit: None,
                         video_quality_mode: None,
                     },
@@ -291,6 +292,7 @@ mod tests {
                         rtc_region: None,
                         thread_metadata: None,
                         topic: None,
+                        total_message_sent: None,
                         user_limit: None,
                         video_quality_mode: None,
                     },
@@ -328,6 +330,7 @@ mod tests {
                         rtc_region: None,
                         thread_metadata: None,
                         topic: None,
+                        total_message_sent: None,
                         user_limit: None,
                         video_quality_mode: None,
                     },
@@ -365,6 +368,7 @@ mod tests {
                         rtc_region: None,
                         thread_metadata: None,
                         topic: None,
+                        total_message_sent: None,
                         user_limit: Some(0),
                         video_quality_mode: None,
                     },
@@ -25,7 +25,14 @@ use serde::{Deserialize, Serialize};
 /// attachment.description("Raw data about Twilight Sparkle".to_owned());
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
 pub struct Attachment {
+    /// Media type of the file, such as `image/png`.
+    ///
+    /// Sent as the content type of the file's part of the multipart form, if
+    /// present.
+    #[serde(skip)]
+    pub content_type: Option<String>,
     /// Description of the attachment, useful for screen readers and users
     /// requiring alt text.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -64,6 +71,7 @@ impl Attachment {
     /// ```
     pub const fn from_bytes(filename: String, file: Vec<u8>, id: u64) -> Self {
         Self {
+            content_type: None,
             description: None,
             file,
             filename,
@@ -71,6 +79,11 @@ impl Attachment {
         }
     }
 
+    /// Set the media type of the file, such as `image/png`.
+    pub fn content_type(&mut self, content_type: String) {
+        self.content_type = Some(content_type);
+    }
+
     /// Set the description of the attachment.
     ///
     /// Attachment descriptions are useful for those requiring screen readers
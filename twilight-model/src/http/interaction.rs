@@ -195,6 +195,7 @@ mod tests {
             kind: InteractionResponseType::ChannelMessageWithSource,
             data: Some(InteractionResponseData {
                 attachments: Some(Vec::from([Attachment {
+                    content_type: None,
                     description: None,
                     file: "file data".into(),
                     filename: "filename.jpg".into(),
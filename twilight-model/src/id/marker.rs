@@ -108,8 +108,13 @@ pub struct SkuMarker;
 /// Types such as [`AuditLogChange::Id`] or [`CommandOptionValue`] use this
 /// ID marker.
 ///
+/// IDs of any other marker can be cast to this marker via
+/// [`Id::into_generic`], for use where the type of resource is unknown or
+/// varies.
+///
 /// [`AuditLogChange::Id`]: crate::guild::audit_log::AuditLogChange::Id
 /// [`CommandOptionValue`]: crate::application::interaction::application_command::CommandOptionValue
+/// [`Id::into_generic`]: super::Id::into_generic
 #[derive(Debug)]
 #[non_exhaustive]
 pub struct GenericMarker;
@@ -40,8 +40,14 @@
 //!
 //! assert_eq!(guild_id.get(), role_id.get());
 //! ```
+//!
+//! # Prelude
+//!
+//! The [`prelude`] exports [`Id`] and every marker, allowing them to be
+//! imported with a single glob import.
 
 pub mod marker;
+pub mod prelude;
 
 mod anonymizable;
 
@@ -61,6 +67,13 @@ use std::{
     str::FromStr,
 };
 
+/// Discord's custom epoch, the Unix time in milliseconds of the first second
+/// of 2015.
+///
+/// The timestamp of an ID is stored in its upper 42 bits, relative to this
+/// epoch.
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
 /// ID of a resource, such as the ID of a [channel] or [user].
 ///
 /// Markers themselves perform no logical action, and are only used to ensure
@@ -190,6 +203,12 @@ impl<T> Id<T> {
 
     /// Cast an ID from one type to another.
     ///
+    /// Casting reinterprets the ID as belonging to another type of resource
+    /// while preserving its value. This is only meaningful where Discord shares
+    /// IDs across resources, such as the `@everyone` role sharing the ID of its
+    /// guild or the author of a webhook message sharing the ID of the webhook.
+    /// Prefer casting over `Id::new(id.get())` to make the intent explicit.
+    ///
     /// # Examples
     ///
     /// Cast a role ID to a guild ID, useful for the `@everyone` role:
@@ -208,6 +227,27 @@ impl<T> Id<T> {
     pub const fn cast<New>(self) -> Id<New> {
         Id::from_nonzero(self.value)
     }
+
+    /// Cast an ID to an ID of [`GenericMarker`], for use where the type of
+    /// resource is unknown or varies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::id::{
+    ///     marker::{GenericMarker, UserMarker},
+    ///     Id,
+    /// };
+    ///
+    /// let user_id = Id::<UserMarker>::new(1);
+    /// let ids: Vec<Id<GenericMarker>> = vec![user_id.into_generic()];
+    /// assert_eq!(1, ids[0].get());
+    /// ```
+    ///
+    /// [`GenericMarker`]: marker::GenericMarker
+    pub const fn into_generic(self) -> Id<marker::GenericMarker> {
+        self.cast()
+    }
 }
 
 impl<T> Clone for Id<T> {
@@ -324,6 +364,12 @@ impl<T> From<Id<T>> for NonZeroU64 {
     }
 }
 
+/// Parse an ID from its numeric value, such as from command line arguments.
+///
+/// Returns a [`ParseIntError`] of kind [`IntErrorKind::Zero`] if the value is
+/// zero, and of another kind if it isn't a valid [`u64`].
+///
+/// [`IntErrorKind::Zero`]: std::num::IntErrorKind::Zero
 impl<T> FromStr for Id<T> {
     type Err = ParseIntError;
 
@@ -428,7 +474,7 @@ mod tests {
         error::Error,
         fmt::{Debug, Display},
         hash::{Hash, Hasher},
-        num::NonZeroU64,
+        num::{IntErrorKind, NonZeroU64},
         str::FromStr,
     };
 
@@ -514,6 +560,23 @@ mod tests {
     fn cast() {
         let id = Id::<GenericMarker>::new(123);
         assert_eq!(123_u64, id.cast::<RoleMarker>());
+        assert_eq!(
+            u64::MAX,
+            Id::<WebhookMarker>::new(u64::MAX).cast::<UserMarker>()
+        );
+        assert_eq!(123_u64, Id::<UserMarker>::new(123).into_generic());
+    }
+
+    /// Test that the prelude exports IDs and markers via a single glob import.
+    #[test]
+    fn prelude() {
+        use super::prelude::*;
+
+        let user_id: Id<UserMarker> = Id::new(1);
+        let generic_id: Id<GenericMarker> = user_id.into_generic();
+        let webhook_id = generic_id.cast::<WebhookMarker>();
+
+        assert_eq!(user_id.get(), webhook_id.get());
     }
 
     /// Test that debugging IDs formats the generic and value as a newtype.
@@ -532,6 +595,32 @@ mod tests {
         assert_eq!("114941315417899012", id.to_string());
     }
 
+    /// Test that IDs of any marker round-trip through their display and
+    /// parsed forms, and that invalid values are rejected.
+    #[test]
+    fn display_from_str() {
+        let channel_id = Id::<ChannelMarker>::new(1);
+        let guild_id = Id::<GuildMarker>::new(81_384_788_765_712_384);
+        let user_id = Id::<UserMarker>::new(u64::MAX);
+
+        assert_eq!(Ok(channel_id), channel_id.to_string().parse());
+        assert_eq!(Ok(guild_id), guild_id.to_string().parse());
+        assert_eq!(Ok(user_id), user_id.to_string().parse());
+
+        assert_eq!(
+            &IntErrorKind::Zero,
+            Id::<UserMarker>::from_str("0").unwrap_err().kind()
+        );
+        assert_eq!(
+            &IntErrorKind::InvalidDigit,
+            Id::<UserMarker>::from_str("abc").unwrap_err().kind()
+        );
+        assert_eq!(
+            &IntErrorKind::Empty,
+            Id::<UserMarker>::from_str("").unwrap_err().kind()
+        );
+    }
+
     /// Test that hashing an ID is equivalent to hashing only its inner value.
     #[test]
     fn hash() {
@@ -0,0 +1,12 @@
+//! Exports of [`Id`] and every marker for use with a single glob import.
+//!
+//! # Examples
+//!
+//! ```
+//! use twilight_model::id::prelude::*;
+//!
+//! let guild_id: Id<GuildMarker> = Id::new(1);
+//! let role_id: Id<RoleMarker> = guild_id.cast();
+//! ```
+
+pub use super::{marker::*, Id};
@@ -1,3 +1,4 @@
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![doc = include_str!("../README.md")]
 #![warn(clippy::missing_const_for_fn, clippy::pedantic, unsafe_code)]
 #![allow(
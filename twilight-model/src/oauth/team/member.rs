@@ -1,6 +1,6 @@
 use crate::{
     id::{marker::OauthTeamMarker, Id},
-    oauth::team::TeamMembershipState,
+    oauth::team::{TeamMemberRole, TeamMembershipState},
     user::User,
 };
 use serde::{Deserialize, Serialize};
@@ -9,13 +9,16 @@ use serde::{Deserialize, Serialize};
 pub struct TeamMember {
     pub membership_state: TeamMembershipState,
     pub permissions: Vec<String>,
+    /// Role of the member in the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<TeamMemberRole>,
     pub team_id: Id<OauthTeamMarker>,
     pub user: User,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{TeamMember, TeamMembershipState, User};
+    use super::{TeamMember, TeamMemberRole, TeamMembershipState, User};
     use crate::id::Id;
     use serde_test::Token;
 
@@ -24,6 +27,7 @@ mod tests {
         let value = TeamMember {
             membership_state: TeamMembershipState::Accepted,
             permissions: vec!["*".to_owned()],
+            role: Some(TeamMemberRole::Developer),
             team_id: Id::new(1),
             user: User {
                 accent_color: None,
@@ -52,7 +56,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "TeamMember",
-                    len: 4,
+                    len: 5,
                 },
                 Token::Str("membership_state"),
                 Token::U8(2),
@@ -60,6 +64,9 @@ mod tests {
                 Token::Seq { len: Some(1) },
                 Token::Str("*"),
                 Token::SeqEnd,
+                Token::Str("role"),
+                Token::Some,
+                Token::Str("developer"),
                 Token::Str("team_id"),
                 Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
@@ -95,4 +102,24 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn team_member_without_role() {
+        let raw = r#"{
+            "membership_state": 2,
+            "permissions": ["*"],
+            "team_id": "1",
+            "user": {
+                "avatar": null,
+                "discriminator": "0",
+                "global_name": "test",
+                "id": "2",
+                "username": "test"
+            }
+        }"#;
+
+        let member = serde_json::from_str::<TeamMember>(raw).unwrap();
+        assert!(member.role.is_none());
+        assert!(!serde_json::to_string(&member).unwrap().contains("role"));
+    }
 }
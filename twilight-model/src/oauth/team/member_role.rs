@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Role of a [`TeamMember`] determining what they can do with the team's
+/// applications.
+///
+/// See [Discord Docs/Team Member Roles].
+///
+/// [`TeamMember`]: super::TeamMember
+/// [Discord Docs/Team Member Roles]: https://discord.com/developers/docs/topics/teams#team-member-roles
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "String", into = "Cow<'static, str>")]
+pub enum TeamMemberRole {
+    /// Manage the team and its applications, except for deleting them.
+    ///
+    /// The owner of the team has more permissions, but isn't a role.
+    Admin,
+    /// Access the information of the team's applications and manage them,
+    /// except for their monetization settings.
+    Developer,
+    /// Access the information of the team's applications.
+    ReadOnly,
+    /// Variant value is unknown to the library.
+    Unknown(String),
+}
+
+impl From<TeamMemberRole> for Cow<'static, str> {
+    fn from(value: TeamMemberRole) -> Self {
+        match value {
+            TeamMemberRole::Admin => "admin".into(),
+            TeamMemberRole::Developer => "developer".into(),
+            TeamMemberRole::ReadOnly => "read_only".into(),
+            TeamMemberRole::Unknown(unknown) => unknown.into(),
+        }
+    }
+}
+
+impl From<String> for TeamMemberRole {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "admin" => Self::Admin,
+            "developer" => Self::Developer,
+            "read_only" => Self::ReadOnly,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TeamMemberRole;
+    use serde_test::Token;
+
+    #[test]
+    fn variants() {
+        const MAP: &[(TeamMemberRole, &str)] = &[
+            (TeamMemberRole::Admin, "admin"),
+            (TeamMemberRole::Developer, "developer"),
+            (TeamMemberRole::ReadOnly, "read_only"),
+        ];
+
+        for (role, value) in MAP {
+            serde_test::assert_tokens(role, &[Token::Str(value)]);
+        }
+
+        serde_test::assert_tokens(
+            &TeamMemberRole::Unknown("owner".to_owned()),
+            &[Token::Str("owner")],
+        );
+    }
+}
@@ -1,7 +1,10 @@
 mod member;
+mod member_role;
 mod membership_state;
 
-pub use self::{member::TeamMember, membership_state::TeamMembershipState};
+pub use self::{
+    member::TeamMember, member_role::TeamMemberRole, membership_state::TeamMembershipState,
+};
 
 use crate::{
     id::{
@@ -26,6 +26,13 @@ impl TimestampParseError {
         source: None,
     };
 
+    /// Error that was caused by the datetime being out of range.
+    #[cfg(feature = "time")]
+    pub(super) const RANGE: TimestampParseError = TimestampParseError {
+        kind: TimestampParseErrorType::Range,
+        source: None,
+    };
+
     /// Immutable reference to the type of error that occurred.
     #[must_use = "retrieving the type has no effect if left unused"]
     pub const fn kind(&self) -> &TimestampParseErrorType {
@@ -49,6 +49,7 @@ use serde::{
 use std::{
     fmt::{Formatter, Result as FmtResult},
     str::FromStr,
+    time::{Duration, SystemTime},
 };
 use time::{format_description::well_known::Rfc3339, OffsetDateTime, PrimitiveDateTime};
 
@@ -109,6 +110,59 @@ impl Timestamp {
             .map_err(TimestampParseError::from_component_range)
     }
 
+    /// Create a timestamp of the current system time with microseconds
+    /// precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the Unix epoch or too far in the
+    /// future to be represented.
+    pub fn now() -> Self {
+        let since_epoch = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("system time is after the unix epoch");
+        let micros = i64::try_from(since_epoch.as_micros()).expect("system time fits in an i64");
+
+        Self::from_micros(micros).expect("system time is a valid timestamp")
+    }
+
+    /// Add a duration to the timestamp, returning `None` if the result would
+    /// be out of range.
+    ///
+    /// The duration is truncated to microseconds precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::Duration;
+    /// use twilight_model::util::Timestamp;
+    ///
+    /// let timestamp = Timestamp::from_secs(1_580_608_922)?;
+    /// let later = timestamp.checked_add(Duration::from_secs(60)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "2020-02-02T02:03:02.000000+00:00",
+    ///     later.iso_8601().to_string(),
+    /// );
+    /// # Ok(()) }
+    /// ```
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        let micros = i64::try_from(duration.as_micros()).ok()?;
+
+        Self::from_micros(self.as_micros().checked_add(micros)?).ok()
+    }
+
+    /// Subtract a duration from the timestamp, returning `None` if the result
+    /// would be out of range.
+    ///
+    /// The duration is truncated to microseconds precision.
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        let micros = i64::try_from(duration.as_micros()).ok()?;
+
+        Self::from_micros(self.as_micros().checked_sub(micros)?).ok()
+    }
+
     /// Parse a timestamp from an ISO 8601 datetime string emitted by Discord.
     ///
     /// Discord emits two ISO 8601 valid formats of datetimes: with microseconds
@@ -289,6 +343,61 @@ impl TryFrom<&'_ str> for Timestamp {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(value: Timestamp) -> Self {
+        Self::from_timestamp_micros(value.as_micros())
+            .expect("chrono's range includes the timestamp's")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Timestamp {
+    type Error = TimestampParseError;
+
+    /// Convert a datetime into a timestamp, truncating it to microseconds
+    /// precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimestampParseErrorType::Range`] error type if the datetime
+    /// is out of the supported range.
+    fn try_from(value: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
+        Self::from_micros(value.timestamp_micros())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for OffsetDateTime {
+    fn from(value: Timestamp) -> Self {
+        value.0.assume_utc()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<OffsetDateTime> for Timestamp {
+    type Error = TimestampParseError;
+
+    /// Convert a datetime into a timestamp in UTC, truncating it to
+    /// microseconds precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimestampParseErrorType::Range`] error type if the datetime
+    /// is out of the supported range in UTC.
+    fn try_from(value: OffsetDateTime) -> Result<Self, Self::Error> {
+        let utc = value
+            .checked_to_offset(time::UtcOffset::UTC)
+            .ok_or(TimestampParseError::RANGE)?;
+        let time = utc
+            .time()
+            .replace_microsecond(utc.microsecond())
+            .map_err(TimestampParseError::from_component_range)?;
+
+        Ok(Self(PrimitiveDateTime::new(utc.date(), time)))
+    }
+}
+
 /// Parse an input ISO 8601 timestamp into a Unix timestamp with microseconds.
 ///
 /// Input in the format of "2021-01-01T01:01:01.010000+00:00" is acceptable.
@@ -314,7 +423,12 @@ mod tests {
     use super::{Timestamp, TimestampParseError};
     use serde::{Deserialize, Serialize};
     use static_assertions::assert_impl_all;
-    use std::{fmt::Debug, hash::Hash, str::FromStr};
+    use std::{
+        fmt::Debug,
+        hash::Hash,
+        str::FromStr,
+        time::{Duration, SystemTime},
+    };
     use time::{OffsetDateTime, PrimitiveDateTime};
 
     assert_impl_all!(
@@ -388,4 +502,100 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn now() {
+        let before = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let now = Timestamp::now().as_secs();
+
+        assert!(u64::try_from(now).unwrap() >= before);
+    }
+
+    #[test]
+    fn checked_arithmetic() -> Result<(), TimestampParseError> {
+        let timestamp = Timestamp::from_str("2021-12-31T23:59:59.999999+00:00")?;
+
+        assert_eq!(
+            "2022-01-01T00:00:00.999999+00:00",
+            timestamp
+                .checked_add(Duration::from_secs(1))
+                .unwrap()
+                .iso_8601()
+                .to_string(),
+        );
+        assert_eq!(
+            "2021-12-31T23:59:58.999999+00:00",
+            timestamp
+                .checked_sub(Duration::from_secs(1))
+                .unwrap()
+                .iso_8601()
+                .to_string(),
+        );
+        // Nanoseconds are truncated.
+        assert_eq!(
+            timestamp,
+            timestamp.checked_add(Duration::from_nanos(999)).unwrap()
+        );
+        assert_eq!(
+            timestamp.as_micros() + 1,
+            timestamp
+                .checked_add(Duration::from_nanos(1_999))
+                .unwrap()
+                .as_micros()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn checked_arithmetic_overflow() -> Result<(), TimestampParseError> {
+        let timestamp = Timestamp::from_secs(0)?;
+
+        assert!(timestamp.checked_add(Duration::MAX).is_none());
+        assert!(timestamp
+            .checked_add(Duration::from_secs(u64::from(u32::MAX) * 1_000))
+            .is_none());
+        assert!(timestamp.checked_sub(Duration::MAX).is_none());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() -> Result<(), TimestampParseError> {
+        use chrono::{DateTime, Utc};
+
+        let timestamp = Timestamp::from_str("2021-08-10T11:16:37.123456+00:00")?;
+        let datetime = DateTime::<Utc>::from(timestamp);
+        assert_eq!(1_628_594_197_123_456, datetime.timestamp_micros());
+        assert_eq!(timestamp, Timestamp::try_from(datetime)?);
+
+        // Nanoseconds are truncated.
+        let datetime = DateTime::from_timestamp_nanos(1_628_594_197_123_456_789);
+        assert_eq!(timestamp, Timestamp::try_from(datetime)?);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() -> Result<(), TimestampParseError> {
+        use time::UtcOffset;
+
+        let timestamp = Timestamp::from_str("2021-08-10T11:16:37.123456+00:00")?;
+        let datetime = OffsetDateTime::from(timestamp);
+        assert_eq!(1_628_594_197_123_456_000, datetime.unix_timestamp_nanos());
+        assert_eq!(timestamp, Timestamp::try_from(datetime)?);
+
+        // Nanoseconds are truncated and offsets are converted to UTC.
+        let datetime = OffsetDateTime::from_unix_timestamp_nanos(1_628_594_197_123_456_789)
+            .unwrap()
+            .to_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(timestamp, Timestamp::try_from(datetime)?);
+
+        Ok(())
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! [`Standby`]: super::Standby
 
+use crate::BystanderGuard;
 use futures_core::Stream;
 use std::{
     error::Error,
@@ -20,6 +21,7 @@ use twilight_model::{
         event::Event,
         payload::incoming::{MessageCreate, ReactionAdd},
     },
+    id::{marker::MessageMarker, Id},
 };
 
 /// Future canceled due to Standby being dropped.
@@ -159,6 +161,8 @@ impl Stream for WaitForMessageStream {
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForReactionFuture {
+    /// Guard removing the bystander once this is dropped.
+    pub(crate) _guard: BystanderGuard<Id<MessageMarker>, ReactionAdd>,
     /// Receiver half of the oneshot channel.
     pub(crate) rx: Receiver<ReactionAdd>,
 }
@@ -177,6 +181,8 @@ impl Future for WaitForReactionFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForReactionStream {
+    /// Guard removing the bystander once this is dropped.
+    pub(crate) _guard: BystanderGuard<Id<MessageMarker>, ReactionAdd>,
     /// Receiver half of the MPSC channel.
     pub(crate) rx: MpscReceiver<ReactionAdd>,
 }
@@ -195,6 +201,8 @@ impl Stream for WaitForReactionStream {
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForComponentFuture {
+    /// Guard removing the bystander once this is dropped.
+    pub(crate) _guard: BystanderGuard<Id<MessageMarker>, Interaction>,
     /// Receiver half of the oneshot channel.
     pub(crate) rx: Receiver<Interaction>,
 }
@@ -213,6 +221,8 @@ impl Future for WaitForComponentFuture {
 #[derive(Debug)]
 #[must_use]
 pub struct WaitForComponentStream {
+    /// Guard removing the bystander once this is dropped.
+    pub(crate) _guard: BystanderGuard<Id<MessageMarker>, Interaction>,
     /// Receiver half of the MPSC channel.
     pub(crate) rx: MpscReceiver<Interaction>,
 }
@@ -23,7 +23,10 @@ use dashmap::DashMap;
 use std::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     hash::Hash,
-    sync::atomic::{AtomicU64, Ordering},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Weak,
+    },
 };
 use tokio::sync::{
     mpsc::{self, UnboundedReceiver, UnboundedSender as MpscSender},
@@ -69,6 +72,8 @@ impl<E> Sender<E> {
 struct Bystander<T> {
     /// Predicate check to perform on an event.
     func: Box<dyn Fn(&T) -> bool + Send + Sync>,
+    /// ID of the bystander, generated via [`Standby::next_event_id`].
+    id: u64,
     /// [`Sender::Future`]s consume themselves once upon sending so the sender
     /// needs to be able to be taken out separately.
     sender: Option<Sender<T>>,
@@ -78,11 +83,53 @@ impl<T: Debug> Debug for Bystander<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("Bystander")
             .field("func", &"<dyn Fn(&T) -> bool>")
+            .field("id", &self.id)
             .field("sender", &self.sender)
             .finish()
     }
 }
 
+/// Guard removing a bystander from its map once the future or stream waiting
+/// on it is dropped, such as when it's timed out.
+///
+/// Bystanders are otherwise only removed once an event with their key is
+/// processed, which may never happen.
+#[derive(Debug)]
+struct BystanderGuard<K: Eq + Hash, V> {
+    /// ID of the bystander.
+    id: u64,
+    /// Key of the bystander in the map.
+    key: K,
+    /// Map of the bystander, which may have been dropped along with its
+    /// [`Standby`].
+    map: Weak<BystanderMap<K, V>>,
+}
+
+impl<K: Eq + Hash, V> BystanderGuard<K, V> {
+    /// Create a guard for the bystander with an ID under a key.
+    fn new(map: &Arc<BystanderMap<K, V>>, key: K, id: u64) -> Self {
+        Self {
+            id,
+            key,
+            map: Arc::downgrade(map),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> Drop for BystanderGuard<K, V> {
+    fn drop(&mut self) {
+        let Some(map) = self.map.upgrade() else {
+            return;
+        };
+
+        map.remove_if_mut(&self.key, |_, bystanders| {
+            bystanders.retain(|bystander| bystander.id != self.id);
+
+            bystanders.is_empty()
+        });
+    }
+}
+
 /// The `Standby` struct, used by the main event loop to process events and by
 /// tasks to wait for an event.
 ///
@@ -117,14 +164,14 @@ impl<T: Debug> Debug for Bystander<T> {
 pub struct Standby {
     /// List of component bystanders where the ID of the message is known
     /// beforehand.
-    components: DashMap<Id<MessageMarker>, Vec<Bystander<Interaction>>>,
+    components: Arc<BystanderMap<Id<MessageMarker>, Interaction>>,
     /// Bystanders for any event that may not be in any particular guild.
     ///
     /// The key is generated via [`event_counter`].
     ///
     /// [`event_counter`]: Self::event_counter
     events: DashMap<u64, Bystander<Event>>,
-    /// Event counter to be used as the key of [`events`].
+    /// Counter of bystander IDs, also used as the key of [`events`].
     ///
     /// [`events`]: Self::events
     event_counter: AtomicU64,
@@ -135,7 +182,7 @@ pub struct Standby {
     messages: DashMap<Id<ChannelMarker>, Vec<Bystander<MessageCreate>>>,
     /// List of reaction bystanders where the ID of the message is known
     /// beforehand.
-    reactions: DashMap<Id<MessageMarker>, Vec<Bystander<ReactionAdd>>>,
+    reactions: Arc<BystanderMap<Id<MessageMarker>, ReactionAdd>>,
 }
 
 impl Standby {
@@ -251,7 +298,7 @@ impl Standby {
         tracing::trace!(%guild_id, "waiting for event in guild");
 
         WaitForGuildEventFuture {
-            rx: Self::insert_future(&self.guilds, guild_id, check),
+            rx: Self::insert_future(&self.guilds, guild_id, self.next_event_id(), check),
         }
     }
 
@@ -304,7 +351,7 @@ impl Standby {
         tracing::trace!(%guild_id, "waiting for event in guild");
 
         WaitForGuildEventStream {
-            rx: Self::insert_stream(&self.guilds, guild_id, check),
+            rx: Self::insert_stream(&self.guilds, guild_id, self.next_event_id(), check),
         }
     }
 
@@ -353,11 +400,13 @@ impl Standby {
         tracing::trace!("waiting for event");
 
         let (tx, rx) = oneshot::channel();
+        let id = self.next_event_id();
 
         self.events.insert(
-            self.next_event_id(),
+            id,
             Bystander {
                 func: check.into(),
+                id,
                 sender: Some(Sender::Future(tx)),
             },
         );
@@ -412,11 +461,13 @@ impl Standby {
         tracing::trace!("waiting for event");
 
         let (tx, rx) = mpsc::unbounded_channel();
+        let id = self.next_event_id();
 
         self.events.insert(
-            self.next_event_id(),
+            id,
             Bystander {
                 func: check.into(),
+                id,
                 sender: Some(Sender::Stream(tx)),
             },
         );
@@ -467,7 +518,7 @@ impl Standby {
         tracing::trace!(%channel_id, "waiting for message in channel");
 
         WaitForMessageFuture {
-            rx: Self::insert_future(&self.messages, channel_id, check),
+            rx: Self::insert_future(&self.messages, channel_id, self.next_event_id(), check),
         }
     }
 
@@ -517,7 +568,7 @@ impl Standby {
         tracing::trace!(%channel_id, "waiting for message in channel");
 
         WaitForMessageStream {
-            rx: Self::insert_stream(&self.messages, channel_id, check),
+            rx: Self::insert_stream(&self.messages, channel_id, self.next_event_id(), check),
         }
     }
 
@@ -563,8 +614,11 @@ impl Standby {
     ) -> WaitForReactionFuture {
         tracing::trace!(%message_id, "waiting for reaction on message");
 
+        let id = self.next_event_id();
+
         WaitForReactionFuture {
-            rx: Self::insert_future(&self.reactions, message_id, check),
+            _guard: BystanderGuard::new(&self.reactions, message_id, id),
+            rx: Self::insert_future(&self.reactions, message_id, id, check),
         }
     }
 
@@ -615,8 +669,11 @@ impl Standby {
     ) -> WaitForReactionStream {
         tracing::trace!(%message_id, "waiting for reaction on message");
 
+        let id = self.next_event_id();
+
         WaitForReactionStream {
-            rx: Self::insert_stream(&self.reactions, message_id, check),
+            _guard: BystanderGuard::new(&self.reactions, message_id, id),
+            rx: Self::insert_stream(&self.reactions, message_id, id, check),
         }
     }
 
@@ -655,8 +712,11 @@ impl Standby {
     ) -> WaitForComponentFuture {
         tracing::trace!(%message_id, "waiting for component on message");
 
+        let id = self.next_event_id();
+
         WaitForComponentFuture {
-            rx: Self::insert_future(&self.components, message_id, check),
+            _guard: BystanderGuard::new(&self.components, message_id, id),
+            rx: Self::insert_future(&self.components, message_id, id, check),
         }
     }
 
@@ -706,12 +766,15 @@ impl Standby {
     ) -> WaitForComponentStream {
         tracing::trace!(%message_id, "waiting for component on message");
 
+        let id = self.next_event_id();
+
         WaitForComponentStream {
-            rx: Self::insert_stream(&self.components, message_id, check),
+            _guard: BystanderGuard::new(&self.components, message_id, id),
+            rx: Self::insert_stream(&self.components, message_id, id, check),
         }
     }
 
-    /// Next event ID in [`Standby::event_counter`].
+    /// Next bystander ID in [`Standby::event_counter`].
     fn next_event_id(&self) -> u64 {
         self.event_counter.fetch_add(1, Ordering::SeqCst)
     }
@@ -719,14 +782,16 @@ impl Standby {
     /// Append a new future bystander into a map according to the ID.
     fn insert_future<F: Fn(&V) -> bool + Send + Sync + 'static, K: Eq + Hash, V>(
         map: &BystanderMap<K, V>,
-        id: K,
+        key: K,
+        id: u64,
         check: impl Into<Box<F>>,
     ) -> Receiver<V> {
         let (tx, rx) = oneshot::channel();
 
-        let mut entry = map.entry(id).or_default();
+        let mut entry = map.entry(key).or_default();
         entry.push(Bystander {
             func: check.into(),
+            id,
             sender: Some(Sender::Future(tx)),
         });
 
@@ -736,14 +801,16 @@ impl Standby {
     /// Append a new stream bystander into a map according to the ID.
     fn insert_stream<F: Fn(&V) -> bool + Send + Sync + 'static, K: Eq + Hash, V>(
         map: &BystanderMap<K, V>,
-        id: K,
+        key: K,
+        id: u64,
         check: impl Into<Box<F>>,
     ) -> UnboundedReceiver<V> {
         let (tx, rx) = mpsc::unbounded_channel();
 
-        let mut entry = map.entry(id).or_default();
+        let mut entry = map.entry(key).or_default();
         entry.push(Bystander {
             func: check.into(),
+            id,
             sender: Some(Sender::Stream(tx)),
         });
 
@@ -1057,7 +1124,8 @@ mod tests {
 
     use crate::Standby;
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, time::Duration};
+    use tokio::time;
     use tokio_stream::StreamExt;
     use twilight_gateway::{Event, EventType};
     use twilight_model::{
@@ -1129,6 +1197,7 @@ mod tests {
             message_snapshots: Vec::new(),
             pinned: false,
             poll: None,
+            position: None,
             reactions: Vec::new(),
             reference: None,
             referenced_message: None,
@@ -1183,6 +1252,7 @@ mod tests {
                 recipients: None,
                 rtc_region: None,
                 topic: None,
+                total_message_sent: None,
                 user_limit: None,
                 application_id: None,
                 applied_tags: None,
@@ -1503,7 +1573,7 @@ mod tests {
         assert!(stream.next().await.is_some());
         assert!(stream.next().await.is_some());
         drop(stream);
-        assert_eq!(1, standby.reactions.len());
+        assert!(standby.reactions.is_empty());
         standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
         assert!(standby.reactions.is_empty());
     }
@@ -1542,13 +1612,70 @@ mod tests {
         assert!(stream.next().await.is_some());
         assert!(stream.next().await.is_some());
         drop(stream);
-        assert_eq!(1, standby.components.len());
+        assert!(standby.components.is_empty());
         standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
             button(),
         ))));
         assert!(standby.components.is_empty());
     }
 
+    /// Test that timed out component futures are removed from the map,
+    /// without an event for their message.
+    #[tokio::test]
+    async fn test_wait_for_component_timeout() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_component(Id::new(5), |_: &Interaction| true);
+        let _stream = standby.wait_for_component_stream(Id::new(6), |_: &Interaction| true);
+        assert_eq!(2, standby.components.len());
+        assert!(time::timeout(Duration::from_millis(1), wait).await.is_err());
+        assert_eq!(1, standby.components.len());
+        assert!(!standby.components.contains_key(&Id::new(5)));
+
+        let results = standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            button(),
+        ))));
+        assert_eq!(0, results.dropped());
+        assert_eq!(0, results.matched());
+        assert_eq!(1, standby.components.len());
+    }
+
+    /// Test that dropped reaction calls are removed from the map and that
+    /// processing an event for them afterwards doesn't panic.
+    #[tokio::test]
+    async fn test_wait_for_reaction_dropped() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_reaction(Id::new(4), |_: &ReactionAdd| true);
+        let stream = standby.wait_for_reaction_stream(Id::new(4), |_: &ReactionAdd| true);
+        let other = standby.wait_for_reaction(Id::new(5), |_: &ReactionAdd| true);
+        assert_eq!(2, standby.reactions.len());
+        drop(wait);
+        assert_eq!(1, standby.reactions.get(&Id::new(4)).unwrap().len());
+        drop(stream);
+        assert_eq!(1, standby.reactions.len());
+        assert!(!standby.reactions.contains_key(&Id::new(4)));
+
+        let results = standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
+        assert_eq!(0, results.dropped());
+        assert_eq!(0, results.matched());
+        assert_eq!(1, standby.reactions.len());
+
+        let _stream = standby.wait_for_reaction_stream(Id::new(6), |_: &ReactionAdd| true);
+        drop(other);
+        let _wait = standby.wait_for_reaction(Id::new(6), |_: &ReactionAdd| true);
+        assert_eq!(1, standby.reactions.len());
+        assert_eq!(2, standby.reactions.get(&Id::new(6)).unwrap().len());
+    }
+
+    /// Test that dropping a future after its standby doesn't panic.
+    #[tokio::test]
+    async fn test_wait_for_reaction_standby_dropped() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_reaction(Id::new(4), |_: &ReactionAdd| true);
+        drop(standby);
+
+        assert!(wait.await.is_err());
+    }
+
     #[tokio::test]
     async fn test_handles_wrong_events() {
         let standby = Standby::new();
@@ -0,0 +1,308 @@
+//! Create attachments from files on disk.
+//!
+//! # Examples
+//!
+//! Attach a generated report to a message:
+//!
+//! ```no_run
+//! # #[tokio::main(flavor = "current_thread")] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_util::attachment::AttachmentFile;
+//!
+//! let file = AttachmentFile::from_path("reports/weekly.csv").await?;
+//! assert_eq!(file.filename(), "weekly.csv");
+//! assert_eq!(file.content_type(), "text/csv");
+//!
+//! let attachment = file.into_attachment(1);
+//! # Ok(()) }
+//! ```
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Error as IoError, ErrorKind as IoErrorKind},
+    path::Path,
+};
+use twilight_model::http::attachment::Attachment;
+
+/// Content type of files with an unknown extension.
+const OCTET_STREAM: &str = "application/octet-stream";
+
+/// Error when [reading] an [`AttachmentFile`] from a path.
+///
+/// [reading]: AttachmentFile::from_path
+#[derive(Debug)]
+pub struct AttachmentFileError {
+    kind: AttachmentFileErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl AttachmentFileError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &AttachmentFileErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        AttachmentFileErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+
+    /// Create an error from an I/O error that occurred reading the file.
+    fn read(source: IoError) -> Self {
+        let kind = match source.kind() {
+            IoErrorKind::NotFound => AttachmentFileErrorType::NotFound,
+            IoErrorKind::PermissionDenied => AttachmentFileErrorType::PermissionDenied,
+            _ => AttachmentFileErrorType::Reading,
+        };
+
+        Self {
+            kind,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for AttachmentFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            AttachmentFileErrorType::FilenameMissing => f.write_str("path has no filename"),
+            AttachmentFileErrorType::NotFound => f.write_str("file was not found"),
+            AttachmentFileErrorType::PermissionDenied => {
+                f.write_str("permission to read the file was denied")
+            }
+            AttachmentFileErrorType::Reading => f.write_str("failed to read the file"),
+        }
+    }
+}
+
+impl Error for AttachmentFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`AttachmentFileError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AttachmentFileErrorType {
+    /// Path doesn't end in a filename, such as `..` or `/`.
+    FilenameMissing,
+    /// File doesn't exist.
+    NotFound,
+    /// Permission to read the file was denied.
+    PermissionDenied,
+    /// Reading the file failed for another reason.
+    ///
+    /// The source error is the [`std::io::Error`] that occurred.
+    Reading,
+}
+
+/// File read from disk to be sent as an [`Attachment`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct AttachmentFile {
+    /// Content type guessed from the file's extension.
+    content_type: &'static str,
+    /// Content of the file.
+    file: Vec<u8>,
+    /// Name of the file.
+    filename: String,
+}
+
+impl AttachmentFile {
+    /// Read a file, inferring its filename from the path and guessing its
+    /// content type from its extension.
+    ///
+    /// Files with an unknown extension have a content type of
+    /// `application/octet-stream`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`FilenameMissing`] if the path doesn't end in
+    /// a filename.
+    ///
+    /// Returns an error of type [`NotFound`] if the file doesn't exist.
+    ///
+    /// Returns an error of type [`PermissionDenied`] if permission to read the
+    /// file was denied.
+    ///
+    /// Returns an error of type [`Reading`] if reading the file otherwise
+    /// failed.
+    ///
+    /// [`FilenameMissing`]: AttachmentFileErrorType::FilenameMissing
+    /// [`NotFound`]: AttachmentFileErrorType::NotFound
+    /// [`PermissionDenied`]: AttachmentFileErrorType::PermissionDenied
+    /// [`Reading`]: AttachmentFileErrorType::Reading
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<Self, AttachmentFileError> {
+        let path = path.as_ref();
+
+        let filename = path
+            .file_name()
+            .ok_or(AttachmentFileError {
+                kind: AttachmentFileErrorType::FilenameMissing,
+                source: None,
+            })?
+            .to_string_lossy()
+            .into_owned();
+
+        let file = tokio::fs::read(path)
+            .await
+            .map_err(AttachmentFileError::read)?;
+
+        Ok(Self {
+            content_type: content_type(path),
+            file,
+            filename,
+        })
+    }
+
+    /// Content type guessed from the file's extension.
+    pub const fn content_type(&self) -> &'static str {
+        self.content_type
+    }
+
+    /// Content of the file.
+    pub fn file(&self) -> &[u8] {
+        &self.file
+    }
+
+    /// Name of the file.
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Consume the file, creating an attachment with the given ID and the
+    /// guessed content type.
+    ///
+    /// Refer to [`Attachment::id`] for the requirements of attachment IDs.
+    pub fn into_attachment(self, id: u64) -> Attachment {
+        let mut attachment = Attachment::from_bytes(self.filename, self.file, id);
+        attachment.content_type(self.content_type.to_owned());
+
+        attachment
+    }
+}
+
+/// Guess the content type of a file from its extension.
+fn content_type(path: &Path) -> &'static str {
+    let Some(extension) = path.extension().and_then(|extension| extension.to_str()) else {
+        return OCTET_STREAM;
+    };
+
+    match extension.to_ascii_lowercase().as_str() {
+        "avif" => "image/avif",
+        "csv" => "text/csv",
+        "gif" => "image/gif",
+        "htm" | "html" => "text/html",
+        "jpeg" | "jpg" => "image/jpeg",
+        "json" => "application/json",
+        "log" | "txt" => "text/plain",
+        "md" => "text/markdown",
+        "mov" => "video/quicktime",
+        "mp3" => "audio/mpeg",
+        "mp4" => "video/mp4",
+        "ogg" => "audio/ogg",
+        "pdf" => "application/pdf",
+        "png" => "image/png",
+        "svg" => "image/svg+xml",
+        "wav" => "audio/wav",
+        "webm" => "video/webm",
+        "webp" => "image/webp",
+        "zip" => "application/zip",
+        _ => OCTET_STREAM,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{content_type, AttachmentFile, AttachmentFileError, AttachmentFileErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{
+        env,
+        error::Error,
+        fmt::Debug,
+        fs,
+        path::{Path, PathBuf},
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    assert_impl_all!(AttachmentFile: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(AttachmentFileErrorType: Debug, Send, Sync);
+    assert_impl_all!(AttachmentFileError: Error, Send, Sync);
+
+    /// Path to a file in the temporary directory unique to this test run,
+    /// so that concurrent runs don't share files.
+    fn temp_path(extension: &str) -> PathBuf {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        env::temp_dir().join(format!(
+            "twilight_util_attachment_{}_{}.{extension}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed),
+        ))
+    }
+
+    #[test]
+    fn guess_content_type() {
+        assert_eq!("image/png", content_type(Path::new("cat.png")));
+        assert_eq!("image/jpeg", content_type(Path::new("dir/cat.JPG")));
+        assert_eq!("text/plain", content_type(Path::new("logs.tar.txt")));
+        assert_eq!("application/octet-stream", content_type(Path::new("cat")));
+        assert_eq!(
+            "application/octet-stream",
+            content_type(Path::new("cat.unknown"))
+        );
+    }
+
+    #[tokio::test]
+    async fn from_path() {
+        let path = temp_path("csv");
+        let filename = path.file_name().unwrap().to_str().unwrap().to_owned();
+        fs::write(&path, b"a,b\n1,2\n").unwrap();
+
+        let file = AttachmentFile::from_path(&path).await.unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(filename, file.filename());
+        assert_eq!("text/csv", file.content_type());
+        assert_eq!(b"a,b\n1,2\n", file.file());
+
+        let attachment = file.into_attachment(3);
+        assert_eq!(filename, attachment.filename);
+        assert_eq!(Some("text/csv"), attachment.content_type.as_deref());
+        assert_eq!(3, attachment.id);
+    }
+
+    #[tokio::test]
+    async fn not_found() {
+        let path = temp_path("txt");
+        let error = AttachmentFile::from_path(path).await.unwrap_err();
+
+        assert!(matches!(error.kind(), AttachmentFileErrorType::NotFound));
+        assert!(error.source().is_some());
+    }
+
+    #[tokio::test]
+    async fn filename_missing() {
+        let error = AttachmentFile::from_path("..").await.unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            AttachmentFileErrorType::FilenameMissing
+        ));
+    }
+}
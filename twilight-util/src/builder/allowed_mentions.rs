@@ -0,0 +1,253 @@
+use twilight_model::{
+    channel::message::{AllowedMentions, MentionType},
+    id::{
+        marker::{RoleMarker, UserMarker},
+        Id,
+    },
+};
+use twilight_validate::message::{
+    allowed_mentions as validate_allowed_mentions, MessageValidationError,
+};
+
+/// Create an [`AllowedMentions`] with a builder.
+///
+/// A new builder allows no mentions at all. Discord rejects allowed mentions
+/// that both parse all roles or users and list specific ones, so listing
+/// specific roles or users removes the corresponding [`MentionType`] from
+/// [`parse`], and vice versa. Each list may have at most 100 IDs, which is
+/// checked by [`validate`].
+///
+/// # Examples
+///
+/// Allow mentioning a role, but not `@everyone` or any other role:
+///
+/// ```
+/// use twilight_model::id::Id;
+/// use twilight_util::builder::AllowedMentionsBuilder;
+///
+/// let allowed_mentions = AllowedMentionsBuilder::new()
+///     .roles([Id::new(1)])
+///     .build();
+///
+/// assert!(allowed_mentions.parse.is_empty());
+/// assert_eq!(Vec::from([Id::new(1)]), allowed_mentions.roles);
+/// ```
+///
+/// [`parse`]: AllowedMentions::parse
+/// [`validate`]: Self::validate
+#[derive(Clone, Debug)]
+#[must_use = "builders have no effect if unused"]
+pub struct AllowedMentionsBuilder(AllowedMentions);
+
+impl AllowedMentionsBuilder {
+    /// Create a new builder to construct an [`AllowedMentions`].
+    ///
+    /// Allows no mentions until otherwise configured.
+    pub const fn new() -> Self {
+        Self(AllowedMentions {
+            parse: Vec::new(),
+            replied_user: false,
+            roles: Vec::new(),
+            users: Vec::new(),
+        })
+    }
+
+    /// Consume the builder, returning an [`AllowedMentions`].
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "builders have no effect if unused"]
+    pub fn build(self) -> AllowedMentions {
+        self.0
+    }
+
+    /// Ensure the allowed mentions are valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the documentation of
+    /// [`twilight_validate::message::allowed_mentions`] for possible errors.
+    pub fn validate(self) -> Result<Self, MessageValidationError> {
+        validate_allowed_mentions(&self.0)?;
+
+        Ok(self)
+    }
+
+    /// Allow mentioning all roles.
+    ///
+    /// Clears roles previously set via [`roles`].
+    ///
+    /// [`roles`]: Self::roles
+    pub fn all_roles(mut self) -> Self {
+        self.0.roles.clear();
+        self.parse(MentionType::Roles)
+    }
+
+    /// Allow mentioning all users.
+    ///
+    /// Clears users previously set via [`users`].
+    ///
+    /// [`users`]: Self::users
+    pub fn all_users(mut self) -> Self {
+        self.0.users.clear();
+        self.parse(MentionType::Users)
+    }
+
+    /// Allow `@everyone` and `@here` mentions.
+    pub fn everyone(self) -> Self {
+        self.parse(MentionType::Everyone)
+    }
+
+    /// Set whether to mention the author of the message being replied to.
+    ///
+    /// Defaults to `false`.
+    pub const fn replied_user(mut self, replied_user: bool) -> Self {
+        self.0.replied_user = replied_user;
+
+        self
+    }
+
+    /// Allow mentioning specific roles.
+    ///
+    /// No longer allows mentioning all roles if [`all_roles`] was called.
+    ///
+    /// [`all_roles`]: Self::all_roles
+    pub fn roles(mut self, roles: impl IntoIterator<Item = Id<RoleMarker>>) -> Self {
+        self.0.parse.retain(|kind| *kind != MentionType::Roles);
+        self.0.roles.extend(roles);
+
+        self
+    }
+
+    /// Allow mentioning specific users.
+    ///
+    /// No longer allows mentioning all users if [`all_users`] was called.
+    ///
+    /// [`all_users`]: Self::all_users
+    pub fn users(mut self, users: impl IntoIterator<Item = Id<UserMarker>>) -> Self {
+        self.0.parse.retain(|kind| *kind != MentionType::Users);
+        self.0.users.extend(users);
+
+        self
+    }
+
+    /// Add a mention type to parse, if not already present.
+    fn parse(mut self, kind: MentionType) -> Self {
+        if !self.0.parse.contains(&kind) {
+            self.0.parse.push(kind);
+        }
+
+        self
+    }
+}
+
+impl Default for AllowedMentionsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<AllowedMentionsBuilder> for AllowedMentions {
+    fn from(builder: AllowedMentionsBuilder) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_validate::message::MessageValidationErrorType;
+
+    assert_impl_all!(AllowedMentionsBuilder: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(AllowedMentions: From<AllowedMentionsBuilder>);
+
+    #[test]
+    fn nobody() {
+        assert_eq!(
+            AllowedMentions::default(),
+            AllowedMentionsBuilder::new().build()
+        );
+    }
+
+    #[test]
+    fn replied_user_only() {
+        let allowed_mentions = AllowedMentionsBuilder::new().replied_user(true).build();
+
+        assert_eq!(
+            AllowedMentions {
+                replied_user: true,
+                ..AllowedMentions::default()
+            },
+            allowed_mentions
+        );
+    }
+
+    #[test]
+    fn everyone() {
+        let allowed_mentions = AllowedMentionsBuilder::new().everyone().everyone().build();
+
+        assert_eq!(Vec::from([MentionType::Everyone]), allowed_mentions.parse);
+    }
+
+    #[test]
+    fn specific_ids_remove_parse() {
+        let allowed_mentions = AllowedMentionsBuilder::new()
+            .everyone()
+            .all_roles()
+            .all_users()
+            .roles([Id::new(1)])
+            .users([Id::new(2), Id::new(3)])
+            .build();
+
+        assert_eq!(
+            AllowedMentions {
+                parse: Vec::from([MentionType::Everyone]),
+                replied_user: false,
+                roles: Vec::from([Id::new(1)]),
+                users: Vec::from([Id::new(2), Id::new(3)]),
+            },
+            allowed_mentions
+        );
+    }
+
+    #[test]
+    fn parse_removes_specific_ids() {
+        let allowed_mentions = AllowedMentionsBuilder::new()
+            .roles([Id::new(1)])
+            .users([Id::new(2)])
+            .all_roles()
+            .all_users()
+            .build();
+
+        assert_eq!(
+            AllowedMentions {
+                parse: Vec::from([MentionType::Roles, MentionType::Users]),
+                replied_user: false,
+                roles: Vec::new(),
+                users: Vec::new(),
+            },
+            allowed_mentions
+        );
+    }
+
+    #[test]
+    fn validate() {
+        assert!(AllowedMentionsBuilder::new()
+            .users((1..=100).map(Id::new))
+            .validate()
+            .is_ok());
+
+        let error = AllowedMentionsBuilder::new()
+            .all_users()
+            .roles((1..=101).map(Id::new))
+            .validate()
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            MessageValidationErrorType::AllowedMentionsTooMany {
+                kind: MentionType::Roles,
+                len: 101
+            }
+        ));
+    }
+}
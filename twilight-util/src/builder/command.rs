@@ -57,7 +57,9 @@ use twilight_model::{
     id::{marker::GuildMarker, Id},
     oauth::ApplicationIntegrationType,
 };
-use twilight_validate::command::{command as validate_command, CommandValidationError};
+use twilight_validate::command::{
+    command as validate_command, option as validate_option, CommandValidationError,
+};
 
 /// Builder to create a [`Command`].
 #[derive(Clone, Debug)]
@@ -511,9 +513,31 @@ impl IntegerBuilder {
         self.0
     }
 
+    /// Ensure the command option is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`OptionAutocompleteChoicesConflict`] if
+    /// autocomplete is enabled and choices were set.
+    ///
+    /// Refer to the errors section of [`twilight_validate::command::option`]
+    /// for other possible errors.
+    ///
+    /// [`OptionAutocompleteChoicesConflict`]: twilight_validate::command::CommandValidationErrorType::OptionAutocompleteChoicesConflict
+    pub fn validate(self) -> Result<Self, CommandValidationError> {
+        validate_option(&self.0)?;
+
+        Ok(self)
+    }
+
     /// Set whether this option supports autocomplete.
     ///
+    /// Autocomplete may not be enabled if choices are set, which is checked by
+    /// [`validate`].
+    ///
     /// Defaults to `false`.
+    ///
+    /// [`validate`]: Self::validate
     pub const fn autocomplete(mut self, autocomplete: bool) -> Self {
         self.0.autocomplete = Some(autocomplete);
 
@@ -764,9 +788,31 @@ impl NumberBuilder {
         self.0
     }
 
+    /// Ensure the command option is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`OptionAutocompleteChoicesConflict`] if
+    /// autocomplete is enabled and choices were set.
+    ///
+    /// Refer to the errors section of [`twilight_validate::command::option`]
+    /// for other possible errors.
+    ///
+    /// [`OptionAutocompleteChoicesConflict`]: twilight_validate::command::CommandValidationErrorType::OptionAutocompleteChoicesConflict
+    pub fn validate(self) -> Result<Self, CommandValidationError> {
+        validate_option(&self.0)?;
+
+        Ok(self)
+    }
+
     /// Set whether this option supports autocomplete.
     ///
+    /// Autocomplete may not be enabled if choices are set, which is checked by
+    /// [`validate`].
+    ///
     /// Defaults to `false`.
+    ///
+    /// [`validate`]: Self::validate
     pub const fn autocomplete(mut self, autocomplete: bool) -> Self {
         self.0.autocomplete = Some(autocomplete);
 
@@ -1017,9 +1063,31 @@ impl StringBuilder {
         self.0
     }
 
+    /// Ensure the command option is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`OptionAutocompleteChoicesConflict`] if
+    /// autocomplete is enabled and choices were set.
+    ///
+    /// Refer to the errors section of [`twilight_validate::command::option`]
+    /// for other possible errors.
+    ///
+    /// [`OptionAutocompleteChoicesConflict`]: twilight_validate::command::CommandValidationErrorType::OptionAutocompleteChoicesConflict
+    pub fn validate(self) -> Result<Self, CommandValidationError> {
+        validate_option(&self.0)?;
+
+        Ok(self)
+    }
+
     /// Set whether this option supports autocomplete.
     ///
+    /// Autocomplete may not be enabled if choices are set, which is checked by
+    /// [`validate`].
+    ///
     /// Defaults to `false`.
+    ///
+    /// [`validate`]: Self::validate
     pub const fn autocomplete(mut self, autocomplete: bool) -> Self {
         self.0.autocomplete = Some(autocomplete);
 
@@ -1420,6 +1488,7 @@ mod tests {
     use super::*;
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
+    use twilight_validate::command::CommandValidationErrorType;
 
     assert_impl_all!(AttachmentBuilder: Clone, Debug, Send, Sync);
     assert_impl_all!(CommandBuilder: Clone, Debug, Send, Sync);
@@ -1753,4 +1822,34 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn validate_autocomplete() {
+        assert!(StringBuilder::new("name", "description")
+            .autocomplete(true)
+            .validate()
+            .is_ok());
+        assert!(IntegerBuilder::new("name", "description")
+            .autocomplete(true)
+            .validate()
+            .is_ok());
+
+        let result = StringBuilder::new("name", "description")
+            .autocomplete(true)
+            .choices([("choice", "value")])
+            .validate();
+        assert!(matches!(
+            result.unwrap_err().kind(),
+            CommandValidationErrorType::OptionAutocompleteChoicesConflict
+        ));
+
+        let result = IntegerBuilder::new("name", "description")
+            .choices([("choice", 1)])
+            .autocomplete(true)
+            .validate();
+        assert!(matches!(
+            result.unwrap_err().kind(),
+            CommandValidationErrorType::OptionAutocompleteChoicesConflict
+        ));
+    }
 }
@@ -0,0 +1,285 @@
+//! Create mock [`CommandData`] for testing command handlers.
+
+use std::collections::HashMap;
+use twilight_model::{
+    application::{
+        command::Command,
+        interaction::{
+            application_command::{CommandData, CommandDataOption, CommandOptionValue},
+            InteractionChannel, InteractionDataResolved, InteractionMember,
+        },
+    },
+    channel::Attachment,
+    guild::Role,
+    id::{
+        marker::{CommandMarker, GenericMarker},
+        Id,
+    },
+    user::User,
+};
+
+/// Create [`CommandData`] from a [`Command`] with a builder.
+///
+/// Useful for testing command handlers without receiving interactions from
+/// the gateway. The created data may be wrapped in an
+/// [`InteractionData::ApplicationCommand`] to mock a full interaction.
+///
+/// Channel, role, and user options also insert the provided models into the
+/// data's [`InteractionDataResolved`], like Discord does.
+///
+/// # Example
+///
+/// ```
+/// use twilight_model::application::{
+///     command::CommandType, interaction::application_command::CommandOptionValue,
+/// };
+/// use twilight_util::builder::{
+///     command::{CommandBuilder, StringBuilder},
+///     CommandDataBuilder,
+/// };
+///
+/// let command = CommandBuilder::new("echo", "Repeat a message", CommandType::ChatInput)
+///     .option(StringBuilder::new("message", "Message to repeat").required(true))
+///     .build();
+///
+/// let data = CommandDataBuilder::new(&command)
+///     .option("message", CommandOptionValue::String("hello".to_owned()))
+///     .build();
+///
+/// assert_eq!(data.name, "echo");
+/// assert_eq!(
+///     data.options[0].value,
+///     CommandOptionValue::String("hello".to_owned()),
+/// );
+/// ```
+///
+/// [`InteractionData::ApplicationCommand`]: twilight_model::application::interaction::InteractionData::ApplicationCommand
+#[derive(Clone, Debug)]
+#[must_use = "builders have no effect if unused"]
+pub struct CommandDataBuilder(CommandData);
+
+impl CommandDataBuilder {
+    /// ID used when the command hasn't been registered and has no ID.
+    const PLACEHOLDER_ID: Id<CommandMarker> = Id::new(1);
+
+    /// Create a new builder to construct [`CommandData`] for a [`Command`].
+    ///
+    /// The name, type, and guild ID are taken from the command. Its ID is
+    /// used if present, otherwise an ID of `1` is used.
+    pub fn new(command: &Command) -> Self {
+        Self(CommandData {
+            guild_id: command.guild_id,
+            id: command.id.unwrap_or(Self::PLACEHOLDER_ID),
+            name: command.name.clone(),
+            kind: command.kind,
+            options: Vec::new(),
+            resolved: None,
+            target_id: None,
+        })
+    }
+
+    /// Consume the builder, returning [`CommandData`].
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "builders have no effect if unused"]
+    pub fn build(self) -> CommandData {
+        self.0
+    }
+
+    /// Add an attachment option, resolving the attachment.
+    pub fn attachment(mut self, name: impl Into<String>, attachment: Attachment) -> Self {
+        self = self.option(name, CommandOptionValue::Attachment(attachment.id));
+        self.resolved()
+            .attachments
+            .insert(attachment.id, attachment);
+
+        self
+    }
+
+    /// Add a channel option, resolving the channel.
+    pub fn channel(mut self, name: impl Into<String>, channel: InteractionChannel) -> Self {
+        self = self.option(name, CommandOptionValue::Channel(channel.id));
+        self.resolved().channels.insert(channel.id, channel);
+
+        self
+    }
+
+    /// Add an option with a value.
+    ///
+    /// Values referencing other resources, such as users, are not resolved;
+    /// use the dedicated methods for those.
+    pub fn option(mut self, name: impl Into<String>, value: CommandOptionValue) -> Self {
+        self.0.options.push(CommandDataOption {
+            name: name.into(),
+            value,
+        });
+
+        self
+    }
+
+    /// Add a role option, resolving the role.
+    pub fn role(mut self, name: impl Into<String>, role: Role) -> Self {
+        self = self.option(name, CommandOptionValue::Role(role.id));
+        self.resolved().roles.insert(role.id, role);
+
+        self
+    }
+
+    /// Set the ID of the user or message targeted by a user or message
+    /// command.
+    ///
+    /// Defaults to [`None`].
+    pub const fn target_id(mut self, target_id: Id<GenericMarker>) -> Self {
+        self.0.target_id = Some(target_id);
+
+        self
+    }
+
+    /// Add a user option, resolving the user and, if provided, their member.
+    pub fn user(
+        mut self,
+        name: impl Into<String>,
+        user: User,
+        member: Option<InteractionMember>,
+    ) -> Self {
+        self = self.option(name, CommandOptionValue::User(user.id));
+
+        let resolved = self.resolved();
+        if let Some(member) = member {
+            resolved.members.insert(user.id, member);
+        }
+        resolved.users.insert(user.id, user);
+
+        self
+    }
+
+    /// Resolved data, created if not yet present.
+    fn resolved(&mut self) -> &mut InteractionDataResolved {
+        self.0
+            .resolved
+            .get_or_insert_with(|| InteractionDataResolved {
+                attachments: HashMap::new(),
+                channels: HashMap::new(),
+                members: HashMap::new(),
+                messages: HashMap::new(),
+                roles: HashMap::new(),
+                users: HashMap::new(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::command::{
+        ChannelBuilder, CommandBuilder, IntegerBuilder, RoleBuilder, UserBuilder,
+    };
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        application::command::CommandType,
+        channel::ChannelType,
+        guild::{Permissions, RoleFlags},
+        id::Id,
+    };
+
+    assert_impl_all!(CommandDataBuilder: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn command_data() {
+        let mut command = CommandBuilder::new("test", "Test command", CommandType::ChatInput)
+            .guild_id(Id::new(2))
+            .option(ChannelBuilder::new("channel", "A channel"))
+            .option(IntegerBuilder::new("count", "A count"))
+            .option(RoleBuilder::new("role", "A role"))
+            .option(UserBuilder::new("user", "A user"))
+            .build();
+
+        let channel = InteractionChannel {
+            id: Id::new(3),
+            kind: ChannelType::GuildText,
+            name: "general".to_owned(),
+            parent_id: None,
+            permissions: Permissions::empty(),
+            thread_metadata: None,
+        };
+        let role = Role {
+            color: 0,
+            hoist: false,
+            icon: None,
+            id: Id::new(4),
+            managed: false,
+            mentionable: false,
+            name: "role".to_owned(),
+            permissions: Permissions::empty(),
+            position: 0,
+            flags: RoleFlags::empty(),
+            tags: None,
+            unicode_emoji: None,
+        };
+        let user = User {
+            accent_color: None,
+            avatar: None,
+            avatar_decoration: None,
+            avatar_decoration_data: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            global_name: None,
+            id: Id::new(5),
+            locale: None,
+            mfa_enabled: None,
+            name: "user".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        };
+
+        let data = CommandDataBuilder::new(&command)
+            .channel("channel", channel.clone())
+            .option("count", CommandOptionValue::Integer(7))
+            .role("role", role.clone())
+            .user("user", user.clone(), None)
+            .build();
+
+        assert_eq!(data.guild_id, Some(Id::new(2)));
+        assert_eq!(data.id, Id::new(1));
+        assert_eq!(data.name, "test");
+        assert_eq!(data.kind, CommandType::ChatInput);
+        assert_eq!(
+            data.options,
+            [
+                CommandDataOption {
+                    name: "channel".to_owned(),
+                    value: CommandOptionValue::Channel(channel.id),
+                },
+                CommandDataOption {
+                    name: "count".to_owned(),
+                    value: CommandOptionValue::Integer(7),
+                },
+                CommandDataOption {
+                    name: "role".to_owned(),
+                    value: CommandOptionValue::Role(role.id),
+                },
+                CommandDataOption {
+                    name: "user".to_owned(),
+                    value: CommandOptionValue::User(user.id),
+                },
+            ]
+        );
+
+        let resolved = data.resolved.unwrap();
+        assert_eq!(resolved.channels.get(&channel.id), Some(&channel));
+        assert_eq!(resolved.roles.get(&role.id), Some(&role));
+        assert_eq!(resolved.users.get(&user.id), Some(&user));
+        assert!(resolved.members.is_empty());
+
+        command.id = Some(Id::new(6));
+        let data = CommandDataBuilder::new(&command).build();
+        assert_eq!(data.id, Id::new(6));
+        assert!(data.options.is_empty());
+        assert!(data.resolved.is_none());
+    }
+}
@@ -5,15 +5,19 @@ pub mod image_source;
 mod author;
 mod field;
 mod footer;
+mod template;
 
 pub use self::{
-    author::EmbedAuthorBuilder, field::EmbedFieldBuilder, footer::EmbedFooterBuilder,
+    author::EmbedAuthorBuilder,
+    field::EmbedFieldBuilder,
+    footer::EmbedFooterBuilder,
     image_source::ImageSource,
+    template::{EmbedTemplate, EmbedTemplateError, EmbedTemplateErrorType, UnknownPlaceholders},
 };
 
 use twilight_model::{
     channel::message::embed::{
-        Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedThumbnail,
+        Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedThumbnail, EmbedVideo,
     },
     util::Timestamp,
 };
@@ -338,6 +342,39 @@ impl EmbedBuilder {
 
         self
     }
+
+    /// Set the video.
+    ///
+    /// Discord may not display videos in embeds sent by bots, so prefer
+    /// linking to the video in the description or via [`url`] where that
+    /// matters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_util::builder::embed::{EmbedBuilder, ImageSource};
+    ///
+    /// let embed = EmbedBuilder::new()
+    ///     .title("a video of twilight")
+    ///     .video(ImageSource::url("https://example.com/twilight.mp4")?)
+    ///     .validate()?
+    ///     .build();
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`url`]: Self::url
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn video(mut self, source: ImageSource) -> Self {
+        self.0.video = Some(EmbedVideo {
+            height: None,
+            proxy_url: None,
+            url: Some(source.0),
+            width: None,
+        });
+
+        self
+    }
 }
 
 impl Default for EmbedBuilder {
@@ -350,6 +387,24 @@ impl Default for EmbedBuilder {
 }
 
 impl From<Embed> for EmbedBuilder {
+    /// Create an embed builder from an existing embed, such as to edit it.
+    ///
+    /// All fields are kept, except for the type being set to "rich".
+    ///
+    /// # Examples
+    ///
+    /// Change the description of an embed:
+    ///
+    /// ```
+    /// use twilight_model::channel::message::Embed;
+    /// use twilight_util::builder::embed::EmbedBuilder;
+    ///
+    /// fn edit(embed: Embed) -> Embed {
+    ///     EmbedBuilder::from(embed)
+    ///         .description("an edited description")
+    ///         .build()
+    /// }
+    /// ```
     fn from(value: Embed) -> Self {
         Self(Embed {
             kind: "rich".to_owned(),
@@ -425,4 +480,58 @@ mod tests {
 
         assert_eq!(embed, expected);
     }
+
+    #[test]
+    fn from_embed() -> Result<(), Box<dyn std::error::Error>> {
+        let embed = EmbedBuilder::new()
+            .author(EmbedAuthorBuilder::new("author").url("https://example.com"))
+            .color(0x00_43_ff)
+            .description("description")
+            .field(EmbedFieldBuilder::new("name", "value").inline())
+            .field(EmbedFieldBuilder::new("other name", "other value"))
+            .footer(EmbedFooterBuilder::new("footer"))
+            .image(ImageSource::url("https://example.com/image.png")?)
+            .thumbnail(ImageSource::attachment("thumbnail.png")?)
+            .timestamp(Timestamp::from_secs(1_580_608_922)?)
+            .title("title")
+            .url("https://example.com")
+            .build();
+
+        assert_eq!(embed, EmbedBuilder::from(embed.clone()).build());
+
+        let edited = EmbedBuilder::from(embed.clone())
+            .description("edited")
+            .build();
+        assert_eq!(
+            Embed {
+                description: Some("edited".to_owned()),
+                ..embed
+            },
+            edited
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_sources() -> Result<(), Box<dyn std::error::Error>> {
+        let embed = EmbedBuilder::new()
+            .image(ImageSource::url("https://example.com/image.png")?)
+            .thumbnail(ImageSource::attachment("thumbnail.png")?)
+            .video(ImageSource::url("https://example.com/video.mp4")?)
+            .validate()?
+            .build();
+
+        assert_eq!(
+            serde_json::json!({
+                "image": {"url": "https://example.com/image.png"},
+                "thumbnail": {"url": "attachment://thumbnail.png"},
+                "type": "rich",
+                "video": {"url": "https://example.com/video.mp4"},
+            }),
+            serde_json::to_value(embed)?
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,354 @@
+use super::EmbedBuilder;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::channel::message::Embed;
+use twilight_validate::embed::embed as validate_embed;
+
+/// Error rendering an [`EmbedTemplate`].
+#[derive(Debug)]
+pub struct EmbedTemplateError {
+    kind: EmbedTemplateErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl EmbedTemplateError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &EmbedTemplateErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (EmbedTemplateErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for EmbedTemplateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            EmbedTemplateErrorType::UnknownPlaceholder { name } => {
+                f.write_str("placeholder `")?;
+                f.write_str(name)?;
+
+                f.write_str("` has no value")
+            }
+            EmbedTemplateErrorType::Validation => f.write_str("rendered embed is invalid"),
+        }
+    }
+}
+
+impl Error for EmbedTemplateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`EmbedTemplateError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EmbedTemplateErrorType {
+    /// A placeholder has no value and [`UnknownPlaceholders::Error`] is
+    /// configured.
+    UnknownPlaceholder {
+        /// Name of the placeholder, without braces.
+        name: String,
+    },
+    /// The rendered embed is invalid.
+    ///
+    /// The source is an [`EmbedValidationError`].
+    ///
+    /// [`EmbedValidationError`]: twilight_validate::embed::EmbedValidationError
+    Validation,
+}
+
+/// How to render placeholders without a value.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum UnknownPlaceholders {
+    /// Return an error of type [`EmbedTemplateErrorType::UnknownPlaceholder`].
+    Error,
+    /// Leave the placeholder in the text as written.
+    #[default]
+    Keep,
+}
+
+/// Embed with `{key}` placeholders substituted when rendered.
+///
+/// Placeholders are substituted in the title, description, field names and
+/// values, footer text, and author name. Substituted values aren't searched
+/// for placeholders themselves, so user provided values can't inject other
+/// values. Braces not enclosing a name, such as `{}`, are kept as text.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::collections::HashMap;
+/// use twilight_util::builder::embed::{EmbedBuilder, EmbedTemplate};
+///
+/// let template =
+///     EmbedTemplate::from(EmbedBuilder::new().description("Welcome {user} to {guild}!"));
+/// let vars = HashMap::from([("user", "Twilight"), ("guild", "Equestria")]);
+///
+/// let embed = template.render(&vars)?;
+/// assert_eq!(
+///     Some("Welcome Twilight to Equestria!"),
+///     embed.description.as_deref()
+/// );
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmbedTemplate {
+    embed: Embed,
+    unknown_placeholders: UnknownPlaceholders,
+}
+
+impl EmbedTemplate {
+    /// Create a new template from an embed.
+    ///
+    /// Placeholders without a value are kept as written by default.
+    pub const fn new(embed: Embed) -> Self {
+        Self {
+            embed,
+            unknown_placeholders: UnknownPlaceholders::Keep,
+        }
+    }
+
+    /// Set how to render placeholders without a value.
+    ///
+    /// Defaults to [`UnknownPlaceholders::Keep`].
+    #[must_use = "has no effect if unused"]
+    pub const fn unknown_placeholders(mut self, unknown_placeholders: UnknownPlaceholders) -> Self {
+        self.unknown_placeholders = unknown_placeholders;
+
+        self
+    }
+
+    /// Render the template, substituting placeholders by their value in
+    /// `vars`, and validate the rendered embed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`UnknownPlaceholder`] if a placeholder has no
+    /// value and [`UnknownPlaceholders::Error`] is configured.
+    ///
+    /// Returns an error of type [`Validation`] if the rendered embed is
+    /// invalid, such as a value making a field too long. Refer to
+    /// [`twilight_validate::embed::embed`] for possible errors.
+    ///
+    /// [`UnknownPlaceholder`]: EmbedTemplateErrorType::UnknownPlaceholder
+    /// [`Validation`]: EmbedTemplateErrorType::Validation
+    pub fn render(&self, vars: &HashMap<&str, &str>) -> Result<Embed, EmbedTemplateError> {
+        let mut embed = self.embed.clone();
+        let render = |text: &mut String| -> Result<(), EmbedTemplateError> {
+            *text = substitute(text, vars, self.unknown_placeholders)?;
+
+            Ok(())
+        };
+
+        if let Some(author) = embed.author.as_mut() {
+            render(&mut author.name)?;
+        }
+
+        if let Some(description) = embed.description.as_mut() {
+            render(description)?;
+        }
+
+        for field in &mut embed.fields {
+            render(&mut field.name)?;
+            render(&mut field.value)?;
+        }
+
+        if let Some(footer) = embed.footer.as_mut() {
+            render(&mut footer.text)?;
+        }
+
+        if let Some(title) = embed.title.as_mut() {
+            render(title)?;
+        }
+
+        validate_embed(&embed).map_err(|source| EmbedTemplateError {
+            kind: EmbedTemplateErrorType::Validation,
+            source: Some(Box::new(source)),
+        })?;
+
+        Ok(embed)
+    }
+}
+
+impl From<Embed> for EmbedTemplate {
+    fn from(embed: Embed) -> Self {
+        Self::new(embed)
+    }
+}
+
+impl From<EmbedBuilder> for EmbedTemplate {
+    fn from(builder: EmbedBuilder) -> Self {
+        Self::new(builder.build())
+    }
+}
+
+/// Substitute the `{key}` placeholders in a text.
+///
+/// Braces not enclosing a placeholder name, such as a lone `{` or `{}`, are
+/// kept.
+fn substitute(
+    text: &str,
+    vars: &HashMap<&str, &str>,
+    unknown_placeholders: UnknownPlaceholders,
+) -> Result<String, EmbedTemplateError> {
+    let mut rendered = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        match after.find(['{', '}']) {
+            Some(end) if end > 0 && after[end..].starts_with('}') => {
+                let name = &after[..end];
+
+                if let Some(value) = vars.get(name) {
+                    rendered.push_str(value);
+                } else if unknown_placeholders == UnknownPlaceholders::Keep {
+                    rendered.push_str(&rest[start..=start + end + 1]);
+                } else {
+                    return Err(EmbedTemplateError {
+                        kind: EmbedTemplateErrorType::UnknownPlaceholder {
+                            name: name.to_owned(),
+                        },
+                        source: None,
+                    });
+                }
+
+                rest = &after[end + 1..];
+            }
+            _ => {
+                rendered.push('{');
+                rest = after;
+            }
+        }
+    }
+
+    rendered.push_str(rest);
+
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EmbedTemplate, EmbedTemplateError, EmbedTemplateErrorType, UnknownPlaceholders};
+    use crate::builder::embed::{
+        EmbedAuthorBuilder, EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{collections::HashMap, error::Error, fmt::Debug};
+    use twilight_validate::embed::{
+        EmbedValidationError, EmbedValidationErrorType, FIELD_VALUE_LENGTH,
+    };
+
+    assert_impl_all!(EmbedTemplate: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(EmbedTemplateError: Error, Send, Sync);
+    assert_impl_all!(UnknownPlaceholders: Clone, Copy, Debug, Default, Eq, Send, Sync);
+
+    #[test]
+    fn render() -> Result<(), Box<dyn Error>> {
+        let template = EmbedTemplate::from(
+            EmbedBuilder::new()
+                .author(EmbedAuthorBuilder::new("{user}"))
+                .description("Welcome {user} to {guild}!")
+                .field(EmbedFieldBuilder::new("{guild}", "{members} members"))
+                .footer(EmbedFooterBuilder::new("{ {user} }"))
+                .title("{user}{user}"),
+        );
+        let vars = HashMap::from([
+            ("guild", "Equestria"),
+            ("members", "6"),
+            ("user", "{guild}"),
+        ]);
+        let embed = template.render(&vars)?;
+
+        assert_eq!("{guild}", embed.author.unwrap().name);
+        assert_eq!(
+            Some("Welcome {guild} to Equestria!"),
+            embed.description.as_deref()
+        );
+        assert_eq!("Equestria", embed.fields[0].name);
+        assert_eq!("6 members", embed.fields[0].value);
+        assert_eq!("{ {guild} }", embed.footer.unwrap().text);
+        assert_eq!(Some("{guild}{guild}"), embed.title.as_deref());
+
+        Ok(())
+    }
+
+    #[test]
+    fn render_over_limit() {
+        let template =
+            EmbedTemplate::from(EmbedBuilder::new().field(EmbedFieldBuilder::new("name", "{a}")));
+
+        let value = "a".repeat(FIELD_VALUE_LENGTH);
+        assert!(template
+            .render(&HashMap::from([("a", value.as_str())]))
+            .is_ok());
+
+        let value = "a".repeat(FIELD_VALUE_LENGTH + 1);
+        let error = template
+            .render(&HashMap::from([("a", value.as_str())]))
+            .unwrap_err();
+        assert!(matches!(error.kind(), EmbedTemplateErrorType::Validation));
+
+        let source = error
+            .into_source()
+            .unwrap()
+            .downcast::<EmbedValidationError>()
+            .unwrap();
+        assert!(matches!(
+            source.kind(),
+            EmbedValidationErrorType::FieldValueTooLarge { chars } if *chars == FIELD_VALUE_LENGTH + 1
+        ));
+    }
+
+    #[test]
+    fn empty_placeholder() -> Result<(), Box<dyn Error>> {
+        let template = EmbedTemplate::from(EmbedBuilder::new().description("{} {{a}}"))
+            .unknown_placeholders(UnknownPlaceholders::Error);
+        let vars = HashMap::from([("a", "1")]);
+
+        let embed = template.render(&vars)?;
+        assert_eq!(Some("{} {1}"), embed.description.as_deref());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_placeholders() -> Result<(), Box<dyn Error>> {
+        let template = EmbedTemplate::from(EmbedBuilder::new().description("{a} {b} {c {} {"));
+        let vars = HashMap::from([("a", "1")]);
+
+        let embed = template.render(&vars)?;
+        assert_eq!(Some("1 {b} {c {} {"), embed.description.as_deref());
+
+        let error = template
+            .unknown_placeholders(UnknownPlaceholders::Error)
+            .render(&vars)
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            EmbedTemplateErrorType::UnknownPlaceholder { name } if name == "b"
+        ));
+
+        Ok(())
+    }
+}
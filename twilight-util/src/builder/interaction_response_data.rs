@@ -3,6 +3,7 @@ use twilight_model::{
     channel::message::{AllowedMentions, Component, Embed, MessageFlags},
     http::{attachment::Attachment, interaction::InteractionResponseData},
 };
+use twilight_validate::message::{components as validate_components, MessageValidationError};
 
 /// Create an [`InteractionResponseData`] with a builder.
 ///
@@ -62,6 +63,22 @@ impl InteractionResponseDataBuilder {
         self.0
     }
 
+    /// Ensure the callback data is valid.
+    ///
+    /// Validates the message [`Component`]s, if any were set.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of
+    /// [`twilight_validate::message::components`] for possible errors.
+    pub fn validate(self) -> Result<Self, MessageValidationError> {
+        if let Some(components) = &self.0.components {
+            validate_components(components)?;
+        }
+
+        Ok(self)
+    }
+
     /// Set the [`AllowedMentions`] of the callback.
     ///
     /// Defaults to [`None`].
@@ -95,7 +112,11 @@ impl InteractionResponseDataBuilder {
 
     /// Set the message [`Component`]s of the callback.
     ///
+    /// Components may be checked with [`validate`].
+    ///
     /// Defaults to [`None`].
+    ///
+    /// [`validate`]: Self::validate
     pub fn components(mut self, components: impl IntoIterator<Item = Component>) -> Self {
         self.0.components = Some(components.into_iter().collect());
 
@@ -175,7 +196,7 @@ mod tests {
     use std::fmt::Debug;
     use twilight_model::{
         channel::message::{
-            component::{Button, ButtonStyle},
+            component::{ActionRow, Button, ButtonStyle},
             MentionType,
         },
         util::Timestamp,
@@ -246,4 +267,59 @@ mod tests {
 
         assert_eq!(value, expected);
     }
+
+    #[test]
+    fn components() {
+        let component = Component::ActionRow(ActionRow {
+            components: Vec::from([Component::Button(Button {
+                custom_id: Some("button_id".to_owned()),
+                disabled: false,
+                emoji: None,
+                label: Some("Button label".to_owned()),
+                style: ButtonStyle::Primary,
+                url: None,
+                sku_id: None,
+            })]),
+        });
+
+        let value = InteractionResponseDataBuilder::new()
+            .content("a content")
+            .components([component])
+            .validate()
+            .unwrap()
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(value).unwrap(),
+            serde_json::json!({
+                "components": [{
+                    "type": 1,
+                    "components": [{
+                        "type": 2,
+                        "custom_id": "button_id",
+                        "label": "Button label",
+                        "style": 1,
+                    }],
+                }],
+                "content": "a content",
+            })
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let result = InteractionResponseDataBuilder::new()
+            .components([Component::Button(Button {
+                custom_id: Some("button_id".to_owned()),
+                disabled: false,
+                emoji: None,
+                label: Some("Button label".to_owned()),
+                style: ButtonStyle::Primary,
+                url: None,
+                sku_id: None,
+            })])
+            .validate();
+
+        assert!(result.is_err());
+    }
 }
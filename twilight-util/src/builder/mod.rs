@@ -2,7 +2,13 @@
 
 pub mod command;
 pub mod embed;
+pub mod select_menu;
 
+mod allowed_mentions;
+mod command_data;
 mod interaction_response_data;
 
-pub use self::interaction_response_data::InteractionResponseDataBuilder;
+pub use self::{
+    allowed_mentions::AllowedMentionsBuilder, command_data::CommandDataBuilder,
+    interaction_response_data::InteractionResponseDataBuilder,
+};
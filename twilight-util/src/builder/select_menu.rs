@@ -0,0 +1,349 @@
+//! Create a text [`SelectMenu`] with a builder.
+//!
+//! # Examples
+//!
+//! ```
+//! use twilight_model::{channel::message::EmojiReactionType, id::Id};
+//! use twilight_util::builder::select_menu::{SelectMenuBuilder, SelectMenuOptionBuilder};
+//!
+//! let select_menu = SelectMenuBuilder::new("class")
+//!     .placeholder("Choose a class")
+//!     .option(
+//!         SelectMenuOptionBuilder::new("Rogue", "rogue")
+//!             .description("Sneak n stab")
+//!             .emoji(EmojiReactionType::Custom {
+//!                 animated: false,
+//!                 id: Id::new(625891304148303894),
+//!                 name: Some("rogue".to_owned()),
+//!             }),
+//!     )
+//!     .option(
+//!         SelectMenuOptionBuilder::new("Mage", "mage")
+//!             .description("Turn 'em into a sheep")
+//!             .default(true),
+//!     )
+//!     .validate()?
+//!     .build();
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use twilight_model::channel::message::{
+    component::{SelectMenu, SelectMenuOption, SelectMenuType},
+    Component, EmojiReactionType,
+};
+use twilight_validate::component::{
+    select_menu as validate_select_menu, select_menu_option as validate_select_menu_option,
+    ComponentValidationError,
+};
+
+/// Create a text [`SelectMenu`] with a builder.
+#[derive(Clone, Debug)]
+#[must_use = "must be built into a select menu"]
+pub struct SelectMenuBuilder(SelectMenu);
+
+impl SelectMenuBuilder {
+    /// Create a new text select menu builder.
+    pub fn new(custom_id: impl Into<String>) -> Self {
+        Self(SelectMenu {
+            channel_types: None,
+            custom_id: custom_id.into(),
+            default_values: None,
+            disabled: false,
+            kind: SelectMenuType::Text,
+            max_values: None,
+            min_values: None,
+            options: Some(Vec::new()),
+            placeholder: None,
+        })
+    }
+
+    /// Consume the builder, returning a [`SelectMenu`].
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "must be built into a select menu"]
+    pub fn build(self) -> SelectMenu {
+        self.0
+    }
+
+    /// Ensure the select menu is valid.
+    ///
+    /// The select menu must have at least [`SELECT_OPTION_COUNT_MINIMUM`] and
+    /// at most [`SELECT_OPTION_COUNT`] options, and the values of its options
+    /// must be unique.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of
+    /// [`twilight_validate::component::select_menu`] for possible errors.
+    ///
+    /// [`SELECT_OPTION_COUNT`]: twilight_validate::component::SELECT_OPTION_COUNT
+    /// [`SELECT_OPTION_COUNT_MINIMUM`]: twilight_validate::component::SELECT_OPTION_COUNT_MINIMUM
+    pub fn validate(self) -> Result<Self, ComponentValidationError> {
+        validate_select_menu(&self.0)?;
+
+        Ok(self)
+    }
+
+    /// Set whether the select menu is disabled.
+    ///
+    /// Defaults to `false`.
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.0.disabled = disabled;
+
+        self
+    }
+
+    /// Set the maximum number of options that may be chosen.
+    ///
+    /// Defaults to [`None`].
+    pub const fn max_values(mut self, max_values: u8) -> Self {
+        self.0.max_values = Some(max_values);
+
+        self
+    }
+
+    /// Set the minimum number of options that must be chosen.
+    ///
+    /// Defaults to [`None`].
+    pub const fn min_values(mut self, min_values: u8) -> Self {
+        self.0.min_values = Some(min_values);
+
+        self
+    }
+
+    /// Add an option to the select menu.
+    ///
+    /// Defaults to no options.
+    pub fn option(mut self, option: impl Into<SelectMenuOption>) -> Self {
+        self.0
+            .options
+            .get_or_insert_with(Vec::new)
+            .push(option.into());
+
+        self
+    }
+
+    /// Set the placeholder text shown when no option is selected.
+    ///
+    /// Defaults to [`None`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.0.placeholder = Some(placeholder.into());
+
+        self
+    }
+}
+
+impl From<SelectMenuBuilder> for SelectMenu {
+    /// Convert a select menu builder into a select menu.
+    ///
+    /// This is equivalent to calling [`SelectMenuBuilder::build`].
+    fn from(builder: SelectMenuBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<SelectMenuBuilder> for Component {
+    fn from(builder: SelectMenuBuilder) -> Self {
+        Component::SelectMenu(builder.build())
+    }
+}
+
+/// Create a [`SelectMenuOption`] with a builder.
+///
+/// This can be passed into [`SelectMenuBuilder::option`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a select menu option"]
+pub struct SelectMenuOptionBuilder(SelectMenuOption);
+
+impl SelectMenuOptionBuilder {
+    /// Create a new select menu option builder.
+    ///
+    /// Refer to [`SELECT_OPTION_LABEL_LENGTH`] and
+    /// [`SELECT_OPTION_VALUE_LENGTH`] for the maximum number of codepoints
+    /// that can be in a label and value.
+    ///
+    /// [`SELECT_OPTION_LABEL_LENGTH`]: twilight_validate::component::SELECT_OPTION_LABEL_LENGTH
+    /// [`SELECT_OPTION_VALUE_LENGTH`]: twilight_validate::component::SELECT_OPTION_VALUE_LENGTH
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self(SelectMenuOption {
+            default: false,
+            description: None,
+            emoji: None,
+            label: label.into(),
+            value: value.into(),
+        })
+    }
+
+    /// Consume the builder, returning a [`SelectMenuOption`].
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of a select menu builder"]
+    pub fn build(self) -> SelectMenuOption {
+        self.0
+    }
+
+    /// Ensure the select menu option is valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of
+    /// [`twilight_validate::component::select_menu_option`] for possible
+    /// errors.
+    pub fn validate(self) -> Result<Self, ComponentValidationError> {
+        validate_select_menu_option(&self.0)?;
+
+        Ok(self)
+    }
+
+    /// Set whether the option is selected by default.
+    ///
+    /// Defaults to `false`.
+    pub const fn default(mut self, default: bool) -> Self {
+        self.0.default = default;
+
+        self
+    }
+
+    /// Set the additional description of the option.
+    ///
+    /// Defaults to [`None`].
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = Some(description.into());
+
+        self
+    }
+
+    /// Set the emoji shown left of the label and description.
+    ///
+    /// Defaults to [`None`].
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn emoji(mut self, emoji: EmojiReactionType) -> Self {
+        self.0.emoji = Some(emoji);
+
+        self
+    }
+}
+
+impl From<SelectMenuOptionBuilder> for SelectMenuOption {
+    /// Convert a select menu option builder into a select menu option.
+    ///
+    /// This is equivalent to calling [`SelectMenuOptionBuilder::build`].
+    fn from(builder: SelectMenuOptionBuilder) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_validate::component::ComponentValidationErrorType;
+
+    assert_impl_all!(SelectMenuBuilder: Clone, Debug, Send, Sync);
+    assert_impl_all!(SelectMenuOptionBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(SelectMenu: From<SelectMenuBuilder>);
+    assert_impl_all!(SelectMenuOption: From<SelectMenuOptionBuilder>);
+
+    #[test]
+    fn builder() {
+        let emoji = EmojiReactionType::Unicode {
+            name: "🦀".to_owned(),
+        };
+
+        let select_menu = SelectMenuBuilder::new("id")
+            .disabled(true)
+            .max_values(2)
+            .min_values(1)
+            .option(
+                SelectMenuOptionBuilder::new("Label", "value")
+                    .default(true)
+                    .description("description")
+                    .emoji(emoji.clone()),
+            )
+            .placeholder("placeholder")
+            .validate()
+            .unwrap()
+            .build();
+
+        let expected = SelectMenu {
+            channel_types: None,
+            custom_id: "id".to_owned(),
+            default_values: None,
+            disabled: true,
+            kind: SelectMenuType::Text,
+            max_values: Some(2),
+            min_values: Some(1),
+            options: Some(Vec::from([SelectMenuOption {
+                default: true,
+                description: Some("description".to_owned()),
+                emoji: Some(emoji),
+                label: "Label".to_owned(),
+                value: "value".to_owned(),
+            }])),
+            placeholder: Some("placeholder".to_owned()),
+        };
+
+        assert_eq!(select_menu, expected);
+    }
+
+    #[test]
+    fn option_lengths() {
+        assert!(
+            SelectMenuOptionBuilder::new("a".repeat(100), "b".repeat(100))
+                .validate()
+                .is_ok()
+        );
+
+        assert!(matches!(
+            SelectMenuOptionBuilder::new("a".repeat(101), "value")
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::SelectOptionLabelLength { chars: 101 }
+        ));
+        assert!(matches!(
+            SelectMenuOptionBuilder::new("label", "b".repeat(101))
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::SelectOptionValueLength { chars: 101 }
+        ));
+    }
+
+    #[test]
+    fn option_count() {
+        assert!(matches!(
+            SelectMenuBuilder::new("id").validate().unwrap_err().kind(),
+            ComponentValidationErrorType::SelectOptionCount { count: 0 }
+        ));
+
+        let builder = (0..25).fold(SelectMenuBuilder::new("id"), |builder, index| {
+            builder.option(SelectMenuOptionBuilder::new("label", index.to_string()))
+        });
+        let builder = builder.validate().unwrap();
+
+        assert!(matches!(
+            builder
+                .option(SelectMenuOptionBuilder::new("label", "25"))
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::SelectOptionCount { count: 26 }
+        ));
+    }
+
+    #[test]
+    fn option_values_unique() {
+        let error = SelectMenuBuilder::new("id")
+            .option(SelectMenuOptionBuilder::new("First", "value"))
+            .option(SelectMenuOptionBuilder::new("Second", "other"))
+            .option(SelectMenuOptionBuilder::new("Third", "value"))
+            .validate()
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ComponentValidationErrorType::SelectOptionDuplicateValue { value }
+            if value == "value"
+        ));
+    }
+}
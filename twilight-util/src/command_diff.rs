@@ -0,0 +1,680 @@
+//! Compare registered commands against desired commands to avoid redundant
+//! registrations.
+//!
+//! Overwriting all commands on every startup creates new command versions and
+//! counts against Discord's daily command creation limit. [`command_diff`]
+//! instead compares the commands currently registered with the desired
+//! commands, and [`CommandDiff::sync`] decides on the minimal set of
+//! operations required to bring them in line.
+//!
+//! # Normalization
+//!
+//! Commands are matched by their name and type. Only fields that affect the
+//! command's behavior are compared; IDs, application IDs, guild IDs, and
+//! versions are ignored. Fields that Discord fills in with defaults are
+//! considered equal to their defaults, so that commands created with the
+//! [builders] compare equal to the commands the API returns:
+//!
+//! - missing localizations equal no localizations;
+//! - a missing `nsfw`, option `autocomplete`, or option `required` equals
+//!   `false`;
+//! - a missing `dm_permission` equals `true`;
+//! - missing `integration_types` equal only [`GuildInstall`];
+//! - missing option `channel_types`, `choices`, or `options` equal none of
+//!   them; and
+//! - `channel_types`, `contexts`, and `integration_types` are compared
+//!   regardless of their order.
+//!
+//! # Examples
+//!
+//! ```
+//! # use twilight_model::application::command::Command;
+//! use twilight_util::command_diff::{command_diff, CommandSync};
+//!
+//! # fn registered_commands() -> Vec<Command> { Vec::new() }
+//! # fn desired_commands() -> Vec<Command> { Vec::new() }
+//! // Commands returned by the API, such as via `Client::guild_commands`.
+//! let existing = registered_commands();
+//! // Commands the application should have, such as created via builders.
+//! let desired = desired_commands();
+//!
+//! let diff = command_diff(&existing, &desired);
+//!
+//! match diff.sync(5) {
+//!     CommandSync::Unchanged => {}
+//!     CommandSync::Individual {
+//!         create,
+//!         update,
+//!         delete,
+//!     } => {
+//!         // Create, update, and delete each command individually.
+//!     }
+//!     CommandSync::Overwrite(commands) => {
+//!         // Overwrite all commands at once.
+//!     }
+//! }
+//! ```
+//!
+//! [builders]: crate::builder::command
+//! [`GuildInstall`]: ApplicationIntegrationType::GuildInstall
+
+use std::collections::HashMap;
+use twilight_model::{
+    application::command::{Command, CommandOption, CommandOptionChoice, CommandType},
+    id::{marker::CommandMarker, Id},
+    oauth::ApplicationIntegrationType,
+};
+
+/// Field of a [`Command`] that differs between the registered and desired
+/// command.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CommandField {
+    /// [`Command::contexts`].
+    Contexts,
+    /// [`Command::default_member_permissions`].
+    DefaultMemberPermissions,
+    /// [`Command::description`].
+    Description,
+    /// [`Command::description_localizations`].
+    DescriptionLocalizations,
+    /// [`Command::dm_permission`].
+    DmPermission,
+    /// [`Command::integration_types`].
+    IntegrationTypes,
+    /// [`Command::name_localizations`].
+    NameLocalizations,
+    /// [`Command::nsfw`].
+    Nsfw,
+    /// [`Command::options`].
+    Options,
+}
+
+/// Registered command that differs from its desired state.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommandChange<'a> {
+    /// Desired state of the command.
+    desired: &'a Command,
+    /// Registered command.
+    existing: &'a Command,
+    /// Fields that differ.
+    fields: Vec<CommandField>,
+}
+
+impl<'a> CommandChange<'a> {
+    /// Desired state of the command.
+    pub const fn desired(&self) -> &'a Command {
+        self.desired
+    }
+
+    /// Registered command.
+    pub const fn existing(&self) -> &'a Command {
+        self.existing
+    }
+
+    /// Fields that differ between the registered and desired command.
+    pub fn fields(&self) -> &[CommandField] {
+        &self.fields
+    }
+}
+
+/// Differences between registered and desired commands.
+///
+/// Created via [`command_diff`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommandDiff<'a> {
+    /// Desired commands that aren't registered.
+    added: Vec<&'a Command>,
+    /// Registered commands that differ from their desired state.
+    changed: Vec<CommandChange<'a>>,
+    /// All desired commands.
+    desired: &'a [Command],
+    /// Registered commands that aren't desired.
+    removed: Vec<&'a Command>,
+}
+
+impl<'a> CommandDiff<'a> {
+    /// Desired commands that aren't registered.
+    pub fn added(&self) -> &[&'a Command] {
+        &self.added
+    }
+
+    /// Registered commands that differ from their desired state.
+    pub fn changed(&self) -> &[CommandChange<'a>] {
+        &self.changed
+    }
+
+    /// Whether the registered commands are already in their desired state.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+
+    /// Registered commands that aren't desired.
+    pub fn removed(&self) -> &[&'a Command] {
+        &self.removed
+    }
+
+    /// Decide on the operations required to bring the registered commands to
+    /// their desired state.
+    ///
+    /// Individual operations are preferred, unless more than `threshold`
+    /// operations are required, in which case a single bulk overwrite is
+    /// used. A bulk overwrite is also used if a registered command that has
+    /// to be updated or deleted has no ID.
+    pub fn sync(&self, threshold: usize) -> CommandSync<'a> {
+        if self.is_empty() {
+            return CommandSync::Unchanged;
+        }
+
+        let operations = self.added.len() + self.changed.len() + self.removed.len();
+
+        if operations > threshold {
+            return CommandSync::Overwrite(self.desired);
+        }
+
+        let update = self
+            .changed
+            .iter()
+            .map(|change| change.existing.id.map(|id| (id, change.desired)))
+            .collect::<Option<Vec<_>>>();
+        let delete = self
+            .removed
+            .iter()
+            .map(|command| command.id)
+            .collect::<Option<Vec<_>>>();
+
+        match (update, delete) {
+            (Some(update), Some(delete)) => CommandSync::Individual {
+                create: self.added.clone(),
+                update,
+                delete,
+            },
+            _ => CommandSync::Overwrite(self.desired),
+        }
+    }
+}
+
+/// Operations to bring registered commands to their desired state.
+///
+/// Created via [`CommandDiff::sync`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum CommandSync<'a> {
+    /// Registered commands are already in their desired state.
+    Unchanged,
+    /// Create, update, and delete commands individually.
+    Individual {
+        /// Commands to create.
+        create: Vec<&'a Command>,
+        /// IDs of the commands to update, along with their desired state.
+        update: Vec<(Id<CommandMarker>, &'a Command)>,
+        /// IDs of the commands to delete.
+        delete: Vec<Id<CommandMarker>>,
+    },
+    /// Overwrite all commands with the desired commands.
+    Overwrite(&'a [Command]),
+}
+
+/// Compare the `existing` registered commands against the `desired` commands.
+///
+/// Refer to the [module-level documentation] for how commands are compared.
+///
+/// [module-level documentation]: self
+pub fn command_diff<'a>(existing: &'a [Command], desired: &'a [Command]) -> CommandDiff<'a> {
+    let find = |commands: &'a [Command], name: &str, kind: CommandType| {
+        commands
+            .iter()
+            .find(|command| command.name == name && command.kind == kind)
+    };
+
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+
+    for command in desired {
+        match find(existing, &command.name, command.kind) {
+            Some(registered) => {
+                let fields = changed_fields(registered, command);
+
+                if !fields.is_empty() {
+                    changed.push(CommandChange {
+                        desired: command,
+                        existing: registered,
+                        fields,
+                    });
+                }
+            }
+            None => added.push(command),
+        }
+    }
+
+    let removed = existing
+        .iter()
+        .filter(|command| find(desired, &command.name, command.kind).is_none())
+        .collect();
+
+    CommandDiff {
+        added,
+        changed,
+        desired,
+        removed,
+    }
+}
+
+/// Fields that differ between two commands with the same name and type.
+#[allow(deprecated)]
+fn changed_fields(existing: &Command, desired: &Command) -> Vec<CommandField> {
+    let integration_types = |command: &Command| {
+        command
+            .integration_types
+            .clone()
+            .unwrap_or_else(|| Vec::from([ApplicationIntegrationType::GuildInstall]))
+    };
+
+    let comparisons = [
+        (
+            CommandField::Contexts,
+            same_set(existing.contexts.as_deref(), desired.contexts.as_deref()),
+        ),
+        (
+            CommandField::DefaultMemberPermissions,
+            existing.default_member_permissions == desired.default_member_permissions,
+        ),
+        (
+            CommandField::Description,
+            existing.description == desired.description,
+        ),
+        (
+            CommandField::DescriptionLocalizations,
+            same_localizations(
+                existing.description_localizations.as_ref(),
+                desired.description_localizations.as_ref(),
+            ),
+        ),
+        (
+            CommandField::DmPermission,
+            existing.dm_permission.unwrap_or(true) == desired.dm_permission.unwrap_or(true),
+        ),
+        (
+            CommandField::IntegrationTypes,
+            same_set(
+                Some(integration_types(existing).as_slice()),
+                Some(integration_types(desired).as_slice()),
+            ),
+        ),
+        (
+            CommandField::NameLocalizations,
+            same_localizations(
+                existing.name_localizations.as_ref(),
+                desired.name_localizations.as_ref(),
+            ),
+        ),
+        (
+            CommandField::Nsfw,
+            existing.nsfw.unwrap_or_default() == desired.nsfw.unwrap_or_default(),
+        ),
+        (
+            CommandField::Options,
+            same_options(&existing.options, &desired.options),
+        ),
+    ];
+
+    comparisons
+        .into_iter()
+        .filter_map(|(field, same)| (!same).then_some(field))
+        .collect()
+}
+
+/// Whether two choice lists are equal.
+fn same_choices(a: &[CommandOptionChoice], b: &[CommandOptionChoice]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).all(|(a, b)| {
+            a.name == b.name
+                && a.value == b.value
+                && same_localizations(a.name_localizations.as_ref(), b.name_localizations.as_ref())
+        })
+}
+
+/// Whether two localization dictionaries are equal, treating a missing
+/// dictionary as an empty one.
+fn same_localizations(
+    a: Option<&HashMap<String, String>>,
+    b: Option<&HashMap<String, String>>,
+) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a == b,
+        (Some(map), None) | (None, Some(map)) => map.is_empty(),
+        (None, None) => true,
+    }
+}
+
+/// Whether two options are equal.
+fn same_option(a: &CommandOption, b: &CommandOption) -> bool {
+    a.kind == b.kind
+        && a.name == b.name
+        && a.description == b.description
+        && a.autocomplete.unwrap_or_default() == b.autocomplete.unwrap_or_default()
+        && a.required.unwrap_or_default() == b.required.unwrap_or_default()
+        && a.max_length == b.max_length
+        && a.max_value == b.max_value
+        && a.min_length == b.min_length
+        && a.min_value == b.min_value
+        && same_set(
+            Some(a.channel_types.as_deref().unwrap_or_default()),
+            Some(b.channel_types.as_deref().unwrap_or_default()),
+        )
+        && same_choices(
+            a.choices.as_deref().unwrap_or_default(),
+            b.choices.as_deref().unwrap_or_default(),
+        )
+        && same_localizations(
+            a.description_localizations.as_ref(),
+            b.description_localizations.as_ref(),
+        )
+        && same_localizations(a.name_localizations.as_ref(), b.name_localizations.as_ref())
+        && same_options(
+            a.options.as_deref().unwrap_or_default(),
+            b.options.as_deref().unwrap_or_default(),
+        )
+}
+
+/// Whether two option lists are equal, including their order.
+fn same_options(a: &[CommandOption], b: &[CommandOption]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same_option(a, b))
+}
+
+/// Whether two lists contain the same items, regardless of their order.
+fn same_set<T: PartialEq>(a: Option<&[T]>, b: Option<&[T]>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            a.iter().all(|item| b.contains(item)) && b.iter().all(|item| a.contains(item))
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::{
+        application::{
+            command::{CommandOption, CommandOptionType, CommandType},
+            interaction::InteractionContextType,
+        },
+        channel::ChannelType,
+        guild::Permissions,
+    };
+
+    assert_impl_all!(CommandChange<'_>: Clone, Debug, PartialEq, Send, Sync);
+    assert_impl_all!(CommandDiff<'_>: Clone, Debug, PartialEq, Send, Sync);
+    assert_impl_all!(CommandField: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(CommandSync<'_>: Clone, Debug, PartialEq, Send, Sync);
+
+    /// Command without any optional fields, as created by a command builder.
+    #[allow(deprecated)]
+    fn command(name: &str, description: &str, kind: CommandType) -> Command {
+        Command {
+            application_id: None,
+            contexts: None,
+            default_member_permissions: None,
+            dm_permission: None,
+            description: description.to_owned(),
+            description_localizations: None,
+            guild_id: None,
+            id: None,
+            integration_types: None,
+            kind,
+            name: name.to_owned(),
+            name_localizations: None,
+            nsfw: None,
+            options: Vec::new(),
+            version: Id::new(1),
+        }
+    }
+
+    /// Command option without any optional fields, as created by an option
+    /// builder.
+    fn option(kind: CommandOptionType, name: &str, description: &str) -> CommandOption {
+        CommandOption {
+            autocomplete: None,
+            channel_types: None,
+            choices: None,
+            description: description.to_owned(),
+            description_localizations: None,
+            kind,
+            max_length: None,
+            max_value: None,
+            min_length: None,
+            min_value: None,
+            name: name.to_owned(),
+            name_localizations: None,
+            options: None,
+            required: None,
+        }
+    }
+
+    fn echo() -> Command {
+        Command {
+            options: Vec::from([
+                CommandOption {
+                    required: Some(true),
+                    ..option(CommandOptionType::String, "message", "Message to repeat")
+                },
+                option(
+                    CommandOptionType::Channel,
+                    "channel",
+                    "Channel to repeat in",
+                ),
+            ]),
+            ..command("echo", "Repeat a message", CommandType::ChatInput)
+        }
+    }
+
+    /// Response of the API to registering [`echo`].
+    fn echo_response() -> Command {
+        serde_json::from_value(serde_json::json!({
+            "application_id": "1",
+            "contexts": null,
+            "default_member_permissions": null,
+            "description": "Repeat a message",
+            "description_localizations": null,
+            "dm_permission": true,
+            "id": "2",
+            "integration_types": [0],
+            "name": "echo",
+            "name_localizations": null,
+            "nsfw": false,
+            "options": [
+                {
+                    "description": "Message to repeat",
+                    "name": "message",
+                    "required": true,
+                    "type": 3,
+                },
+                {
+                    "description": "Channel to repeat in",
+                    "name": "channel",
+                    "type": 7,
+                },
+            ],
+            "type": 1,
+            "version": "3",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn equals_response() {
+        let existing = [echo_response()];
+        let desired = [echo()];
+
+        let diff = command_diff(&existing, &desired);
+        assert!(diff.is_empty(), "{diff:?}");
+        assert_eq!(diff.sync(0), CommandSync::Unchanged);
+    }
+
+    #[cfg(feature = "builder")]
+    #[test]
+    fn builder_equals_response() {
+        use crate::builder::command::{ChannelBuilder, CommandBuilder, StringBuilder};
+
+        let existing = [echo_response()];
+        let desired = [
+            CommandBuilder::new("echo", "Repeat a message", CommandType::ChatInput)
+                .option(StringBuilder::new("message", "Message to repeat").required(true))
+                .option(ChannelBuilder::new("channel", "Channel to repeat in"))
+                .build(),
+        ];
+
+        let diff = command_diff(&existing, &desired);
+        assert!(diff.is_empty(), "{diff:?}");
+        assert_eq!(diff.sync(0), CommandSync::Unchanged);
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn normalization() {
+        let mut existing = echo_response();
+        existing.description_localizations = Some(HashMap::new());
+        existing.integration_types = None;
+        existing.name_localizations = Some(HashMap::new());
+        existing.nsfw = None;
+        existing.options[0].autocomplete = Some(false);
+        existing.options[0].choices = Some(Vec::new());
+        existing.options[1].channel_types = Some(Vec::new());
+        existing.options[1].options = Some(Vec::new());
+        existing.options[1].required = Some(false);
+        existing.dm_permission = None;
+
+        let existing = [existing];
+        let desired = [echo()];
+        assert!(command_diff(&existing, &desired).is_empty());
+
+        let existing = [Command {
+            contexts: Some(Vec::from([
+                InteractionContextType::Guild,
+                InteractionContextType::BotDm,
+            ])),
+            ..echo()
+        }];
+        let desired = [Command {
+            contexts: Some(Vec::from([
+                InteractionContextType::BotDm,
+                InteractionContextType::Guild,
+            ])),
+            ..echo()
+        }];
+        assert!(command_diff(&existing, &desired).is_empty());
+    }
+
+    #[test]
+    fn changed_fields() {
+        let existing = [echo_response()];
+        let mut desired = echo();
+        desired.default_member_permissions = Some(Permissions::MANAGE_MESSAGES);
+        desired.description_localizations = Some(HashMap::from([(
+            "fr".to_owned(),
+            "Répéter un message".to_owned(),
+        )]));
+        desired.nsfw = Some(true);
+        desired.options.push(option(
+            CommandOptionType::Integer,
+            "count",
+            "Number of repetitions",
+        ));
+        let desired = [desired];
+
+        let diff = command_diff(&existing, &desired);
+        assert!(diff.added().is_empty());
+        assert!(diff.removed().is_empty());
+        assert_eq!(diff.changed().len(), 1);
+
+        let change = &diff.changed()[0];
+        assert_eq!(change.existing(), &existing[0]);
+        assert_eq!(change.desired(), &desired[0]);
+        assert_eq!(
+            change.fields(),
+            [
+                CommandField::DefaultMemberPermissions,
+                CommandField::DescriptionLocalizations,
+                CommandField::Nsfw,
+                CommandField::Options,
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_options() {
+        let command = |channel_types| Command {
+            options: Vec::from([CommandOption {
+                options: Some(Vec::from([CommandOption {
+                    channel_types: Some(channel_types),
+                    ..option(CommandOptionType::Channel, "channel", "Log channel")
+                }])),
+                ..option(CommandOptionType::SubCommand, "log", "Set the log channel")
+            }]),
+            ..command("config", "Configure the bot", CommandType::ChatInput)
+        };
+
+        let existing = [command(Vec::from([
+            ChannelType::GuildText,
+            ChannelType::GuildAnnouncement,
+        ]))];
+        let same = [command(Vec::from([
+            ChannelType::GuildAnnouncement,
+            ChannelType::GuildText,
+        ]))];
+        let different = [command(Vec::from([ChannelType::GuildText]))];
+
+        assert!(command_diff(&existing, &same).is_empty());
+        assert_eq!(
+            command_diff(&existing, &different).changed()[0].fields(),
+            [CommandField::Options]
+        );
+    }
+
+    #[test]
+    fn added_and_removed() {
+        let ping = |kind| command("ping", "", kind);
+
+        let existing = [echo_response(), ping(CommandType::User)];
+        let desired = [ping(CommandType::Message)];
+
+        let diff = command_diff(&existing, &desired);
+        assert_eq!(diff.added(), [&desired[0]]);
+        assert_eq!(diff.removed(), [&existing[0], &existing[1]]);
+        assert!(diff.changed().is_empty());
+    }
+
+    #[test]
+    fn sync() {
+        let mut changed = echo_response();
+        changed.description = "Outdated description".to_owned();
+        let mut removed = echo_response();
+        removed.id = Some(Id::new(4));
+        removed.name = "removed".to_owned();
+
+        let existing = [changed, removed];
+        let added = command("ping", "Ping the bot", CommandType::ChatInput);
+        let desired = [echo(), added];
+
+        let diff = command_diff(&existing, &desired);
+        assert_eq!(
+            diff.sync(3),
+            CommandSync::Individual {
+                create: Vec::from([&desired[1]]),
+                update: Vec::from([(Id::new(2), &desired[0])]),
+                delete: Vec::from([Id::new(4)]),
+            }
+        );
+        assert_eq!(diff.sync(2), CommandSync::Overwrite(&desired));
+
+        let mut existing = existing;
+        existing[1].id = None;
+        assert_eq!(
+            command_diff(&existing, &desired).sync(3),
+            CommandSync::Overwrite(&desired)
+        );
+    }
+}
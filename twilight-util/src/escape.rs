@@ -0,0 +1,256 @@
+//! Escape user provided text before interpolating it into messages or embeds.
+//!
+//! Discord renders markdown and mentions in message content and most embed
+//! fields, so text such as usernames or message content can otherwise break
+//! formatting or ping users.
+//!
+//! Each function only allocates if the text contains something to escape and
+//! never splits multi-byte characters.
+//!
+//! # Examples
+//!
+//! ```
+//! use twilight_util::escape;
+//!
+//! let name = "*cool* @everyone";
+//!
+//! assert_eq!(
+//!     "\\*cool\\* @\u{200b}everyone",
+//!     escape::sanitize_for_embed(name)
+//! );
+//! assert_eq!("no formatting", escape::escape_markdown("no formatting"));
+//! ```
+
+use std::borrow::Cow;
+
+/// Characters with a meaning in Discord's markdown.
+///
+/// The backslash is included so that escapes in the text can't cancel out
+/// ours.
+const MARKDOWN: [char; 11] = ['\\', '*', '_', '`', '~', '|', '>', '#', '-', '[', ']'];
+
+/// Zero-width space, separating characters without being visible.
+const ZERO_WIDTH_SPACE: char = '\u{200b}';
+
+/// Escape markdown by prefixing its characters with a backslash.
+///
+/// Escapes `*`, `_`, `` ` ``, `~`, `|`, `>`, `#`, `-`, `[`, `]`, and
+/// backslashes, so the text renders as written.
+///
+/// Text within code blocks is rendered as written regardless, where the
+/// backslashes would be shown; use [`escape_code_block`] instead.
+pub fn escape_markdown(text: &str) -> Cow<'_, str> {
+    let Some(index) = text.find(MARKDOWN) else {
+        return Cow::Borrowed(text);
+    };
+
+    let (start, rest) = text.split_at(index);
+    let mut escaped = String::with_capacity(text.len() + 1);
+    escaped.push_str(start);
+
+    for c in rest.chars() {
+        if MARKDOWN.contains(&c) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    Cow::Owned(escaped)
+}
+
+/// Escape mentions by inserting a zero-width space after every `@`.
+///
+/// Neutralizes `@everyone`, `@here`, and user and role mentions such as
+/// `<@123>` and `<@&123>`, which are then rendered as written.
+pub fn escape_mentions(text: &str) -> Cow<'_, str> {
+    if text.contains('@') {
+        Cow::Owned(text.replace('@', "@\u{200b}"))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Escape text to be placed within a code block.
+///
+/// Inserts a zero-width space between consecutive backticks, so the text
+/// can't contain triple backticks ending the code block early. Place the text
+/// on its own lines, such as `` format!("```\n{text}\n```") ``, so that a
+/// leading or trailing backtick doesn't merge with the code block's.
+pub fn escape_code_block(text: &str) -> Cow<'_, str> {
+    if !text.contains("``") {
+        return Cow::Borrowed(text);
+    }
+
+    let mut escaped = String::with_capacity(text.len() + 3);
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        escaped.push(c);
+
+        if c == '`' && chars.peek() == Some(&'`') {
+            escaped.push(ZERO_WIDTH_SPACE);
+        }
+    }
+
+    Cow::Owned(escaped)
+}
+
+/// Escape both markdown and mentions, such as for embed descriptions and
+/// fields.
+///
+/// Refer to [`escape_markdown`] and [`escape_mentions`] for what's escaped.
+pub fn sanitize_for_embed(text: &str) -> Cow<'_, str> {
+    match escape_mentions(text) {
+        Cow::Borrowed(text) => escape_markdown(text),
+        Cow::Owned(text) => Cow::Owned(escape_markdown(&text).into_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        escape_code_block, escape_markdown, escape_mentions, sanitize_for_embed, MARKDOWN,
+        ZERO_WIDTH_SPACE,
+    };
+    use std::borrow::Cow;
+
+    /// Characters to build inputs from, including all escaped characters and
+    /// multi-byte characters.
+    const ALPHABET: [char; 17] = [
+        'a', ' ', '\n', 'é', '🦀', '@', '<', '&', '\\', '*', '_', '`', '~', '|', '>', '#', '[',
+    ];
+
+    /// All strings of up to four characters of the alphabet.
+    fn inputs() -> Vec<String> {
+        let mut inputs = vec![String::new()];
+        let mut previous = inputs.clone();
+
+        for _ in 0..4 {
+            previous = previous
+                .iter()
+                .flat_map(|input| {
+                    ALPHABET.iter().map(move |c| {
+                        let mut input = input.clone();
+                        input.push(*c);
+
+                        input
+                    })
+                })
+                .collect();
+            inputs.extend_from_slice(&previous);
+        }
+
+        inputs
+    }
+
+    /// Remove escapes, asserting that every markdown character is escaped.
+    fn unescape_markdown(escaped: &str) -> String {
+        let mut text = String::new();
+        let mut chars = escaped.chars();
+
+        while let Some(c) = chars.next() {
+            assert!(c == '\\' || !MARKDOWN.contains(&c), "{escaped:?}");
+
+            if c == '\\' {
+                let escaped_char = chars.next().unwrap();
+                assert!(MARKDOWN.contains(&escaped_char), "{escaped:?}");
+                text.push(escaped_char);
+            } else {
+                text.push(c);
+            }
+        }
+
+        text
+    }
+
+    #[test]
+    fn markdown() {
+        assert_eq!(
+            "\\*\\*bold\\*\\* \\_🦀\\_ \\\\\\`",
+            escape_markdown("**bold** _🦀_ \\`")
+        );
+        assert_eq!("\\-\\# small", escape_markdown("-# small"));
+        assert_eq!("\\> \\[link\\]", escape_markdown("> [link]"));
+    }
+
+    #[test]
+    fn markdown_inert() {
+        for input in inputs() {
+            let escaped = escape_markdown(&input);
+
+            assert_eq!(input, unescape_markdown(&escaped));
+            assert!(escaped.len() <= input.len() * 2);
+            assert_eq!(
+                matches!(escaped, Cow::Borrowed(_)),
+                !input.contains(MARKDOWN)
+            );
+        }
+    }
+
+    #[test]
+    fn mentions() {
+        assert_eq!(
+            "@\u{200b}everyone <@\u{200b}1> <@\u{200b}&2> @\u{200b}here",
+            escape_mentions("@everyone <@1> <@&2> @here")
+        );
+        assert!(matches!(escape_mentions("<#1>"), Cow::Borrowed("<#1>")));
+    }
+
+    #[test]
+    fn mentions_inert() {
+        for input in inputs() {
+            let escaped = escape_mentions(&input);
+            let mentions = input.matches('@').count();
+
+            assert!(escaped
+                .match_indices('@')
+                .all(|(index, _)| escaped[index + 1..].starts_with(ZERO_WIDTH_SPACE)));
+            assert_eq!(input, escaped.replace(ZERO_WIDTH_SPACE, ""));
+            assert_eq!(
+                input.len() + mentions * ZERO_WIDTH_SPACE.len_utf8(),
+                escaped.len()
+            );
+            assert_eq!(matches!(escaped, Cow::Borrowed(_)), mentions == 0);
+        }
+    }
+
+    #[test]
+    fn code_block() {
+        assert_eq!(
+            "`\u{200b}`\u{200b}`rust\n`code`",
+            escape_code_block("```rust\n`code`")
+        );
+        assert!(matches!(escape_code_block("`a`"), Cow::Borrowed("`a`")));
+    }
+
+    #[test]
+    fn code_block_inert() {
+        for input in inputs() {
+            let escaped = escape_code_block(&input);
+
+            assert!(!escaped.contains("``"));
+            assert_eq!(input, escaped.replace(ZERO_WIDTH_SPACE, ""));
+            assert!(escaped.len() <= input.len() * (1 + ZERO_WIDTH_SPACE.len_utf8()));
+            assert_eq!(matches!(escaped, Cow::Borrowed(_)), !input.contains("``"));
+        }
+    }
+
+    #[test]
+    fn embed() {
+        assert_eq!("\\_@\u{200b}here\\_", sanitize_for_embed("_@here_"));
+        assert!(matches!(
+            sanitize_for_embed("plain"),
+            Cow::Borrowed("plain")
+        ));
+
+        for input in inputs() {
+            let escaped = sanitize_for_embed(&input);
+
+            assert_eq!(
+                input,
+                unescape_markdown(&escaped).replace(ZERO_WIDTH_SPACE, "")
+            );
+        }
+    }
+}
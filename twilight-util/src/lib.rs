@@ -13,14 +13,32 @@
     clippy::used_underscore_items
 )]
 
+#[cfg(feature = "attachment")]
+pub mod attachment;
+
 #[cfg(feature = "builder")]
 pub mod builder;
 
+#[cfg(feature = "command-diff")]
+pub mod command_diff;
+
+#[cfg(feature = "escape")]
+pub mod escape;
+
 #[cfg(feature = "link")]
 pub mod link;
 
 #[cfg(feature = "permission-calculator")]
 pub mod permission_calculator;
 
+#[cfg(feature = "prefix")]
+pub mod prefix;
+
+#[cfg(feature = "rotation")]
+pub mod rotation;
+
 #[cfg(feature = "snowflake")]
 pub mod snowflake;
+
+#[cfg(feature = "starboard")]
+pub mod starboard;
@@ -0,0 +1,363 @@
+//! Utilities for formatting CDN URLs of guild images.
+//!
+//! Image hashes are provided by models such as [`Guild`] and may be passed
+//! alongside the guild's ID to format a URL to the image.
+//!
+//! [`Guild`]: twilight_model::guild::Guild
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    id::{marker::GuildMarker, Id},
+    util::ImageHash,
+};
+
+/// Base URL of the Discord CDN.
+pub(crate) const BASE_URL: &str = "https://cdn.discordapp.com";
+
+/// Maximum size of an image in pixels.
+const SIZE_MAXIMUM: u16 = 4096;
+
+/// Minimum size of an image in pixels.
+const SIZE_MINIMUM: u16 = 16;
+
+/// Error when [formatting] a CDN URL.
+///
+/// [formatting]: guild_icon_url
+#[derive(Debug)]
+pub struct CdnUrlError {
+    kind: CdnUrlErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CdnUrlError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CdnUrlErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CdnUrlErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CdnUrlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            CdnUrlErrorType::FormatUnsupported { format } => {
+                f.write_str("image format ")?;
+                f.write_str(format.extension())?;
+
+                f.write_str(" isn't supported for this image")
+            }
+            CdnUrlErrorType::SizeInvalid { size } => {
+                f.write_str("size ")?;
+                Display::fmt(&size, f)?;
+                f.write_str(" isn't a power of two between ")?;
+                Display::fmt(&SIZE_MINIMUM, f)?;
+                f.write_str(" and ")?;
+
+                Display::fmt(&SIZE_MAXIMUM, f)
+            }
+        }
+    }
+}
+
+impl Error for CdnUrlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CdnUrlError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CdnUrlErrorType {
+    /// Image format isn't supported for the image.
+    ///
+    /// GIFs are only supported for animated icons and banners.
+    FormatUnsupported {
+        /// Provided format.
+        format: ImageFormat,
+    },
+    /// Size isn't a power of two between 16 and 4096.
+    SizeInvalid {
+        /// Provided size.
+        size: u16,
+    },
+}
+
+/// Format of an image on the CDN.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    /// GIF image, only supported for animated images.
+    Gif,
+    /// JPEG image.
+    Jpeg,
+    /// PNG image.
+    Png,
+    /// `WebP` image.
+    WebP,
+}
+
+impl ImageFormat {
+    /// File extension of the format.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::WebP => "webp",
+        }
+    }
+}
+
+/// Format the CDN URL of a guild's banner.
+///
+/// # Errors
+///
+/// Returns an error of type [`FormatUnsupported`] if the format is
+/// [`ImageFormat::Gif`] and the banner isn't animated.
+///
+/// Returns an error of type [`SizeInvalid`] if the size isn't a power of two
+/// between 16 and 4096.
+///
+/// [`FormatUnsupported`]: CdnUrlErrorType::FormatUnsupported
+/// [`SizeInvalid`]: CdnUrlErrorType::SizeInvalid
+pub fn guild_banner_url(
+    guild_id: Id<GuildMarker>,
+    banner: &ImageHash,
+    format: ImageFormat,
+    size: Option<u16>,
+) -> Result<String, CdnUrlError> {
+    url(
+        "banners",
+        guild_id,
+        banner,
+        format,
+        size,
+        banner.is_animated(),
+    )
+}
+
+/// Format the CDN URL of a guild's discovery splash.
+///
+/// The discovery splash is displayed when previewing the guild in Server
+/// Discovery.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::{id::Id, util::ImageHash};
+/// use twilight_util::link::cdn::{self, ImageFormat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let hash = ImageHash::parse(b"1acefe340fafb4ecefae407f3abdb323")?;
+/// let url = cdn::guild_discovery_splash_url(Id::new(123), &hash, ImageFormat::Png, Some(512))?;
+///
+/// assert_eq!(
+///     "https://cdn.discordapp.com/discovery-splashes/123/1acefe340fafb4ecefae407f3abdb323.png?size=512",
+///     url,
+/// );
+/// # Ok(()) }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error of type [`FormatUnsupported`] if the format is
+/// [`ImageFormat::Gif`].
+///
+/// Returns an error of type [`SizeInvalid`] if the size isn't a power of two
+/// between 16 and 4096.
+///
+/// [`FormatUnsupported`]: CdnUrlErrorType::FormatUnsupported
+/// [`SizeInvalid`]: CdnUrlErrorType::SizeInvalid
+pub fn guild_discovery_splash_url(
+    guild_id: Id<GuildMarker>,
+    discovery_splash: &ImageHash,
+    format: ImageFormat,
+    size: Option<u16>,
+) -> Result<String, CdnUrlError> {
+    url(
+        "discovery-splashes",
+        guild_id,
+        discovery_splash,
+        format,
+        size,
+        false,
+    )
+}
+
+/// Format the CDN URL of a guild's icon.
+///
+/// # Errors
+///
+/// Returns an error of type [`FormatUnsupported`] if the format is
+/// [`ImageFormat::Gif`] and the icon isn't animated.
+///
+/// Returns an error of type [`SizeInvalid`] if the size isn't a power of two
+/// between 16 and 4096.
+///
+/// [`FormatUnsupported`]: CdnUrlErrorType::FormatUnsupported
+/// [`SizeInvalid`]: CdnUrlErrorType::SizeInvalid
+pub fn guild_icon_url(
+    guild_id: Id<GuildMarker>,
+    icon: &ImageHash,
+    format: ImageFormat,
+    size: Option<u16>,
+) -> Result<String, CdnUrlError> {
+    url("icons", guild_id, icon, format, size, icon.is_animated())
+}
+
+/// Format the CDN URL of a guild's invite splash.
+///
+/// # Errors
+///
+/// Returns an error of type [`FormatUnsupported`] if the format is
+/// [`ImageFormat::Gif`].
+///
+/// Returns an error of type [`SizeInvalid`] if the size isn't a power of two
+/// between 16 and 4096.
+///
+/// [`FormatUnsupported`]: CdnUrlErrorType::FormatUnsupported
+/// [`SizeInvalid`]: CdnUrlErrorType::SizeInvalid
+pub fn guild_splash_url(
+    guild_id: Id<GuildMarker>,
+    splash: &ImageHash,
+    format: ImageFormat,
+    size: Option<u16>,
+) -> Result<String, CdnUrlError> {
+    url("splashes", guild_id, splash, format, size, false)
+}
+
+/// Format a CDN URL, validating the format and size.
+fn url(
+    path: &str,
+    guild_id: Id<GuildMarker>,
+    hash: &ImageHash,
+    format: ImageFormat,
+    size: Option<u16>,
+    gif_supported: bool,
+) -> Result<String, CdnUrlError> {
+    if format == ImageFormat::Gif && !gif_supported {
+        return Err(CdnUrlError {
+            kind: CdnUrlErrorType::FormatUnsupported { format },
+            source: None,
+        });
+    }
+
+    let mut url = format!("{BASE_URL}/{path}/{guild_id}/{hash}.{}", format.extension());
+
+    if let Some(size) = size {
+        if !size.is_power_of_two() || !(SIZE_MINIMUM..=SIZE_MAXIMUM).contains(&size) {
+            return Err(CdnUrlError {
+                kind: CdnUrlErrorType::SizeInvalid { size },
+                source: None,
+            });
+        }
+
+        url.push_str("?size=");
+        url.push_str(&size.to_string());
+    }
+
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CdnUrlError, CdnUrlErrorType, ImageFormat};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash};
+    use twilight_model::{id::Id, util::ImageHash};
+
+    assert_impl_all!(CdnUrlErrorType: Debug, Send, Sync);
+    assert_impl_all!(CdnUrlError: Debug, Error, Send, Sync);
+    assert_impl_all!(ImageFormat: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    const ANIMATED: &[u8] = b"a_1acefe340fafb4ecefae407f3abdb323";
+    const STATIC: &[u8] = b"1acefe340fafb4ecefae407f3abdb323";
+
+    #[test]
+    fn guild_discovery_splash_url() {
+        let hash = ImageHash::parse(STATIC).unwrap();
+
+        assert_eq!(
+            super::guild_discovery_splash_url(Id::new(123), &hash, ImageFormat::Png, None)
+                .unwrap(),
+            "https://cdn.discordapp.com/discovery-splashes/123/1acefe340fafb4ecefae407f3abdb323.png",
+        );
+        assert_eq!(
+            super::guild_discovery_splash_url(Id::new(123), &hash, ImageFormat::WebP, Some(4096))
+                .unwrap(),
+            "https://cdn.discordapp.com/discovery-splashes/123/1acefe340fafb4ecefae407f3abdb323.webp?size=4096",
+        );
+        assert!(matches!(
+            super::guild_discovery_splash_url(Id::new(123), &hash, ImageFormat::Gif, None)
+                .unwrap_err()
+                .kind(),
+            CdnUrlErrorType::FormatUnsupported {
+                format: ImageFormat::Gif
+            }
+        ));
+    }
+
+    #[test]
+    fn guild_image_urls() {
+        let animated = ImageHash::parse(ANIMATED).unwrap();
+        let hash = ImageHash::parse(STATIC).unwrap();
+
+        assert_eq!(
+            super::guild_icon_url(Id::new(1), &animated, ImageFormat::Gif, Some(64)).unwrap(),
+            "https://cdn.discordapp.com/icons/1/a_1acefe340fafb4ecefae407f3abdb323.gif?size=64",
+        );
+        assert_eq!(
+            super::guild_banner_url(Id::new(2), &hash, ImageFormat::Jpeg, None).unwrap(),
+            "https://cdn.discordapp.com/banners/2/1acefe340fafb4ecefae407f3abdb323.jpg",
+        );
+        assert_eq!(
+            super::guild_splash_url(Id::new(3), &hash, ImageFormat::Png, Some(16)).unwrap(),
+            "https://cdn.discordapp.com/splashes/3/1acefe340fafb4ecefae407f3abdb323.png?size=16",
+        );
+
+        assert!(matches!(
+            super::guild_icon_url(Id::new(1), &hash, ImageFormat::Gif, None)
+                .unwrap_err()
+                .kind(),
+            CdnUrlErrorType::FormatUnsupported { .. }
+        ));
+        assert!(matches!(
+            super::guild_splash_url(Id::new(3), &animated, ImageFormat::Gif, None)
+                .unwrap_err()
+                .kind(),
+            CdnUrlErrorType::FormatUnsupported { .. }
+        ));
+    }
+
+    #[test]
+    fn size() {
+        let hash = ImageHash::parse(STATIC).unwrap();
+
+        for size in [0, 8, 100, 8192] {
+            assert!(matches!(
+                super::guild_icon_url(Id::new(1), &hash, ImageFormat::Png, Some(size))
+                    .unwrap_err()
+                    .kind(),
+                CdnUrlErrorType::SizeInvalid { size: invalid } if *invalid == size
+            ));
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Utilities for formatting links to messages.
+
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker},
+    Id,
+};
+
+/// Base URL of links to channels.
+const BASE_URL: &str = "https://discord.com/channels";
+
+/// Format the jump link of a message, which opens the message in clients.
+///
+/// Messages outside of guilds, such as direct messages, have no guild ID.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::id::Id;
+/// use twilight_util::link::message;
+///
+/// assert_eq!(
+///     "https://discord.com/channels/1/2/3",
+///     message::jump_url(Some(Id::new(1)), Id::new(2), Id::new(3)),
+/// );
+/// assert_eq!(
+///     "https://discord.com/channels/@me/2/3",
+///     message::jump_url(None, Id::new(2), Id::new(3)),
+/// );
+/// ```
+pub fn jump_url(
+    guild_id: Option<Id<GuildMarker>>,
+    channel_id: Id<ChannelMarker>,
+    message_id: Id<MessageMarker>,
+) -> String {
+    match guild_id {
+        Some(guild_id) => format!("{BASE_URL}/{guild_id}/{channel_id}/{message_id}"),
+        None => format!("{BASE_URL}/@me/{channel_id}/{message_id}"),
+    }
+}
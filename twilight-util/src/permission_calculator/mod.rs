@@ -96,7 +96,7 @@ mod preset;
 
 use self::preset::{
     PERMISSIONS_MESSAGING, PERMISSIONS_ROOT_ONLY, PERMISSIONS_STAGE_OMIT, PERMISSIONS_TEXT_OMIT,
-    PERMISSIONS_VOICE_OMIT,
+    PERMISSIONS_TIMED_OUT, PERMISSIONS_VOICE_OMIT,
 };
 use twilight_model::{
     channel::{
@@ -108,6 +108,7 @@ use twilight_model::{
         marker::{GuildMarker, RoleMarker, UserMarker},
         Id,
     },
+    util::Timestamp,
 };
 
 /// Calculate the permissions of a member.
@@ -128,6 +129,8 @@ pub struct PermissionCalculator<'a> {
     member_roles: &'a [(Id<RoleMarker>, Permissions)],
     /// ID of the owner.
     owner_id: Option<Id<UserMarker>>,
+    /// Whether the member is timed out.
+    timed_out: bool,
     /// ID of the user whose permissions are being calculated.
     user_id: Id<UserMarker>,
 }
@@ -152,10 +155,58 @@ impl<'a> PermissionCalculator<'a> {
             guild_id,
             owner_id: None,
             member_roles,
+            timed_out: false,
             user_id,
         }
     }
 
+    /// Configure when the member's timeout expires, relative to the current
+    /// time.
+    ///
+    /// If the timeout hasn't expired yet, the member is timed out and only
+    /// keeps the [View Channel] and [Read Message History] permissions. As with
+    /// Discord, the guild owner and members with the [Administrator]
+    /// permission are unaffected.
+    ///
+    /// `now` is taken as a parameter, such as [`Timestamp::now`], to keep
+    /// calculations deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::{
+    ///     guild::Permissions,
+    ///     id::Id,
+    ///     util::Timestamp,
+    /// };
+    /// use twilight_util::permission_calculator::PermissionCalculator;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let now = Timestamp::from_secs(1_700_000_000)?;
+    /// let until = Timestamp::from_secs(1_700_000_060)?;
+    ///
+    /// let calculator = PermissionCalculator::new(
+    ///     Id::new(1),
+    ///     Id::new(2),
+    ///     Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+    ///     &[],
+    /// )
+    /// .communication_disabled_until(until, now);
+    ///
+    /// assert_eq!(Permissions::VIEW_CHANNEL, calculator.root());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [Administrator]: twilight_model::guild::Permissions::ADMINISTRATOR
+    /// [Read Message History]: twilight_model::guild::Permissions::READ_MESSAGE_HISTORY
+    /// [View Channel]: twilight_model::guild::Permissions::VIEW_CHANNEL
+    #[must_use = "calculators should be used to calculate permissions"]
+    pub const fn communication_disabled_until(mut self, until: Timestamp, now: Timestamp) -> Self {
+        self.timed_out = until.as_micros() > now.as_micros();
+
+        self
+    }
+
     /// Configure the ID of the owner of the guild.
     ///
     /// This should be used if you don't want to manually take the user ID and
@@ -176,6 +227,12 @@ impl<'a> PermissionCalculator<'a> {
     /// Calculate the guild-level permissions of a member.
     #[must_use = "calculating permissions is only useful if they're used"]
     pub const fn root(&self) -> Permissions {
+        self.time_out(self.root_unrestricted())
+    }
+
+    /// Calculate the guild-level permissions of a member, not taking timeouts
+    /// into account.
+    const fn root_unrestricted(&self) -> Permissions {
         // If the user is the owner, then we can just return all of the
         // permissions.
         if matches!(self.owner_id, Some(id) if id.get() == self.user_id.get()) {
@@ -359,7 +416,7 @@ impl<'a> PermissionCalculator<'a> {
         channel_type: ChannelType,
         channel_overwrites: &[PermissionOverwrite],
     ) -> Permissions {
-        let mut permissions = self.root();
+        let mut permissions = self.root_unrestricted();
 
         // If the user contains the administrator privilege from the calculated
         // root permissions, then we do not need to do any more work.
@@ -377,6 +434,10 @@ impl<'a> PermissionCalculator<'a> {
             self.user_id,
         );
 
+        // Timeouts apply after overwrites, which can't grant timed out members
+        // permissions.
+        permissions = self.time_out(permissions);
+
         // If the permission set is empty then we don't need to do any removals.
         if permissions.is_empty() {
             return permissions;
@@ -398,6 +459,16 @@ impl<'a> PermissionCalculator<'a> {
 
         permissions
     }
+
+    /// Remove the permissions timed out members lose, unless they are an
+    /// administrator.
+    const fn time_out(&self, permissions: Permissions) -> Permissions {
+        if !self.timed_out || permissions.contains(Permissions::ADMINISTRATOR) {
+            return permissions;
+        }
+
+        permissions.intersection(PERMISSIONS_TIMED_OUT)
+    }
 }
 
 const fn has_role(roles: &[(Id<RoleMarker>, Permissions)], role_id: Id<RoleMarker>) -> bool {
@@ -525,6 +596,7 @@ mod tests {
         },
         guild::Permissions,
         id::Id,
+        util::Timestamp,
     };
 
     assert_impl_all!(PermissionCalculator<'_>: Clone, Debug, Eq, PartialEq, Send, Sync);
@@ -701,4 +773,79 @@ mod tests {
             assert!(!calculated.intersects(PERMISSIONS_ROOT_ONLY));
         }
     }
+
+    /// Test that timed out members only keep the "View Channel" and "Read
+    /// Message History" permissions, even if overwrites allow more.
+    #[test]
+    fn timed_out() {
+        let now = Timestamp::from_secs(1_700_000_000).unwrap();
+        let until = Timestamp::from_secs(1_700_000_060).unwrap();
+        let everyone = Permissions::READ_MESSAGE_HISTORY
+            | Permissions::SEND_MESSAGES
+            | Permissions::VIEW_CHANNEL;
+        let member_roles = &[(Id::new(3), Permissions::KICK_MEMBERS)];
+        let overwrites = &[PermissionOverwrite {
+            allow: Permissions::ADD_REACTIONS | Permissions::SEND_MESSAGES,
+            deny: Permissions::empty(),
+            id: Id::new(2),
+            kind: PermissionOverwriteType::Member,
+        }];
+
+        let calc = PermissionCalculator::new(Id::new(1), Id::new(2), everyone, member_roles)
+            .communication_disabled_until(until, now);
+
+        let expected = Permissions::READ_MESSAGE_HISTORY | Permissions::VIEW_CHANNEL;
+        assert_eq!(expected, calc.root());
+        assert_eq!(
+            expected,
+            calc.clone().in_channel(ChannelType::GuildText, overwrites)
+        );
+
+        // Overwrites denying "View Channel" still apply.
+        let overwrites = &[PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::VIEW_CHANNEL,
+            id: Id::new(2),
+            kind: PermissionOverwriteType::Member,
+        }];
+        assert!(calc
+            .in_channel(ChannelType::GuildText, overwrites)
+            .is_empty());
+    }
+
+    /// Test that timeouts only apply until they expire.
+    #[test]
+    fn timed_out_expired() {
+        let now = Timestamp::from_secs(1_700_000_000).unwrap();
+        let everyone = Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL;
+        let calc = PermissionCalculator::new(Id::new(1), Id::new(2), everyone, &[]);
+
+        for until in [now, Timestamp::from_secs(1_699_999_940).unwrap()] {
+            let calc = calc.clone().communication_disabled_until(until, now);
+
+            assert_eq!(everyone, calc.root());
+            assert_eq!(everyone, calc.in_channel(ChannelType::GuildText, &[]));
+        }
+    }
+
+    /// Test that, as with Discord, timeouts don't apply to administrators and
+    /// the guild owner.
+    #[test]
+    fn timed_out_admin_and_owner() {
+        let now = Timestamp::from_secs(1_700_000_000).unwrap();
+        let until = Timestamp::from_secs(1_700_000_060).unwrap();
+
+        let member_roles = &[(Id::new(3), Permissions::ADMINISTRATOR)];
+        let admin =
+            PermissionCalculator::new(Id::new(1), Id::new(2), Permissions::empty(), member_roles)
+                .communication_disabled_until(until, now);
+        assert!(admin.root().is_all());
+        assert!(admin.in_channel(ChannelType::GuildText, &[]).is_all());
+
+        let owner = PermissionCalculator::new(Id::new(1), Id::new(2), Permissions::empty(), &[])
+            .owner_id(Id::new(2))
+            .communication_disabled_until(until, now);
+        assert!(owner.root().is_all());
+        assert!(owner.in_channel(ChannelType::GuildText, &[]).is_all());
+    }
 }
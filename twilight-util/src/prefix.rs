@@ -0,0 +1,103 @@
+//! Detect and strip command prefixes from message content.
+//!
+//! # Examples
+//!
+//! ```
+//! use twilight_util::prefix;
+//!
+//! let prefixes = ["!", "!!", "bot "];
+//!
+//! assert_eq!(
+//!     Some(("!!", "ping")),
+//!     prefix::strip_prefix("!!ping", &prefixes)
+//! );
+//! assert_eq!(
+//!     Some(("BOT ", "ping")),
+//!     prefix::strip_prefix_ignore_ascii_case("BOT ping", &prefixes)
+//! );
+//! assert!(prefix::strip_prefix("ping", &prefixes).is_none());
+//! ```
+
+/// Strip the longest of the prefixes that the content starts with.
+///
+/// Returns the matched prefix and the remaining content, which is not trimmed.
+/// Returns `None` if the content doesn't start with any of the prefixes. Empty
+/// prefixes never match.
+pub fn strip_prefix<'a>(content: &'a str, prefixes: &[&str]) -> Option<(&'a str, &'a str)> {
+    strip(content, prefixes, |start, prefix| start == prefix)
+}
+
+/// Strip the longest of the prefixes that the content starts with, ignoring
+/// ASCII case.
+///
+/// Returns the prefix as written in the content and the remaining content,
+/// which is not trimmed. Returns `None` if the content doesn't start with any
+/// of the prefixes. Empty prefixes never match.
+pub fn strip_prefix_ignore_ascii_case<'a>(
+    content: &'a str,
+    prefixes: &[&str],
+) -> Option<(&'a str, &'a str)> {
+    strip(content, prefixes, str::eq_ignore_ascii_case)
+}
+
+/// Strip the longest prefix matching the start of the content.
+fn strip<'a>(
+    content: &'a str,
+    prefixes: &[&str],
+    matches: impl Fn(&str, &str) -> bool,
+) -> Option<(&'a str, &'a str)> {
+    let len = prefixes
+        .iter()
+        .filter(|prefix| !prefix.is_empty())
+        .filter(|prefix| {
+            content
+                .get(..prefix.len())
+                .is_some_and(|start| matches(start, prefix))
+        })
+        .map(|prefix| prefix.len())
+        .max()?;
+
+    Some(content.split_at(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{strip_prefix, strip_prefix_ignore_ascii_case};
+
+    #[test]
+    fn longest_match() {
+        assert_eq!(Some(("!!", "ping")), strip_prefix("!!ping", &["!", "!!"]));
+        assert_eq!(Some(("!!", "ping")), strip_prefix("!!ping", &["!!", "!"]));
+        assert_eq!(Some(("!", "ping")), strip_prefix("!ping", &["!!", "!"]));
+    }
+
+    #[test]
+    fn no_match() {
+        assert!(strip_prefix("ping", &["!"]).is_none());
+        assert!(strip_prefix("ping", &[""]).is_none());
+        assert!(strip_prefix("ping", &[]).is_none());
+        assert!(strip_prefix("!", &["!!"]).is_none());
+        assert!(strip_prefix("BOT ping", &["bot "]).is_none());
+    }
+
+    #[test]
+    fn remaining_content() {
+        assert_eq!(Some(("!", "")), strip_prefix("!", &["!"]));
+        assert_eq!(Some(("!", " ping")), strip_prefix("! ping", &["!"]));
+    }
+
+    #[test]
+    fn ignore_ascii_case() {
+        assert_eq!(
+            Some(("BoT ", "ping")),
+            strip_prefix_ignore_ascii_case("BoT ping", &["bot ", "b"])
+        );
+        assert!(strip_prefix_ignore_ascii_case("ping", &["bot "]).is_none());
+    }
+
+    #[test]
+    fn char_boundary() {
+        assert!(strip_prefix("🦀ping", &["!"]).is_none());
+        assert_eq!(Some(("🦀", "ping")), strip_prefix("🦀ping", &["🦀"]));
+    }
+}
@@ -0,0 +1,154 @@
+//! Cycle through a list of items on a schedule, such as a bot's presences.
+//!
+//! # Examples
+//!
+//! Rotate between statuses, at most once every 30 seconds:
+//!
+//! ```
+//! use std::time::Duration;
+//! use twilight_util::rotation::Rotation;
+//!
+//! let mut statuses = Rotation::new(["Watching the server", "Type !help"])
+//!     .min_interval(Duration::from_secs(30));
+//!
+//! assert_eq!(Some(&"Watching the server"), statuses.tick());
+//! // Less than 30 seconds passed since the previous item.
+//! assert!(statuses.tick().is_none());
+//! ```
+
+use std::time::{Duration, Instant};
+
+/// Items yielded in order, wrapping around after the last item.
+///
+/// Each [`tick`] yields the next item. If a [minimum interval] is configured,
+/// ticks before it elapsed since the previously yielded item yield nothing,
+/// so items aren't rotated faster than a ratelimit allows; for example, the
+/// gateway allows a shard to send 120 commands per 60 seconds.
+///
+/// [`tick`]: Self::tick
+/// [minimum interval]: Self::min_interval
+#[derive(Clone, Debug)]
+pub struct Rotation<T> {
+    /// Index of the next item.
+    index: usize,
+    /// Items to rotate through.
+    items: Vec<T>,
+    /// When an item was last yielded.
+    last_tick: Option<Instant>,
+    /// Minimum duration between yielded items.
+    min_interval: Duration,
+}
+
+impl<T> Rotation<T> {
+    /// Create a new rotation starting at the first item, without a minimum
+    /// interval.
+    pub fn new(items: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            index: 0,
+            items: items.into_iter().collect(),
+            last_tick: None,
+            min_interval: Duration::ZERO,
+        }
+    }
+
+    /// Set the minimum duration between yielded items.
+    ///
+    /// The first item is always yielded immediately.
+    #[must_use = "builders have no effect if unused"]
+    pub const fn min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+
+        self
+    }
+
+    /// Items to rotate through.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Duration until the next item may be yielded, if the minimum interval
+    /// hasn't elapsed yet.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.remaining_at(Instant::now())
+    }
+
+    /// Yield the next item, wrapping around after the last item.
+    ///
+    /// Returns `None` if there are no items or the minimum interval hasn't
+    /// elapsed since the previously yielded item.
+    pub fn tick(&mut self) -> Option<&T> {
+        self.tick_at(Instant::now())
+    }
+
+    /// Duration until the next item may be yielded at a point in time.
+    fn remaining_at(&self, now: Instant) -> Option<Duration> {
+        let elapsed = now.saturating_duration_since(self.last_tick?);
+
+        self.min_interval
+            .checked_sub(elapsed)
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    /// Yield the next item at a point in time.
+    fn tick_at(&mut self, now: Instant) -> Option<&T> {
+        if self.items.is_empty() || self.remaining_at(now).is_some() {
+            return None;
+        }
+
+        let index = self.index;
+        self.index = (index + 1) % self.items.len();
+        self.last_tick = Some(now);
+
+        self.items.get(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rotation;
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        time::{Duration, Instant},
+    };
+
+    assert_impl_all!(Rotation<&str>: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn wraps_around() {
+        let mut rotation = Rotation::new([1, 2, 3]);
+
+        let items = (0..7).map(|_| rotation.tick().copied()).collect::<Vec<_>>();
+        assert_eq!([1, 2, 3, 1, 2, 3, 1].map(Some).as_slice(), items.as_slice());
+    }
+
+    #[test]
+    fn empty() {
+        let mut rotation = Rotation::<u8>::new([]);
+
+        assert!(rotation.tick().is_none());
+        assert!(rotation.remaining().is_none());
+    }
+
+    #[test]
+    fn min_interval() {
+        const INTERVAL: Duration = Duration::from_secs(10);
+
+        let mut rotation = Rotation::new(["a", "b"]).min_interval(INTERVAL);
+        let start = Instant::now();
+
+        assert_eq!(Some(&"a"), rotation.tick_at(start));
+        assert_eq!(Some(INTERVAL), rotation.remaining_at(start));
+
+        let before = start + Duration::from_secs(9);
+        assert!(rotation.tick_at(before).is_none());
+        assert_eq!(Some(Duration::from_secs(1)), rotation.remaining_at(before));
+
+        // Skipped ticks don't advance the rotation.
+        let after = start + INTERVAL;
+        assert!(rotation.remaining_at(after).is_none());
+        assert_eq!(Some(&"b"), rotation.tick_at(after));
+        assert!(rotation.tick_at(after).is_none());
+        assert_eq!(Some(&"a"), rotation.tick_at(after + INTERVAL));
+    }
+}
@@ -1,5 +1,22 @@
 //! Provides the Snowflake trait for defining extractable information from a Discord Snowflake.
-
+//!
+//! The [`timestamp`] and [`from_timestamp`] functions convert between IDs of any
+//! type and the Unix time they were generated at, such as to paginate messages
+//! sent after a point in time:
+//!
+//! ```
+//! use twilight_model::id::{marker::MessageMarker, Id};
+//! use twilight_util::snowflake;
+//!
+//! // 2023-01-01T00:00:00Z
+//! let after = snowflake::from_timestamp(1_672_531_200_000);
+//! let after = Id::<MessageMarker>::new(after);
+//!
+//! assert_eq!(1_672_531_200_000, snowflake::timestamp(after));
+//! ```
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use twilight_model::id::{
     marker::{
         ApplicationMarker, AttachmentMarker, AuditLogEntryMarker, ChannelMarker, CommandMarker,
@@ -8,9 +25,84 @@ use twilight_model::id::{
         RoleSubscriptionSkuMarker, ScheduledEventEntityMarker, ScheduledEventMarker, StageMarker,
         StickerMarker, StickerPackMarker, StickerPackSkuMarker, UserMarker, WebhookMarker,
     },
-    Id,
+    Id, DISCORD_EPOCH,
 };
 
+/// Largest timestamp, relative to [`DISCORD_EPOCH`], fitting in bits 22..63.
+const MAX_TIMESTAMP: u64 = u64::MAX >> 22;
+
+/// The Unix epoch of an ID in milliseconds, indicating when it was generated.
+///
+/// Unlike [`Snowflake::timestamp`], this works with IDs of any type.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::id::{marker::UserMarker, Id};
+/// use twilight_util::snowflake;
+///
+/// let id = Id::<UserMarker>::new(105484726235607040);
+///
+/// assert_eq!(1_445_219_918_546, snowflake::timestamp(id));
+/// ```
+#[allow(clippy::cast_possible_wrap)]
+pub const fn timestamp<T>(id: Id<T>) -> i64 {
+    ((id.get() >> 22) + DISCORD_EPOCH) as i64
+}
+
+/// Construct the lowest snowflake generated at a Unix epoch in milliseconds.
+///
+/// The worker ID, process ID, and increment are zero, so the snowflake is
+/// suitable as a `before` or `after` pagination anchor. Timestamps before
+/// Discord's epoch, the first second of 2015, saturate to `0`, which isn't a
+/// valid ID; timestamps too far in the future saturate to the latest
+/// representable timestamp.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_util::snowflake;
+///
+/// assert_eq!(105484726235561984, snowflake::from_timestamp(1_445_219_918_546));
+/// ```
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+pub const fn from_timestamp(timestamp: i64) -> u64 {
+    let relative = timestamp.saturating_sub(DISCORD_EPOCH as i64);
+
+    if relative <= 0 {
+        return 0;
+    }
+
+    let relative = relative as u64;
+
+    (if relative > MAX_TIMESTAMP {
+        MAX_TIMESTAMP
+    } else {
+        relative
+    }) << 22
+}
+
+/// When an ID was generated.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::id::{marker::UserMarker, Id};
+/// use twilight_util::snowflake;
+///
+/// let id = Id::<UserMarker>::new(105484726235607040);
+///
+/// assert_eq!(
+///     "2015-10-19T01:58:38.546+00:00",
+///     snowflake::to_datetime(id).to_rfc3339()
+/// );
+/// ```
+#[cfg(feature = "chrono")]
+#[allow(clippy::missing_panics_doc)]
+pub fn to_datetime<T>(id: Id<T>) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(timestamp(id)).expect("chrono's range includes snowflakes'")
+}
+
 /// Snowflake is a trait for defining extractable information from a Snowflake. A Snowflake is a
 /// u64 generated by Discord to uniquely identify a resource.
 pub trait Snowflake {
@@ -59,9 +151,6 @@ pub trait Snowflake {
     /// ```
     #[allow(clippy::cast_possible_wrap)]
     fn timestamp(&self) -> i64 {
-        // Discord's custom epoch, the unix time in milliseconds for the first second of 2015.
-        const DISCORD_EPOCH: u64 = 1_420_070_400_000;
-
         ((self.id() >> 22) + DISCORD_EPOCH) as i64
     }
 
@@ -274,6 +363,50 @@ mod tests {
         assert_eq!(expected, id.timestamp());
     }
 
+    #[test]
+    fn timestamp_generic() {
+        // The first pair is the example from Discord's documentation.
+        const PAIRS: &[(u64, i64)] = &[
+            (175_928_847_299_117_063, 1_462_015_105_796),
+            (105_484_726_235_607_040, 1_445_219_918_546),
+        ];
+
+        for (id, expected) in PAIRS {
+            assert_eq!(*expected, super::timestamp(Id::<GenericMarker>::new(*id)));
+            assert_eq!(*expected, super::timestamp(Id::<MessageMarker>::new(*id)));
+        }
+    }
+
+    #[test]
+    fn from_timestamp() {
+        let id = super::from_timestamp(1_462_015_105_796);
+        assert_eq!(175_928_847_298_985_984, id);
+        assert_eq!(175_928_847_299_117_063 >> 22, id >> 22);
+
+        let id = Id::<MessageMarker>::new(id);
+        assert_eq!(1_462_015_105_796, super::timestamp(id));
+        assert_eq!(0, id.worker_id());
+        assert_eq!(0, id.process_id());
+        assert_eq!(0, id.increment());
+
+        assert_eq!(1 << 22, super::from_timestamp(1_420_070_400_001));
+        assert_eq!(0, super::from_timestamp(1_420_070_400_000));
+        assert_eq!(0, super::from_timestamp(0));
+        assert_eq!(0, super::from_timestamp(i64::MIN));
+        assert_eq!(u64::MAX << 22, super::from_timestamp(i64::MAX));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn to_datetime() {
+        let id = Id::<UserMarker>::new(105_484_726_235_607_040);
+
+        assert_eq!(
+            "2015-10-19T01:58:38.546+00:00",
+            super::to_datetime(id).to_rfc3339()
+        );
+    }
+
     #[test]
     fn worker_id() {
         let expected: u8 = 8;
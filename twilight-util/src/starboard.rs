@@ -0,0 +1,283 @@
+//! Embeds quoting messages, such as for starboards.
+//!
+//! # Examples
+//!
+//! Quote a message, adding the number of stars it received:
+//!
+//! ```
+//! use twilight_model::channel::Message;
+//! use twilight_util::{builder::embed::EmbedFooterBuilder, starboard};
+//!
+//! fn quote(message: &Message, stars: u64) -> twilight_model::channel::message::Embed {
+//!     starboard::embed(message)
+//!         .footer(EmbedFooterBuilder::new(format!("⭐ {stars}")))
+//!         .build()
+//! }
+//! ```
+
+use crate::{
+    builder::embed::{EmbedAuthorBuilder, EmbedBuilder, EmbedFieldBuilder, ImageSource},
+    link::{cdn::BASE_URL, message::jump_url},
+};
+use twilight_model::channel::{Attachment, Message};
+use twilight_validate::embed::DESCRIPTION_LENGTH;
+
+/// Appended to content that was truncated.
+const ELLIPSIS: char = '…';
+
+/// Extensions of images that can be embedded.
+const IMAGE_EXTENSIONS: [&str; 5] = ["gif", "jpeg", "jpg", "png", "webp"];
+
+/// Create an embed builder quoting a message.
+///
+/// The embed is pre-filled with:
+///
+/// - the message's author, by their nickname if available, and their avatar;
+/// - the message's content as the description, truncated with an ellipsis if
+///   it's too long;
+/// - a field with the message's [jump link];
+/// - the message's first image attachment as the image;
+/// - the message's timestamp.
+///
+/// [jump link]: crate::link::message::jump_url
+pub fn embed(message: &Message) -> EmbedBuilder {
+    let author = &message.author;
+    let name = message
+        .member
+        .as_ref()
+        .and_then(|member| member.nick.as_deref())
+        .or(author.global_name.as_deref())
+        .unwrap_or(&author.name);
+
+    let mut author_builder = EmbedAuthorBuilder::new(name);
+
+    if let Some(avatar) = author.avatar {
+        let extension = if avatar.is_animated() { "gif" } else { "png" };
+        let url = format!("{BASE_URL}/avatars/{}/{avatar}.{extension}", author.id);
+
+        if let Ok(source) = ImageSource::url(url) {
+            author_builder = author_builder.icon_url(source);
+        }
+    }
+
+    let jump_url = jump_url(message.guild_id, message.channel_id, message.id);
+    let mut builder = EmbedBuilder::new()
+        .author(author_builder)
+        .field(EmbedFieldBuilder::new(
+            "Source",
+            format!("[Jump to message]({jump_url})"),
+        ))
+        .timestamp(message.timestamp);
+
+    if !message.content.is_empty() {
+        builder = builder.description(truncate(&message.content, DESCRIPTION_LENGTH));
+    }
+
+    if let Some(source) = message
+        .attachments
+        .iter()
+        .find(|attachment| is_image(attachment))
+        .and_then(|attachment| ImageSource::url(attachment.url.as_str()).ok())
+    {
+        builder = builder.image(source);
+    }
+
+    builder
+}
+
+/// Whether an attachment is an image that can be embedded.
+fn is_image(attachment: &Attachment) -> bool {
+    if let Some(content_type) = &attachment.content_type {
+        return content_type.starts_with("image/");
+    }
+
+    attachment
+        .filename
+        .rsplit_once('.')
+        .is_some_and(|(_, extension)| {
+            IMAGE_EXTENSIONS
+                .iter()
+                .any(|image| extension.eq_ignore_ascii_case(image))
+        })
+}
+
+/// Truncate text to a maximum number of UTF-16 code units, appending an
+/// ellipsis if it was truncated.
+///
+/// Characters are never split, so surrogate pairs are kept intact.
+fn truncate(text: &str, max: usize) -> String {
+    if text.encode_utf16().count() <= max {
+        return text.to_owned();
+    }
+
+    let budget = max - ELLIPSIS.len_utf16();
+    let mut len = 0;
+    let mut truncated = text
+        .chars()
+        .take_while(|c| {
+            len += c.len_utf16();
+
+            len <= budget
+        })
+        .collect::<String>();
+    truncated.push(ELLIPSIS);
+
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ELLIPSIS;
+    use twilight_model::{
+        channel::{
+            message::{embed::EmbedField, MessageFlags, MessageType},
+            Attachment, Message,
+        },
+        id::Id,
+        user::User,
+        util::{ImageHash, Timestamp},
+    };
+    use twilight_validate::embed::DESCRIPTION_LENGTH;
+
+    fn attachment(id: u64, filename: &str, content_type: Option<&str>) -> Attachment {
+        Attachment {
+            content_type: content_type.map(ToOwned::to_owned),
+            ephemeral: false,
+            duration_secs: None,
+            filename: filename.to_owned(),
+            flags: None,
+            description: None,
+            height: None,
+            id: Id::new(id),
+            proxy_url: String::new(),
+            size: 1,
+            title: None,
+            url: format!("https://cdn.discordapp.com/attachments/2/{id}/{filename}"),
+            waveform: None,
+            width: None,
+        }
+    }
+
+    #[allow(deprecated)]
+    fn message(content: &str, attachments: Vec<Attachment>) -> Message {
+        Message {
+            activity: None,
+            application: None,
+            application_id: None,
+            attachments,
+            author: User {
+                accent_color: None,
+                avatar: Some(ImageHash::parse(b"6961d9f1fdb5880bf4a3ec6348d3bbcf").unwrap()),
+                avatar_decoration: None,
+                avatar_decoration_data: None,
+                banner: None,
+                bot: false,
+                discriminator: 0,
+                email: None,
+                flags: None,
+                global_name: Some("Twilight".to_owned()),
+                id: Id::new(3),
+                locale: None,
+                mfa_enabled: None,
+                name: "twilight".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+            call: None,
+            channel_id: Id::new(2),
+            components: Vec::new(),
+            content: content.to_owned(),
+            edited_timestamp: None,
+            embeds: Vec::new(),
+            flags: Some(MessageFlags::empty()),
+            guild_id: Some(Id::new(1)),
+            id: Id::new(4),
+            interaction: None,
+            interaction_metadata: None,
+            kind: MessageType::Regular,
+            member: None,
+            mention_channels: Vec::new(),
+            mention_everyone: false,
+            mention_roles: Vec::new(),
+            mentions: Vec::new(),
+            message_snapshots: Vec::new(),
+            pinned: false,
+            poll: None,
+            position: None,
+            reactions: Vec::new(),
+            reference: None,
+            referenced_message: None,
+            role_subscription_data: None,
+            sticker_items: Vec::new(),
+            timestamp: Timestamp::from_secs(1_632_072_645).unwrap(),
+            thread: None,
+            tts: false,
+            webhook_id: None,
+        }
+    }
+
+    #[test]
+    fn embed() {
+        let message = message(
+            "look at this",
+            vec![
+                attachment(5, "notes.txt", Some("text/plain")),
+                attachment(6, "cat.PNG", None),
+                attachment(7, "dog.png", Some("image/png")),
+            ],
+        );
+        let embed = super::embed(&message).validate().unwrap().build();
+
+        let author = embed.author.unwrap();
+        assert_eq!("Twilight", author.name);
+        assert_eq!(
+            Some("https://cdn.discordapp.com/avatars/3/6961d9f1fdb5880bf4a3ec6348d3bbcf.png"),
+            author.icon_url.as_deref()
+        );
+        assert_eq!(Some("look at this"), embed.description.as_deref());
+        assert_eq!(
+            [EmbedField {
+                inline: false,
+                name: "Source".to_owned(),
+                value: "[Jump to message](https://discord.com/channels/1/2/4)".to_owned(),
+            }]
+            .as_slice(),
+            embed.fields
+        );
+        assert_eq!(
+            Some("https://cdn.discordapp.com/attachments/2/6/cat.PNG"),
+            embed.image.map(|image| image.url).as_deref()
+        );
+        assert_eq!(Some(message.timestamp), embed.timestamp);
+    }
+
+    #[test]
+    fn embed_without_content() {
+        let message = message("", vec![attachment(5, "notes.txt", None)]);
+        let embed = super::embed(&message).build();
+
+        assert!(embed.description.is_none());
+        assert!(embed.image.is_none());
+    }
+
+    #[test]
+    fn truncate() {
+        assert_eq!("abc", super::truncate("abc", 3));
+        assert_eq!("ab…", super::truncate("abcd", 3));
+
+        // The crab is a surrogate pair, which isn't split.
+        assert_eq!("a…", super::truncate("a🦀b", 3));
+        assert_eq!("a🦀…", super::truncate("a🦀bc", 4));
+
+        let content = "🦀".repeat(DESCRIPTION_LENGTH);
+        let truncated = super::truncate(&content, DESCRIPTION_LENGTH);
+        assert_eq!(DESCRIPTION_LENGTH - 1, truncated.encode_utf16().count());
+        assert!(truncated.ends_with(ELLIPSIS));
+        assert!(truncated.chars().rev().skip(1).all(|c| c == '🦀'));
+
+        let message = message(&content, Vec::new());
+        assert!(super::embed(&message).validate().is_ok());
+    }
+}
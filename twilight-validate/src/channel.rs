@@ -4,7 +4,7 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::ChannelType;
+use twilight_model::channel::{thread::AutoArchiveDuration, ChannelType};
 
 /// Minimum bitrate of a voice channel.
 pub const CHANNEL_BITRATE_MIN: u32 = 8000;
@@ -75,6 +75,12 @@ impl ChannelValidationError {
 impl Display for ChannelValidationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            ChannelValidationErrorType::AutoArchiveDurationInvalid { value } => {
+                f.write_str("auto archive duration of ")?;
+                Display::fmt(value, f)?;
+
+                f.write_str(" minutes is invalid")
+            }
             ChannelValidationErrorType::BitrateInvalid => {
                 f.write_str("bitrate is less than ")?;
                 Display::fmt(&CHANNEL_BITRATE_MIN, f)
@@ -123,6 +129,12 @@ impl Error for ChannelValidationError {}
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ChannelValidationErrorType {
+    /// The auto archive duration is not one of the durations accepted by
+    /// Discord.
+    AutoArchiveDurationInvalid {
+        /// Provided duration in minutes.
+        value: u16,
+    },
     /// The bitrate is less than 8000.
     BitrateInvalid,
     /// Number of messages being deleted in bulk is invalid.
@@ -150,6 +162,29 @@ pub enum ChannelValidationErrorType {
     UserLimitInvalid,
 }
 
+/// Ensure a thread's auto archive duration is correct.
+///
+/// The duration must be one of 60, 1440, 4320, or 10080 minutes, so
+/// [`AutoArchiveDuration::Unknown`] values are rejected.
+///
+/// # Errors
+///
+/// Returns an error of type [`AutoArchiveDurationInvalid`] if the duration is
+/// invalid.
+///
+/// [`AutoArchiveDurationInvalid`]: ChannelValidationErrorType::AutoArchiveDurationInvalid
+pub const fn auto_archive_duration(
+    value: AutoArchiveDuration,
+) -> Result<(), ChannelValidationError> {
+    if let AutoArchiveDuration::Unknown { value } = value {
+        Err(ChannelValidationError {
+            kind: ChannelValidationErrorType::AutoArchiveDurationInvalid { value },
+        })
+    } else {
+        Ok(())
+    }
+}
+
 /// Ensure a channel's bitrate is collect.
 ///
 /// Must be at least 8000.
@@ -342,6 +377,21 @@ pub const fn user_limit(value: u16) -> Result<(), ChannelValidationError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn auto_archive_duration_value() {
+        assert!(auto_archive_duration(AutoArchiveDuration::Hour).is_ok());
+        assert!(auto_archive_duration(AutoArchiveDuration::Day).is_ok());
+        assert!(auto_archive_duration(AutoArchiveDuration::ThreeDays).is_ok());
+        assert!(auto_archive_duration(AutoArchiveDuration::Week).is_ok());
+
+        assert!(matches!(
+            auto_archive_duration(AutoArchiveDuration::from(30))
+                .unwrap_err()
+                .kind(),
+            ChannelValidationErrorType::AutoArchiveDurationInvalid { value: 30 },
+        ));
+    }
+
     #[test]
     fn bulk_delete_messages() {
         assert!(matches!(
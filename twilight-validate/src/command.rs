@@ -194,6 +194,9 @@ impl Display for CommandValidationError {
 
                 f.write_str("`")
             }
+            CommandValidationErrorType::OptionAutocompleteChoicesConflict => {
+                f.write_str("command option must not have choices while autocomplete is enabled")
+            }
             CommandValidationErrorType::OptionChoiceNameLengthInvalid => {
                 f.write_str("command option choice name must be between ")?;
                 Display::fmt(&OPTION_CHOICE_NAME_LENGTH_MIN, f)?;
@@ -277,6 +280,8 @@ pub enum CommandValidationErrorType {
         /// Invalid character.
         character: char,
     },
+    /// Command option has both autocomplete enabled and choices set.
+    OptionAutocompleteChoicesConflict,
     /// Command option choice name length is invalid.
     OptionChoiceNameLengthInvalid,
     /// String command option choice value length is invalid.
@@ -637,6 +642,10 @@ pub fn choice(choice: &CommandOptionChoice) -> Result<(), CommandValidationError
 /// Returns an error of type [`OptionNameLengthInvalid`] or [`OptionNameCharacterInvalid`]
 /// if the name is invalid.
 ///
+/// Returns an error of type [`OptionAutocompleteChoicesConflict`] if the option
+/// has autocomplete enabled and also has choices.
+///
+/// [`OptionAutocompleteChoicesConflict`]: CommandValidationErrorType::OptionAutocompleteChoicesConflict
 /// [`OptionDescriptionInvalid`]: CommandValidationErrorType::OptionDescriptionInvalid
 /// [`OptionNameLengthInvalid`]: CommandValidationErrorType::OptionNameLengthInvalid
 /// [`OptionNameCharacterInvalid`]: CommandValidationErrorType::OptionNameCharacterInvalid
@@ -649,6 +658,13 @@ pub fn option(option: &CommandOption) -> Result<(), CommandValidationError> {
     }
 
     if let Some(choices) = &option.choices {
+        // https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-structure
+        if option.autocomplete.unwrap_or_default() && !choices.is_empty() {
+            return Err(CommandValidationError {
+                kind: CommandValidationErrorType::OptionAutocompleteChoicesConflict,
+            });
+        }
+
         choices.iter().try_for_each(self::choice)?;
     }
 
@@ -1083,4 +1099,44 @@ mod tests {
         assert!(option(&maxlen).is_ok());
         assert!(option(&minlen).is_ok());
     }
+
+    /// Test that options with both autocomplete and choices are rejected.
+    #[test]
+    fn option_autocomplete_choices() {
+        let base = CommandOption {
+            autocomplete: Some(true),
+            channel_types: None,
+            choices: Some(Vec::new()),
+            description: "a description".to_owned(),
+            description_localizations: None,
+            kind: CommandOptionType::String,
+            max_length: None,
+            max_value: None,
+            min_length: None,
+            min_value: None,
+            name: "name".to_owned(),
+            name_localizations: None,
+            options: None,
+            required: None,
+        };
+        let with_choices = CommandOption {
+            choices: Some(Vec::from([CommandOptionChoice {
+                name: "choice".to_owned(),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::String("value".to_owned()),
+            }])),
+            ..base.clone()
+        };
+        let without_autocomplete = CommandOption {
+            autocomplete: Some(false),
+            ..with_choices.clone()
+        };
+
+        assert!(option(&base).is_ok());
+        assert!(option(&without_autocomplete).is_ok());
+        assert!(matches!(
+            option(&with_choices).unwrap_err().kind(),
+            CommandValidationErrorType::OptionAutocompleteChoicesConflict
+        ));
+    }
 }
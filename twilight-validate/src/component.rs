@@ -81,6 +81,14 @@ pub const SELECT_MINIMUM_VALUES_LIMIT: usize = 25;
 /// [1]: https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-menu-structure
 pub const SELECT_OPTION_COUNT: usize = 25;
 
+/// Minimum number of [`SelectMenuOption`]s in a [`SelectMenu`].
+///
+/// This is defined in Discord's documentation, per
+/// [Discord Docs/Select Menu][1].
+///
+/// [1]: https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-menu-structure
+pub const SELECT_OPTION_COUNT_MINIMUM: usize = 1;
+
 /// Maximum length of a [`SelectMenuOption::description`] in codepoints.
 ///
 /// This is defined in Discord's documentation, per
@@ -300,10 +308,18 @@ impl Display for ComponentValidationError {
             ComponentValidationErrorType::SelectOptionCount { count } => {
                 f.write_str("a select menu has ")?;
                 Display::fmt(&count, f)?;
-                f.write_str(" options, but the max is ")?;
+                f.write_str(" options, but it must have at least ")?;
+                Display::fmt(&SELECT_OPTION_COUNT_MINIMUM, f)?;
+                f.write_str(" and at most ")?;
 
                 Display::fmt(&SELECT_OPTION_COUNT, f)
             }
+            ComponentValidationErrorType::SelectOptionDuplicateValue { value } => {
+                f.write_str("a select menu has multiple options with the value `")?;
+                Display::fmt(value, f)?;
+
+                f.write_str("`")
+            }
             ComponentValidationErrorType::SelectTooManyDefaultValues { provided, max } => {
                 f.write_str("a select menu provided ")?;
                 Display::fmt(provided, f)?;
@@ -432,12 +448,18 @@ pub enum ComponentValidationErrorType {
     ///
     /// [text-select]: SelectMenuType::Text
     SelectOptionsMissing,
-    /// Number of select menu options provided is larger than
+    /// Number of select menu options provided is smaller than
+    /// [the minimum][`SELECT_OPTION_COUNT_MINIMUM`] or larger than
     /// [the maximum][`SELECT_OPTION_COUNT`].
     SelectOptionCount {
         /// Number of options that were provided.
         count: usize,
     },
+    /// Multiple select menu options have the same value.
+    SelectOptionDuplicateValue {
+        /// Value shared by the options.
+        value: String,
+    },
     /// Description of a select menu option is larger than
     /// [the maximum][`SELECT_OPTION_DESCRIPTION_LENGTH`].
     SelectOptionDescriptionLength {
@@ -643,6 +665,9 @@ pub fn button(button: &Button) -> Result<(), ComponentValidationError> {
 
 /// Ensure that a select menu is correct.
 ///
+/// Options are only validated for [text select menus], as other select menus
+/// don't have options.
+///
 /// # Errors
 ///
 /// Returns an error of type [`ComponentCustomIdLength`] if the provided custom
@@ -667,6 +692,12 @@ pub fn button(button: &Button) -> Result<(), ComponentValidationError> {
 /// Returns an error of type [`SelectOptionValueLength`] error type if
 /// a provided select option value is too long.
 ///
+/// Returns an error of type [`SelectOptionCount`] if a text select menu has
+/// too few or too many options.
+///
+/// Returns an error of type [`SelectOptionDuplicateValue`] if multiple options
+/// of a text select menu have the same value.
+///
 /// Returns an error of type [`SelectPlaceholderLength`] if a provided select
 /// placeholder is too long.
 ///
@@ -686,10 +717,13 @@ pub fn button(button: &Button) -> Result<(), ComponentValidationError> {
 /// [`SelectOptionDescriptionLength`]: ComponentValidationErrorType::SelectOptionDescriptionLength
 /// [`SelectOptionLabelLength`]: ComponentValidationErrorType::SelectOptionLabelLength
 /// [`SelectOptionValueLength`]: ComponentValidationErrorType::SelectOptionValueLength
+/// [`SelectOptionCount`]: ComponentValidationErrorType::SelectOptionCount
+/// [`SelectOptionDuplicateValue`]: ComponentValidationErrorType::SelectOptionDuplicateValue
 /// [`SelectPlaceholderLength`]: ComponentValidationErrorType::SelectPlaceholderLength
 /// [`SelectUnsupportedDefaultValues`]: ComponentValidationErrorType::SelectUnsupportedDefaultValues
 /// [`SelectNotEnoughDefaultValues`]: ComponentValidationErrorType::SelectNotEnoughDefaultValues
 /// [`SelectTooManyDefaultValues`]: ComponentValidationErrorType::SelectTooManyDefaultValues
+/// [text select menus]: SelectMenuType::Text
 pub fn select_menu(select_menu: &SelectMenu) -> Result<(), ComponentValidationError> {
     self::component_custom_id(&select_menu.custom_id)?;
 
@@ -702,14 +736,10 @@ pub fn select_menu(select_menu: &SelectMenu) -> Result<(), ComponentValidationEr
                 kind: ComponentValidationErrorType::SelectOptionsMissing,
             })?;
         for option in options {
-            component_select_option_label(&option.label)?;
-            component_select_option_value(&option.value)?;
-
-            if let Some(description) = option.description.as_ref() {
-                component_option_description(description)?;
-            }
+            self::select_menu_option(option)?;
         }
         component_select_options(options)?;
+        component_select_option_values_unique(options)?;
     }
 
     if let Some(placeholder) = select_menu.placeholder.as_ref() {
@@ -736,6 +766,33 @@ pub fn select_menu(select_menu: &SelectMenu) -> Result<(), ComponentValidationEr
     Ok(())
 }
 
+/// Ensure that a select menu option is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`SelectOptionDescriptionLength`] if the provided
+/// description is too long.
+///
+/// Returns an error of type [`SelectOptionLabelLength`] if the provided label
+/// is too long.
+///
+/// Returns an error of type [`SelectOptionValueLength`] if the provided value
+/// is too long.
+///
+/// [`SelectOptionDescriptionLength`]: ComponentValidationErrorType::SelectOptionDescriptionLength
+/// [`SelectOptionLabelLength`]: ComponentValidationErrorType::SelectOptionLabelLength
+/// [`SelectOptionValueLength`]: ComponentValidationErrorType::SelectOptionValueLength
+pub fn select_menu_option(option: &SelectMenuOption) -> Result<(), ComponentValidationError> {
+    component_select_option_label(&option.label)?;
+    component_select_option_value(&option.value)?;
+
+    if let Some(description) = option.description.as_ref() {
+        component_option_description(description)?;
+    }
+
+    Ok(())
+}
+
 /// Ensure that a text input is correct.
 ///
 /// # Errors
@@ -1034,7 +1091,7 @@ fn component_select_option_value(value: impl AsRef<str>) -> Result<(), Component
 /// # Errors
 ///
 /// Returns an error of type [`SelectOptionCount`] if the provided list of
-/// [`SelectMenuOption`]s is too many for a [`SelectMenu`].
+/// [`SelectMenuOption`]s is too few or too many for a [`SelectMenu`].
 ///
 /// [`SelectMenu::options`]: twilight_model::application::component::select_menu::SelectMenu::options
 /// [`SelectMenuOption`]: twilight_model::application::component::select_menu::SelectMenuOption
@@ -1045,7 +1102,7 @@ const fn component_select_options(
 ) -> Result<(), ComponentValidationError> {
     let count = options.len();
 
-    if count > SELECT_OPTION_COUNT {
+    if count < SELECT_OPTION_COUNT_MINIMUM || count > SELECT_OPTION_COUNT {
         return Err(ComponentValidationError {
             kind: ComponentValidationErrorType::SelectOptionCount { count },
         });
@@ -1054,6 +1111,33 @@ const fn component_select_options(
     Ok(())
 }
 
+/// Validate that the values of a [`SelectMenu`]'s options are unique.
+///
+/// # Errors
+///
+/// Returns an error of type [`SelectOptionDuplicateValue`] if multiple options
+/// have the same value.
+///
+/// [`SelectOptionDuplicateValue`]: ComponentValidationErrorType::SelectOptionDuplicateValue
+fn component_select_option_values_unique(
+    options: &[SelectMenuOption],
+) -> Result<(), ComponentValidationError> {
+    for (index, option) in options.iter().enumerate() {
+        if options[..index]
+            .iter()
+            .any(|previous| previous.value == option.value)
+        {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectOptionDuplicateValue {
+                    value: option.value.clone(),
+                },
+            });
+        }
+    }
+
+    Ok(())
+}
+
 /// Validate a [`SelectMenu::placeholder`]'s length.
 ///
 /// # Errors
@@ -1433,6 +1517,73 @@ mod tests {
             .collect::<Vec<SelectMenuOption>>();
 
         assert!(component_select_options(&select_menu_options_26).is_err());
+        assert!(component_select_options(&[]).is_err());
+    }
+
+    /// Test that only text select menus require unique options, whereas other
+    /// select menus have none.
+    #[test]
+    fn select_menu_options() {
+        let option = SelectMenuOption {
+            default: false,
+            description: None,
+            emoji: None,
+            label: "label".into(),
+            value: "value".into(),
+        };
+        let select_menu = |kind, options| SelectMenu {
+            channel_types: None,
+            custom_id: "custom id".into(),
+            default_values: None,
+            disabled: false,
+            kind,
+            max_values: None,
+            min_values: None,
+            options,
+            placeholder: None,
+        };
+
+        assert!(super::select_menu(&select_menu(
+            SelectMenuType::Text,
+            Some(Vec::from([option.clone()]))
+        ))
+        .is_ok());
+        assert!(super::select_menu(&select_menu(SelectMenuType::Text, None)).is_err());
+        assert!(super::select_menu(&select_menu(SelectMenuType::Text, Some(Vec::new()))).is_err());
+        assert!(super::select_menu(&select_menu(
+            SelectMenuType::Text,
+            Some(Vec::from([option.clone(), option]))
+        ))
+        .is_err());
+
+        for kind in [
+            SelectMenuType::Channel,
+            SelectMenuType::Mentionable,
+            SelectMenuType::Role,
+            SelectMenuType::User,
+        ] {
+            assert!(super::select_menu(&select_menu(kind, None)).is_ok());
+            assert!(super::select_menu(&select_menu(kind, Some(Vec::new()))).is_ok());
+        }
+    }
+
+    #[test]
+    fn component_select_option_values_uniqueness() {
+        let option = |value: &str| SelectMenuOption {
+            default: false,
+            description: None,
+            emoji: None,
+            label: "label".into(),
+            value: value.into(),
+        };
+
+        assert!(component_select_option_values_unique(&[option("a"), option("b")]).is_ok());
+        assert!(matches!(
+            component_select_option_values_unique(&[option("a"), option("b"), option("a")])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::SelectOptionDuplicateValue { value } if value == "a"
+        ));
     }
 
     #[test]
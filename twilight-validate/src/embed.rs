@@ -4,7 +4,7 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::message::Embed;
+use twilight_model::channel::message::{embed::EmbedField, Embed};
 
 /// The maximum embed author name length in codepoints.
 pub const AUTHOR_NAME_LENGTH: usize = 256;
@@ -247,109 +247,188 @@ pub fn embed(embed: &Embed) -> Result<(), EmbedValidationError> {
     }
 
     if let Some(color) = embed.color {
-        if color > COLOR_MAXIMUM {
-            return Err(EmbedValidationError {
-                kind: EmbedValidationErrorType::ColorNotRgb { color },
-            });
-        }
+        self::color(color)?;
     }
 
     if let Some(description) = embed.description.as_ref() {
-        let chars = description.chars().count();
-
-        if chars > DESCRIPTION_LENGTH {
-            return Err(EmbedValidationError {
-                kind: EmbedValidationErrorType::DescriptionTooLarge { chars },
-            });
-        }
+        self::description(description)?;
     }
 
-    if embed.fields.len() > FIELD_COUNT {
-        return Err(EmbedValidationError {
-            kind: EmbedValidationErrorType::TooManyFields {
-                amount: embed.fields.len(),
-            },
-        });
-    }
+    field_count(embed.fields.len())?;
 
     for field in &embed.fields {
-        let name_chars = field.name.chars().count();
+        self::field(field)?;
+    }
 
-        if name_chars > FIELD_NAME_LENGTH {
-            return Err(EmbedValidationError {
-                kind: EmbedValidationErrorType::FieldNameTooLarge { chars: name_chars },
-            });
-        }
+    if let Some(footer) = embed.footer.as_ref() {
+        footer_text(&footer.text)?;
+    }
 
-        let value_chars = field.value.chars().count();
+    if let Some(author) = embed.author.as_ref() {
+        author_name(&author.name)?;
+    }
 
-        if value_chars > FIELD_VALUE_LENGTH {
-            return Err(EmbedValidationError {
-                kind: EmbedValidationErrorType::FieldValueTooLarge { chars: value_chars },
-            });
-        }
+    if let Some(title) = embed.title.as_ref() {
+        self::title(title)?;
     }
 
-    if let Some(footer) = embed.footer.as_ref() {
-        let chars = footer.text.chars().count();
+    Ok(())
+}
 
-        if chars > FOOTER_TEXT_LENGTH {
-            return Err(EmbedValidationError {
-                kind: EmbedValidationErrorType::FooterTextTooLarge { chars },
-            });
-        }
+/// Ensure an embed author's name is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`AuthorNameTooLarge`] if the name is longer than
+/// [`AUTHOR_NAME_LENGTH`].
+///
+/// [`AuthorNameTooLarge`]: EmbedValidationErrorType::AuthorNameTooLarge
+pub fn author_name(name: impl AsRef<str>) -> Result<(), EmbedValidationError> {
+    let chars = name.as_ref().chars().count();
+
+    if chars > AUTHOR_NAME_LENGTH {
+        return Err(EmbedValidationError {
+            kind: EmbedValidationErrorType::AuthorNameTooLarge { chars },
+        });
     }
 
-    if let Some(name) = embed.author.as_ref().map(|author| &author.name) {
-        let chars = name.chars().count();
+    Ok(())
+}
 
-        if chars > AUTHOR_NAME_LENGTH {
-            return Err(EmbedValidationError {
-                kind: EmbedValidationErrorType::AuthorNameTooLarge { chars },
-            });
-        }
+/// Ensure an embed's color is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`ColorNotRgb`] if the color is larger than
+/// [`COLOR_MAXIMUM`].
+///
+/// [`ColorNotRgb`]: EmbedValidationErrorType::ColorNotRgb
+pub const fn color(color: u32) -> Result<(), EmbedValidationError> {
+    if color > COLOR_MAXIMUM {
+        return Err(EmbedValidationError {
+            kind: EmbedValidationErrorType::ColorNotRgb { color },
+        });
     }
 
-    if let Some(title) = embed.title.as_ref() {
-        let chars = title.chars().count();
+    Ok(())
+}
 
-        if chars > TITLE_LENGTH {
-            return Err(EmbedValidationError {
-                kind: EmbedValidationErrorType::TitleTooLarge { chars },
-            });
-        }
+/// Ensure an embed's description is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`DescriptionTooLarge`] if the description is
+/// longer than [`DESCRIPTION_LENGTH`].
+///
+/// [`DescriptionTooLarge`]: EmbedValidationErrorType::DescriptionTooLarge
+pub fn description(description: impl AsRef<str>) -> Result<(), EmbedValidationError> {
+    let chars = description.as_ref().chars().count();
+
+    if chars > DESCRIPTION_LENGTH {
+        return Err(EmbedValidationError {
+            kind: EmbedValidationErrorType::DescriptionTooLarge { chars },
+        });
     }
 
     Ok(())
 }
 
-/// Calculate the total character count of an embed.
-#[must_use]
-pub fn chars(embed: &Embed) -> usize {
-    let mut chars = 0;
+/// Ensure an embed field is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`FieldNameTooLarge`] if the name is longer than
+/// [`FIELD_NAME_LENGTH`].
+///
+/// Returns an error of type [`FieldValueTooLarge`] if the value is longer
+/// than [`FIELD_VALUE_LENGTH`].
+///
+/// [`FieldNameTooLarge`]: EmbedValidationErrorType::FieldNameTooLarge
+/// [`FieldValueTooLarge`]: EmbedValidationErrorType::FieldValueTooLarge
+pub fn field(field: &EmbedField) -> Result<(), EmbedValidationError> {
+    let chars = field.name.chars().count();
 
-    if let Some(author) = &embed.author {
-        chars += author.name.len();
+    if chars > FIELD_NAME_LENGTH {
+        return Err(EmbedValidationError {
+            kind: EmbedValidationErrorType::FieldNameTooLarge { chars },
+        });
     }
 
-    if let Some(description) = &embed.description {
-        chars += description.len();
+    let chars = field.value.chars().count();
+
+    if chars > FIELD_VALUE_LENGTH {
+        return Err(EmbedValidationError {
+            kind: EmbedValidationErrorType::FieldValueTooLarge { chars },
+        });
     }
 
-    if let Some(footer) = &embed.footer {
-        chars += footer.text.len();
+    Ok(())
+}
+
+/// Ensure the number of fields in an embed is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`TooManyFields`] if there are more than
+/// [`FIELD_COUNT`] fields.
+///
+/// [`TooManyFields`]: EmbedValidationErrorType::TooManyFields
+pub const fn field_count(amount: usize) -> Result<(), EmbedValidationError> {
+    if amount > FIELD_COUNT {
+        return Err(EmbedValidationError {
+            kind: EmbedValidationErrorType::TooManyFields { amount },
+        });
     }
 
-    for field in &embed.fields {
-        chars += field.name.len();
-        chars += field.value.len();
+    Ok(())
+}
+
+/// Ensure an embed footer's text is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`FooterTextTooLarge`] if the text is longer than
+/// [`FOOTER_TEXT_LENGTH`].
+///
+/// [`FooterTextTooLarge`]: EmbedValidationErrorType::FooterTextTooLarge
+pub fn footer_text(text: impl AsRef<str>) -> Result<(), EmbedValidationError> {
+    let chars = text.as_ref().chars().count();
+
+    if chars > FOOTER_TEXT_LENGTH {
+        return Err(EmbedValidationError {
+            kind: EmbedValidationErrorType::FooterTextTooLarge { chars },
+        });
     }
 
-    if let Some(title) = &embed.title {
-        chars += title.len();
+    Ok(())
+}
+
+/// Ensure an embed's title is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`TitleTooLarge`] if the title is longer than
+/// [`TITLE_LENGTH`].
+///
+/// [`TitleTooLarge`]: EmbedValidationErrorType::TitleTooLarge
+pub fn title(title: impl AsRef<str>) -> Result<(), EmbedValidationError> {
+    let chars = title.as_ref().chars().count();
+
+    if chars > TITLE_LENGTH {
+        return Err(EmbedValidationError {
+            kind: EmbedValidationErrorType::TitleTooLarge { chars },
+        });
     }
 
-    chars
+    Ok(())
+}
+
+/// Calculate the total character count of an embed.
+///
+/// Equivalent to [`Embed::total_length`].
+#[must_use]
+pub fn chars(embed: &Embed) -> usize {
+    embed.total_length()
 }
 
 #[cfg(test)]
@@ -572,4 +651,39 @@ mod tests {
             EmbedValidationErrorType::EmbedTooLarge { chars: 6304 }
         ));
     }
+
+    #[test]
+    fn sections() {
+        assert!(super::author_name("a".repeat(256)).is_ok());
+        assert!(super::author_name("a".repeat(257)).is_err());
+        assert!(super::color(0xff_ff_ff).is_ok());
+        assert!(super::color(0x1_00_00_00).is_err());
+        assert!(super::description("a".repeat(4096)).is_ok());
+        assert!(super::description("a".repeat(4097)).is_err());
+        assert!(super::field_count(25).is_ok());
+        assert!(matches!(
+            super::field_count(26).unwrap_err().kind(),
+            EmbedValidationErrorType::TooManyFields { amount: 26 }
+        ));
+        assert!(super::footer_text("a".repeat(2048)).is_ok());
+        assert!(super::footer_text("a".repeat(2049)).is_err());
+        assert!(super::title("a".repeat(256)).is_ok());
+        assert!(super::title("a".repeat(257)).is_err());
+
+        // Limits are in codepoints rather than bytes.
+        assert!(super::title("🦀".repeat(256)).is_ok());
+
+        let mut field = EmbedField {
+            inline: false,
+            name: "a".repeat(256),
+            value: "b".repeat(1024),
+        };
+        assert!(super::field(&field).is_ok());
+
+        field.value.push('b');
+        assert!(matches!(
+            super::field(&field).unwrap_err().kind(),
+            EmbedValidationErrorType::FieldValueTooLarge { chars: 1025 }
+        ));
+    }
 }
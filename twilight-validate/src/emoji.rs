@@ -0,0 +1,171 @@
+//! Constants, error types, and functions for validating [`Emoji`] fields.
+//!
+//! [`Emoji`]: twilight_model::guild::Emoji
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Maximum length of an emoji name.
+pub const EMOJI_NAME_LENGTH_MAX: usize = 32;
+
+/// Minimum length of an emoji name.
+pub const EMOJI_NAME_LENGTH_MIN: usize = 2;
+
+/// Error created if validation of an emoji field fails.
+#[derive(Debug)]
+pub struct EmojiValidationError {
+    /// Type of error that occurred.
+    kind: EmojiValidationErrorType,
+}
+
+impl EmojiValidationError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &EmojiValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        EmojiValidationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for EmojiValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            EmojiValidationErrorType::NameCharacterInvalid { character } => {
+                f.write_str(
+                    "emoji name must only contain alphanumeric characters and underscores, found `",
+                )?;
+                Display::fmt(character, f)?;
+
+                f.write_str("`")
+            }
+            EmojiValidationErrorType::NameLengthInvalid { chars } => {
+                f.write_str("emoji name is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but must be between ")?;
+                Display::fmt(&EMOJI_NAME_LENGTH_MIN, f)?;
+                f.write_str(" and ")?;
+
+                Display::fmt(&EMOJI_NAME_LENGTH_MAX, f)
+            }
+        }
+    }
+}
+
+impl Error for EmojiValidationError {}
+
+/// Type of [`EmojiValidationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EmojiValidationErrorType {
+    /// Emoji name contains a character other than an ASCII alphanumeric
+    /// character or an underscore.
+    NameCharacterInvalid {
+        /// Invalid character.
+        character: char,
+    },
+    /// Emoji name is too short or too long.
+    NameLengthInvalid {
+        /// Number of characters in the name.
+        chars: usize,
+    },
+}
+
+/// Ensure that an emoji's name is correct.
+///
+/// The length must be at least [`EMOJI_NAME_LENGTH_MIN`] and at most
+/// [`EMOJI_NAME_LENGTH_MAX`], and it may only contain ASCII alphanumeric
+/// characters and underscores. This is based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`NameLengthInvalid`] if the length is invalid.
+///
+/// Returns an error of type [`NameCharacterInvalid`] if the name contains an
+/// invalid character.
+///
+/// [`NameCharacterInvalid`]: EmojiValidationErrorType::NameCharacterInvalid
+/// [`NameLengthInvalid`]: EmojiValidationErrorType::NameLengthInvalid
+/// [this documentation entry]: https://discord.com/developers/docs/resources/emoji#create-guild-emoji
+pub fn name(value: impl AsRef<str>) -> Result<(), EmojiValidationError> {
+    let value = value.as_ref();
+    let chars = value.chars().count();
+
+    if !(EMOJI_NAME_LENGTH_MIN..=EMOJI_NAME_LENGTH_MAX).contains(&chars) {
+        return Err(EmojiValidationError {
+            kind: EmojiValidationErrorType::NameLengthInvalid { chars },
+        });
+    }
+
+    if let Some(character) = value
+        .chars()
+        .find(|character| !character.is_ascii_alphanumeric() && *character != '_')
+    {
+        return Err(EmojiValidationError {
+            kind: EmojiValidationErrorType::NameCharacterInvalid { character },
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(EmojiValidationError: Debug, Error, Send, Sync);
+    assert_impl_all!(EmojiValidationErrorType: Debug, Send, Sync);
+
+    #[test]
+    fn name_length() {
+        assert!(name("aa").is_ok());
+        assert!(name("a".repeat(32)).is_ok());
+
+        assert!(matches!(
+            name("a").unwrap_err().kind(),
+            EmojiValidationErrorType::NameLengthInvalid { chars: 1 }
+        ));
+        assert!(matches!(
+            name("a".repeat(33)).unwrap_err().kind(),
+            EmojiValidationErrorType::NameLengthInvalid { chars: 33 }
+        ));
+    }
+
+    #[test]
+    fn name_characters() {
+        assert!(name("twilight_Sparkle_2").is_ok());
+
+        assert!(matches!(
+            name("twilight sparkle").unwrap_err().kind(),
+            EmojiValidationErrorType::NameCharacterInvalid { character: ' ' }
+        ));
+        assert!(matches!(
+            name("twilight-sparkle").unwrap_err().kind(),
+            EmojiValidationErrorType::NameCharacterInvalid { character: '-' }
+        ));
+        assert!(matches!(
+            name("étoile").unwrap_err().kind(),
+            EmojiValidationErrorType::NameCharacterInvalid { character: 'é' }
+        ));
+    }
+}
@@ -12,11 +12,17 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    channel::message::{Component, Embed},
+    channel::message::{AllowedMentions, Component, Embed, MentionType, MessageReference},
     http::attachment::Attachment,
-    id::{marker::StickerMarker, Id},
+    id::{
+        marker::{GuildMarker, StickerMarker},
+        Id,
+    },
 };
 
+/// Maximum number of roles or users that allowed mentions may list.
+pub const ALLOWED_MENTIONS_IDS_MAX: usize = 100;
+
 /// Maximum length of an attachment's description.
 pub const ATTACHMENT_DESCIPTION_LENGTH_MAX: usize = 1024;
 
@@ -87,6 +93,27 @@ impl MessageValidationError {
 impl Display for MessageValidationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            MessageValidationErrorType::AllowedMentionsConflict { kind } => {
+                f.write_str("allowed mentions parse all ")?;
+                f.write_str(mention_type_name(kind))?;
+
+                f.write_str(" but also list specific ones")
+            }
+            MessageValidationErrorType::AllowedMentionsTooMany { kind, len } => {
+                f.write_str("allowed mentions list ")?;
+                Display::fmt(len, f)?;
+                f.write_str(" ")?;
+                f.write_str(mention_type_name(kind))?;
+                f.write_str(", but the max is ")?;
+
+                Display::fmt(&ALLOWED_MENTIONS_IDS_MAX, f)
+            }
+            MessageValidationErrorType::AttachmentContentType { content_type } => {
+                f.write_str("attachment content type `")?;
+                Display::fmt(content_type, f)?;
+
+                f.write_str("` is not a valid media type")
+            }
             MessageValidationErrorType::AttachmentDescriptionTooLarge { chars } => {
                 f.write_str("the attachment description is ")?;
                 Display::fmt(chars, f)?;
@@ -117,6 +144,15 @@ impl Display for MessageValidationError {
 
                 f.write_str(" is invalid")
             }
+            MessageValidationErrorType::ReferenceInvalid => {
+                f.write_str("message reference sets whether to fail if the message doesn't exist, but references no message")
+            }
+            MessageValidationErrorType::StickerUnavailable { id } => {
+                f.write_str("sticker ")?;
+                Display::fmt(id, f)?;
+
+                f.write_str(" belongs to another guild")
+            }
             MessageValidationErrorType::StickersInvalid { len } => {
                 f.write_str("amount of stickers provided is ")?;
                 Display::fmt(len, f)?;
@@ -142,7 +178,26 @@ impl Error for MessageValidationError {}
 
 /// Type of [`MessageValidationError`] that occurred.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum MessageValidationErrorType {
+    /// Allowed mentions parse all roles or users while also listing specific
+    /// ones.
+    AllowedMentionsConflict {
+        /// Mention type that is both parsed and listed.
+        kind: MentionType,
+    },
+    /// Allowed mentions list too many roles or users.
+    AllowedMentionsTooMany {
+        /// Mention type with too many IDs.
+        kind: MentionType,
+        /// Number of IDs provided.
+        len: usize,
+    },
+    /// Attachment content type is not a valid media type.
+    AttachmentContentType {
+        /// Invalid content type.
+        content_type: String,
+    },
     /// Attachment filename is not valid.
     AttachmentFilename {
         /// Invalid filename.
@@ -174,6 +229,15 @@ pub enum MessageValidationErrorType {
         /// Additional details about the validation failure type.
         kind: EmbedValidationErrorType,
     },
+    /// Message reference sets [`fail_if_not_exists`] without a message ID.
+    ///
+    /// [`fail_if_not_exists`]: MessageReference::fail_if_not_exists
+    ReferenceInvalid,
+    /// Sticker belongs to another guild and can't be sent.
+    StickerUnavailable {
+        /// ID of the sticker.
+        id: Id<StickerMarker>,
+    },
     /// Amount of stickers provided is invalid.
     StickersInvalid {
         /// Invalid length.
@@ -187,21 +251,71 @@ pub enum MessageValidationErrorType {
     WebhookUsername,
 }
 
+/// Ensure allowed mentions are correct.
+///
+/// Discord rejects allowed mentions that parse all roles or users while also
+/// listing specific ones, and each list may have at most
+/// [`ALLOWED_MENTIONS_IDS_MAX`] IDs.
+///
+/// # Errors
+///
+/// Returns an error of type [`AllowedMentionsConflict`] if [`parse`] contains
+/// [`MentionType::Roles`] or [`MentionType::Users`] while the corresponding
+/// list is not empty.
+///
+/// Returns an error of type [`AllowedMentionsTooMany`] if a list is too long.
+///
+/// [`AllowedMentionsConflict`]: MessageValidationErrorType::AllowedMentionsConflict
+/// [`AllowedMentionsTooMany`]: MessageValidationErrorType::AllowedMentionsTooMany
+/// [`parse`]: AllowedMentions::parse
+pub fn allowed_mentions(allowed_mentions: &AllowedMentions) -> Result<(), MessageValidationError> {
+    let lists = [
+        (MentionType::Roles, allowed_mentions.roles.len()),
+        (MentionType::Users, allowed_mentions.users.len()),
+    ];
+
+    for (kind, len) in lists {
+        if len > 0 && allowed_mentions.parse.contains(&kind) {
+            return Err(MessageValidationError {
+                kind: MessageValidationErrorType::AllowedMentionsConflict { kind },
+                source: None,
+            });
+        }
+
+        if len > ALLOWED_MENTIONS_IDS_MAX {
+            return Err(MessageValidationError {
+                kind: MessageValidationErrorType::AllowedMentionsTooMany { kind, len },
+                source: None,
+            });
+        }
+    }
+
+    Ok(())
+}
+
 /// Ensure an attachment is correct.
 ///
 /// # Errors
 ///
+/// Returns an error of type [`AttachmentContentType`] if the attachment's
+/// content type is invalid.
+///
 /// Returns an error of type [`AttachmentDescriptionTooLarge`] if
 /// the attachments's description is too large.
 ///
 /// Returns an error of type [`AttachmentFilename`] if the
 /// filename is invalid.
 ///
+/// [`AttachmentContentType`]: MessageValidationErrorType::AttachmentContentType
 /// [`AttachmentDescriptionTooLarge`]: MessageValidationErrorType::AttachmentDescriptionTooLarge
 /// [`AttachmentFilename`]: MessageValidationErrorType::AttachmentFilename
 pub fn attachment(attachment: &Attachment) -> Result<(), MessageValidationError> {
     attachment_filename(&attachment.filename)?;
 
+    if let Some(content_type) = &attachment.content_type {
+        attachment_content_type(content_type)?;
+    }
+
     if let Some(description) = &attachment.description {
         attachment_description(description)?;
     }
@@ -209,6 +323,51 @@ pub fn attachment(attachment: &Attachment) -> Result<(), MessageValidationError>
     Ok(())
 }
 
+/// Ensure an attachment's content type is a valid media type, such as
+/// `image/png` or `text/plain; charset=utf-8`.
+///
+/// The type and subtype must be tokens of printable ASCII characters other
+/// than separators, and parameters may not contain control characters.
+///
+/// # Errors
+///
+/// Returns an error of type [`AttachmentContentType`] if the content type is
+/// invalid.
+///
+/// [`AttachmentContentType`]: MessageValidationErrorType::AttachmentContentType
+pub fn attachment_content_type(
+    content_type: impl AsRef<str>,
+) -> Result<(), MessageValidationError> {
+    /// Whether a type or subtype is a non-empty token.
+    fn is_token(value: &str) -> bool {
+        !value.is_empty()
+            && value
+                .bytes()
+                .all(|byte| byte.is_ascii_graphic() && !b"()<>@,;:\\\"/[]?=".contains(&byte))
+    }
+
+    let content_type = content_type.as_ref();
+    let (essence, parameters) = content_type.split_once(';').unwrap_or((content_type, ""));
+
+    let valid = essence
+        .split_once('/')
+        .is_some_and(|(kind, subtype)| is_token(kind) && is_token(subtype))
+        && parameters
+            .bytes()
+            .all(|byte| byte == b' ' || byte == b'\t' || byte.is_ascii_graphic());
+
+    if valid {
+        Ok(())
+    } else {
+        Err(MessageValidationError {
+            kind: MessageValidationErrorType::AttachmentContentType {
+                content_type: content_type.to_owned(),
+            },
+            source: None,
+        })
+    }
+}
+
 /// Ensure an attachment's description is correct.
 ///
 /// # Errors
@@ -357,6 +516,30 @@ pub fn embeds(embeds: &[Embed]) -> Result<(), MessageValidationError> {
     }
 }
 
+/// Ensure a message reference is correct.
+///
+/// [`fail_if_not_exists`] only applies to a referenced message, so it may
+/// only be set if [`message_id`] is present.
+///
+/// # Errors
+///
+/// Returns an error of type [`ReferenceInvalid`] if `fail_if_not_exists` is
+/// set without a message ID.
+///
+/// [`ReferenceInvalid`]: MessageValidationErrorType::ReferenceInvalid
+/// [`fail_if_not_exists`]: MessageReference::fail_if_not_exists
+/// [`message_id`]: MessageReference::message_id
+pub const fn message_reference(reference: &MessageReference) -> Result<(), MessageValidationError> {
+    if reference.fail_if_not_exists.is_some() && reference.message_id.is_none() {
+        Err(MessageValidationError {
+            kind: MessageValidationErrorType::ReferenceInvalid,
+            source: None,
+        })
+    } else {
+        Ok(())
+    }
+}
+
 /// Ensure that the amount of stickers in a message is correct.
 ///
 /// There must be at most [`STICKER_MAX`] stickers. This is based on [this
@@ -381,10 +564,99 @@ pub fn sticker_ids(sticker_ids: &[Id<StickerMarker>]) -> Result<(), MessageValid
     }
 }
 
+/// Ensure that a sticker may be sent in a guild.
+///
+/// Standard stickers don't belong to a guild and may be sent anywhere, while
+/// guild stickers may only be sent within the guild they belong to. Pass the
+/// sticker's guild ID, such as the one of a cached sticker, to check the
+/// sticker before sending it.
+///
+/// # Errors
+///
+/// Returns an error of type [`StickerUnavailable`] if the sticker belongs to a
+/// guild other than `guild_id`.
+///
+/// [`StickerUnavailable`]: MessageValidationErrorType::StickerUnavailable
+pub fn sticker_guild(
+    guild_id: Option<Id<GuildMarker>>,
+    sticker_id: Id<StickerMarker>,
+    sticker_guild_id: Option<Id<GuildMarker>>,
+) -> Result<(), MessageValidationError> {
+    if sticker_guild_id.is_none() || sticker_guild_id == guild_id {
+        Ok(())
+    } else {
+        Err(MessageValidationError {
+            kind: MessageValidationErrorType::StickerUnavailable { id: sticker_id },
+            source: None,
+        })
+    }
+}
+
+/// Name of a mention type to display in errors.
+const fn mention_type_name(kind: &MentionType) -> &'static str {
+    match kind {
+        MentionType::Everyone => "everyone",
+        MentionType::Roles => "roles",
+        MentionType::Users => "users",
+        _ => "mentions",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn allowed_mentions_validation() {
+        let mut value = AllowedMentions {
+            parse: Vec::from([MentionType::Everyone, MentionType::Roles]),
+            users: Vec::from([Id::new(1)]),
+            ..AllowedMentions::default()
+        };
+        assert!(allowed_mentions(&value).is_ok());
+
+        value.parse.push(MentionType::Users);
+        assert!(matches!(
+            allowed_mentions(&value).unwrap_err().kind(),
+            MessageValidationErrorType::AllowedMentionsConflict {
+                kind: MentionType::Users
+            }
+        ));
+
+        value.parse.clear();
+        value.roles = (1..=100).map(Id::new).collect();
+        assert!(allowed_mentions(&value).is_ok());
+
+        value.roles.push(Id::new(101));
+        assert!(matches!(
+            allowed_mentions(&value).unwrap_err().kind(),
+            MessageValidationErrorType::AllowedMentionsTooMany {
+                kind: MentionType::Roles,
+                len: 101
+            }
+        ));
+    }
+
+    #[test]
+    fn attachment_content_type_media_type() {
+        assert!(attachment_content_type("image/png").is_ok());
+        assert!(attachment_content_type("application/vnd.api+json").is_ok());
+        assert!(attachment_content_type("text/plain; charset=utf-8").is_ok());
+
+        assert!(attachment_content_type("").is_err());
+        assert!(attachment_content_type("image").is_err());
+        assert!(attachment_content_type("image/").is_err());
+        assert!(attachment_content_type("image /png").is_err());
+        assert!(matches!(
+            attachment_content_type("image/png\r\nx-injected: 1")
+                .unwrap_err()
+                .kind(),
+            MessageValidationErrorType::AttachmentContentType { content_type }
+                if content_type == "image/png\r\nx-injected: 1"
+        ));
+        assert!(attachment_content_type("text/plain; charset=utf-8\n").is_err());
+    }
+
     #[test]
     fn attachment_description_limit() {
         assert!(attachment_description("").is_ok());
@@ -415,4 +687,41 @@ mod tests {
 
         assert!(content("a".repeat(2001)).is_err());
     }
+
+    #[test]
+    fn message_reference_fail_if_not_exists() {
+        let mut reference = MessageReference::reply(Id::new(1), Id::new(2));
+        assert!(message_reference(&reference).is_ok());
+
+        reference.fail_if_not_exists = Some(false);
+        assert!(message_reference(&reference).is_ok());
+
+        reference.message_id = None;
+        assert!(matches!(
+            message_reference(&reference).unwrap_err().kind(),
+            MessageValidationErrorType::ReferenceInvalid
+        ));
+
+        reference.fail_if_not_exists = None;
+        assert!(message_reference(&reference).is_ok());
+    }
+
+    #[test]
+    fn sticker_guild_ownership() {
+        let guild_id = Id::new(1);
+        let other_guild_id = Id::new(2);
+        let sticker_id = Id::new(3);
+
+        assert!(sticker_guild(Some(guild_id), sticker_id, None).is_ok());
+        assert!(sticker_guild(None, sticker_id, None).is_ok());
+        assert!(sticker_guild(Some(guild_id), sticker_id, Some(guild_id)).is_ok());
+
+        assert!(matches!(
+            sticker_guild(Some(guild_id), sticker_id, Some(other_guild_id))
+                .unwrap_err()
+                .kind(),
+            MessageValidationErrorType::StickerUnavailable { id } if *id == sticker_id
+        ));
+        assert!(sticker_guild(None, sticker_id, Some(guild_id)).is_err());
+    }
 }
@@ -7,6 +7,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     time::{SystemTime, UNIX_EPOCH},
 };
+use twilight_model::guild::GuildFeature;
 use twilight_model::id::marker::{ChannelMarker, RoleMarker};
 use twilight_model::id::Id;
 use twilight_model::util::Timestamp;
@@ -167,6 +168,12 @@ pub const USERNAME_LIMIT_MAX: usize = 32;
 /// Minimum length of a username.
 pub const USERNAME_LIMIT_MIN: usize = 2;
 
+/// Maximum length of a guild vanity URL code.
+pub const VANITY_URL_CODE_LENGTH_MAX: usize = 32;
+
+/// Minimum length of a guild vanity URL code.
+pub const VANITY_URL_CODE_LENGTH_MIN: usize = 2;
+
 /// Maximum length of a webhook username.
 pub const WEBHOOK_USERNAME_LIMIT_MAX: usize = 80;
 
@@ -179,7 +186,7 @@ const USERNAME_INVALID_SUBSTRINGS: [&str; 5] = ["@", "#", ":", "```", "discord"]
 /// Forbidden usernames.
 const USERNAME_INVALID_STRINGS: [&str; 2] = ["everyone", "here"];
 
-/// Forbidden webhook usernames.
+/// Forbidden substrings in webhook usernames, in lowercase.
 const WEBHOOK_INVALID_STRINGS: [&str; 1] = ["clyde"];
 
 /// A field is not valid.
@@ -495,6 +502,26 @@ impl Display for ValidationError {
 
                 Display::fmt(&TEMPLATE_NAME_LENGTH_MAX, f)
             }
+            ValidationErrorType::VanityUrlCode { len } => {
+                f.write_str("provided vanity url code length is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(", but it must be at least ")?;
+                Display::fmt(&VANITY_URL_CODE_LENGTH_MIN, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&VANITY_URL_CODE_LENGTH_MAX, f)
+            }
+            ValidationErrorType::VanityUrlCodeCharacter { character } => {
+                f.write_str(
+                    "vanity url code must only contain alphanumeric characters and dashes, found `",
+                )?;
+                Display::fmt(character, f)?;
+
+                f.write_str("`")
+            }
+            ValidationErrorType::VanityUrlFeature => {
+                f.write_str("guild does not have the vanity url feature")
+            }
             ValidationErrorType::Username { len, substring }
             | ValidationErrorType::WebhookUsername { len, substring } => {
                 f.write_str("provided username")?;
@@ -527,6 +554,7 @@ impl Error for ValidationError {}
 
 /// Type of [`ValidationError`] that occurred.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ValidationErrorType {
     /// Provided audit reason was too large.
     AuditReason {
@@ -718,6 +746,18 @@ pub enum ValidationErrorType {
         /// Invalid substring.
         substring: Option<&'static str>,
     },
+    /// Provided vanity URL code length was invalid.
+    VanityUrlCode {
+        /// Invalid length.
+        len: usize,
+    },
+    /// Provided vanity URL code contained an invalid character.
+    VanityUrlCodeCharacter {
+        /// Invalid character.
+        character: char,
+    },
+    /// Guild does not have the [`GuildFeature::VanityUrl`] feature.
+    VanityUrlFeature,
     /// Provided webhook username was invalid.
     WebhookUsername {
         /// Invalid length.
@@ -1636,15 +1676,80 @@ pub fn username(value: impl AsRef<str>) -> Result<(), ValidationError> {
     }
 }
 
+/// Ensure that a guild vanity URL code is correct.
+///
+/// The length must be at least [`VANITY_URL_CODE_LENGTH_MIN`] and at most
+/// [`VANITY_URL_CODE_LENGTH_MAX`]. It may only contain ASCII alphanumeric
+/// characters and dashes.
+///
+/// # Errors
+///
+/// Returns an error of type [`VanityUrlCode`] if the length is invalid.
+///
+/// Returns an error of type [`VanityUrlCodeCharacter`] if the code contains an
+/// invalid character.
+///
+/// [`VanityUrlCode`]: ValidationErrorType::VanityUrlCode
+/// [`VanityUrlCodeCharacter`]: ValidationErrorType::VanityUrlCodeCharacter
+pub fn vanity_url_code(code: impl AsRef<str>) -> Result<(), ValidationError> {
+    let code = code.as_ref();
+    let len = code.chars().count();
+
+    if !(VANITY_URL_CODE_LENGTH_MIN..=VANITY_URL_CODE_LENGTH_MAX).contains(&len) {
+        return Err(ValidationError {
+            kind: ValidationErrorType::VanityUrlCode { len },
+        });
+    }
+
+    if let Some(character) = code
+        .chars()
+        .find(|character| !character.is_ascii_alphanumeric() && *character != '-')
+    {
+        return Err(ValidationError {
+            kind: ValidationErrorType::VanityUrlCodeCharacter { character },
+        });
+    }
+
+    Ok(())
+}
+
+/// Ensure that a guild may have a vanity URL.
+///
+/// The guild's features must include [`GuildFeature::VanityUrl`]. Accepts
+/// anything iterating over a guild's features, such as a guild model's
+/// `features` field or a cached guild's features.
+///
+/// # Errors
+///
+/// Returns an error of type [`VanityUrlFeature`] if the guild does not have the
+/// feature.
+///
+/// [`VanityUrlFeature`]: ValidationErrorType::VanityUrlFeature
+pub fn vanity_url_feature<'a>(
+    features: impl IntoIterator<Item = &'a GuildFeature>,
+) -> Result<(), ValidationError> {
+    if features
+        .into_iter()
+        .any(|feature| *feature == GuildFeature::VanityUrl)
+    {
+        Ok(())
+    } else {
+        Err(ValidationError {
+            kind: ValidationErrorType::VanityUrlFeature,
+        })
+    }
+}
+
 /// Ensure that a webhook is correct.
 ///
 /// The length must be at least [`WEBHOOK_USERNAME_LIMIT_MIN`] and at most
-/// [`WEBHOOK_USERNAME_LIMIT_MAX`]. It must also be free of certain substrings.
-/// This is based on [this documentation entry].
+/// [`WEBHOOK_USERNAME_LIMIT_MAX`]. It must also be free of certain substrings,
+/// such as `clyde` in any case. This is based on [this documentation entry].
 ///
 /// # Errors
 ///
-/// Returns an error of type [`WebhookUsername`] if the length is invalid.
+/// Returns an error of type [`WebhookUsername`] if the length is invalid or
+/// the username contains a forbidden substring.
 ///
 /// [`WebhookUsername`]: ValidationErrorType::WebhookUsername
 /// [this documentation entry]: https://discord.com/developers/docs/resources/webhook#create-webhook
@@ -1655,9 +1760,10 @@ pub fn webhook_username(value: impl AsRef<str>) -> Result<(), ValidationError> {
     let range = WEBHOOK_USERNAME_LIMIT_MIN..=WEBHOOK_USERNAME_LIMIT_MAX;
     let invalid_len = (!range.contains(&len)).then_some(len);
 
+    let lowercase = value.to_lowercase();
     let invalid_substring = WEBHOOK_INVALID_STRINGS
         .into_iter()
-        .find(|invalid_string| value == *invalid_string);
+        .find(|invalid_string| lowercase.contains(invalid_string));
 
     if invalid_len.is_none() && invalid_substring.is_none() {
         Ok(())
@@ -2032,6 +2138,37 @@ mod tests {
         assert!(username("here").is_err());
     }
 
+    #[test]
+    fn vanity_url_code_rules() {
+        assert!(vanity_url_code("ab").is_ok());
+        assert!(vanity_url_code("twilight-rs").is_ok());
+        assert!(vanity_url_code("a".repeat(32)).is_ok());
+
+        assert!(matches!(
+            vanity_url_code("a").unwrap_err().kind(),
+            ValidationErrorType::VanityUrlCode { len: 1 }
+        ));
+        assert!(vanity_url_code("a".repeat(33)).is_err());
+        assert!(matches!(
+            vanity_url_code("twilight_rs").unwrap_err().kind(),
+            ValidationErrorType::VanityUrlCodeCharacter { character: '_' }
+        ));
+        assert!(vanity_url_code("twilight rs").is_err());
+    }
+
+    #[test]
+    fn vanity_url_feature_present() {
+        assert!(vanity_url_feature(&[GuildFeature::Community, GuildFeature::VanityUrl]).is_ok());
+
+        assert!(matches!(
+            vanity_url_feature(&[GuildFeature::Community])
+                .unwrap_err()
+                .kind(),
+            ValidationErrorType::VanityUrlFeature
+        ));
+        assert!(vanity_url_feature(&[]).is_err());
+    }
+
     #[test]
     fn webhook_username_length() {
         assert!(webhook_username("aa").is_ok());
@@ -2041,5 +2178,8 @@ mod tests {
         assert!(webhook_username("a".repeat(81)).is_err());
 
         assert!(webhook_username("clyde").is_err());
+        assert!(webhook_username("Not Clyde").is_err());
+        assert!(webhook_username("CLYDE bot").is_err());
+        assert!(webhook_username("clay").is_ok());
     }
 }